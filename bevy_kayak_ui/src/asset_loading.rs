@@ -0,0 +1,79 @@
+use bevy::{
+    asset::{Asset, AssetServer, Handle, HandleUntyped, LoadState},
+    prelude::Res,
+};
+use kayak_core::{bind, Binding, Bound, MutableBound};
+
+/// Tracks how many of a set of assets have finished loading, e.g. for a loading screen
+///
+/// Add the handles to wait for with [AssetLoadingProgress::track]. Assets that failed to load
+/// count as finished, so the progress always reaches 1.
+pub struct AssetLoadingProgress {
+    handles: Vec<HandleUntyped>,
+    progress: Binding<f32>,
+}
+
+impl Default for AssetLoadingProgress {
+    fn default() -> Self {
+        Self {
+            handles: Vec::new(),
+            progress: bind(1.0),
+        }
+    }
+}
+
+impl AssetLoadingProgress {
+    /// Waits for the given asset to load
+    pub fn track<T: Asset>(&mut self, handle: &Handle<T>) {
+        self.track_untyped(handle.clone_untyped());
+    }
+
+    /// Waits for the given asset to load
+    pub fn track_untyped(&mut self, handle: HandleUntyped) {
+        self.handles.push(handle);
+    }
+
+    /// Stops tracking all assets, resetting the progress to 1
+    pub fn clear(&mut self) {
+        self.handles.clear();
+        self.progress.set(1.0);
+    }
+
+    /// The portion of the tracked assets that have finished loading, from 0 to 1
+    ///
+    /// Widgets can bind to this to re-render as assets load.
+    pub fn progress(&self) -> Binding<f32> {
+        self.progress.clone()
+    }
+
+    /// Returns true if all of the tracked assets have finished loading
+    pub fn is_done(&self) -> bool {
+        self.progress.get() >= 1.0
+    }
+}
+
+pub(crate) fn update_asset_loading_progress(
+    asset_server: Res<AssetServer>,
+    loading_progress: Res<AssetLoadingProgress>,
+) {
+    let total = loading_progress.handles.len();
+    let finished = loading_progress
+        .handles
+        .iter()
+        .filter(|handle| {
+            matches!(
+                asset_server.get_load_state(handle.id),
+                LoadState::Loaded | LoadState::Failed
+            )
+        })
+        .count();
+    let progress = if total == 0 {
+        1.0
+    } else {
+        finished as f32 / total as f32
+    };
+
+    if loading_progress.progress.get() != progress {
+        loading_progress.progress.set(progress);
+    }
+}
@@ -0,0 +1,54 @@
+use bevy::{
+    math::{Rect, Size},
+    prelude::{Entity, Query, Res, Transform},
+    ui::{PositionType, Style, Val},
+};
+use kayak_core::{Binding, Bound};
+
+use crate::{BevyContext, WindowSize};
+
+/// Marks a widget as reserving layout space for the given (non-Kayak) entity
+///
+/// This is attached as widget metadata by the `BevyEmbed` widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmbeddedEntity(pub Entity);
+
+/// Moves embedded entities into the layout of the widgets that reserve space for them
+pub(crate) fn position_embedded_entities(
+    bevy_context: Res<BevyContext>,
+    window_size: Res<Binding<WindowSize>>,
+    mut nodes: Query<(Option<&mut Style>, Option<&mut Transform>)>,
+) {
+    let layouts = if let Ok(context) = bevy_context.kayak_context.read() {
+        context
+            .find_all(|context, index| context.get_metadata::<EmbeddedEntity>(index).is_some())
+            .into_iter()
+            .filter_map(|index| {
+                let EmbeddedEntity(entity) = context.get_metadata(index)?;
+                let layout = *context.widget_manager.get_layout(&index)?;
+                Some((entity, layout))
+            })
+            .collect::<Vec<_>>()
+    } else {
+        return;
+    };
+
+    let WindowSize(window_width, window_height) = window_size.get();
+    for (entity, layout) in layouts {
+        if let Ok((style, transform)) = nodes.get_mut(entity) {
+            if let Some(mut style) = style {
+                // bevy_ui's vertical axis points up, while Kayak's points down
+                style.position_type = PositionType::Absolute;
+                style.position = Rect {
+                    left: Val::Px(layout.posx),
+                    top: Val::Px(window_height - layout.posy - layout.height),
+                    ..Default::default()
+                };
+                style.size = Size::new(Val::Px(layout.width), Val::Px(layout.height));
+            } else if let Some(mut transform) = transform {
+                transform.translation.x = layout.posx + layout.width / 2.0 - window_width / 2.0;
+                transform.translation.y = window_height / 2.0 - layout.posy - layout.height / 2.0;
+            }
+        }
+    }
+}
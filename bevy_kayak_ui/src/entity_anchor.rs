@@ -0,0 +1,62 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, GlobalTransform, Query, Res},
+    render::camera::Camera,
+    window::Windows,
+};
+use kayak_core::{Binding, Bound, MutableBound};
+
+use crate::projection::{find_world_camera, project_to_screen, ScreenProjection};
+use crate::BevyContext;
+
+/// Marks a widget as following the screen projection of the given entity
+///
+/// This is attached as widget metadata by the `AnchorToEntity` widget. Every frame, the entity's
+/// position (plus `offset`, in world units) is projected onto the screen and stored in
+/// `projection`, which is `None` while the entity (or a world camera) doesn't exist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityAnchor {
+    pub entity: Entity,
+    pub offset: Vec3,
+    pub projection: Binding<Option<ScreenProjection>>,
+}
+
+/// Projects the entities followed by anchored widgets onto the screen
+pub(crate) fn project_entity_anchors(
+    bevy_context: Res<BevyContext>,
+    windows: Res<Windows>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    transforms: Query<&GlobalTransform>,
+) {
+    let anchors = if let Ok(context) = bevy_context.kayak_context.read() {
+        context
+            .find_all(|context, index| context.get_metadata::<EntityAnchor>(index).is_some())
+            .into_iter()
+            .filter_map(|index| context.get_metadata::<EntityAnchor>(index))
+            .collect::<Vec<_>>()
+    } else {
+        return;
+    };
+    if anchors.is_empty() {
+        return;
+    }
+
+    let window_size = windows
+        .get_primary()
+        .map_or((0.0, 0.0), |window| (window.width(), window.height()));
+    let camera = find_world_camera(cameras.iter());
+    for anchor in anchors {
+        let projection = camera.and_then(|(camera, camera_transform)| {
+            let transform = transforms.get(anchor.entity).ok()?;
+            Some(project_to_screen(
+                camera,
+                camera_transform,
+                window_size,
+                transform.translation + anchor.offset,
+            ))
+        });
+        if anchor.projection.get() != projection {
+            anchor.projection.set(projection);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use bevy::{
+    core::Time,
+    math::Vec3,
+    prelude::{GlobalTransform, Query, Res, ResMut},
+    render::camera::Camera,
+    window::Windows,
+};
+use kayak_core::{bind, color::Color, Binding, Bound, MutableBound};
+
+use crate::projection::{find_world_camera, project_to_screen};
+
+/// A transient label floating above a position in the world, such as a damage number
+///
+/// Spawn one with [FloatingTexts::spawn]. Over its lifetime, the label rises and fades out, after
+/// which it's removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatingText {
+    /// The text to show
+    pub content: String,
+    /// The world position the label starts at
+    pub position: Vec3,
+    /// The color of the text
+    pub color: Color,
+    /// The font size of the text
+    pub size: f32,
+    /// How long the label is shown (in seconds)
+    pub duration: f32,
+    /// How far the label rises over its lifetime (in pixels)
+    pub rise: f32,
+}
+
+impl FloatingText {
+    pub fn new<S: Into<String>>(content: S, position: Vec3) -> Self {
+        Self {
+            content: content.into(),
+            position,
+            color: Color::WHITE,
+            size: 18.0,
+            duration: 1.0,
+            rise: 40.0,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_rise(mut self, rise: f32) -> Self {
+        self.rise = rise;
+        self
+    }
+}
+
+/// A floating text as currently shown on screen
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FloatingTextLabel {
+    pub content: String,
+    /// The position the label is centered on (in pixels, relative to the top-left of the window)
+    pub position: (f32, f32),
+    /// The color of the text, faded according to the label's age
+    pub color: Color,
+    pub size: f32,
+}
+
+/// A spawned floating text and how long it has been shown
+#[derive(Debug)]
+struct ActiveFloatingText {
+    text: FloatingText,
+    elapsed: f32,
+}
+
+/// The floating texts (e.g. damage numbers and pickup notifications) currently shown
+///
+/// Texts spawned here are projected from the world onto the screen every frame, using the first
+/// camera that isn't the UI camera, and shown by the `FloatingTextLayer` widget. Finished texts are
+/// kept around and reused by later spawns, so spawning many short-lived labels doesn't allocate
+/// every time.
+///
+/// # Examples
+///
+/// ```ignore
+/// fn on_hit(mut floating_texts: ResMut<FloatingTexts>, hits: Query<&Transform, Added<Hit>>) {
+///     for transform in hits.iter() {
+///         floating_texts.spawn(
+///             FloatingText::new("-12", transform.translation).with_color(Color::new(1.0, 0.2, 0.2, 1.0)),
+///         );
+///     }
+/// }
+/// ```
+pub struct FloatingTexts {
+    active: Vec<ActiveFloatingText>,
+    recycled: Vec<ActiveFloatingText>,
+    labels: Binding<Vec<FloatingTextLabel>>,
+}
+
+impl Default for FloatingTexts {
+    fn default() -> Self {
+        Self {
+            active: Vec::new(),
+            recycled: Vec::new(),
+            labels: bind(Vec::new()),
+        }
+    }
+}
+
+impl FloatingTexts {
+    /// Show a new floating text
+    pub fn spawn(&mut self, text: FloatingText) {
+        let active = if let Some(mut recycled) = self.recycled.pop() {
+            recycled.text.content.clear();
+            recycled.text.content.push_str(&text.content);
+            recycled.text.position = text.position;
+            recycled.text.color = text.color;
+            recycled.text.size = text.size;
+            recycled.text.duration = text.duration;
+            recycled.text.rise = text.rise;
+            recycled.elapsed = 0.0;
+            recycled
+        } else {
+            ActiveFloatingText { text, elapsed: 0.0 }
+        };
+        self.active.push(active);
+    }
+
+    /// Remove all floating texts currently shown
+    pub fn clear(&mut self) {
+        self.recycled.extend(self.active.drain(..));
+    }
+
+    /// The number of floating texts currently shown
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// The on-screen labels, updated every frame
+    pub fn labels(&self) -> Binding<Vec<FloatingTextLabel>> {
+        self.labels.clone()
+    }
+}
+
+pub(crate) fn update_floating_texts(
+    time: Res<Time>,
+    mut floating_texts: ResMut<FloatingTexts>,
+    windows: Res<Windows>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    if floating_texts.active.is_empty() && floating_texts.labels.get().is_empty() {
+        return;
+    }
+
+    let delta = time.delta_seconds();
+    let FloatingTexts {
+        active, recycled, ..
+    } = &mut *floating_texts;
+    let mut index = 0;
+    while index < active.len() {
+        active[index].elapsed += delta;
+        if active[index].elapsed >= active[index].text.duration {
+            recycled.push(active.swap_remove(index));
+        } else {
+            index += 1;
+        }
+    }
+
+    let window_size = windows
+        .get_primary()
+        .map_or((0.0, 0.0), |window| (window.width(), window.height()));
+    let labels = if let Some((camera, camera_transform)) = find_world_camera(cameras.iter()) {
+        floating_texts
+            .active
+            .iter()
+            .filter_map(|active| {
+                let projection =
+                    project_to_screen(camera, camera_transform, window_size, active.text.position);
+                if !projection.is_visible {
+                    return None;
+                }
+
+                let progress = if active.text.duration > 0.0 {
+                    active.elapsed / active.text.duration
+                } else {
+                    1.0
+                };
+                Some(FloatingTextLabel {
+                    content: active.text.content.clone(),
+                    position: (
+                        projection.position.0,
+                        projection.position.1 - active.text.rise * progress,
+                    ),
+                    color: Color {
+                        a: active.text.color.a * (1.0 - progress),
+                        ..active.text.color
+                    },
+                    size: active.text.size,
+                })
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    floating_texts.labels.set(labels);
+}
@@ -0,0 +1,111 @@
+use bevy::{
+    core::Time,
+    input::{
+        gamepad::{GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads},
+        Axis, Input,
+    },
+    prelude::{Res, ResMut},
+    window::Windows,
+};
+use kayak_core::{color::Color, InputEvent};
+
+use crate::gamepad_input::GamepadInput;
+
+/// Settings for emulating a mouse cursor with a gamepad
+///
+/// When enabled, the left stick of any connected gamepad moves a virtual cursor around the UI. This
+/// cursor feeds regular mouse input events to Kayak, so mouse-oriented UIs work with a controller
+/// without any per-widget navigation support. Its presses follow the
+/// [gamepad_buttons](crate::InputPolicies::gamepad_buttons) input policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadCursor {
+    /// Whether gamepad cursor emulation is active
+    pub enabled: bool,
+    /// The speed of the cursor (in pixels per second) when the stick is fully tilted
+    pub speed: f32,
+    /// Stick tilt at or below this value is ignored
+    pub dead_zone: f32,
+    /// The gamepad button that acts as the left mouse button
+    pub press_button: GamepadButtonType,
+    /// Whether the virtual cursor should be drawn
+    pub visible: bool,
+    /// The size of the drawn cursor (in pixels)
+    pub size: f32,
+    /// The color of the drawn cursor
+    pub color: Color,
+    /// The current position of the cursor (in pixels, relative to the top-left of the window)
+    pub position: (f32, f32),
+}
+
+impl Default for GamepadCursor {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            speed: 800.0,
+            dead_zone: 0.15,
+            press_button: GamepadButtonType::South,
+            visible: true,
+            size: 12.0,
+            color: Color::WHITE,
+            position: (0.0, 0.0),
+        }
+    }
+}
+
+pub(crate) fn process_gamepad_cursor(
+    mut gamepad_input: ResMut<GamepadInput>,
+    mut cursor: ResMut<GamepadCursor>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    time: Res<Time>,
+    windows: Res<Windows>,
+) {
+    if !cursor.enabled {
+        return;
+    }
+
+    let window_size = if let Some(window) = windows.get_primary() {
+        (window.width(), window.height())
+    } else {
+        return;
+    };
+
+    let mut direction = (0.0, 0.0);
+    let mut presses = Vec::new();
+    for gamepad in gamepads.iter() {
+        let x = axes
+            .get(GamepadAxis(*gamepad, GamepadAxisType::LeftStickX))
+            .unwrap_or_default();
+        let y = axes
+            .get(GamepadAxis(*gamepad, GamepadAxisType::LeftStickY))
+            .unwrap_or_default();
+        if x.abs() > cursor.dead_zone {
+            direction.0 += x;
+        }
+        if y.abs() > cursor.dead_zone {
+            // The stick's Y-axis points up while the UI's points down
+            direction.1 -= y;
+        }
+
+        let button = GamepadButton(*gamepad, cursor.press_button);
+        if buttons.just_pressed(button) {
+            presses.push((Some(button), InputEvent::MouseLeftPress));
+        } else if buttons.just_released(button) {
+            presses.push((Some(button), InputEvent::MouseLeftRelease));
+        }
+    }
+
+    if direction != (0.0, 0.0) {
+        let distance = cursor.speed * time.delta_seconds();
+        cursor.position = (
+            (cursor.position.0 + direction.0 * distance).clamp(0.0, window_size.0),
+            (cursor.position.1 + direction.1 * distance).clamp(0.0, window_size.1),
+        );
+        gamepad_input.push(None, InputEvent::MouseMoved(cursor.position));
+    }
+
+    for (button, event) in presses {
+        gamepad_input.push(button, event);
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::input::gamepad::GamepadButton;
+use kayak_core::InputEvent;
+
+/// The UI input of the gamepads, queued by the gamepad systems until
+/// [process_events](crate::process_events) sends it along with the mouse and keyboard input
+///
+/// Sending it there keeps the gamepad input ordered with the rest of the frame's input, and
+/// subject to the [InputPolicies](crate::InputPolicies).
+#[derive(Debug, Default)]
+pub(crate) struct GamepadInput {
+    /// The input events, with the gamepad button each one comes from (if any)
+    events: Vec<(Option<GamepadButton>, InputEvent)>,
+    /// The gamepad button that requested back navigation (if any)
+    back_button: Option<GamepadButton>,
+}
+
+impl GamepadInput {
+    /// Queue an input event, coming from the given gamepad button (if any)
+    pub fn push(&mut self, button: Option<GamepadButton>, event: InputEvent) {
+        self.events.push((button, event));
+    }
+
+    /// Request back navigation with the given gamepad button
+    pub fn request_back(&mut self, button: GamepadButton) {
+        self.back_button = Some(button);
+    }
+
+    /// Take the queued input events and back navigation request
+    pub fn take(
+        &mut self,
+    ) -> (
+        Vec<(Option<GamepadButton>, InputEvent)>,
+        Option<GamepadButton>,
+    ) {
+        (std::mem::take(&mut self.events), self.back_button.take())
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{
+    core::Time,
+    input::{
+        gamepad::{GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads},
+        Axis, Input,
+    },
+    prelude::{Local, Res, ResMut},
+};
+use kayak_core::{InputEvent, NavigationRepeater, NavigationSettings};
+
+use crate::gamepad_input::GamepadInput;
+use crate::GamepadCursor;
+
+/// Settings for navigating the UI with a gamepad
+///
+/// The back and activate buttons follow the
+/// [gamepad_buttons](crate::InputPolicies::gamepad_buttons) input policy, while directional
+/// navigation is always sent to the UI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadNavigation {
+    /// The gamepad button that requests back navigation (see [KayakContext::navigate_back](kayak_core::KayakContext::navigate_back))
+    ///
+    /// Set to `None` to disable
+    pub back_button: Option<GamepadButtonType>,
+    /// The gamepad button that activates (clicks) the focused widget
+    ///
+    /// Set to `None` to disable
+    pub activate_button: Option<GamepadButtonType>,
+    /// Whether the D-pad and the left stick move focus to the nearest focusable widget in their
+    /// direction (see [InputEvent::Navigate])
+    pub directional: bool,
+    /// How the D-pad and the left stick are turned into navigation steps
+    pub settings: NavigationSettings,
+}
+
+impl Default for GamepadNavigation {
+    fn default() -> Self {
+        Self {
+            back_button: Some(GamepadButtonType::East),
+            activate_button: Some(GamepadButtonType::South),
+            directional: true,
+            settings: NavigationSettings::default(),
+        }
+    }
+}
+
+/// Sends the navigation input of the gamepads to the UI
+///
+/// While the [GamepadCursor] is enabled, the left stick and its press button drive the cursor
+/// instead, so only the D-pad navigates and the activate button is ignored.
+pub(crate) fn process_gamepad_navigation(
+    mut gamepad_input: ResMut<GamepadInput>,
+    navigation: Res<GamepadNavigation>,
+    cursor: Res<GamepadCursor>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    time: Res<Time>,
+    mut repeater: Local<NavigationRepeater>,
+) {
+    let just_pressed = |button: Option<GamepadButtonType>| {
+        button.and_then(|button| {
+            gamepads
+                .iter()
+                .map(|gamepad| GamepadButton(*gamepad, button))
+                .find(|button| buttons.just_pressed(*button))
+        })
+    };
+
+    if navigation.directional {
+        // The D-pad and stick of every gamepad are combined, favoring the most tilted
+        let mut axis = (0.0_f32, 0.0_f32);
+        for gamepad in gamepads.iter() {
+            let pressed = |button| buttons.pressed(GamepadButton(*gamepad, button));
+            let mut x = match (
+                pressed(GamepadButtonType::DPadLeft),
+                pressed(GamepadButtonType::DPadRight),
+            ) {
+                (true, false) => -1.0,
+                (false, true) => 1.0,
+                _ => 0.0,
+            };
+            let mut y = match (
+                pressed(GamepadButtonType::DPadDown),
+                pressed(GamepadButtonType::DPadUp),
+            ) {
+                (true, false) => -1.0,
+                (false, true) => 1.0,
+                _ => 0.0,
+            };
+            if !cursor.enabled && x == 0.0 && y == 0.0 {
+                x = axes
+                    .get(GamepadAxis(*gamepad, GamepadAxisType::LeftStickX))
+                    .unwrap_or_default();
+                y = axes
+                    .get(GamepadAxis(*gamepad, GamepadAxisType::LeftStickY))
+                    .unwrap_or_default();
+            }
+
+            if x.abs().max(y.abs()) > axis.0.abs().max(axis.1.abs()) {
+                axis = (x, y);
+            }
+        }
+
+        if let Some(direction) = repeater.update(&navigation.settings, axis, time.delta_seconds()) {
+            gamepad_input.push(None, InputEvent::Navigate(direction));
+        }
+    } else {
+        repeater.reset();
+    }
+
+    if !cursor.enabled {
+        if let Some(button) = just_pressed(navigation.activate_button) {
+            gamepad_input.push(Some(button), InputEvent::Activate);
+        }
+    }
+
+    if let Some(button) = just_pressed(navigation.back_button) {
+        gamepad_input.request_back(button);
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::{
+    input::{gamepad::GamepadButton, keyboard::KeyCode},
+    prelude::MouseButton,
+    utils::HashSet,
+};
+
+/// Which of the game and the UI gets an input when both want it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputPolicy {
+    /// The UI gets the input, which is marked as consumed (see [ConsumedInput]) whenever the UI
+    /// wants it: mouse input over a widget, keyboard input while a widget has focus, or gamepad
+    /// input the UI acted on
+    UiFirst,
+    /// The game gets the input, and the UI only gets the input the game didn't claim (see
+    /// [ConsumedInput::claim_key] and the like) before the UI processes its events
+    ///
+    /// The systems claiming input need to run before
+    /// [KayakUiSystem::ProcessEvents](crate::KayakUiSystem::ProcessEvents), or the claims only
+    /// apply from the next frame on.
+    GameFirst,
+    /// Both get the input, which is never marked as consumed
+    Share,
+}
+
+impl Default for InputPolicy {
+    fn default() -> Self {
+        Self::UiFirst
+    }
+}
+
+/// The [InputPolicy] of each kind of input
+///
+/// All kinds of input are [UiFirst](InputPolicy::UiFirst) by default.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InputPolicies {
+    /// Mouse button presses and releases
+    pub mouse_buttons: InputPolicy,
+    /// Mouse wheel (or touchpad) scrolls
+    pub mouse_wheel: InputPolicy,
+    /// Key presses and releases (the characters typed are always sent to the UI)
+    pub keyboard: InputPolicy,
+    /// The presses and releases of the gamepad buttons used by the [GamepadCursor](crate::GamepadCursor)
+    /// and [GamepadNavigation](crate::GamepadNavigation)
+    pub gamepad_buttons: InputPolicy,
+}
+
+/// The input consumed by the UI this frame, and the input claimed by the game before the UI
+///
+/// Game systems running after the UI processes its events can check whether an input was already
+/// handled by the UI:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use kayak_ui::bevy::{ConsumedInput, KayakUiSystem};
+/// fn shoot(mouse: Res<Input<MouseButton>>, consumed: Res<ConsumedInput>) {
+///     if mouse.just_pressed(MouseButton::Left)
+///         && !consumed.is_mouse_button_consumed(MouseButton::Left)
+///     {
+///         // Fire!
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_system(shoot.after(KayakUiSystem::ProcessEvents));
+/// ```
+///
+/// For input with the [GameFirst](InputPolicy::GameFirst) policy, game systems running before
+/// the UI (with `.before(KayakUiSystem::ProcessEvents)`) claim the input they handle, so it isn't
+/// sent to the UI this frame.
+#[derive(Debug, Default, Clone)]
+pub struct ConsumedInput {
+    mouse_buttons: HashSet<MouseButton>,
+    keys: HashSet<KeyCode>,
+    mouse_wheel: bool,
+    gamepad_buttons: HashSet<GamepadButton>,
+    claimed_mouse_buttons: HashSet<MouseButton>,
+    claimed_keys: HashSet<KeyCode>,
+    claimed_mouse_wheel: bool,
+    claimed_gamepad_buttons: HashSet<GamepadButton>,
+}
+
+impl ConsumedInput {
+    /// Checks if the UI consumed the presses or releases of the given mouse button this frame
+    pub fn is_mouse_button_consumed(&self, button: MouseButton) -> bool {
+        self.mouse_buttons.contains(&button)
+    }
+
+    /// Checks if the UI consumed the presses or releases of the given key this frame
+    pub fn is_key_consumed(&self, key: KeyCode) -> bool {
+        self.keys.contains(&key)
+    }
+
+    /// Checks if the UI consumed the mouse wheel scrolls this frame
+    pub fn is_mouse_wheel_consumed(&self) -> bool {
+        self.mouse_wheel
+    }
+
+    /// Checks if the UI consumed the presses or releases of the given gamepad button this frame
+    pub fn is_gamepad_button_consumed(&self, button: GamepadButton) -> bool {
+        self.gamepad_buttons.contains(&button)
+    }
+
+    /// Keep this frame's presses and releases of the given mouse button from the UI
+    pub fn claim_mouse_button(&mut self, button: MouseButton) {
+        self.claimed_mouse_buttons.insert(button);
+    }
+
+    /// Keep this frame's presses and releases of the given key from the UI
+    pub fn claim_key(&mut self, key: KeyCode) {
+        self.claimed_keys.insert(key);
+    }
+
+    /// Keep this frame's mouse wheel scrolls from the UI
+    pub fn claim_mouse_wheel(&mut self) {
+        self.claimed_mouse_wheel = true;
+    }
+
+    /// Keep this frame's presses and releases of the given gamepad button from the UI
+    pub fn claim_gamepad_button(&mut self, button: GamepadButton) {
+        self.claimed_gamepad_buttons.insert(button);
+    }
+
+    pub(crate) fn is_mouse_button_claimed(&self, button: MouseButton) -> bool {
+        self.claimed_mouse_buttons.contains(&button)
+    }
+
+    pub(crate) fn is_key_claimed(&self, key: KeyCode) -> bool {
+        self.claimed_keys.contains(&key)
+    }
+
+    pub(crate) fn is_mouse_wheel_claimed(&self) -> bool {
+        self.claimed_mouse_wheel
+    }
+
+    pub(crate) fn is_gamepad_button_claimed(&self, button: GamepadButton) -> bool {
+        self.claimed_gamepad_buttons.contains(&button)
+    }
+
+    pub(crate) fn consume_mouse_button(&mut self, button: MouseButton) {
+        self.mouse_buttons.insert(button);
+    }
+
+    pub(crate) fn consume_key(&mut self, key: KeyCode) {
+        self.keys.insert(key);
+    }
+
+    pub(crate) fn consume_mouse_wheel(&mut self) {
+        self.mouse_wheel = true;
+    }
+
+    pub(crate) fn consume_gamepad_button(&mut self, button: GamepadButton) {
+        self.gamepad_buttons.insert(button);
+    }
+
+    /// Forget the input consumed last frame, as the UI starts processing this frame's input
+    pub(crate) fn clear_consumed(&mut self) {
+        self.mouse_buttons.clear();
+        self.keys.clear();
+        self.mouse_wheel = false;
+        self.gamepad_buttons.clear();
+    }
+
+    /// Forget the input claimed by the game, once the UI has processed this frame's input
+    pub(crate) fn clear_claimed(&mut self) {
+        self.claimed_mouse_buttons.clear();
+        self.claimed_keys.clear();
+        self.claimed_mouse_wheel = false;
+        self.claimed_gamepad_buttons.clear();
+    }
+}
@@ -167,3 +167,128 @@ pub fn convert_virtual_key_code(virtual_key_code: bevy::prelude::KeyCode) -> Key
         bevy::prelude::KeyCode::Cut => KeyCode::Cut,
     }
 }
+
+/// Converts the scan code of a key to the key at the same position on a US QWERTY keyboard
+///
+/// Only the keys that move between layouts (letters, digits, and punctuation) are converted.
+/// Scan codes are the PC set 1 codes on Windows and Linux, and the virtual key codes on macOS.
+/// They aren't reported on the web.
+pub fn convert_scan_code(scan_code: u32) -> Option<KeyCode> {
+    if cfg!(target_arch = "wasm32") {
+        None
+    } else if cfg!(target_os = "macos") {
+        convert_mac_scan_code(scan_code)
+    } else {
+        convert_pc_scan_code(scan_code)
+    }
+}
+
+fn convert_pc_scan_code(scan_code: u32) -> Option<KeyCode> {
+    let key = match scan_code {
+        0x02 => KeyCode::Key1,
+        0x03 => KeyCode::Key2,
+        0x04 => KeyCode::Key3,
+        0x05 => KeyCode::Key4,
+        0x06 => KeyCode::Key5,
+        0x07 => KeyCode::Key6,
+        0x08 => KeyCode::Key7,
+        0x09 => KeyCode::Key8,
+        0x0A => KeyCode::Key9,
+        0x0B => KeyCode::Key0,
+        0x0C => KeyCode::Minus,
+        0x0D => KeyCode::Equals,
+        0x10 => KeyCode::Q,
+        0x11 => KeyCode::W,
+        0x12 => KeyCode::E,
+        0x13 => KeyCode::R,
+        0x14 => KeyCode::T,
+        0x15 => KeyCode::Y,
+        0x16 => KeyCode::U,
+        0x17 => KeyCode::I,
+        0x18 => KeyCode::O,
+        0x19 => KeyCode::P,
+        0x1A => KeyCode::LBracket,
+        0x1B => KeyCode::RBracket,
+        0x1E => KeyCode::A,
+        0x1F => KeyCode::S,
+        0x20 => KeyCode::D,
+        0x21 => KeyCode::F,
+        0x22 => KeyCode::G,
+        0x23 => KeyCode::H,
+        0x24 => KeyCode::J,
+        0x25 => KeyCode::K,
+        0x26 => KeyCode::L,
+        0x27 => KeyCode::Semicolon,
+        0x28 => KeyCode::Apostrophe,
+        0x29 => KeyCode::Grave,
+        0x2B => KeyCode::Backslash,
+        0x2C => KeyCode::Z,
+        0x2D => KeyCode::X,
+        0x2E => KeyCode::C,
+        0x2F => KeyCode::V,
+        0x30 => KeyCode::B,
+        0x31 => KeyCode::N,
+        0x32 => KeyCode::M,
+        0x33 => KeyCode::Comma,
+        0x34 => KeyCode::Period,
+        0x35 => KeyCode::Slash,
+        0x56 => KeyCode::Oem102,
+        _ => return None,
+    };
+    Some(key)
+}
+
+fn convert_mac_scan_code(scan_code: u32) -> Option<KeyCode> {
+    let key = match scan_code {
+        0x00 => KeyCode::A,
+        0x01 => KeyCode::S,
+        0x02 => KeyCode::D,
+        0x03 => KeyCode::F,
+        0x04 => KeyCode::H,
+        0x05 => KeyCode::G,
+        0x06 => KeyCode::Z,
+        0x07 => KeyCode::X,
+        0x08 => KeyCode::C,
+        0x09 => KeyCode::V,
+        0x0A => KeyCode::Oem102,
+        0x0B => KeyCode::B,
+        0x0C => KeyCode::Q,
+        0x0D => KeyCode::W,
+        0x0E => KeyCode::E,
+        0x0F => KeyCode::R,
+        0x10 => KeyCode::Y,
+        0x11 => KeyCode::T,
+        0x12 => KeyCode::Key1,
+        0x13 => KeyCode::Key2,
+        0x14 => KeyCode::Key3,
+        0x15 => KeyCode::Key4,
+        0x16 => KeyCode::Key6,
+        0x17 => KeyCode::Key5,
+        0x18 => KeyCode::Equals,
+        0x19 => KeyCode::Key9,
+        0x1A => KeyCode::Key7,
+        0x1B => KeyCode::Minus,
+        0x1C => KeyCode::Key8,
+        0x1D => KeyCode::Key0,
+        0x1E => KeyCode::RBracket,
+        0x1F => KeyCode::O,
+        0x20 => KeyCode::U,
+        0x21 => KeyCode::LBracket,
+        0x22 => KeyCode::I,
+        0x23 => KeyCode::P,
+        0x25 => KeyCode::L,
+        0x26 => KeyCode::J,
+        0x27 => KeyCode::Apostrophe,
+        0x28 => KeyCode::K,
+        0x29 => KeyCode::Semicolon,
+        0x2A => KeyCode::Backslash,
+        0x2B => KeyCode::Comma,
+        0x2C => KeyCode::Slash,
+        0x2D => KeyCode::N,
+        0x2E => KeyCode::M,
+        0x2F => KeyCode::Period,
+        0x32 => KeyCode::Grave,
+        _ => return None,
+    };
+    Some(key)
+}
@@ -1,32 +1,97 @@
 use bevy::{
-    input::{keyboard::KeyboardInput, mouse::MouseButtonInput, ElementState},
+    core::Time,
+    input::{
+        gamepad::GamepadButton,
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel},
+        touch::{TouchInput, TouchPhase},
+        ElementState,
+    },
     math::Vec2,
-    prelude::{EventReader, IntoExclusiveSystem, MouseButton, Plugin, Res, World},
+    prelude::{
+        EventReader, IntoExclusiveSystem, Local, MouseButton, ParallelSystemDescriptorCoercion,
+        Plugin, Res, ResMut, SystemLabel, World,
+    },
     render::color::Color,
-    window::{CursorMoved, ReceivedCharacter, WindowCreated, WindowResized, Windows},
+    window::{
+        CursorLeft, CursorMoved, ReceivedCharacter, WindowCreated, WindowFocused, WindowResized,
+        Windows,
+    },
 };
 
+mod asset_loading;
 mod bevy_context;
 mod camera;
+mod embed;
+mod entity_anchor;
+mod floating_text;
+mod gamepad_cursor;
+mod gamepad_input;
+mod gamepad_navigation;
+mod input_consumption;
 mod key;
+mod projection;
 mod render;
+mod state_roots;
+mod state_route;
 
+pub use asset_loading::AssetLoadingProgress;
 pub use bevy_context::BevyContext;
 pub use camera::*;
-use kayak_core::{bind, Binding, InputEvent, MutableBound};
+pub use embed::EmbeddedEntity;
+pub use entity_anchor::EntityAnchor;
+pub use floating_text::{FloatingText, FloatingTextLabel, FloatingTexts};
+pub use gamepad_cursor::GamepadCursor;
+pub use gamepad_navigation::GamepadNavigation;
+pub use input_consumption::{ConsumedInput, InputPolicies, InputPolicy};
+use kayak_core::{bind, Binding, CursorIcon, InputEvent, MutableBound, ScrollEvent, ScrollUnit};
+pub use projection::{project_to_screen, ScreenProjection};
 pub use render::unified::font::FontMapping;
 pub use render::unified::image::ImageManager;
+pub use render::unified::UIAntiAliasing;
+pub use state_roots::{mount_state_roots, StateRoots, UICrossfade};
+pub use state_route::{sync_state_route, StateRoute};
 
 #[derive(Default)]
 pub struct BevyKayakUIPlugin;
 
+/// Labels of the systems added by the [BevyKayakUIPlugin], to order game systems relative to them
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemLabel)]
+pub enum KayakUiSystem {
+    /// Sends the input of the frame to the UI (including that of the [GamepadCursor] and
+    /// [GamepadNavigation]), and marks the input it consumes (see [ConsumedInput])
+    ///
+    /// Systems checking [ConsumedInput] need to run `.after()` this, while systems claiming
+    /// [GameFirst](InputPolicy::GameFirst) input need to run `.before()` it.
+    ProcessEvents,
+}
+
 impl Plugin for BevyKayakUIPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.insert_resource(bind(WindowSize::default()))
+            .init_resource::<GamepadCursor>()
+            .init_resource::<GamepadNavigation>()
+            .init_resource::<gamepad_input::GamepadInput>()
+            .init_resource::<InputPolicies>()
+            .init_resource::<ConsumedInput>()
+            .init_resource::<UICrossfade>()
+            .init_resource::<FloatingTexts>()
+            .init_resource::<AssetLoadingProgress>()
             .add_plugin(render::BevyKayakUIRenderPlugin)
             .add_plugin(camera::KayakUICameraPlugin)
             .add_system(update_window_size)
-            .add_system(process_events)
+            .add_system(process_events.label(KayakUiSystem::ProcessEvents))
+            .add_system(gamepad_cursor::process_gamepad_cursor.before(KayakUiSystem::ProcessEvents))
+            .add_system(
+                gamepad_navigation::process_gamepad_navigation.before(KayakUiSystem::ProcessEvents),
+            )
+            .add_system(state_roots::update_crossfade)
+            .add_system(advance_notifications)
+            .add_system(update_cursor_icon)
+            .add_system(embed::position_embedded_entities)
+            .add_system(entity_anchor::project_entity_anchors)
+            .add_system(floating_text::update_floating_texts)
+            .add_system(asset_loading::update_asset_loading_progress)
             .add_system(update.exclusive_system());
     }
 }
@@ -49,19 +114,33 @@ pub fn update(world: &mut World) {
 pub fn process_events(
     bevy_context: Res<BevyContext>,
     windows: Res<Windows>,
+    policies: Res<InputPolicies>,
+    mut consumed_input: ResMut<ConsumedInput>,
+    mut gamepad_input: ResMut<gamepad_input::GamepadInput>,
     mut cursor_moved_events: EventReader<CursorMoved>,
+    mut cursor_left_events: EventReader<CursorLeft>,
+    mut window_focused_events: EventReader<WindowFocused>,
     mut mouse_button_input_events: EventReader<MouseButtonInput>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut touch_input_events: EventReader<TouchInput>,
     mut char_input_events: EventReader<ReceivedCharacter>,
     mut keyboard_input_events: EventReader<KeyboardInput>,
 ) {
-    let window_size = if let Some(window) = windows.get_primary() {
-        Vec2::new(window.width(), window.height())
+    let (window_id, window_size) = if let Some(window) = windows.get_primary() {
+        (window.id(), Vec2::new(window.width(), window.height()))
     } else {
         panic!("Couldn't find primary window!");
     };
 
+    consumed_input.clear_consumed();
     if let Ok(mut context) = bevy_context.kayak_context.write() {
         let mut input_events = Vec::new();
+        // The input that the UI may consume, depending on the policies
+        let mut mouse_buttons = Vec::new();
+        let mut keys = Vec::new();
+        let mut has_scrolled = false;
+        // The gamepad buttons the UI may consume, with whether they act as the mouse
+        let mut gamepad_buttons: Vec<(GamepadButton, bool)> = Vec::new();
 
         if let Some(event) = cursor_moved_events.iter().last() {
             // Currently, we can only handle a single MouseMoved event at a time so everything but the last needs to be skipped
@@ -71,7 +150,23 @@ pub fn process_events(
             )));
         }
 
+        if cursor_left_events.iter().any(|event| event.id == window_id) {
+            input_events.push(InputEvent::CursorLeft);
+        }
+
+        for event in window_focused_events.iter() {
+            if event.id == window_id {
+                input_events.push(InputEvent::WindowFocused(event.focused));
+            }
+        }
+
         for event in mouse_button_input_events.iter() {
+            if policies.mouse_buttons == InputPolicy::GameFirst
+                && consumed_input.is_mouse_button_claimed(event.button)
+            {
+                continue;
+            }
+            mouse_buttons.push(event.button);
             match event.button {
                 MouseButton::Left => {
                     if event.state == ElementState::Pressed {
@@ -80,25 +175,174 @@ pub fn process_events(
                         input_events.push(InputEvent::MouseLeftRelease);
                     }
                 }
+                MouseButton::Right => {
+                    if event.state == ElementState::Pressed {
+                        input_events.push(InputEvent::MouseRightPress);
+                    } else if event.state == ElementState::Released {
+                        input_events.push(InputEvent::MouseRightRelease);
+                    }
+                }
+                MouseButton::Middle => {
+                    if event.state == ElementState::Pressed {
+                        input_events.push(InputEvent::MouseMiddlePress);
+                    } else if event.state == ElementState::Released {
+                        input_events.push(InputEvent::MouseMiddleRelease);
+                    }
+                }
                 _ => {}
             }
         }
 
+        for event in mouse_wheel_events.iter() {
+            if policies.mouse_wheel == InputPolicy::GameFirst
+                && consumed_input.is_mouse_wheel_claimed()
+            {
+                continue;
+            }
+            has_scrolled = true;
+            // Bevy's deltas are positive when scrolling up (or left)
+            input_events.push(InputEvent::Scroll(ScrollEvent {
+                delta_x: -event.x,
+                delta_y: -event.y,
+                unit: match event.unit {
+                    MouseScrollUnit::Line => ScrollUnit::Line,
+                    MouseScrollUnit::Pixel => ScrollUnit::Pixel,
+                },
+            }));
+        }
+
+        for event in touch_input_events.iter() {
+            input_events.push(InputEvent::Touch {
+                id: event.id,
+                phase: match event.phase {
+                    TouchPhase::Started => kayak_core::TouchPhase::Started,
+                    TouchPhase::Moved => kayak_core::TouchPhase::Moved,
+                    TouchPhase::Ended => kayak_core::TouchPhase::Ended,
+                    TouchPhase::Cancelled => kayak_core::TouchPhase::Cancelled,
+                },
+                position: (event.position.x, window_size.y - event.position.y),
+            });
+        }
+
+        // === Gamepads === //
+        let (gamepad_events, back_button) = gamepad_input.take();
+        for (button, event) in gamepad_events {
+            if let Some(button) = button {
+                if policies.gamepad_buttons == InputPolicy::GameFirst
+                    && consumed_input.is_gamepad_button_claimed(button)
+                {
+                    continue;
+                }
+                let is_mouse = !matches!(event, InputEvent::Activate);
+                gamepad_buttons.push((button, is_mouse));
+            }
+            if matches!(event, InputEvent::MouseMoved(..)) {
+                // Only a single MouseMoved event can be handled at a time, so the virtual cursor
+                // wins over the mouse. It moves first, like the mouse.
+                input_events
+                    .retain(|input_event| !matches!(input_event, InputEvent::MouseMoved(..)));
+                input_events.insert(0, event);
+            } else {
+                input_events.push(event);
+            }
+        }
+        let back_button = back_button.filter(|button| {
+            policies.gamepad_buttons != InputPolicy::GameFirst
+                || !consumed_input.is_gamepad_button_claimed(*button)
+        });
+
         for event in char_input_events.iter() {
             input_events.push(InputEvent::CharEvent { c: event.char });
         }
 
         for event in keyboard_input_events.iter() {
             if let Some(key_code) = event.key_code {
+                if policies.keyboard == InputPolicy::GameFirst
+                    && consumed_input.is_key_claimed(key_code)
+                {
+                    continue;
+                }
+                keys.push(key_code);
                 let kayak_key_code = key::convert_virtual_key_code(key_code);
                 input_events.push(InputEvent::Keyboard {
                     key: kayak_key_code,
+                    physical_key: key::convert_scan_code(event.scan_code),
                     is_pressed: matches!(event.state, ElementState::Pressed),
                 });
             }
         }
 
         context.process_events(input_events);
+        let has_navigated_back = back_button.map_or(false, |_| context.navigate_back());
+
+        // === Consumption === //
+        let wants_mouse = context.contains_cursor() || context.wants_cursor();
+        let wants_keyboard = context.current_focus().is_some();
+        if policies.mouse_buttons == InputPolicy::UiFirst && wants_mouse {
+            for button in mouse_buttons {
+                consumed_input.consume_mouse_button(button);
+            }
+        }
+        if policies.mouse_wheel == InputPolicy::UiFirst && wants_mouse && has_scrolled {
+            consumed_input.consume_mouse_wheel();
+        }
+        if policies.keyboard == InputPolicy::UiFirst && wants_keyboard {
+            for key in keys {
+                consumed_input.consume_key(key);
+            }
+        }
+        if policies.gamepad_buttons == InputPolicy::UiFirst {
+            for (button, is_mouse) in gamepad_buttons {
+                if (is_mouse && wants_mouse) || (!is_mouse && wants_keyboard) {
+                    consumed_input.consume_gamepad_button(button);
+                }
+            }
+            if let (Some(button), true) = (back_button, has_navigated_back) {
+                consumed_input.consume_gamepad_button(button);
+            }
+        }
+    }
+    consumed_input.clear_claimed();
+}
+
+/// Expires the notifications pushed with `KayakContext::push_notification`
+fn advance_notifications(time: Res<Time>, bevy_context: Res<BevyContext>) {
+    if let Ok(mut context) = bevy_context.kayak_context.write() {
+        // Simulated time advances the notifications itself
+        if !context.is_time_simulated() {
+            context.advance_notifications(time.delta_seconds());
+        }
+    }
+}
+
+/// Shows the cursor icon asked for with `KayakContext::set_cursor_icon`
+///
+/// The icon is only set when the UI asks for a different one, so the app can still set its own.
+fn update_cursor_icon(
+    bevy_context: Res<BevyContext>,
+    mut windows: ResMut<Windows>,
+    mut last_icon: Local<Option<CursorIcon>>,
+) {
+    let icon = match bevy_context.kayak_context.read() {
+        Ok(context) => context.cursor_icon(),
+        Err(_) => return,
+    };
+    if *last_icon == Some(icon) {
+        return;
+    }
+
+    if let Some(window) = windows.get_primary_mut() {
+        window.set_cursor_icon(match icon {
+            CursorIcon::Default => bevy::window::CursorIcon::Default,
+            CursorIcon::Pointer => bevy::window::CursorIcon::Hand,
+            CursorIcon::Text => bevy::window::CursorIcon::Text,
+            CursorIcon::Move => bevy::window::CursorIcon::Move,
+            CursorIcon::ResizeHorizontal => bevy::window::CursorIcon::EwResize,
+            CursorIcon::ResizeVertical => bevy::window::CursorIcon::NsResize,
+            CursorIcon::ResizeNwSe => bevy::window::CursorIcon::NwseResize,
+            CursorIcon::ResizeNeSw => bevy::window::CursorIcon::NeswResize,
+        });
+        *last_icon = Some(icon);
     }
 }
 
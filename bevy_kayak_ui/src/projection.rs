@@ -0,0 +1,52 @@
+use bevy::{
+    math::{Vec3, Vec4},
+    prelude::GlobalTransform,
+    render::camera::Camera,
+};
+
+use crate::UICameraBundle;
+
+/// A world position projected onto the screen
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ScreenProjection {
+    /// The projected position (in pixels, relative to the top-left of the window)
+    ///
+    /// This may be outside of the window when the position isn't visible. For positions behind the
+    /// camera, it's pushed outside of the window in the direction of the position.
+    pub position: (f32, f32),
+    /// Whether the position is in front of the camera and within the window
+    pub is_visible: bool,
+}
+
+/// Project a world position onto the screen using the given camera
+pub fn project_to_screen(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    window_size: (f32, f32),
+    world_position: Vec3,
+) -> ScreenProjection {
+    let world_to_clip = camera.projection_matrix * camera_transform.compute_matrix().inverse();
+    let clip = world_to_clip * Vec4::new(world_position.x, world_position.y, world_position.z, 1.0);
+    let (ndc, is_in_front) = if clip.w > 0.0 {
+        (clip.truncate().truncate() / clip.w, true)
+    } else {
+        // Dividing by a negative w would mirror the position, so only keep its direction
+        (clip.truncate().truncate().normalize_or_zero() * 2.0, false)
+    };
+
+    // NDC's vertical axis points up, while the UI's points down
+    ScreenProjection {
+        position: (
+            (ndc.x + 1.0) / 2.0 * window_size.0,
+            (1.0 - ndc.y) / 2.0 * window_size.1,
+        ),
+        is_visible: is_in_front && ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0,
+    }
+}
+
+/// Find the camera rendering the world (i.e. the first one that isn't the UI camera)
+pub(crate) fn find_world_camera<'a>(
+    mut cameras: impl Iterator<Item = (&'a Camera, &'a GlobalTransform)>,
+) -> Option<(&'a Camera, &'a GlobalTransform)> {
+    cameras.find(|(camera, _)| camera.name.as_deref() != Some(UICameraBundle::UI_CAMERA))
+}
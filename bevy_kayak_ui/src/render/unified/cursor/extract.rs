@@ -0,0 +1,62 @@
+use bevy::{math::Vec2, render::color::Color, sprite::Rect};
+
+use crate::{
+    render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
+    to_bevy_color, GamepadCursor, WindowSize,
+};
+
+/// The z-index of the virtual cursor, which should always be drawn above everything else
+const CURSOR_Z_INDEX: f32 = 100000.0;
+
+pub fn extract_cursor(
+    cursor: &GamepadCursor,
+    window_size: WindowSize,
+    dpi: f32,
+) -> Vec<ExtractQuadBundle> {
+    let half_size = cursor.size / 2.0;
+    let (x, y) = cursor.position;
+
+    vec![
+        // Reset the clip so the cursor isn't cut off by the last clipped widget
+        ExtractQuadBundle {
+            extracted_quad: ExtractedQuad {
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: Vec2::new(window_size.0, window_size.1) * dpi,
+                },
+                color: Color::default(),
+                instance_index: 0,
+                char_id: 0,
+                z_index: CURSOR_Z_INDEX - 0.1,
+                font_handle: None,
+                quad_type: UIQuadType::Clip,
+                type_index: 0,
+                border_radius: (0.0, 0.0, 0.0, 0.0),
+                image: None,
+                uv_min: None,
+                uv_max: None,
+                clip: None,
+            },
+        },
+        ExtractQuadBundle {
+            extracted_quad: ExtractedQuad {
+                rect: Rect {
+                    min: Vec2::new(x - half_size, y - half_size) * dpi,
+                    max: Vec2::new(x + half_size, y + half_size) * dpi,
+                },
+                color: to_bevy_color(&cursor.color),
+                instance_index: 0,
+                char_id: 0,
+                z_index: CURSOR_Z_INDEX,
+                font_handle: None,
+                quad_type: UIQuadType::Quad,
+                type_index: 0,
+                border_radius: (half_size, half_size, half_size, half_size),
+                image: None,
+                uv_min: None,
+                uv_max: None,
+                clip: None,
+            },
+        },
+    ]
+}
@@ -3,21 +3,32 @@ use bevy::{
     prelude::{Assets, Res},
     sprite::Rect,
 };
-use kayak_core::render_primitive::RenderPrimitive;
-use kayak_font::{Alignment, CoordinateSystem, KayakFont};
+use kayak_core::{color::Color, render_primitive::RenderPrimitive};
+use kayak_font::{Alignment, CoordinateSystem, GlyphPositioning, KayakFont};
 
 use crate::{
-    render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
+    render::unified::{
+        pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
+        quad::create_plain_quad,
+    },
     to_bevy_color,
 };
 
 use super::font_mapping::FontMapping;
 
+/// How far the second pass of a bold run is shifted (relative to the font size)
+const BOLD_OFFSET: f32 = 0.04;
+/// How far below the top of a run its underline is drawn (relative to the font size)
+const UNDERLINE_POSITION: f32 = 1.1;
+/// The thickness of underlines (relative to the font size)
+const UNDERLINE_THICKNESS: f32 = 0.07;
+
 pub fn extract_texts(
     render_primitive: &RenderPrimitive,
     fonts: &Res<Assets<KayakFont>>,
     font_mapping: &Res<FontMapping>,
-    _dpi: f32,
+    glyph_positioning: GlyphPositioning,
+    dpi: f32,
 ) -> Vec<ExtractQuadBundle> {
     let mut extracted_texts = Vec::new();
     let (background_color, layout, font_size, content, font, parent_size, line_height) =
@@ -51,7 +62,7 @@ pub fn extract_texts(
 
     let font = font.unwrap();
 
-    let chars_layouts = font.get_layout(
+    let mut chars_layouts = font.get_layout(
         CoordinateSystem::PositiveYDown,
         Alignment::Start,
         (layout.posx, layout.posy + font_size),
@@ -60,6 +71,7 @@ pub fn extract_texts(
         *line_height,
         font_size,
     );
+    glyph_positioning.apply(&mut chars_layouts, dpi);
 
     for char_layout in chars_layouts {
         let position = char_layout.position.into();
@@ -72,7 +84,7 @@ pub fn extract_texts(
                     max: position + size,
                 },
                 color: to_bevy_color(background_color),
-                vertex_index: 0,
+                instance_index: 0,
                 char_id: font.get_char_id(char_layout.content).unwrap(),
                 z_index: layout.z_index,
                 quad_type: UIQuadType::Text,
@@ -80,6 +92,7 @@ pub fn extract_texts(
                 border_radius: (0.0, 0.0, 0.0, 0.0),
                 image: None,
                 uv_max: None,
+                clip: None,
                 uv_min: None,
             },
         });
@@ -87,3 +100,98 @@ pub fn extract_texts(
 
     extracted_texts
 }
+
+pub fn extract_rich_texts(
+    render_primitive: &RenderPrimitive,
+    fonts: &Res<Assets<KayakFont>>,
+    font_mapping: &Res<FontMapping>,
+    glyph_positioning: GlyphPositioning,
+    dpi: f32,
+) -> Vec<ExtractQuadBundle> {
+    let mut extracted_texts = Vec::new();
+    let (layout, runs) = match render_primitive {
+        RenderPrimitive::RichText { layout, runs } => (layout, runs),
+        _ => panic!(""),
+    };
+
+    for run in runs {
+        let font_handle = match font_mapping.get_handle(run.font.clone()) {
+            Some(font_handle) => font_handle,
+            None => continue,
+        };
+        let font = match fonts.get(font_handle.clone()) {
+            Some(font) => font,
+            None => continue,
+        };
+        let run_color = run.color.unwrap_or(Color::WHITE);
+        let color = to_bevy_color(&run_color);
+        let origin = (layout.posx + run.position.0, layout.posy + run.position.1);
+
+        // Runs are laid out beforehand, so they're never wrapped here
+        let mut chars_layouts = font.get_layout(
+            CoordinateSystem::PositiveYDown,
+            Alignment::Start,
+            (origin.0, origin.1 + run.size),
+            (f32::MAX, f32::MAX),
+            &run.content,
+            run.size * 1.2,
+            run.size,
+        );
+        glyph_positioning.apply(&mut chars_layouts, dpi);
+
+        // Bold runs are drawn twice, the second time slightly to the right
+        let offsets: &[f32] = if run.bold {
+            &[0.0, BOLD_OFFSET]
+        } else {
+            &[0.0]
+        };
+        for offset in offsets {
+            for char_layout in chars_layouts.iter() {
+                let position =
+                    Vec2::from(char_layout.position) + Vec2::new(offset * run.size * dpi, 0.0);
+                let size: Vec2 = char_layout.size.into();
+                extracted_texts.push(ExtractQuadBundle {
+                    extracted_quad: ExtractedQuad {
+                        font_handle: Some(font_handle.clone()),
+                        rect: Rect {
+                            min: position,
+                            max: position + size,
+                        },
+                        color,
+                        instance_index: 0,
+                        char_id: font.get_char_id(char_layout.content).unwrap(),
+                        z_index: layout.z_index,
+                        quad_type: UIQuadType::Text,
+                        type_index: 0,
+                        border_radius: (0.0, 0.0, 0.0, 0.0),
+                        image: None,
+                        uv_max: None,
+                        clip: None,
+                        uv_min: None,
+                    },
+                });
+            }
+        }
+
+        if run.underline {
+            let width: f32 = run
+                .content
+                .chars()
+                .map(|c| {
+                    font.get_glyph(c)
+                        .map_or(0.0, |glyph| glyph.advance * run.size)
+                })
+                .sum();
+            let thickness = (run.size * UNDERLINE_THICKNESS).max(1.0);
+            let min = Vec2::new(origin.0, origin.1 + run.size * UNDERLINE_POSITION);
+            extracted_texts.push(create_plain_quad(
+                min * dpi,
+                (min + Vec2::new(width, thickness)) * dpi,
+                &run_color,
+                layout.z_index,
+            ));
+        }
+    }
+
+    extracted_texts
+}
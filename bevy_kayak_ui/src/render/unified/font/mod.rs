@@ -9,7 +9,7 @@ use bevy::{
 };
 use kayak_font::{
     bevy::{FontTextureCache, KayakFontPlugin},
-    KayakFont,
+    GlyphPositioning, KayakFont,
 };
 
 mod extract;
@@ -18,7 +18,7 @@ mod font_mapping;
 use crate::BevyContext;
 
 use super::pipeline::UnifiedPipeline;
-pub use extract::extract_texts;
+pub use extract::{extract_rich_texts, extract_texts};
 pub use font_mapping::*;
 
 #[derive(Default)]
@@ -28,6 +28,7 @@ impl Plugin for TextRendererPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_plugin(KayakFontPlugin)
             .init_resource::<FontMapping>()
+            .init_resource::<GlyphPositioning>()
             .add_system(process_loaded_fonts);
 
         let render_app = app.sub_app_mut(RenderApp);
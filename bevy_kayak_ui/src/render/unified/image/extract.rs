@@ -1,5 +1,10 @@
-use bevy::{math::Vec2, prelude::Res, render::color::Color, sprite::Rect};
-use kayak_core::render_primitive::RenderPrimitive;
+use bevy::{
+    math::Vec2,
+    prelude::{Assets, Res},
+    render::{color::Color, texture::Image},
+    sprite::Rect,
+};
+use kayak_core::{render_command::ImageFit, render_primitive::RenderPrimitive};
 
 use crate::{
     render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
@@ -23,7 +28,7 @@ pub fn extract_images(
                 max: Vec2::new(layout.posx + layout.width, layout.posy + layout.height) * dpi,
             },
             color: Color::WHITE,
-            vertex_index: 0,
+            instance_index: 0,
             char_id: 0,
             z_index: layout.z_index,
             font_handle: None,
@@ -34,7 +39,90 @@ pub fn extract_images(
                 .get_handle(handle)
                 .and_then(|a| Some(a.clone_weak())),
             uv_max: None,
+            clip: None,
             uv_min: None,
         },
     }]
 }
+
+pub fn extract_textures(
+    render_primitive: &RenderPrimitive,
+    image_manager: &Res<ImageManager>,
+    images: &Res<Assets<Image>>,
+    dpi: f32,
+) -> Vec<ExtractQuadBundle> {
+    let (layout, handle, uv_min, uv_max, fit) = match render_primitive {
+        RenderPrimitive::Texture {
+            layout,
+            handle,
+            uv_min,
+            uv_max,
+            fit,
+        } => (layout, handle, *uv_min, *uv_max, *fit),
+        _ => panic!(""),
+    };
+
+    let image_handle = match image_manager.get_handle(handle) {
+        Some(image_handle) => image_handle.clone_weak(),
+        None => return vec![],
+    };
+    // The image may change size between frames (e.g. a resized render target)
+    let image_size = match images.get(&image_handle) {
+        Some(image) => Vec2::new(
+            image.texture_descriptor.size.width as f32,
+            image.texture_descriptor.size.height as f32,
+        ),
+        None => return vec![],
+    };
+
+    let mut uv_min = Vec2::new(uv_min.0, uv_min.1);
+    let mut uv_max = Vec2::new(uv_max.0, uv_max.1);
+    let region_size = image_size * (uv_max - uv_min).abs();
+    let mut min = Vec2::new(layout.posx, layout.posy);
+    let mut max = Vec2::new(layout.posx + layout.width, layout.posy + layout.height);
+    if region_size.x > 0.0 && region_size.y > 0.0 && layout.width > 0.0 && layout.height > 0.0 {
+        let scale_x = layout.width / region_size.x;
+        let scale_y = layout.height / region_size.y;
+        match fit {
+            ImageFit::Fill => {}
+            ImageFit::Contain => {
+                // Shrink the quad to the scaled region, centered within the layout
+                let size = region_size * scale_x.min(scale_y);
+                let center = (min + max) / 2.0;
+                min = center - size / 2.0;
+                max = center + size / 2.0;
+            }
+            ImageFit::Cover => {
+                // Shrink the region to the part of it that fits within the layout, keeping its center
+                let visible = Vec2::new(layout.width, layout.height) / scale_x.max(scale_y);
+                let portion = visible / region_size;
+                let center = (uv_min + uv_max) / 2.0;
+                let half_extent = (uv_max - uv_min) * portion / 2.0;
+                uv_min = center - half_extent;
+                uv_max = center + half_extent;
+            }
+        }
+    }
+
+    vec![ExtractQuadBundle {
+        extracted_quad: ExtractedQuad {
+            rect: Rect {
+                min: min * dpi,
+                max: max * dpi,
+            },
+            color: Color::WHITE,
+            instance_index: 0,
+            char_id: 0,
+            z_index: layout.z_index,
+            font_handle: None,
+            quad_type: UIQuadType::Image,
+            type_index: 0,
+            border_radius: (0.0, 0.0, 0.0, 0.0),
+            image: Some(image_handle),
+            // The shader flips the V coordinate, and the quad's top uses the max
+            uv_min: Some(Vec2::new(uv_min.x, 1.0 - uv_max.y)),
+            uv_max: Some(Vec2::new(uv_max.x, 1.0 - uv_min.y)),
+            clip: None,
+        },
+    }]
+}
@@ -1,10 +1,26 @@
-use bevy::{prelude::Handle, render::texture::Image, utils::HashMap};
+use bevy::{
+    asset::{AssetPath, LoadState},
+    prelude::Handle,
+    prelude::{AssetServer, Assets},
+    render::{render_resource::TextureFormat, texture::Image},
+    utils::{HashMap, HashSet},
+};
+use kayak_core::{bind, Binding, HitMask};
 
 #[derive(Debug, Clone)]
 pub struct ImageManager {
     count: u16,
     mapping: HashMap<u16, Handle<Image>>,
     reverse_mapping: HashMap<Handle<Image>, u16>,
+    /// Images that have been rendered by at least one widget since they were last (re)acquired
+    in_use: HashSet<u16>,
+    /// Images that are kept loaded even while no widget uses them
+    preloaded: HashSet<u16>,
+    /// The paths of the released images, to load them again once widgets use them
+    paths: HashMap<u16, AssetPath<'static>>,
+    load_states: HashMap<u16, Binding<LoadState>>,
+    /// The alpha masks read from images so far, to hit test them
+    hit_masks: HashMap<u16, HitMask>,
 }
 
 impl ImageManager {
@@ -13,22 +29,162 @@ impl ImageManager {
             count: 0,
             mapping: HashMap::default(),
             reverse_mapping: HashMap::default(),
+            in_use: HashSet::default(),
+            preloaded: HashSet::default(),
+            paths: HashMap::default(),
+            load_states: HashMap::default(),
+            hit_masks: HashMap::default(),
         }
     }
 
+    /// Get the ID of the given image, to be used as an `Image` or `NinePatch` handle
+    ///
+    /// The image is kept loaded for as long as widgets are using it. Once the last widget using it
+    /// is removed, the manager only keeps a weak handle, and loads the image again from its path
+    /// when a widget uses the ID again. So the ID stays valid, and can be kept instead of the
+    /// handle. Images without a path (e.g. added directly to the assets) can't be loaded again,
+    /// so they're never released. Calling this method again with a strong handle keeps the same ID
+    /// and makes the manager hold onto the image again.
     pub fn get(&mut self, image_handle: &Handle<Image>) -> u16 {
         if let Some(id) = self.reverse_mapping.get(image_handle) {
-            return *id;
+            let id = *id;
+            if let Some(handle) = self.mapping.get_mut(&id) {
+                if handle.is_weak() && image_handle.is_strong() {
+                    *handle = image_handle.clone();
+                }
+            }
+            return id;
         } else {
             let id = self.count;
             self.count += 1;
             self.mapping.insert(id, image_handle.clone());
             self.reverse_mapping.insert(image_handle.clone_weak(), id);
+            self.load_states.insert(id, bind(LoadState::NotLoaded));
             return id;
         }
     }
 
+    /// Get the ID of the given image and keep it loaded, even while no widget is using it
+    ///
+    /// This is useful for images that should display immediately when a screen is opened. Call
+    /// [release](Self::release) to let the image be unloaded again.
+    pub fn preload(&mut self, image_handle: &Handle<Image>) -> u16 {
+        let id = self.get(image_handle);
+        self.preloaded.insert(id);
+        id
+    }
+
+    /// Stop keeping the given preloaded image loaded while unused
+    pub fn release(&mut self, id: u16) {
+        self.preloaded.remove(&id);
+    }
+
+    /// Point the given ID at another image, without changing the widgets that render it
+    ///
+    /// This is meant for images that are swapped often (e.g. a video decoded into a new image
+    /// every frame): widgets rendering the ID draw the new image from the next frame on, without
+    /// having to re-render. Returns false if the ID is unknown.
+    pub fn replace(&mut self, id: u16, image_handle: &Handle<Image>) -> bool {
+        let previous = match self.mapping.get_mut(&id) {
+            Some(handle) => std::mem::replace(handle, image_handle.clone()),
+            None => return false,
+        };
+        if previous == *image_handle {
+            return true;
+        }
+        self.hit_masks.remove(&id);
+
+        if self.reverse_mapping.get(&previous) == Some(&id) {
+            self.reverse_mapping.remove(&previous);
+        }
+        // The load state is updated for the new image along with the others
+        self.reverse_mapping.insert(image_handle.clone_weak(), id);
+        true
+    }
+
     pub fn get_handle(&self, id: &u16) -> Option<&Handle<Image>> {
         self.mapping.get(id)
     }
+
+    /// Get a binding to the load state of the given image
+    ///
+    /// Widgets can bind to this in order to re-render once the image finishes loading.
+    pub fn get_load_state(&self, id: &u16) -> Option<Binding<LoadState>> {
+        self.load_states.get(id).cloned()
+    }
+
+    /// Get the alpha mask of the given image, used to only hit its opaque pixels
+    ///
+    /// The mask is read from the decoded image the first time it's needed, so this returns `None`
+    /// until the image is loaded. Only 8-bit RGBA and BGRA images are supported.
+    pub fn get_hit_mask(&mut self, id: u16, images: &Assets<Image>) -> Option<HitMask> {
+        if let Some(hit_mask) = self.hit_masks.get(&id) {
+            return Some(hit_mask.clone());
+        }
+
+        let image = images.get(self.mapping.get(&id)?)?;
+        match image.texture_descriptor.format {
+            TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb => {}
+            _ => return None,
+        }
+        let size = image.texture_descriptor.size;
+        let hit_mask = HitMask::from_alpha(size.width as usize, size.height as usize, &image.data);
+        self.hit_masks.insert(id, hit_mask.clone());
+        Some(hit_mask)
+    }
+
+    pub(crate) fn iter_load_states(
+        &self,
+    ) -> impl Iterator<Item = (&Handle<Image>, &Binding<LoadState>)> {
+        self.load_states
+            .iter()
+            .filter_map(|(id, state)| Some((self.mapping.get(id)?, state)))
+    }
+
+    /// Update the set of images currently used by widgets
+    ///
+    /// Images that were in use but no longer are (and aren't preloaded) are downgraded to weak
+    /// handles so they can be unloaded, and released images that are used again are loaded again.
+    /// Images that haven't been used yet are left untouched.
+    pub fn update_usage(
+        &mut self,
+        used: &std::collections::HashSet<u16>,
+        asset_server: &AssetServer,
+    ) {
+        let unused = self
+            .in_use
+            .iter()
+            .filter(|id| !used.contains(id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for id in unused {
+            self.in_use.remove(&id);
+            if self.preloaded.contains(&id) {
+                continue;
+            }
+            if let Some(handle) = self.mapping.get_mut(&id) {
+                if let Some(path) = asset_server.get_handle_path(&*handle) {
+                    self.paths.insert(id, path.to_owned());
+                    *handle = handle.clone_weak();
+                }
+            }
+        }
+
+        for id in used {
+            if let Some(handle) = self.mapping.get_mut(id) {
+                if handle.is_weak() {
+                    if let Some(path) = self.paths.get(id) {
+                        *handle = asset_server.load(path.clone());
+                    }
+                }
+                if handle.is_strong() {
+                    self.in_use.insert(*id);
+                }
+            }
+        }
+    }
 }
@@ -1,14 +1,55 @@
-use bevy::prelude::Plugin;
+use bevy::{
+    asset::LoadState,
+    prelude::{AssetServer, Assets, Plugin, Res, ResMut},
+    render::texture::Image,
+};
+use kayak_core::{Bound, MutableBound};
+
+use crate::BevyContext;
 
 mod extract;
 mod image_manager;
-pub use extract::extract_images;
+pub use extract::{extract_images, extract_textures};
 pub use image_manager::ImageManager;
 
 pub struct ImageRendererPlugin;
 
 impl Plugin for ImageRendererPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.insert_resource(ImageManager::new());
+        app.insert_resource(ImageManager::new())
+            .add_system(update_image_usage)
+            .add_system(update_image_load_states);
+    }
+}
+
+/// Lets the [ImageManager] release images that are no longer used by any widget
+fn update_image_usage(
+    bevy_context: Res<BevyContext>,
+    asset_server: Res<AssetServer>,
+    mut image_manager: ResMut<ImageManager>,
+) {
+    if let Ok(context) = bevy_context.kayak_context.read() {
+        let used = context.widget_manager.get_image_handles();
+        image_manager.update_usage(&used, &asset_server);
+    }
+}
+
+/// Updates the load state bindings of all images known to the [ImageManager]
+fn update_image_load_states(
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    image_manager: Res<ImageManager>,
+) {
+    for (handle, load_state) in image_manager.iter_load_states() {
+        let next_state = if images.get(handle).is_some() {
+            // Images added directly to the assets (rather than loaded) are unknown to the asset server
+            LoadState::Loaded
+        } else {
+            asset_server.get_load_state(handle)
+        };
+
+        if load_state.get() != next_state {
+            load_state.set(next_state);
+        }
     }
 }
@@ -0,0 +1,100 @@
+use std::ops::Range;
+
+use bevy::render::{
+    render_resource::{Buffer, BufferDescriptor, BufferUsages},
+    renderer::{RenderDevice, RenderQueue},
+};
+use bytemuck::Pod;
+
+/// The number of unchanged instances allowed within a single upload
+///
+/// Merging nearby changes into one write is cheaper than issuing a write for every change.
+const MERGE_GAP: usize = 16;
+
+/// A GPU buffer of instances that persists across frames
+///
+/// Each frame, the instances are pushed again from scratch. When written, they are compared to the
+/// instances already on the GPU and only the ranges that changed are uploaded. The buffer is only
+/// recreated when it needs to grow.
+pub struct InstanceBuffer<T: Pod + PartialEq> {
+    buffer: Option<Buffer>,
+    capacity: usize,
+    /// The instances currently stored in the buffer
+    uploaded: Vec<T>,
+    /// The instances pushed for the current frame
+    values: Vec<T>,
+    label: &'static str,
+}
+
+impl<T: Pod + PartialEq> InstanceBuffer<T> {
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            buffer: None,
+            capacity: 0,
+            uploaded: Vec::new(),
+            values: Vec::new(),
+            label,
+        }
+    }
+
+    pub fn buffer(&self) -> Option<&Buffer> {
+        self.buffer.as_ref()
+    }
+
+    /// Adds an instance for the current frame, returning its index
+    pub fn push(&mut self, value: T) -> usize {
+        self.values.push(value);
+        self.values.len() - 1
+    }
+
+    /// Removes the instances pushed for the current frame (the buffer itself is kept)
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Uploads the instances that changed since the last write
+    pub fn write_buffer(&mut self, render_device: &RenderDevice, render_queue: &RenderQueue) {
+        if self.values.is_empty() {
+            return;
+        }
+
+        if self.values.len() > self.capacity || self.buffer.is_none() {
+            self.capacity = self.values.len().next_power_of_two();
+            self.buffer = Some(render_device.create_buffer(&BufferDescriptor {
+                label: Some(self.label),
+                size: (self.capacity * std::mem::size_of::<T>()) as u64,
+                usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+            // The new buffer is empty, so everything needs to be uploaded
+            self.uploaded.clear();
+        }
+
+        let buffer = self.buffer.as_ref().unwrap();
+        for range in changed_ranges(&self.uploaded, &self.values) {
+            let offset = (range.start * std::mem::size_of::<T>()) as u64;
+            render_queue.write_buffer(buffer, offset, bytemuck::cast_slice(&self.values[range]));
+        }
+
+        std::mem::swap(&mut self.uploaded, &mut self.values);
+        self.values.clear();
+    }
+}
+
+/// Finds the ranges of `next` that differ from `previous`, merging ranges separated by fewer than
+/// [MERGE_GAP] unchanged items
+fn changed_ranges<T: PartialEq>(previous: &[T], next: &[T]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (index, value) in next.iter().enumerate() {
+        if previous.get(index) == Some(value) {
+            continue;
+        }
+
+        match ranges.last_mut() {
+            Some(range) if index - range.end < MERGE_GAP => range.end = index + 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+
+    ranges
+}
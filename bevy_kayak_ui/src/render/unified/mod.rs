@@ -9,21 +9,25 @@ use bevy::{
     sprite::Rect,
     window::Windows,
 };
-use kayak_core::{render_primitive::RenderPrimitive, Binding, Bound};
-use kayak_font::KayakFont;
+use kayak_core::{layout_cache::Rect as Layout, render_primitive::RenderPrimitive, Binding, Bound};
+use kayak_font::{GlyphPositioning, KayakFont};
 
 use crate::{
     render::{
         ui_pass::TransparentUI,
         unified::pipeline::{DrawUI, QuadMeta, UnifiedPipeline},
     },
-    BevyContext, FontMapping, ImageManager, WindowSize,
+    BevyContext, FontMapping, GamepadCursor, ImageManager, UICrossfade, WindowSize,
 };
 
-use self::pipeline::{ExtractQuadBundle, ExtractedQuad, ImageBindGroups, UIQuadType};
+use self::pipeline::{
+    ExtractQuadBundle, ExtractedQuad, ImageAssetEvents, ImageBindGroups, UIQuadType,
+};
 
+mod cursor;
 pub mod font;
 pub mod image;
+mod instance_buffer;
 mod nine_patch;
 mod pipeline;
 mod quad;
@@ -33,6 +37,25 @@ pub const UNIFIED_SHADER_HANDLE: HandleUntyped =
 
 pub struct UnifiedRenderPlugin;
 
+/// How the edges of UI quads are anti-aliased
+///
+/// The pipeline is created with this setting, so it needs to be inserted before adding the
+/// [BevyKayakUIPlugin](crate::BevyKayakUIPlugin).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UIAntiAliasing {
+    /// Only rounded corners are smoothed
+    None,
+    /// Quad edges and rounded corners fade out over a pixel based on their distance to the
+    /// fragment, which keeps fractional positions and scales from aliasing
+    Analytic,
+}
+
+impl Default for UIAntiAliasing {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
 impl Plugin for UnifiedRenderPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         let mut shaders = app.world.get_resource_mut::<Assets<Shader>>().unwrap();
@@ -42,12 +65,21 @@ impl Plugin for UnifiedRenderPlugin {
         app.add_plugin(font::TextRendererPlugin)
             .add_plugin(image::ImageRendererPlugin);
 
+        let antialiasing = app
+            .world
+            .get_resource::<UIAntiAliasing>()
+            .copied()
+            .unwrap_or_default();
+
         let render_app = app.sub_app_mut(RenderApp);
         render_app
+            .insert_resource(antialiasing)
             .init_resource::<ImageBindGroups>()
+            .init_resource::<ImageAssetEvents>()
             .init_resource::<UnifiedPipeline>()
             .init_resource::<QuadMeta>()
             .add_system_to_stage(RenderStage::Extract, extract)
+            .add_system_to_stage(RenderStage::Extract, pipeline::extract_image_events)
             .add_system_to_stage(RenderStage::Prepare, pipeline::prepare_quads)
             .add_system_to_stage(RenderStage::Queue, pipeline::queue_quads);
 
@@ -64,18 +96,35 @@ impl Plugin for UnifiedRenderPlugin {
 
 pub struct Dpi(f32);
 
+/// How far below the current root the outgoing root of a crossfade is drawn
+const CROSSFADE_Z_OFFSET: f32 = 10000.0;
+
 pub fn extract(
     mut commands: Commands,
     context: Res<BevyContext>,
+    crossfade: Res<UICrossfade>,
     fonts: Res<Assets<KayakFont>>,
     font_mapping: Res<FontMapping>,
+    glyph_positioning: Res<GlyphPositioning>,
     image_manager: Res<ImageManager>,
     images: Res<Assets<Image>>,
     windows: Res<Windows>,
     window_size: Res<Binding<WindowSize>>,
+    gamepad_cursor: Res<GamepadCursor>,
 ) {
+    // Primitives outside of the window are never drawn, so skip extracting them
+    let viewport = Layout {
+        posx: 0.0,
+        posy: 0.0,
+        width: window_size.get().0,
+        height: window_size.get().1,
+        z_index: 0.0,
+    };
+
     let render_primitives = if let Ok(context) = context.kayak_context.read() {
-        context.widget_manager.build_render_primitives()
+        context
+            .widget_manager
+            .build_visible_render_primitives(viewport)
     } else {
         vec![]
     };
@@ -89,52 +138,193 @@ pub fn extract(
     };
 
     let mut extracted_quads = Vec::new();
+
+    let mut alpha = 1.0;
+    if let (Some(progress), Some(outgoing)) = (crossfade.progress(), &crossfade.outgoing) {
+        let outgoing_primitives = if let Ok(context) = outgoing.kayak_context.read() {
+            context
+                .widget_manager
+                .build_visible_render_primitives(viewport)
+        } else {
+            vec![]
+        };
+
+        let mut outgoing_quads = extract_primitives(
+            outgoing_primitives,
+            &fonts,
+            &font_mapping,
+            *glyph_positioning,
+            &image_manager,
+            &images,
+            dpi,
+            1.0 - progress,
+        );
+        for quad in outgoing_quads.iter_mut() {
+            quad.extracted_quad.z_index -= CROSSFADE_Z_OFFSET;
+        }
+        extracted_quads.extend(outgoing_quads);
+
+        // Reset the clip so the current root isn't cut off by the outgoing root's last clipped widget
+        extracted_quads.push(extract_clip(
+            &Layout {
+                posx: 0.0,
+                posy: 0.0,
+                width: window_size.get().0,
+                height: window_size.get().1,
+                z_index: -CROSSFADE_Z_OFFSET / 2.0,
+            },
+            dpi,
+        ));
+
+        alpha = progress;
+    }
+
+    extracted_quads.extend(extract_primitives(
+        render_primitives,
+        &fonts,
+        &font_mapping,
+        *glyph_positioning,
+        &image_manager,
+        &images,
+        dpi,
+        alpha,
+    ));
+
+    if gamepad_cursor.enabled && gamepad_cursor.visible {
+        let cursor_quads = cursor::extract_cursor(&gamepad_cursor, window_size.get(), dpi);
+        extracted_quads.extend(cursor_quads);
+    }
+
+    commands.insert_resource(window_size.get());
+    commands.insert_resource(Dpi(dpi));
+    commands.spawn_batch(extracted_quads);
+}
+
+/// Extracts the quads for a set of render primitives, applying the given opacity to all of them
+fn extract_primitives(
+    render_primitives: Vec<RenderPrimitive>,
+    fonts: &Res<Assets<KayakFont>>,
+    font_mapping: &Res<FontMapping>,
+    glyph_positioning: GlyphPositioning,
+    image_manager: &Res<ImageManager>,
+    images: &Res<Assets<Image>>,
+    dpi: f32,
+    alpha: f32,
+) -> Vec<ExtractQuadBundle> {
+    let mut extracted_quads = Vec::new();
+    // The clip rect of the primitives that follow
+    let mut clip = None;
+    // Canvases are drawn with the same primitives as everything else
+    let render_primitives =
+        render_primitives
+            .into_iter()
+            .flat_map(|render_primitive| match render_primitive {
+                RenderPrimitive::Canvas { layout, shapes } => shapes
+                    .iter()
+                    .map(|shape| shape.to_primitive(&layout))
+                    .collect(),
+                render_primitive => vec![render_primitive],
+            });
     for render_primitive in render_primitives {
+        let first_quad = extracted_quads.len();
         match render_primitive {
             RenderPrimitive::Text { .. } => {
-                let text_quads = font::extract_texts(&render_primitive, &fonts, &font_mapping, dpi);
+                let text_quads = font::extract_texts(
+                    &render_primitive,
+                    fonts,
+                    font_mapping,
+                    glyph_positioning,
+                    dpi,
+                );
+                extracted_quads.extend(text_quads);
+            }
+            RenderPrimitive::RichText { .. } => {
+                let text_quads = font::extract_rich_texts(
+                    &render_primitive,
+                    fonts,
+                    font_mapping,
+                    glyph_positioning,
+                    dpi,
+                );
                 extracted_quads.extend(text_quads);
             }
             RenderPrimitive::Image { .. } => {
-                let image_quads = image::extract_images(&render_primitive, &image_manager, dpi);
+                let image_quads = image::extract_images(&render_primitive, image_manager, dpi);
                 extracted_quads.extend(image_quads);
             }
-            RenderPrimitive::Quad { .. } => {
+            RenderPrimitive::Texture { .. } => {
+                let texture_quads =
+                    image::extract_textures(&render_primitive, image_manager, images, dpi);
+                extracted_quads.extend(texture_quads);
+            }
+            RenderPrimitive::Quad { .. }
+            | RenderPrimitive::RadialFill { .. }
+            | RenderPrimitive::Sector { .. } => {
                 let quad_quads = quad::extract_quads(&render_primitive, dpi);
                 extracted_quads.extend(quad_quads);
             }
+            RenderPrimitive::Line { .. } => {
+                let quad_quads = quad::extract_lines(&render_primitive, dpi);
+                extracted_quads.extend(quad_quads);
+            }
+            RenderPrimitive::Caret { .. } => {
+                let quad_quads = quad::extract_carets(&render_primitive, dpi);
+                extracted_quads.extend(quad_quads);
+            }
+            RenderPrimitive::Highlight { .. } => {
+                let quad_quads = quad::extract_highlights(&render_primitive, dpi);
+                extracted_quads.extend(quad_quads);
+            }
             RenderPrimitive::NinePatch { .. } => {
                 let nine_patch_quads =
-                    nine_patch::extract_nine_patch(&render_primitive, &image_manager, &images, dpi);
+                    nine_patch::extract_nine_patch(&render_primitive, image_manager, images, dpi);
                 extracted_quads.extend(nine_patch_quads);
             }
             RenderPrimitive::Clip { layout } => {
-                extracted_quads.push(ExtractQuadBundle {
-                    extracted_quad: ExtractedQuad {
-                        rect: Rect {
-                            min: Vec2::new(layout.posx, layout.posy) * dpi,
-                            max: Vec2::new(layout.posx + layout.width, layout.posy + layout.height)
-                                * dpi,
-                        },
-                        color: Color::default(),
-                        vertex_index: 0,
-                        char_id: 0,
-                        z_index: layout.z_index,
-                        font_handle: None,
-                        quad_type: UIQuadType::Clip,
-                        type_index: 0,
-                        border_radius: (0.0, 0.0, 0.0, 0.0),
-                        image: None,
-                        uv_min: None,
-                        uv_max: None,
-                    },
-                });
+                let clip_quad = extract_clip(&layout, dpi);
+                clip = Some(clip_quad.extracted_quad.rect);
+                extracted_quads.push(clip_quad);
             }
             _ => {}
         }
+
+        // Each instance carries its clip rect, so it is clipped no matter when it is drawn
+        for quad in extracted_quads[first_quad..].iter_mut() {
+            if quad.extracted_quad.quad_type != UIQuadType::Clip {
+                quad.extracted_quad.clip = clip;
+            }
+        }
     }
 
-    commands.insert_resource(window_size.get());
-    commands.insert_resource(Dpi(dpi));
-    commands.spawn_batch(extracted_quads);
+    if alpha < 1.0 {
+        for quad in extracted_quads.iter_mut() {
+            let color = &mut quad.extracted_quad.color;
+            color.set_a(color.a() * alpha);
+        }
+    }
+
+    extracted_quads
+}
+
+fn extract_clip(layout: &Layout, dpi: f32) -> ExtractQuadBundle {
+    ExtractQuadBundle {
+        extracted_quad: ExtractedQuad {
+            rect: Rect {
+                min: Vec2::new(layout.posx, layout.posy) * dpi,
+                max: Vec2::new(layout.posx + layout.width, layout.posy + layout.height) * dpi,
+            },
+            color: Color::default(),
+            instance_index: 0,
+            char_id: 0,
+            z_index: layout.z_index,
+            font_handle: None,
+            quad_type: UIQuadType::Clip,
+            type_index: 0,
+            border_radius: (0.0, 0.0, 0.0, 0.0),
+            image: None,
+            uv_min: None,
+            uv_max: None,
+            clip: None,
+        },
+    }
 }
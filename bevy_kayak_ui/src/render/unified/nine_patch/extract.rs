@@ -54,7 +54,7 @@ pub fn extract_nine_patch(
             max: Vec2::ZERO,
         },
         color: Color::WHITE,
-        vertex_index: 0,
+        instance_index: 0,
         char_id: 0,
         z_index: layout.z_index,
         font_handle: None,
@@ -63,6 +63,7 @@ pub fn extract_nine_patch(
         border_radius: (0.0, 0.0, 0.0, 0.0),
         image: image_handle,
         uv_max: None,
+        clip: None,
         uv_min: None,
     };
 
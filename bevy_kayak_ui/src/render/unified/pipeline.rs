@@ -1,13 +1,17 @@
 use bevy::math::Size;
 use bevy::render::render_resource::std140::AsStd140;
 use bevy::{
+    asset::AssetEvent,
     core::FloatOrd,
     ecs::system::{
         lifetimeless::{Read, SQuery, SRes},
         SystemState,
     },
-    math::{const_vec3, Mat4, Quat, Vec2, Vec3, Vec4},
-    prelude::{Bundle, Component, Entity, FromWorld, Handle, Query, Res, ResMut, World},
+    math::Vec2,
+    prelude::{
+        Bundle, Commands, Component, Entity, EventReader, FromWorld, Handle, Query, Res, ResMut,
+        World,
+    },
     render::{
         color::Color,
         render_asset::RenderAssets,
@@ -15,12 +19,12 @@ use bevy::{
         render_resource::{
             BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout,
             BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingResource, BindingType,
-            BlendComponent, BlendFactor, BlendOperation, BlendState, BufferBindingType, BufferSize,
-            BufferUsages, BufferVec, CachedPipelineId, ColorTargetState, ColorWrites,
-            DynamicUniformVec, Extent3d, FragmentState, FrontFace, MultisampleState, PolygonMode,
-            PrimitiveState, PrimitiveTopology, RenderPipelineCache, RenderPipelineDescriptor,
-            SamplerBindingType, SamplerDescriptor, Shader, ShaderStages, TextureDescriptor,
-            TextureDimension, TextureFormat, TextureSampleType, TextureUsages,
+            BlendComponent, BlendFactor, BlendOperation, BlendState, Buffer, BufferBindingType,
+            BufferInitDescriptor, BufferSize, BufferUsages, CachedPipelineId, ColorTargetState,
+            ColorWrites, DynamicUniformVec, Extent3d, FragmentState, FrontFace, MultisampleState,
+            PolygonMode, PrimitiveState, PrimitiveTopology, RenderPipelineCache,
+            RenderPipelineDescriptor, SamplerBindingType, SamplerDescriptor, Shader, ShaderStages,
+            TextureDescriptor, TextureDimension, TextureFormat, TextureSampleType, TextureUsages,
             TextureViewDescriptor, TextureViewDimension, VertexAttribute, VertexBufferLayout,
             VertexFormat, VertexState, VertexStepMode,
         },
@@ -37,7 +41,7 @@ use kayak_font::{
     KayakFont,
 };
 
-use super::{Dpi, UNIFIED_SHADER_HANDLE};
+use super::{instance_buffer::InstanceBuffer, Dpi, UIAntiAliasing, UNIFIED_SHADER_HANDLE};
 use crate::{render::ui_pass::TransparentUI, WindowSize};
 
 pub struct UnifiedPipeline {
@@ -48,17 +52,22 @@ pub struct UnifiedPipeline {
     pipeline: CachedPipelineId,
     empty_font_texture: (GpuImage, BindGroup),
     default_image: (GpuImage, BindGroup),
+    /// The unit quad shared by all instances
+    quad_vertices: Buffer,
 }
 
-const QUAD_VERTEX_POSITIONS: &[Vec3] = &[
-    const_vec3!([0.0, 0.0, 0.0]),
-    const_vec3!([1.0, 1.0, 0.0]),
-    const_vec3!([0.0, 1.0, 0.0]),
-    const_vec3!([0.0, 0.0, 0.0]),
-    const_vec3!([1.0, 0.0, 0.0]),
-    const_vec3!([1.0, 1.0, 0.0]),
+const QUAD_VERTEX_POSITIONS: &[[f32; 2]] = &[
+    [0.0, 0.0],
+    [1.0, 1.0],
+    [0.0, 1.0],
+    [0.0, 0.0],
+    [1.0, 0.0],
+    [1.0, 1.0],
 ];
 
+/// The clip rect of instances that aren't clipped
+const NO_CLIP: [f32; 4] = [-1.0e9, -1.0e9, 1.0e9, 1.0e9];
+
 impl FontRenderingPipeline for UnifiedPipeline {
     fn get_font_image_layout(&self) -> &BindGroupLayout {
         &self.font_image_layout
@@ -150,44 +159,84 @@ impl FromWorld for UnifiedPipeline {
         });
 
         let vertex_buffer_layout = VertexBufferLayout {
-            array_stride: 60,
+            array_stride: 8,
             step_mode: VertexStepMode::Vertex,
+            attributes: vec![VertexAttribute {
+                format: VertexFormat::Float32x2,
+                offset: 0,
+                shader_location: 0,
+            }],
+        };
+
+        let instance_buffer_layout = VertexBufferLayout {
+            array_stride: 84,
+            step_mode: VertexStepMode::Instance,
             attributes: vec![
-                VertexAttribute {
-                    format: VertexFormat::Float32x3,
-                    offset: 0,
-                    shader_location: 0,
-                },
+                // Position and size
                 VertexAttribute {
                     format: VertexFormat::Float32x4,
-                    offset: 12,
+                    offset: 0,
                     shader_location: 1,
                 },
+                // Color
                 VertexAttribute {
                     format: VertexFormat::Float32x4,
-                    offset: 28,
+                    offset: 16,
                     shader_location: 2,
                 },
+                // UV min and max
                 VertexAttribute {
                     format: VertexFormat::Float32x4,
-                    offset: 44,
+                    offset: 32,
                     shader_location: 3,
                 },
+                // Border radius
+                VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: 48,
+                    shader_location: 4,
+                },
+                // Clip rect
+                VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: 64,
+                    shader_location: 5,
+                },
+                // Texture layer
+                VertexAttribute {
+                    format: VertexFormat::Float32,
+                    offset: 80,
+                    shader_location: 6,
+                },
             ],
         };
 
+        let quad_vertices = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("ui_quad_vertices"),
+            contents: bytemuck::cast_slice(QUAD_VERTEX_POSITIONS),
+            usage: BufferUsages::VERTEX,
+        });
+
         let empty_font_texture = FontTextureCache::get_empty(&render_device, &font_image_layout);
 
+        let antialiasing = world
+            .get_resource::<UIAntiAliasing>()
+            .map_or(UIAntiAliasing::default(), |antialiasing| *antialiasing);
+        let shader_defs = match antialiasing {
+            UIAntiAliasing::None => vec![],
+            UIAntiAliasing::Analytic => vec!["ANALYTIC_AA".to_string()],
+        };
+
         let pipeline_desc = RenderPipelineDescriptor {
             vertex: VertexState {
                 shader: UNIFIED_SHADER_HANDLE.typed::<Shader>(),
                 entry_point: "vertex".into(),
-                shader_defs: vec![],
-                buffers: vec![vertex_buffer_layout],
+                shader_defs: shader_defs.clone(),
+                buffers: vec![vertex_buffer_layout, instance_buffer_layout],
             },
             fragment: Some(FragmentState {
                 shader: UNIFIED_SHADER_HANDLE.typed::<Shader>(),
-                shader_defs: vec![],
+                shader_defs,
                 entry_point: "fragment".into(),
                 targets: vec![ColorTargetState {
                     format: TextureFormat::bevy_default(),
@@ -293,6 +342,7 @@ impl FromWorld for UnifiedPipeline {
             types_layout,
             image_layout,
             default_image: (image, binding),
+            quad_vertices,
         }
     }
 }
@@ -307,6 +357,9 @@ pub enum UIQuadType {
     Quad,
     Text,
     Image,
+    RadialFill,
+    Line,
+    Sector,
     Clip,
 }
 
@@ -314,7 +367,8 @@ pub enum UIQuadType {
 pub struct ExtractedQuad {
     pub rect: Rect,
     pub color: Color,
-    pub vertex_index: usize,
+    /// The index of the quad within the instance buffer
+    pub instance_index: usize,
     pub char_id: u32,
     pub z_index: f32,
     pub font_handle: Option<Handle<KayakFont>>,
@@ -324,15 +378,19 @@ pub struct ExtractedQuad {
     pub image: Option<Handle<Image>>,
     pub uv_min: Option<Vec2>,
     pub uv_max: Option<Vec2>,
+    /// The rect (in physical pixels) outside of which the quad is discarded
+    pub clip: Option<Rect>,
 }
 
 #[repr(C)]
-#[derive(Copy, Clone, Pod, Zeroable)]
-struct QuadVertex {
-    pub position: [f32; 3],
-    pub color: [f32; 4],
-    pub uv: [f32; 4],
+#[derive(Copy, Clone, PartialEq, Pod, Zeroable)]
+struct QuadInstance {
     pub pos_size: [f32; 4],
+    pub color: [f32; 4],
+    pub uv_min_max: [f32; 4],
+    pub border_radius: [f32; 4],
+    pub clip: [f32; 4],
+    pub layer: f32,
 }
 
 #[repr(C)]
@@ -342,7 +400,7 @@ struct QuadType {
 }
 
 pub struct QuadMeta {
-    vertices: BufferVec<QuadVertex>,
+    instances: InstanceBuffer<QuadInstance>,
     view_bind_group: Option<BindGroup>,
     types_buffer: DynamicUniformVec<QuadType>,
     types_bind_group: Option<BindGroup>,
@@ -351,7 +409,7 @@ pub struct QuadMeta {
 impl Default for QuadMeta {
     fn default() -> Self {
         Self {
-            vertices: BufferVec::new(BufferUsages::VERTEX),
+            instances: InstanceBuffer::new("ui_quad_instances"),
             view_bind_group: None,
             types_buffer: DynamicUniformVec::default(),
             types_bind_group: None,
@@ -364,10 +422,39 @@ pub struct ImageBindGroups {
     values: HashMap<Handle<Image>, BindGroup>,
 }
 
+/// The image asset events of the current frame, extracted so that the bind groups of modified
+/// images are recreated (e.g. when a render target is resized or a video frame is uploaded)
+#[derive(Default)]
+pub struct ImageAssetEvents {
+    images: Vec<AssetEvent<Image>>,
+}
+
+pub fn extract_image_events(
+    mut commands: Commands,
+    mut image_events: EventReader<AssetEvent<Image>>,
+) {
+    let images = image_events
+        .iter()
+        .map(|event| match event {
+            AssetEvent::Created { handle } => AssetEvent::Created {
+                handle: handle.clone_weak(),
+            },
+            AssetEvent::Modified { handle } => AssetEvent::Modified {
+                handle: handle.clone_weak(),
+            },
+            AssetEvent::Removed { handle } => AssetEvent::Removed {
+                handle: handle.clone_weak(),
+            },
+        })
+        .collect();
+    commands.insert_resource(ImageAssetEvents { images });
+}
+
 pub fn prepare_quads(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
     mut sprite_meta: ResMut<QuadMeta>,
+    mut font_texture_cache: ResMut<FontTextureCache>,
     mut extracted_quads: Query<&mut ExtractedQuad>,
 ) {
     let extracted_sprite_len = extracted_quads.iter_mut().len();
@@ -377,95 +464,85 @@ pub fn prepare_quads(
     }
 
     sprite_meta.types_buffer.clear();
-    sprite_meta.types_buffer.reserve(2, &render_device);
+    sprite_meta.types_buffer.reserve(6, &render_device);
     let quad_type_offset = sprite_meta.types_buffer.push(QuadType { t: 0 });
     let text_type_offset = sprite_meta.types_buffer.push(QuadType { t: 1 });
     let image_type_offset = sprite_meta.types_buffer.push(QuadType { t: 2 });
+    let radial_fill_type_offset = sprite_meta.types_buffer.push(QuadType { t: 3 });
+    let line_type_offset = sprite_meta.types_buffer.push(QuadType { t: 4 });
+    let sector_type_offset = sprite_meta.types_buffer.push(QuadType { t: 5 });
     sprite_meta
         .types_buffer
         .write_buffer(&render_device, &render_queue);
 
-    sprite_meta.vertices.clear();
-    sprite_meta.vertices.reserve(
-        extracted_sprite_len * QUAD_VERTEX_POSITIONS.len(),
-        &render_device,
-    );
+    sprite_meta.instances.clear();
 
     for (i, mut extracted_sprite) in extracted_quads
         .iter_mut()
         .filter(|es| es.quad_type != UIQuadType::Clip)
         .enumerate()
     {
-        let sprite_rect = extracted_sprite.rect;
+        // Glyphs are stored in the font's texture array on demand
+        let layer = match (
+            extracted_sprite.quad_type,
+            extracted_sprite.font_handle.as_ref(),
+        ) {
+            (UIQuadType::Text, Some(font_handle)) => {
+                font_texture_cache.get_glyph_layer(font_handle, extracted_sprite.char_id)
+            }
+            _ => Some(extracted_sprite.char_id),
+        };
+        let sprite_rect = if layer.is_some() {
+            extracted_sprite.rect
+        } else {
+            // The font's atlas is full, so skip the glyph for this frame
+            Rect {
+                min: extracted_sprite.rect.min,
+                max: extracted_sprite.rect.min,
+            }
+        };
+        let layer = layer.unwrap_or_default() as f32;
         let color = extracted_sprite.color.as_linear_rgba_f32();
 
         match extracted_sprite.quad_type {
             UIQuadType::Quad => extracted_sprite.type_index = quad_type_offset,
             UIQuadType::Text => extracted_sprite.type_index = text_type_offset,
             UIQuadType::Image => extracted_sprite.type_index = image_type_offset,
+            UIQuadType::RadialFill => extracted_sprite.type_index = radial_fill_type_offset,
+            UIQuadType::Line => extracted_sprite.type_index = line_type_offset,
+            UIQuadType::Sector => extracted_sprite.type_index = sector_type_offset,
             UIQuadType::Clip => {}
         };
 
         let uv_min = extracted_sprite.uv_min.unwrap_or(Vec2::ZERO);
         let uv_max = extracted_sprite.uv_max.unwrap_or(Vec2::ONE);
 
-        let bottom_left = Vec4::new(
-            uv_min.x,
-            uv_max.y,
-            extracted_sprite.char_id as f32,
-            extracted_sprite.border_radius.0,
-        );
-        let top_left = Vec4::new(
-            uv_min.x,
-            uv_min.y,
-            extracted_sprite.char_id as f32,
-            extracted_sprite.border_radius.1,
-        );
-        let top_right = Vec4::new(
-            uv_max.x,
-            uv_min.y,
-            extracted_sprite.char_id as f32,
-            extracted_sprite.border_radius.2,
-        );
-        let bottom_right = Vec4::new(
-            uv_max.x,
-            uv_max.y,
-            extracted_sprite.char_id as f32,
-            extracted_sprite.border_radius.3,
-        );
-
-        let uvs: [[f32; 4]; 6] = [
-            bottom_left.into(),
-            top_right.into(),
-            top_left.into(),
-            bottom_left.into(),
-            bottom_right.into(),
-            top_right.into(),
-        ];
-
-        extracted_sprite.vertex_index = i;
-        for (index, vertex_position) in QUAD_VERTEX_POSITIONS.iter().enumerate() {
-            let world = Mat4::from_scale_rotation_translation(
-                sprite_rect.size().extend(1.0),
-                Quat::default(),
-                sprite_rect.min.extend(0.0),
-            );
-            let final_position = (world * Vec3::from(*vertex_position).extend(1.0)).truncate();
-            sprite_meta.vertices.push(QuadVertex {
-                position: final_position.into(),
-                color,
-                uv: uvs[index],
-                pos_size: [
-                    sprite_rect.min.x,
-                    sprite_rect.min.y,
-                    sprite_rect.size().x,
-                    sprite_rect.size().y,
-                ],
-            });
-        }
+        let clip = extracted_sprite.clip.map_or(NO_CLIP, |clip| {
+            [clip.min.x, clip.min.y, clip.max.x, clip.max.y]
+        });
+
+        extracted_sprite.instance_index = i;
+        sprite_meta.instances.push(QuadInstance {
+            pos_size: [
+                sprite_rect.min.x,
+                sprite_rect.min.y,
+                sprite_rect.size().x,
+                sprite_rect.size().y,
+            ],
+            color,
+            uv_min_max: [uv_min.x, uv_min.y, uv_max.x, uv_max.y],
+            border_radius: [
+                extracted_sprite.border_radius.0,
+                extracted_sprite.border_radius.1,
+                extracted_sprite.border_radius.2,
+                extracted_sprite.border_radius.3,
+            ],
+            clip,
+            layer,
+        });
     }
     sprite_meta
-        .vertices
+        .instances
         .write_buffer(&render_device, &render_queue);
 }
 
@@ -478,9 +555,20 @@ pub fn queue_quads(
     mut extracted_sprites: Query<(Entity, &ExtractedQuad)>,
     mut views: Query<&mut RenderPhase<TransparentUI>>,
     mut image_bind_groups: ResMut<ImageBindGroups>,
+    image_events: Res<ImageAssetEvents>,
     unified_pipeline: Res<UnifiedPipeline>,
     gpu_images: Res<RenderAssets<Image>>,
 ) {
+    // The bind groups of changed images point to their old textures
+    for event in &image_events.images {
+        match event {
+            AssetEvent::Created { .. } => {}
+            AssetEvent::Modified { handle } | AssetEvent::Removed { handle } => {
+                image_bind_groups.values.remove(handle);
+            }
+        }
+    }
+
     if let Some(type_binding) = sprite_meta.types_buffer.binding() {
         sprite_meta.types_bind_group =
             Some(render_device.create_bind_group(&BindGroupDescriptor {
@@ -610,8 +698,10 @@ impl Draw<TransparentUI> for DrawUI {
         }
 
         if let Some(pipeline) = pipelines.into_inner().get(item.pipeline) {
+            let unified_pipeline = unified_pipeline.into_inner();
             pass.set_render_pipeline(pipeline);
-            pass.set_vertex_buffer(0, quad_meta.vertices.buffer().unwrap().slice(..));
+            pass.set_vertex_buffer(0, unified_pipeline.quad_vertices.slice(..));
+            pass.set_vertex_buffer(1, quad_meta.instances.buffer().unwrap().slice(..));
             pass.set_bind_group(
                 0,
                 quad_meta.view_bind_group.as_ref().unwrap(),
@@ -624,7 +714,6 @@ impl Draw<TransparentUI> for DrawUI {
                 &[extracted_quad.type_index],
             );
 
-            let unified_pipeline = unified_pipeline.into_inner();
             if let Some(font_handle) = extracted_quad.font_handle.as_ref() {
                 if let Some(image_bindings) =
                     font_texture_cache.into_inner().get_binding(font_handle)
@@ -647,10 +736,10 @@ impl Draw<TransparentUI> for DrawUI {
                 pass.set_bind_group(3, &unified_pipeline.default_image.1, &[]);
             }
 
+            let instance = extracted_quad.instance_index as u32;
             pass.draw(
-                (extracted_quad.vertex_index * QUAD_VERTEX_POSITIONS.len()) as u32
-                    ..((extracted_quad.vertex_index + 1) * QUAD_VERTEX_POSITIONS.len()) as u32,
-                0..1,
+                0..QUAD_VERTEX_POSITIONS.len() as u32,
+                instance..instance + 1,
             );
         }
     }
@@ -1,5 +1,5 @@
 use bevy::{math::Vec2, sprite::Rect};
-use kayak_core::render_primitive::RenderPrimitive;
+use kayak_core::{color::Color, render_primitive::RenderPrimitive};
 
 use crate::{
     render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
@@ -7,12 +7,44 @@ use crate::{
 };
 
 pub fn extract_quads(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<ExtractQuadBundle> {
-    let (background_color, layout, border_radius) = match render_primitive {
+    // The fill and the sector's angles are passed to the shader through the UVs, which are
+    // otherwise unused by quads
+    let (background_color, layout, border_radius, quad_type, uv) = match render_primitive {
         RenderPrimitive::Quad {
             background_color,
             layout,
             border_radius,
-        } => (background_color, layout, border_radius),
+        } => (
+            background_color,
+            layout,
+            *border_radius,
+            UIQuadType::Quad,
+            None,
+        ),
+        RenderPrimitive::RadialFill {
+            background_color,
+            layout,
+            border_radius,
+            fill,
+        } => (
+            background_color,
+            layout,
+            *border_radius,
+            UIQuadType::RadialFill,
+            Some(Vec2::splat(*fill)),
+        ),
+        RenderPrimitive::Sector {
+            background_color,
+            layout,
+            start,
+            sweep,
+        } => (
+            background_color,
+            layout,
+            (0.0, 0.0, 0.0, 0.0),
+            UIQuadType::Sector,
+            Some(Vec2::new(*start, *sweep)),
+        ),
         _ => panic!(""),
     };
 
@@ -23,16 +55,134 @@ pub fn extract_quads(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<Extrac
                 max: Vec2::new(layout.posx + layout.width, layout.posy + layout.height) * dpi,
             },
             color: to_bevy_color(background_color),
-            vertex_index: 0,
+            instance_index: 0,
             char_id: 0,
             z_index: layout.z_index,
             font_handle: None,
+            quad_type,
+            type_index: 0,
+            border_radius,
+            image: None,
+            uv_max: uv,
+            clip: None,
+            uv_min: uv,
+        },
+    }]
+}
+
+pub fn extract_lines(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<ExtractQuadBundle> {
+    let (color, layout, from, to, thickness) = match render_primitive {
+        RenderPrimitive::Line {
+            color,
+            layout,
+            from,
+            to,
+            thickness,
+        } => (color, layout, *from, *to, *thickness),
+        _ => panic!(""),
+    };
+
+    // The line is drawn along a diagonal of its bounding box, grown so the ends of the line fit
+    let half_thickness = thickness / 2.0;
+    let min = Vec2::new(from.0.min(to.0), from.1.min(to.1)) - Vec2::splat(half_thickness);
+    let max = Vec2::new(from.0.max(to.0), from.1.max(to.1)) + Vec2::splat(half_thickness);
+    let origin = Vec2::new(layout.posx, layout.posy);
+    // Whether the line goes from the bottom-left corner to the top-right one (instead of the
+    // top-left corner to the bottom-right one)
+    let is_ascending = (to.0 - from.0) * (to.1 - from.1) < 0.0;
+
+    // The thickness and direction are passed to the shader through the UVs
+    let uv = Vec2::new(thickness * dpi, if is_ascending { 1.0 } else { 0.0 });
+    vec![ExtractQuadBundle {
+        extracted_quad: ExtractedQuad {
+            rect: Rect {
+                min: (origin + min) * dpi,
+                max: (origin + max) * dpi,
+            },
+            color: to_bevy_color(color),
+            instance_index: 0,
+            char_id: 0,
+            z_index: layout.z_index,
+            font_handle: None,
+            quad_type: UIQuadType::Line,
+            type_index: 0,
+            border_radius: (0.0, 0.0, 0.0, 0.0),
+            image: None,
+            uv_max: Some(uv),
+            clip: None,
+            uv_min: Some(uv),
+        },
+    }]
+}
+
+pub fn extract_carets(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<ExtractQuadBundle> {
+    let (color, layout, position, height, thickness) = match render_primitive {
+        RenderPrimitive::Caret {
+            color,
+            layout,
+            position,
+            height,
+            thickness,
+        } => (color, layout, *position, *height, *thickness),
+        _ => panic!(""),
+    };
+
+    // The caret is centered on its position, so it sits between two characters
+    let min = Vec2::new(
+        layout.posx + position.0 - thickness / 2.0,
+        layout.posy + position.1,
+    );
+    vec![create_plain_quad(
+        min * dpi,
+        (min + Vec2::new(thickness, height)) * dpi,
+        color,
+        layout.z_index,
+    )]
+}
+
+pub fn extract_highlights(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<ExtractQuadBundle> {
+    let (background_color, layout, rects) = match render_primitive {
+        RenderPrimitive::Highlight {
+            background_color,
+            layout,
+            rects,
+        } => (background_color, layout, rects),
+        _ => panic!(""),
+    };
+
+    let origin = Vec2::new(layout.posx, layout.posy);
+    rects
+        .iter()
+        .filter(|(_, _, width, height)| *width > 0.0 && *height > 0.0)
+        .map(|&(x, y, width, height)| {
+            let min = origin + Vec2::new(x, y);
+            create_plain_quad(
+                min * dpi,
+                (min + Vec2::new(width, height)) * dpi,
+                background_color,
+                layout.z_index,
+            )
+        })
+        .collect()
+}
+
+/// Creates a quad without rounded corners covering the given rect (in physical pixels)
+pub fn create_plain_quad(min: Vec2, max: Vec2, color: &Color, z_index: f32) -> ExtractQuadBundle {
+    ExtractQuadBundle {
+        extracted_quad: ExtractedQuad {
+            rect: Rect { min, max },
+            color: to_bevy_color(color),
+            instance_index: 0,
+            char_id: 0,
+            z_index,
+            font_handle: None,
             quad_type: UIQuadType::Quad,
             type_index: 0,
-            border_radius: *border_radius,
+            border_radius: (0.0, 0.0, 0.0, 0.0),
             image: None,
             uv_max: None,
+            clip: None,
             uv_min: None,
         },
-    }]
+    }
 }
@@ -1,2 +1,4 @@
 mod extract;
-pub use extract::extract_quads;
+pub use extract::{
+    create_plain_quad, extract_carets, extract_highlights, extract_lines, extract_quads,
+};
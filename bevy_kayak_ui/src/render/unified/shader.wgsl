@@ -19,23 +19,54 @@ struct VertexOutput {
     [[location(3)]] size: vec2<f32>;
     [[location(4)]] screen_position: vec2<f32>;
     [[location(5)]] border_radius: f32;
+    [[location(6)]] clip: vec4<f32>;
 };
 
 [[stage(vertex)]]
 fn vertex(
-    [[location(0)]] vertex_position: vec3<f32>,
-    [[location(1)]] vertex_color: vec4<f32>,
-    [[location(2)]] vertex_uv: vec4<f32>,
-    [[location(3)]] vertex_pos_size: vec4<f32>,
+    [[location(0)]] vertex_position: vec2<f32>,
+    [[location(1)]] instance_pos_size: vec4<f32>,
+    [[location(2)]] instance_color: vec4<f32>,
+    [[location(3)]] instance_uv: vec4<f32>,
+    [[location(4)]] instance_border_radius: vec4<f32>,
+    [[location(5)]] instance_clip: vec4<f32>,
+    [[location(6)]] instance_layer: f32,
 ) -> VertexOutput {
     var out: VertexOutput;
-    out.color = vertex_color;
-    out.pos = vertex_pos_size.xy;
-    out.position = view.view_proj * vec4<f32>(vertex_position, 1.0);
-    out.screen_position = (view.view_proj * vec4<f32>(vertex_position, 1.0)).xy;
-    out.uv = vertex_uv.xyz;
-    out.size = vertex_pos_size.zw;
-    out.border_radius = vertex_uv.w;
+    var rect_pos = instance_pos_size.xy;
+    var rect_size = instance_pos_size.zw;
+#ifdef ANALYTIC_AA
+    if (quad_type.t == 0) {
+        // Grow the quad so its edges have room to fade out
+        rect_pos = rect_pos - vec2<f32>(1.0, 1.0);
+        rect_size = rect_size + vec2<f32>(2.0, 2.0);
+    }
+#endif
+    var position = vec3<f32>(rect_pos + vertex_position * rect_size, 0.0);
+    out.color = instance_color;
+    out.pos = instance_pos_size.xy;
+    out.position = view.view_proj * vec4<f32>(position, 1.0);
+    out.screen_position = (view.view_proj * vec4<f32>(position, 1.0)).xy;
+    out.uv = vec3<f32>(
+        mix(instance_uv.x, instance_uv.z, vertex_position.x),
+        mix(instance_uv.w, instance_uv.y, vertex_position.y),
+        instance_layer,
+    );
+    out.size = instance_pos_size.zw;
+
+    // Each corner of the quad gets its own radius
+    var border_radius = instance_border_radius.x;
+    if (vertex_position.x < 0.5 && vertex_position.y > 0.5) {
+        border_radius = instance_border_radius.y;
+    }
+    if (vertex_position.x > 0.5 && vertex_position.y > 0.5) {
+        border_radius = instance_border_radius.z;
+    }
+    if (vertex_position.x > 0.5 && vertex_position.y < 0.5) {
+        border_radius = instance_border_radius.w;
+    }
+    out.border_radius = border_radius;
+    out.clip = instance_clip;
     return out;
 }
 
@@ -69,9 +100,29 @@ fn sd_box_rounded(
     return length(dist);
 }
 
+// The signed distance from the edge of a rounded rect (negative inside of it)
+fn sd_rounded_rect(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32,
+) -> f32 {
+    var half_size = size * 0.5;
+    var r = min(radius, min(half_size.x, half_size.y));
+    var q = abs(frag_coord - (position + half_size)) - half_size + r;
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - r;
+}
+
 [[stage(fragment)]]
 fn fragment(in: VertexOutput) -> [[location(0)]] vec4<f32> {
+    if (in.position.x < in.clip.x || in.position.y < in.clip.y || in.position.x > in.clip.z || in.position.y > in.clip.w) {
+        discard;
+    }
     if (quad_type.t == 0) {
+#ifdef ANALYTIC_AA
+        var coverage = clamp(0.5 - sd_rounded_rect(in.position.xy, in.pos, in.size, in.border_radius), 0.0, 1.0);
+        return vec4<f32>(in.color.rgb, coverage);
+#else
         var dist = sd_box_rounded(
             in.position.xy,
             in.pos,
@@ -84,6 +135,7 @@ fn fragment(in: VertexOutput) -> [[location(0)]] vec4<f32> {
             dist);
 
         return vec4<f32>(in.color.rgb, dist);
+#endif
     }
     if (quad_type.t == 1) {
         var px_range = 3.5;
@@ -100,5 +152,46 @@ fn fragment(in: VertexOutput) -> [[location(0)]] vec4<f32> {
         var color = textureSample(image_texture, image_sampler, vec2<f32>(in.uv.x, 1.0 - in.uv.y));
         return vec4<f32>(color.rgb * in.color.rgb, color.a * in.color.a);
     }
+    if (quad_type.t == 3) {
+        var coverage = clamp(0.5 - sd_rounded_rect(in.position.xy, in.pos, in.size, in.border_radius), 0.0, 1.0);
+        // The angle of the fragment around the center, clockwise from the top (from 0 to 1)
+        var offset = in.position.xy - (in.pos + in.size * 0.5);
+        var angle = atan2(offset.x, -offset.y) / 6.28318530718;
+        if (angle < 0.0) {
+            angle = angle + 1.0;
+        }
+        if (angle > in.uv.x) {
+            discard;
+        }
+        return vec4<f32>(in.color.rgb, in.color.a * coverage);
+    }
+    if (quad_type.t == 4) {
+        // The line runs along a diagonal of the quad, inset so its rounded ends fit
+        var half_thickness = in.uv.x * 0.5;
+        var line_start = in.pos + vec2<f32>(half_thickness, half_thickness);
+        var line_end = in.pos + in.size - vec2<f32>(half_thickness, half_thickness);
+        if (in.uv.y > 0.5) {
+            line_start = vec2<f32>(line_start.x, line_end.y);
+            line_end = vec2<f32>(line_end.x, in.pos.y + half_thickness);
+        }
+        var offset = in.position.xy - line_start;
+        var line_dir = line_end - line_start;
+        var h = clamp(dot(offset, line_dir) / max(dot(line_dir, line_dir), 0.0001), 0.0, 1.0);
+        var dist = length(offset - line_dir * h) - half_thickness;
+        var coverage = clamp(0.5 - dist, 0.0, 1.0);
+        return vec4<f32>(in.color.rgb, in.color.a * coverage);
+    }
+    if (quad_type.t == 5) {
+        var offset = in.position.xy - (in.pos + in.size * 0.5);
+        var radius = min(in.size.x, in.size.y) * 0.5;
+        var coverage = clamp(0.5 - (length(offset) - radius), 0.0, 1.0);
+        // The angle of the fragment clockwise from the start of the sector (from 0 to 1)
+        var angle = atan2(offset.x, -offset.y) / 6.28318530718 - in.uv.x;
+        angle = angle - floor(angle);
+        if (angle > in.uv.y) {
+            discard;
+        }
+        return vec4<f32>(in.color.rgb, in.color.a * coverage);
+    }
     return in.color;
 }
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bevy::{
+    core::Time,
+    ecs::schedule::StateData,
+    prelude::{Commands, Res, ResMut, State},
+};
+use kayak_core::KayakContext;
+
+use crate::BevyContext;
+
+type RootFn = Arc<dyn Fn(&mut KayakContext) + Send + Sync>;
+
+/// A set of UI roots, one per variant of the Bevy [State] `T`
+///
+/// Add the [mount_state_roots] system to automatically mount the matching root whenever the state
+/// changes. States without a registered root mount an empty UI.
+///
+/// Mounting replaces the [BevyContext] resource. Since other Kayak systems expect one to exist from
+/// the start, an initial (possibly empty) [BevyContext] still needs to be inserted.
+///
+/// # Examples
+///
+/// ```ignore
+/// app.insert_resource(
+///     StateRoots::new()
+///         .with_root(AppState::MainMenu, |context| render! { <App><MainMenu /></App> })
+///         .with_root(AppState::Paused, |context| render! { <App><PauseMenu /></App> })
+///         .with_crossfade(0.25),
+/// )
+/// .add_system(mount_state_roots::<AppState>);
+/// ```
+pub struct StateRoots<T: StateData> {
+    roots: HashMap<T, RootFn>,
+    crossfade: Option<f32>,
+    mounted: Option<T>,
+}
+
+impl<T: StateData> Default for StateRoots<T> {
+    fn default() -> Self {
+        Self {
+            roots: HashMap::default(),
+            crossfade: None,
+            mounted: None,
+        }
+    }
+}
+
+impl<T: StateData> StateRoots<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the root to mount while in the given state
+    pub fn with_root<F: Fn(&mut KayakContext) + Send + Sync + 'static>(
+        mut self,
+        state: T,
+        f: F,
+    ) -> Self {
+        self.roots.insert(state, Arc::new(f));
+        self
+    }
+
+    /// Crossfade between the outgoing and incoming roots over the given duration (in seconds)
+    pub fn with_crossfade(mut self, duration: f32) -> Self {
+        self.crossfade = Some(duration);
+        self
+    }
+
+    /// The state whose root is currently mounted
+    pub fn mounted(&self) -> Option<&T> {
+        self.mounted.as_ref()
+    }
+}
+
+/// A previous UI root that is fading out
+#[derive(Default)]
+pub struct UICrossfade {
+    pub(crate) outgoing: Option<BevyContext>,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl UICrossfade {
+    /// The progress of the current crossfade (from 0 to 1), or `None` if there isn't one
+    pub fn progress(&self) -> Option<f32> {
+        self.outgoing.as_ref()?;
+        if self.duration > 0.0 {
+            Some((self.elapsed / self.duration).clamp(0.0, 1.0))
+        } else {
+            Some(1.0)
+        }
+    }
+}
+
+/// Mounts the root registered for the current [State] `T` whenever it changes
+pub fn mount_state_roots<T: StateData>(
+    mut commands: Commands,
+    state: Res<State<T>>,
+    mut roots: ResMut<StateRoots<T>>,
+    mut crossfade: ResMut<UICrossfade>,
+    bevy_context: Option<Res<BevyContext>>,
+) {
+    let current = state.current();
+    if roots.mounted.as_ref() == Some(current) {
+        return;
+    }
+
+    let context = if let Some(root) = roots.roots.get(current) {
+        let root = root.clone();
+        BevyContext::new(move |context| root(context))
+    } else {
+        BevyContext::new(|_| {})
+    };
+
+    if let (Some(duration), Some(outgoing)) = (roots.crossfade, bevy_context) {
+        // Keep the outgoing root around (without updating it) so it can be faded out
+        crossfade.outgoing = Some(BevyContext {
+            kayak_context: outgoing.kayak_context.clone(),
+        });
+        crossfade.duration = duration;
+        crossfade.elapsed = 0.0;
+    }
+
+    commands.insert_resource(context);
+    roots.mounted = Some(current.clone());
+}
+
+pub(crate) fn update_crossfade(time: Res<Time>, mut crossfade: ResMut<UICrossfade>) {
+    if crossfade.outgoing.is_none() {
+        return;
+    }
+
+    crossfade.elapsed += time.delta_seconds();
+    if crossfade.elapsed >= crossfade.duration {
+        crossfade.outgoing = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{App, State};
+    use kayak_core::{Fragment, WidgetBuilder};
+
+    use super::{mount_state_roots, StateRoots, UICrossfade};
+    use crate::BevyContext;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum AppState {
+        MainMenu,
+        Loading,
+    }
+
+    #[test]
+    fn states_without_a_root_should_mount_an_empty_ui() {
+        let mut app = App::new();
+        app.add_state(AppState::MainMenu)
+            .insert_resource(
+                StateRoots::new()
+                    .with_root(AppState::MainMenu, |context| {
+                        WidgetBuilder::new::<Fragment>().render_root(context)
+                    })
+                    .with_crossfade(0.25),
+            )
+            .init_resource::<UICrossfade>()
+            .add_system(mount_state_roots::<AppState>);
+        app.update();
+
+        app.world
+            .get_resource_mut::<State<AppState>>()
+            .unwrap()
+            .set(AppState::Loading)
+            .unwrap();
+        app.update();
+        app.update();
+
+        let roots = app.world.get_resource::<StateRoots<AppState>>().unwrap();
+        assert_eq!(Some(&AppState::Loading), roots.mounted());
+
+        let bevy_context = app.world.get_resource::<BevyContext>().unwrap();
+        let mut context = bevy_context.kayak_context.write().unwrap();
+        context.render();
+        assert!(context.widget_manager.build_render_primitives().is_empty());
+
+        // The outgoing root is still drawn while it fades out
+        let crossfade = app.world.get_resource::<UICrossfade>().unwrap();
+        assert!(crossfade.progress().is_some());
+        let outgoing = crossfade.outgoing.as_ref().unwrap();
+        let outgoing = outgoing.kayak_context.read().unwrap();
+        outgoing.widget_manager.build_render_primitives();
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::{
+    ecs::schedule::StateData,
+    prelude::{Res, State},
+};
+use kayak_core::{Binding, Bound, MutableBound};
+
+/// The current Bevy [State] of type `T`, mirrored for use in the UI
+///
+/// Insert a `Binding<StateRoute<T>>` resource and add the [sync_state_route] system to keep it up to
+/// date. The `name` can be passed directly as the `route` of a `Router`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateRoute<T: StateData> {
+    /// The route name of the state (its `Debug` representation)
+    pub name: String,
+    /// The current state
+    pub state: Option<T>,
+}
+
+impl<T: StateData> Default for StateRoute<T> {
+    fn default() -> Self {
+        Self {
+            name: String::default(),
+            state: None,
+        }
+    }
+}
+
+impl<T: StateData> StateRoute<T> {
+    pub fn new(state: T) -> Self {
+        Self {
+            name: format!("{:?}", state),
+            state: Some(state),
+        }
+    }
+}
+
+/// Keeps a `Binding<StateRoute<T>>` resource in sync with the current [State] of type `T`
+pub fn sync_state_route<T: StateData>(state: Res<State<T>>, route: Res<Binding<StateRoute<T>>>) {
+    if route.get().state.as_ref() != Some(state.current()) {
+        route.set(StateRoute::new(state.current().clone()));
+    }
+}
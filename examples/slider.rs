@@ -0,0 +1,90 @@
+use bevy::{
+    prelude::{App as BevyApp, AssetServer, Commands, Res, ResMut},
+    window::WindowDescriptor,
+    DefaultPlugins,
+};
+use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle};
+use kayak_ui::core::{
+    render, rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, Handler, Index,
+};
+use kayak_ui::widgets::{App, Slider, SliderOrientation, Text, Window};
+
+#[widget]
+fn SliderExample(context: &mut KayakContext) {
+    let (value, set_value, _) = use_state!(50.0);
+
+    let on_change = Handler::new(move |value| {
+        set_value(value);
+    });
+
+    let slider_styles = Style {
+        top: StyleProp::Value(Units::Pixels(10.0)),
+        ..Default::default()
+    };
+
+    let vertical_styles = Style {
+        top: StyleProp::Value(Units::Pixels(10.0)),
+        height: StyleProp::Value(Units::Pixels(150.0)),
+        ..Default::default()
+    };
+
+    let text = format!("Value: {}", value);
+
+    rsx! {
+        <Window position={(50.0, 50.0)} size={(300.0, 300.0)} title={"Slider Example".to_string()}>
+            <Text content={text} size={16.0} />
+            <Slider
+                styles={Some(slider_styles)}
+                value={value}
+                min={0.0}
+                max={100.0}
+                step={5.0}
+                on_change={Some(on_change.clone())}
+            />
+            <Slider
+                styles={Some(vertical_styles)}
+                value={value}
+                min={0.0}
+                max={100.0}
+                orientation={SliderOrientation::Vertical}
+                on_change={Some(on_change)}
+            />
+        </Window>
+    }
+}
+
+fn startup(
+    mut commands: Commands,
+    mut font_mapping: ResMut<FontMapping>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn_bundle(UICameraBundle::new());
+
+    font_mapping.add("Roboto", asset_server.load("roboto.kayak_font"));
+
+    let context = BevyContext::new(|context| {
+        render! {
+            <App>
+                <SliderExample />
+            </App>
+        }
+    });
+
+    commands.insert_resource(context);
+}
+
+fn main() {
+    BevyApp::new()
+        .insert_resource(WindowDescriptor {
+            width: 1270.0,
+            height: 720.0,
+            title: String::from("UI Example"),
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BevyKayakUIPlugin)
+        .add_startup_system(startup)
+        .run();
+}
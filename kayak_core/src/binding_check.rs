@@ -0,0 +1,145 @@
+//! Detection of bindings misused during render (in debug builds)
+//!
+//! A widget only re-renders when a binding changes if it's registered for it, either by calling
+//! `context.bind` or by creating the binding with `use_state!` (or as a provider). Reading a
+//! binding during render without registering it shows its value at that time, but the widget
+//! won't update when it changes. Registering a binding that isn't read during render, on the
+//! other hand, re-renders the widget for nothing.
+//!
+//! In debug builds, [Binding::get](crate::Binding) reports each read to the render in progress,
+//! and the reads are compared to the registered bindings once the render ends. Release builds
+//! still keep track of renders, but nothing is read in them.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::flo_binding::Uuid;
+
+thread_local! {
+    /// The bindings read by each render in progress, innermost last (renders nest as widgets
+    /// render their children)
+    static RENDER_READS: RefCell<Vec<HashSet<Uuid>>> = RefCell::new(Vec::new());
+}
+
+/// A mistake in how a widget uses a binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum BindingMisuse {
+    /// The binding was read during render without being registered, so changes to it don't
+    /// re-render the widget
+    NotBound(Uuid),
+    /// The binding was bound with `context.bind` but not read during render
+    NotRead(Uuid),
+}
+
+impl BindingMisuse {
+    /// Describes this misuse by the given widget
+    pub fn message(&self, widget_name: &str) -> String {
+        match self {
+            Self::NotBound(id) => format!(
+                "Widget `{}` read binding {} during render without binding to it, so it won't \
+                 re-render when the binding changes. Call `context.bind(&binding)` in its render \
+                 function.",
+                widget_name, id
+            ),
+            Self::NotRead(id) => format!(
+                "Widget `{}` is bound to binding {} but didn't read it during render, so it \
+                 re-renders for nothing when the binding changes. Call `context.unbind(&binding)` \
+                 if it doesn't need it.",
+                widget_name, id
+            ),
+        }
+    }
+}
+
+/// Records that the binding with the given ID was read, if a render is in progress
+pub(crate) fn record_read(id: Uuid) {
+    RENDER_READS.with(|reads| {
+        if let Some(current) = reads.borrow_mut().last_mut() {
+            current.insert(id);
+        }
+    });
+}
+
+/// Starts recording the bindings read by a render, returning its depth for [end_render]
+pub(crate) fn begin_render() -> usize {
+    RENDER_READS.with(|reads| {
+        let mut reads = reads.borrow_mut();
+        reads.push(HashSet::new());
+        reads.len() - 1
+    })
+}
+
+/// Stops recording the bindings read by the render at the given depth, returning them
+///
+/// Renders nested within it that didn't end (e.g. widgets returning early from their render
+/// function) are discarded too.
+pub(crate) fn end_render(depth: usize) -> HashSet<Uuid> {
+    RENDER_READS.with(|reads| {
+        let mut reads = reads.borrow_mut();
+        reads.truncate(depth + 1);
+        if reads.len() == depth + 1 {
+            reads.pop().unwrap_or_default()
+        } else {
+            HashSet::new()
+        }
+    })
+}
+
+/// Compares the bindings read by a render to the ones registered for the widget
+///
+/// `registered` are all of the bindings re-rendering the widget, and `bound` are the ones among
+/// them that were bound with `context.bind` (state created by the widget is registered even if
+/// it's unused, so it's not reported).
+pub(crate) fn find_misuses<'a>(
+    reads: &HashSet<Uuid>,
+    registered: impl Iterator<Item = &'a Uuid>,
+    bound: &[Uuid],
+) -> Vec<BindingMisuse> {
+    let registered = registered.collect::<HashSet<_>>();
+    let mut misuses = reads
+        .iter()
+        .filter(|id| !registered.contains(id))
+        .map(|id| BindingMisuse::NotBound(*id))
+        .collect::<Vec<_>>();
+    misuses.extend(
+        bound
+            .iter()
+            .filter(|id| !reads.contains(id))
+            .map(|id| BindingMisuse::NotRead(*id)),
+    );
+    misuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{begin_render, end_render, find_misuses, BindingMisuse};
+    use crate::{bind, Bound};
+
+    #[test]
+    fn reads_should_be_compared_to_registered_bindings() {
+        let state = bind(0);
+        let bound = bind(0);
+        let unbound = bind(0);
+        let unread = bind(0);
+
+        let depth = begin_render();
+        state.get();
+        bound.get();
+        let child_depth = begin_render();
+        // Reads by a child render don't count for its parent
+        unread.get();
+        end_render(child_depth);
+        unbound.get();
+        let reads = end_render(depth);
+
+        let registered = [state.id, bound.id, unread.id];
+        let misuses = find_misuses(&reads, registered.iter(), &[bound.id, unread.id]);
+        assert_eq!(
+            vec![
+                BindingMisuse::NotBound(unbound.id),
+                BindingMisuse::NotRead(unread.id)
+            ],
+            misuses
+        );
+    }
+}
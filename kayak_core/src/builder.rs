@@ -0,0 +1,204 @@
+//! A runtime alternative to the `rsx!` macro
+//!
+//! [WidgetBuilder] creates widgets and their children through plain method calls, which is useful
+//! when the UI is made from data at runtime (e.g. loaded from a file) or when macros aren't
+//! wanted. Rendering a builder performs the same operations on the widget tree as the code
+//! generated by `rsx!`, so both can be mixed freely.
+//!
+//! ```ignore
+//! WidgetBuilder::new::<Background>()
+//!     .styles(background_styles)
+//!     .child(
+//!         WidgetBuilder::new::<Text>()
+//!             .prop(|text| text.content = String::from("Hello"))
+//!             .prop(|text| text.size = 16.0),
+//!     )
+//!     .render(&tree, parent_id, context);
+//! ```
+
+use std::sync::Arc;
+
+use crate::{context::KayakContext, styles::Style, Fragment, Index, OnEvent, Widget, WidgetTree};
+
+/// Builds a widget, along with its props and children
+///
+/// Start with [WidgetBuilder::new] (or [WidgetBuilder::from_widget] for a widget that was already
+/// set up), then either render the builder within a widget's render function with
+/// [render](Self::render) (like `rsx!`), render it as the root of the tree with
+/// [render_root](Self::render_root) (like `render!`), or get the widget with [build](Self::build)
+/// (like `constructor!`).
+#[derive(Debug, Clone)]
+pub struct WidgetBuilder<W = Fragment> {
+    widget: W,
+    children: Vec<AnyWidgetBuilder>,
+}
+
+impl WidgetBuilder {
+    /// Starts building a widget of the given type with its default props
+    pub fn new<W: Widget + PartialEq + Default + Clone + 'static>() -> WidgetBuilder<W> {
+        WidgetBuilder::from_widget(W::default())
+    }
+}
+
+impl<W: Widget + PartialEq + Default + Clone + 'static> WidgetBuilder<W> {
+    /// Starts building the given widget, keeping its props (but not its children)
+    pub fn from_widget(widget: W) -> Self {
+        Self {
+            widget,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets props of the widget
+    ///
+    /// The props are the fields of the widget, so they're set like any other field:
+    /// `.prop(|button| button.disabled = true)`.
+    pub fn prop<F: FnOnce(&mut W)>(mut self, set_prop: F) -> Self {
+        set_prop(&mut self.widget);
+        self
+    }
+
+    /// Sets the styles of the widget
+    pub fn styles(mut self, styles: Style) -> Self {
+        self.widget.set_styles(Some(styles));
+        self
+    }
+
+    /// Sets the event handler of the widget
+    pub fn on_event(mut self, on_event: OnEvent) -> Self {
+        self.widget.set_on_event(Some(on_event));
+        self
+    }
+
+    /// Appends a child, which can be a builder of any widget
+    pub fn child(mut self, child: impl Into<AnyWidgetBuilder>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Appends children, which can be builders of any widgets
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<AnyWidgetBuilder>,
+    {
+        self.children
+            .extend(children.into_iter().map(|child| child.into()));
+        self
+    }
+
+    /// Sets the children of the widget to the given ones, like the `children` of the widget
+    /// rendering this builder
+    ///
+    /// This is the same as passing `{children}` as the only child in `rsx!`. It replaces the
+    /// children added with [child](Self::child) and [children](Self::children).
+    pub fn with_children(mut self, children: crate::Children) -> Self {
+        self.children.clear();
+        self.widget.set_children(children);
+        self
+    }
+
+    /// Gets the widget, with its children set
+    pub fn build(mut self) -> W {
+        if !self.children.is_empty() {
+            let children = self.children;
+            self.widget.set_children(Some(Arc::new(
+                move |tree: WidgetTree, parent_id: Option<Index>, context: &mut KayakContext| {
+                    for (index, child) in children.iter().enumerate() {
+                        (child.0)(index, &tree, parent_id, context);
+                    }
+                },
+            )));
+        }
+        self.widget
+    }
+
+    /// Creates the widget within the given parent and renders it
+    ///
+    /// This is meant to be called from a widget's render function, with the `tree` and
+    /// `parent_id` in scope there (like the code generated by `rsx!`).
+    pub fn render(self, tree: &WidgetTree, parent_id: Option<Index>, context: &mut KayakContext) {
+        create_widget(0, self.build(), tree, parent_id, context);
+    }
+
+    /// Creates the widget as the root of the widget tree and renders it (like `render!`)
+    pub fn render_root(self, context: &mut KayakContext) {
+        let tree = WidgetTree::new();
+        self.render(&tree, None, context);
+    }
+}
+
+/// A [WidgetBuilder] of any widget, so builders of different widgets can be siblings
+#[derive(Clone)]
+pub struct AnyWidgetBuilder(
+    Arc<dyn Fn(usize, &WidgetTree, Option<Index>, &mut KayakContext) + Send + Sync>,
+);
+
+impl<W: Widget + PartialEq + Default + Clone + 'static> From<WidgetBuilder<W>>
+    for AnyWidgetBuilder
+{
+    fn from(builder: WidgetBuilder<W>) -> Self {
+        let widget = builder.build();
+        AnyWidgetBuilder(Arc::new(move |index, tree, parent_id, context| {
+            create_widget(index, widget.clone(), tree, parent_id, context);
+        }))
+    }
+}
+
+impl std::fmt::Debug for AnyWidgetBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AnyWidgetBuilder").finish()
+    }
+}
+
+/// Creates (or updates) the widget at the given index within its parent and renders it, as the
+/// code generated by `rsx!` does
+fn create_widget<W: Widget + PartialEq + Default + Clone + 'static>(
+    index: usize,
+    widget: W,
+    tree: &WidgetTree,
+    parent_id: Option<Index>,
+    context: &mut KayakContext,
+) {
+    let (should_rerender, child_id) = context
+        .widget_manager
+        .create_widget(index, widget, parent_id);
+    tree.add(child_id, parent_id);
+    if should_rerender {
+        let mut child_widget = context.widget_manager.take(child_id);
+        child_widget.render(context);
+        context.widget_manager.repossess(child_widget);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WidgetBuilder;
+    use crate::styles::{Style, StyleProp, Units};
+    use crate::{Fragment, KayakContext, Widget};
+
+    #[test]
+    fn builders_should_create_the_widget_tree() {
+        let mut context = KayakContext::new();
+        let styles = Style {
+            width: StyleProp::Value(Units::Pixels(100.0)),
+            ..Style::default()
+        };
+        WidgetBuilder::new::<Fragment>()
+            .child(WidgetBuilder::new::<Fragment>().styles(styles.clone()))
+            .children(vec![
+                WidgetBuilder::new::<Fragment>(),
+                WidgetBuilder::new::<Fragment>(),
+            ])
+            .render_root(&mut context);
+
+        let widget_manager = &context.widget_manager;
+        let root = widget_manager.tree.root_node.unwrap();
+        let children = widget_manager.tree.children.get(&root).unwrap();
+        assert_eq!(3, children.len());
+        let first = widget_manager.current_widgets[children[0]]
+            .as_ref()
+            .unwrap();
+        assert_eq!(Some(styles), first.get_styles());
+    }
+}
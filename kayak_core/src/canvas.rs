@@ -0,0 +1,252 @@
+use crate::{
+    color::Color, layout_cache::Rect, render_command::TextRun, render_primitive::RenderPrimitive,
+};
+
+/// A shape drawn by a [Painter]
+///
+/// Positions are relative to the top-left corner of the canvas (in pixels).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CanvasShape {
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+        thickness: f32,
+        color: Color,
+    },
+    Rect {
+        position: (f32, f32),
+        size: (f32, f32),
+        border_radius: f32,
+        color: Color,
+    },
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+        color: Color,
+    },
+    Text {
+        position: (f32, f32),
+        content: String,
+        font: String,
+        size: f32,
+        color: Color,
+    },
+}
+
+impl CanvasShape {
+    /// Converts this shape into the primitive drawing it within a canvas with the given layout
+    pub fn to_primitive(&self, layout: &Rect) -> RenderPrimitive {
+        match self {
+            CanvasShape::Line {
+                from,
+                to,
+                thickness,
+                color,
+            } => RenderPrimitive::Line {
+                layout: *layout,
+                color: *color,
+                from: *from,
+                to: *to,
+                thickness: thickness.max(0.0),
+            },
+            CanvasShape::Rect {
+                position,
+                size,
+                border_radius,
+                color,
+            } => RenderPrimitive::Quad {
+                layout: offset_layout(layout, *position, *size),
+                background_color: *color,
+                border_radius: (
+                    *border_radius,
+                    *border_radius,
+                    *border_radius,
+                    *border_radius,
+                ),
+            },
+            CanvasShape::Circle {
+                center,
+                radius,
+                color,
+            } => RenderPrimitive::Quad {
+                layout: offset_layout(
+                    layout,
+                    (center.0 - radius, center.1 - radius),
+                    (radius * 2.0, radius * 2.0),
+                ),
+                background_color: *color,
+                border_radius: (*radius, *radius, *radius, *radius),
+            },
+            CanvasShape::Text {
+                position,
+                content,
+                font,
+                size,
+                color,
+            } => RenderPrimitive::RichText {
+                layout: *layout,
+                runs: vec![TextRun {
+                    content: content.clone(),
+                    font: font.clone(),
+                    size: *size,
+                    color: Some(*color),
+                    position: *position,
+                    ..Default::default()
+                }],
+            },
+        }
+    }
+}
+
+fn offset_layout(layout: &Rect, position: (f32, f32), size: (f32, f32)) -> Rect {
+    Rect {
+        posx: layout.posx + position.0,
+        posy: layout.posy + position.1,
+        width: size.0.max(0.0),
+        height: size.1.max(0.0),
+        z_index: layout.z_index,
+    }
+}
+
+/// Records the shapes drawn on a canvas
+///
+/// Shapes are drawn in order, so later shapes cover earlier ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Painter {
+    size: (f32, f32),
+    font: String,
+    shapes: Vec<CanvasShape>,
+}
+
+impl Painter {
+    /// Creates a painter for a canvas of the given size (in pixels)
+    pub fn new(size: (f32, f32)) -> Self {
+        Self {
+            size,
+            font: "Roboto".to_string(),
+            shapes: Vec::new(),
+        }
+    }
+
+    /// The size of the canvas (in pixels)
+    pub fn size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    /// Set the font of the text drawn from now on. Defaults to Roboto.
+    pub fn set_font<S: Into<String>>(&mut self, font: S) {
+        self.font = font.into();
+    }
+
+    pub fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), thickness: f32, color: Color) {
+        self.shapes.push(CanvasShape::Line {
+            from,
+            to,
+            thickness,
+            color,
+        });
+    }
+
+    /// Draw a rect, given its top-left corner and size
+    pub fn draw_rect(&mut self, position: (f32, f32), size: (f32, f32), color: Color) {
+        self.draw_rounded_rect(position, size, 0.0, color);
+    }
+
+    /// Draw a rect with rounded corners, given its top-left corner and size
+    pub fn draw_rounded_rect(
+        &mut self,
+        position: (f32, f32),
+        size: (f32, f32),
+        border_radius: f32,
+        color: Color,
+    ) {
+        self.shapes.push(CanvasShape::Rect {
+            position,
+            size,
+            border_radius,
+            color,
+        });
+    }
+
+    pub fn draw_circle(&mut self, center: (f32, f32), radius: f32, color: Color) {
+        self.shapes.push(CanvasShape::Circle {
+            center,
+            radius: radius.max(0.0),
+            color,
+        });
+    }
+
+    /// Draw a single line of text, given its top-left corner and font size
+    pub fn draw_text<S: Into<String>>(
+        &mut self,
+        position: (f32, f32),
+        content: S,
+        size: f32,
+        color: Color,
+    ) {
+        self.shapes.push(CanvasShape::Text {
+            position,
+            content: content.into(),
+            font: self.font.clone(),
+            size,
+            color,
+        });
+    }
+
+    /// The shapes drawn so far
+    pub fn shapes(&self) -> &[CanvasShape] {
+        &self.shapes
+    }
+
+    pub fn into_shapes(self) -> Vec<CanvasShape> {
+        self.shapes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Painter;
+    use crate::{color::Color, layout_cache::Rect, render_primitive::RenderPrimitive};
+
+    #[test]
+    fn shapes_are_offset_by_the_canvas_layout() {
+        let mut painter = Painter::new((100.0, 100.0));
+        painter.draw_rect((10.0, 20.0), (30.0, 40.0), Color::WHITE);
+        painter.draw_circle((50.0, 50.0), 5.0, Color::WHITE);
+
+        let layout = Rect {
+            posx: 100.0,
+            posy: 200.0,
+            width: 100.0,
+            height: 100.0,
+            z_index: 3.0,
+        };
+        let primitives = painter
+            .shapes()
+            .iter()
+            .map(|shape| shape.to_primitive(&layout))
+            .collect::<Vec<_>>();
+
+        match &primitives[0] {
+            RenderPrimitive::Quad { layout, .. } => {
+                assert_eq!(
+                    (layout.posx, layout.posy, layout.width, layout.height),
+                    (110.0, 220.0, 30.0, 40.0)
+                );
+                assert_eq!(layout.z_index, 3.0);
+            }
+            primitive => panic!("Expected a quad, got {:?}", primitive),
+        }
+        match &primitives[1] {
+            RenderPrimitive::Quad {
+                layout,
+                border_radius,
+                ..
+            } => {
+                assert_eq!((layout.posx, layout.posy), (145.0, 245.0));
+                assert_eq!(border_radius.0, 5.0);
+            }
+            primitive => panic!("Expected a quad, got {:?}", primitive),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use crate::Index;
+
+/// The time seen by widgets, which is either the real time or a simulated one (e.g. for tests)
+///
+/// While simulated, the time only moves when advanced. Time-dependent widgets usually re-render
+/// themselves by storing the time in their state, which no longer changes while the time stands
+/// still, so the widgets that read the simulated time are recorded to be re-rendered once it moves.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Clock {
+    simulated: Option<Instant>,
+    /// The widgets that read the simulated time since it last moved
+    readers: HashSet<Index>,
+}
+
+impl Clock {
+    /// Gets the current time, recording the widget reading it if the time is simulated
+    pub fn now(&mut self, reader: Index) -> Instant {
+        match self.simulated {
+            Some(now) => {
+                self.readers.insert(reader);
+                now
+            }
+            None => Instant::now(),
+        }
+    }
+
+    /// Gets the current time, without recording a reader
+    pub fn peek(&self) -> Instant {
+        self.simulated.unwrap_or_else(Instant::now)
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.simulated.is_some()
+    }
+
+    /// Freezes the time at the current real time (if it isn't simulated already)
+    pub fn simulate(&mut self) {
+        if self.simulated.is_none() {
+            self.simulated = Some(Instant::now());
+        }
+    }
+
+    /// Moves the simulated time forward (simulating it first if needed), returning the widgets
+    /// that read it since it last moved
+    pub fn advance(&mut self, delta: Duration) -> Vec<Index> {
+        self.simulate();
+        self.simulated = self.simulated.map(|now| now + delta);
+        self.readers.drain().collect()
+    }
+
+    /// Goes back to the real time, returning the widgets that read the simulated time since it
+    /// last moved
+    ///
+    /// The real time is always later than the simulated one was started at, but it may be earlier
+    /// than the simulated time, so durations between them should saturate.
+    pub fn resume(&mut self) -> Vec<Index> {
+        self.simulated = None;
+        self.readers.drain().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Clock;
+    use crate::Index;
+
+    #[test]
+    fn simulated_time_should_only_move_when_advanced() {
+        let mut clock = Clock::default();
+        let reader = Index::from_raw_parts(0, 0);
+        clock.simulate();
+        assert!(clock.is_simulated());
+
+        let start = clock.now(reader);
+        assert_eq!(start, clock.now(reader));
+
+        assert_eq!(vec![reader], clock.advance(Duration::from_millis(250)));
+        // Nothing read the time since it moved
+        assert!(clock.advance(Duration::from_millis(250)).is_empty());
+        assert_eq!(Duration::from_millis(500), clock.now(reader) - start);
+
+        assert_eq!(vec![reader], clock.resume());
+        assert!(!clock.is_simulated());
+    }
+}
@@ -45,4 +45,218 @@ impl Color {
     pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
+
+    /// Creates a color from hue (in degrees), saturation, lightness, and alpha
+    pub fn from_hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match (hue / 60.0) as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        Self::new(r + m, g + m, b + m, alpha)
+    }
+
+    /// Gets the hue (in degrees), saturation, lightness, and alpha of this color
+    pub fn to_hsla(&self) -> (f32, f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        if chroma <= f32::EPSILON {
+            return (0.0, 0.0, lightness, self.a);
+        }
+
+        let hue = if max == self.r {
+            60.0 * ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / chroma + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / chroma + 4.0)
+        };
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        (hue, saturation, lightness, self.a)
+    }
+
+    /// Creates a color from its lightness, green-red, and blue-yellow components in the OKLab
+    /// color space, and alpha
+    pub fn from_oklab(lightness: f32, a: f32, b: f32, alpha: f32) -> Self {
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+        Self::new(
+            linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+            alpha,
+        )
+    }
+
+    /// Gets the lightness, green-red, and blue-yellow components of this color in the OKLab color
+    /// space, and its alpha
+    pub fn to_oklab(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b) = (
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        );
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        (
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            self.a,
+        )
+    }
+
+    /// Blends the components of this color and the given one
+    ///
+    /// A `t` of 0 gives this color, and a `t` of 1 gives the other.
+    pub fn lerp(&self, other: &Color, t: f32) -> Self {
+        Self::new(
+            lerp(self.r, other.r, t),
+            lerp(self.g, other.g, t),
+            lerp(self.b, other.b, t),
+            lerp(self.a, other.a, t),
+        )
+    }
+
+    /// Blends this color and the given one in the OKLab color space
+    ///
+    /// Unlike [Color::lerp], the blend changes at a perceptually even rate and doesn't pass through
+    /// muddy or overly dark colors, which makes it the better choice for animating colors.
+    pub fn lerp_oklab(&self, other: &Color, t: f32) -> Self {
+        let from = self.to_oklab();
+        let to = other.to_oklab();
+        Self::from_oklab(
+            lerp(from.0, to.0, t),
+            lerp(from.1, to.1, t),
+            lerp(from.2, to.2, t),
+            lerp(from.3, to.3, t),
+        )
+    }
+
+    /// Blends this color and the given one in the HSL color space, taking the shortest way around
+    /// the hue circle
+    pub fn lerp_hsl(&self, other: &Color, t: f32) -> Self {
+        let from = self.to_hsla();
+        let to = other.to_hsla();
+        let mut hue_delta = (to.0 - from.0).rem_euclid(360.0);
+        if hue_delta > 180.0 {
+            hue_delta -= 360.0;
+        }
+        Self::from_hsla(
+            from.0 + hue_delta * t,
+            lerp(from.1, to.1, t),
+            lerp(from.2, to.2, t),
+            lerp(from.3, to.3, t),
+        )
+    }
+
+    /// Makes this color darker by reducing its perceptual lightness by `amount` (from 0 to 1)
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Makes this color lighter by increasing its perceptual lightness by `amount` (from 0 to 1)
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (lightness, a, b, alpha) = self.to_oklab();
+        Self::from_oklab((lightness + amount).clamp(0.0, 1.0), a, b, alpha)
+    }
+
+    /// Returns this color with the given alpha
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        Self { a: alpha, ..*self }
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+
+    fn assert_close(a: Color, b: Color) {
+        let close = |x: f32, y: f32| (x - y).abs() < 0.001;
+        assert!(
+            close(a.r, b.r) && close(a.g, b.g) && close(a.b, b.b) && close(a.a, b.a),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn converts_to_and_from_oklab() {
+        let color = Color::new(0.8, 0.3, 0.1, 0.5);
+        let (l, a, b, alpha) = color.to_oklab();
+        assert_close(Color::from_oklab(l, a, b, alpha), color);
+        assert!((Color::WHITE.to_oklab().0 - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn converts_to_and_from_hsla() {
+        let color = Color::new(0.2, 0.6, 0.9, 1.0);
+        let (h, s, l, a) = color.to_hsla();
+        assert_close(Color::from_hsla(h, s, l, a), color);
+        assert_close(
+            Color::from_hsla(120.0, 1.0, 0.5, 1.0),
+            Color::new(0.0, 1.0, 0.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn lerp_returns_endpoints() {
+        let from = Color::new(1.0, 0.0, 0.0, 1.0);
+        let to = Color::new(0.0, 0.0, 1.0, 0.0);
+        assert_close(from.lerp_oklab(&to, 0.0), from);
+        assert_close(from.lerp_oklab(&to, 1.0), to);
+        assert_close(from.lerp_hsl(&to, 1.0), to);
+        assert_close(from.lerp(&to, 0.5), Color::new(0.5, 0.0, 0.5, 0.5));
+    }
+
+    #[test]
+    fn hsl_lerp_takes_shortest_hue_path() {
+        // Red (0) to magenta (300) should pass through 330, not through green
+        let from = Color::from_hsla(0.0, 1.0, 0.5, 1.0);
+        let to = Color::from_hsla(300.0, 1.0, 0.5, 1.0);
+        let (hue, ..) = from.lerp_hsl(&to, 0.5).to_hsla();
+        assert!((hue - 330.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn darken_and_lighten_change_lightness() {
+        let color = Color::new(0.5, 0.5, 0.5, 0.75);
+        assert!(color.darken(0.1).to_oklab().0 < color.to_oklab().0);
+        assert!(color.lighten(0.1).to_oklab().0 > color.to_oklab().0);
+        assert_eq!(color.darken(0.1).a, 0.75);
+        assert_eq!(color.with_alpha(0.25).a, 0.25);
+    }
 }
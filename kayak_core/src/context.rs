@@ -1,23 +1,64 @@
 use crate::assets::AssetStorage;
-use crate::{Binding, Changeable};
-use std::collections::HashMap;
+use crate::{Binding, Bound, Changeable};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-use crate::event_dispatcher::EventDispatcher;
+use crate::clock::Clock;
+use crate::event_dispatcher::{CaptureHandler, EventDispatcher};
+use crate::notifications::NotificationQueue;
+use crate::screen_stack::ScreenStack;
+use crate::settings::Settings;
+use crate::visibility::VisibilityTracker;
 use crate::{
-    multi_state::MultiState, widget_manager::WidgetManager, Index, InputEvent, MutableBound,
-    Releasable,
+    multi_state::MultiState, widget_manager::WidgetManager, CursorIcon, CustomEvent, DragPayload,
+    DropFilter, Event, EventCategory, EventType, GlobalListener, Index, InputEvent, Interaction,
+    InteractionSink, KeyCode, KeyRepeat, KeyboardLayout, KeyboardModifiers, MutableBound,
+    Notification, OnBack, OnEvent, Releasable, Theme, WatchedBinding, WidgetLabel,
 };
 
+/// The most times the events caused by handlers are dispatched in a row, after which the events
+/// still being caused are dropped (as handlers are then most likely causing each other's events
+/// forever)
+const MAX_DISPATCH_PASSES: usize = 100;
+
 pub struct KayakContext {
     assets: resources::Resources,
+    check_bindings: bool,
+    pub(crate) clock: Clock,
     current_effect_index: usize,
     current_id: Index,
     current_state_index: usize,
+    cursor_icon: CursorIcon,
+    /// The payloads of the custom events being dispatched, keyed by event ID
+    pub(crate) custom_event_payloads: HashMap<u64, Box<dyn Any + Send + Sync>>,
+    /// The payload of the current drag (if any)
+    pub(crate) drag_payload: Option<DragPayload>,
     event_dispatcher: EventDispatcher,
     global_bindings: HashMap<crate::Index, Vec<crate::flo_binding::Uuid>>,
+    /// The listeners observing every dispatched event of their category, keyed by ID
+    global_listeners: Vec<(u64, EventCategory, GlobalListener)>,
     global_state: resources::Resources,
+    /// Where interactions are recorded (if anywhere)
+    interaction_sink: Option<InteractionSink>,
+    /// Whether events are being dispatched, during which the tree can't change
+    is_dispatching: bool,
     last_state_type_id: Option<std::any::TypeId>,
+    next_custom_event_id: u64,
+    next_global_listener_id: u64,
+    notifications: NotificationQueue,
+    /// Events caused while dispatching others, dispatched once those are handled
+    pending_events: Vec<Event>,
+    /// Whether a render was requested while dispatching events
+    render_deferred: bool,
+    /// The binding misuses already reported, so each one is only reported once per widget
+    reported_binding_misuses: HashSet<(Index, crate::binding_check::BindingMisuse)>,
+    screen_stack: ScreenStack,
+    settings: Settings,
+    visibility: VisibilityTracker,
+    /// The bindings shown in the `Inspector`
+    watched_bindings: Binding<Vec<WatchedBinding>>,
     // TODO: Make widget_manager private.
     pub widget_manager: WidgetManager,
     widget_effects: HashMap<crate::Index, resources::Resources>,
@@ -43,13 +84,31 @@ impl KayakContext {
     pub fn new() -> Self {
         Self {
             assets: resources::Resources::default(),
+            check_bindings: true,
+            clock: Clock::default(),
             current_effect_index: 0,
             current_id: crate::Index::default(),
             current_state_index: 0,
+            cursor_icon: CursorIcon::default(),
+            custom_event_payloads: HashMap::new(),
+            drag_payload: None,
             event_dispatcher: EventDispatcher::new(),
             global_bindings: HashMap::new(),
+            global_listeners: Vec::new(),
             global_state: resources::Resources::default(),
+            interaction_sink: None,
+            is_dispatching: false,
             last_state_type_id: None,
+            next_custom_event_id: 0,
+            next_global_listener_id: 0,
+            notifications: NotificationQueue::default(),
+            pending_events: Vec::new(),
+            render_deferred: false,
+            reported_binding_misuses: HashSet::new(),
+            screen_stack: ScreenStack::default(),
+            settings: Settings::default(),
+            visibility: VisibilityTracker::default(),
+            watched_bindings: Binding::new(Vec::new()),
             widget_effects: HashMap::new(),
             widget_manager: WidgetManager::new(),
             widget_providers: HashMap::new(),
@@ -172,6 +231,63 @@ impl KayakContext {
         self.last_state_type_id = None;
     }
 
+    /// Starts tracking the bindings read while rendering a widget
+    ///
+    /// This is called at the start of the render function of widgets, which then end with
+    /// [end_widget_render](Self::end_widget_render) given the returned depth.
+    #[doc(hidden)]
+    pub fn begin_widget_render(&mut self) -> usize {
+        crate::binding_check::begin_render()
+    }
+
+    /// Stops tracking the bindings read while rendering the given widget, and warns about the
+    /// ones it misused (in debug builds)
+    ///
+    /// See [set_check_bindings](Self::set_check_bindings).
+    #[doc(hidden)]
+    pub fn end_widget_render(&mut self, depth: usize, id: Index, widget_name: &str) {
+        let reads = crate::binding_check::end_render(depth);
+        // Reads are only recorded in debug builds
+        if !cfg!(debug_assertions) || !self.check_bindings {
+            return;
+        }
+
+        let registered = self
+            .widget_state_lifetimes
+            .get(&id)
+            .into_iter()
+            .flat_map(|lifetimes| lifetimes.keys());
+        let bound = self
+            .global_bindings
+            .get(&id)
+            .map(|bound| bound.as_slice())
+            .unwrap_or_default();
+        for misuse in crate::binding_check::find_misuses(&reads, registered, bound) {
+            if self.reported_binding_misuses.insert((id, misuse)) {
+                eprintln!("Warning: {}", misuse.message(widget_name));
+            }
+        }
+    }
+
+    /// Set whether to warn about bindings misused by widgets (enabled by default)
+    ///
+    /// In debug builds, each widget's render is checked for bindings that it reads without
+    /// binding to them (so it doesn't re-render when they change), and for bindings that it's
+    /// bound to without reading them (so it re-renders for nothing). Each misuse is printed once,
+    /// with the name of the widget and the ID of the binding. Release builds aren't checked.
+    pub fn set_check_bindings(&mut self, check_bindings: bool) {
+        self.check_bindings = check_bindings;
+    }
+
+    /// Set the most events dispatched for a single batch of input (1024 by default)
+    ///
+    /// A huge tree can turn one cursor movement into thousands of `MouseIn`, `MouseOut`, and
+    /// `Hover` events. Past this limit, the least important of these are dropped with a warning,
+    /// so that the frame doesn't stall. Other events (clicks, keys, focus, etc.) are never dropped.
+    pub fn set_max_events_per_frame(&mut self, max_events: usize) {
+        self.event_dispatcher.set_max_events(max_events);
+    }
+
     pub fn create_state<T: resources::Resource + Clone + PartialEq>(
         &mut self,
         initial_state: T,
@@ -382,6 +498,12 @@ impl KayakContext {
     }
 
     pub fn render(&mut self) {
+        if self.is_dispatching {
+            // The widgets handling the events are out of the tree, so wait for them to be done
+            self.render_deferred = true;
+            return;
+        }
+
         let dirty_nodes: Vec<_> =
             if let Ok(mut dirty_nodes) = self.widget_manager.dirty_nodes.lock() {
                 dirty_nodes.drain().collect()
@@ -398,6 +520,57 @@ impl KayakContext {
         // self.widget_manager.dirty_nodes.clear();
         self.widget_manager.render();
         self.widget_manager.calculate_layout();
+
+        // Layout is now up to date, so widgets can be told whether they scrolled into view
+        let events = self.visibility.update(&self.widget_manager);
+        if !events.is_empty() {
+            let dispatcher = self.event_dispatcher.to_owned();
+            self.dispatch_with(dispatcher, events);
+        }
+    }
+
+    /// Dispatches the given events with the given dispatcher, which then replaces the current one
+    ///
+    /// Handlers can't change the tree while the events are dispatched: renders they cause are
+    /// deferred until all events are handled, and events they cause (e.g. by focusing a widget)
+    /// are dispatched after the current ones. Those are dispatched in at most
+    /// [MAX_DISPATCH_PASSES] passes, after which the events still being caused are dropped.
+    fn dispatch_with(&mut self, mut dispatcher: EventDispatcher, events: Vec<Event>) {
+        if self.is_dispatching {
+            self.pending_events.extend(events);
+            return;
+        }
+
+        self.is_dispatching = true;
+        let mut events = events;
+        for pass in 1.. {
+            dispatcher.dispatch_events(events, self);
+            events = std::mem::take(&mut self.pending_events);
+            if events.is_empty() {
+                break;
+            }
+
+            if pass == MAX_DISPATCH_PASSES {
+                eprintln!(
+                    "Warning: dropped {} events caused by event handlers after dispatching them {} times in a row",
+                    events.len(),
+                    MAX_DISPATCH_PASSES
+                );
+                for event in events {
+                    if let EventType::Custom(custom) = event.event_type {
+                        self.custom_event_payloads.remove(&custom.id);
+                    }
+                }
+                break;
+            }
+        }
+        self.is_dispatching = false;
+        self.event_dispatcher = dispatcher;
+
+        if self.render_deferred {
+            self.render_deferred = false;
+            self.render();
+        }
     }
 
     /// Processes the given input events
@@ -406,10 +579,10 @@ impl KayakContext {
     /// associated [W3 specifications](https://www.w3.org/TR/uievents/#dom-event-architecture).
     ///
     /// ## Capture:
-    /// Currently, we do not support the Capture Phase. This is because the current event handling system is
-    ///   made to handle events as a single enum. To achieve proper capturing, widgets would need to be able to
-    ///   register separate event handlers so that specific ones could be captured while others would not. It
-    ///   should generally be okay to skip this as it's not a common use-case.
+    ///   The Capture Phase walks down the tree from the root to the target node, calling the capture handlers
+    ///   registered with [`set_capture_handler`](Self::set_capture_handler) along the way. This lets a parent
+    ///   intercept an event before its children see it: calling [`event.stop_propagation()`](Event::stop_propagation)
+    ///   here stops the event before it reaches the target. Only events that propagate have a Capture Phase.
     ///
     /// ## Target:
     ///   The Target Phase simply identifies the target for an event so that we can generate the propagation path
@@ -422,8 +595,17 @@ impl KayakContext {
     ///   they will only fire for their specified target.
     pub fn process_events(&mut self, input_events: Vec<InputEvent>) {
         let mut dispatcher = self.event_dispatcher.to_owned();
-        dispatcher.process_events(input_events, self);
-        self.event_dispatcher = dispatcher;
+        let now = self.clock.peek();
+        let events = dispatcher.build_event_stream(
+            &input_events,
+            &mut self.widget_manager,
+            self.drag_payload.as_ref(),
+            now,
+        );
+        // Sync the cursor state so that widgets handling these events see the latest values
+        // (e.g. `last_mouse_position`)
+        self.event_dispatcher = dispatcher.clone();
+        self.dispatch_with(dispatcher, events);
     }
 
     #[allow(dead_code)]
@@ -451,6 +633,544 @@ impl KayakContext {
         self.widget_manager.set_focusable(focusable, index, false);
     }
 
+    /// Set how far the given widget's content is scrolled (in pixels)
+    ///
+    /// This translates the layout of all its descendants, both for rendering and for hit-testing.
+    pub fn set_scroll_offset(&mut self, index: Index, offset: (f32, f32)) {
+        self.widget_manager.set_scroll_offset(index, offset);
+    }
+
+    /// Get how far the given widget's content is scrolled (in pixels)
+    pub fn get_scroll_offset(&self, index: Index) -> (f32, f32) {
+        self.widget_manager.get_scroll_offset(index)
+    }
+
+    /// Make the given widget modal, placing it above any other modal widgets
+    ///
+    /// While a widget is the topmost modal, pointer events only reach it and its descendants, and
+    /// `Tab` only moves focus between its descendants.
+    pub fn push_modal(&mut self, index: Index) {
+        self.widget_manager.push_modal(index);
+    }
+
+    /// Stop the given widget from being modal
+    pub fn remove_modal(&mut self, index: Index) {
+        self.widget_manager.remove_modal(index);
+    }
+
+    /// Get the topmost modal widget (if any)
+    pub fn get_modal(&self) -> Option<Index> {
+        self.widget_manager.get_modal()
+    }
+
+    /// Attach a piece of metadata to the given widget
+    ///
+    /// Metadata is not part of a widget's props or state, so it can be used to tag widgets (for
+    /// tutorials, analytics, tests, etc.) without affecting how they render. It also survives re-renders.
+    pub fn set_metadata<T: resources::Resource>(&mut self, index: Index, data: T) -> Option<T> {
+        self.widget_manager.set_metadata(index, data)
+    }
+
+    /// Get a copy of the metadata of type `T` attached to the given widget (if any)
+    pub fn get_metadata<T: resources::Resource + Clone>(&self, index: Index) -> Option<T> {
+        self.widget_manager.get_metadata(index)
+    }
+
+    /// Remove the metadata of type `T` from the given widget, returning it (if any)
+    pub fn remove_metadata<T: resources::Resource>(&mut self, index: Index) -> Option<T> {
+        self.widget_manager.remove_metadata(index)
+    }
+
+    /// Start dragging the widget handling the current [DragStart](EventType::DragStart) event,
+    /// carrying the given payload
+    ///
+    /// This only has an effect when called from a `DragStart` handler. The payload can be read
+    /// with [drag_payload](Self::drag_payload) until the [DragEnd](EventType::DragEnd) was handled.
+    pub fn start_drag(&mut self, payload: DragPayload) {
+        self.drag_payload = Some(payload);
+    }
+
+    /// Get the payload of the current drag (if any)
+    pub fn drag_payload(&self) -> Option<DragPayload> {
+        self.drag_payload.clone()
+    }
+
+    /// Returns true if a widget is currently being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.drag_payload.is_some()
+    }
+
+    /// Make the given widget a drop target for the drags whose payload the filter accepts, or stop
+    /// it from being one if the filter is `None`
+    ///
+    /// During a drag, the deepest drop target under the cursor accepting its payload receives
+    /// [DragEnter](EventType::DragEnter), [DragLeave](EventType::DragLeave) and
+    /// [Drop](EventType::Drop) events.
+    pub fn set_drop_target(&mut self, index: Index, filter: Option<DropFilter>) {
+        match filter {
+            Some(filter) => {
+                self.set_metadata(index, filter);
+            }
+            None => {
+                self.remove_metadata::<DropFilter>(index);
+            }
+        }
+    }
+
+    /// Set the handler called with the events whose propagation path goes through the given widget
+    /// before any widget on the path handles them, or remove it if `None`
+    ///
+    /// Capture handlers are called from the root down to the target (see
+    /// [process_events](Self::process_events)), so a parent can intercept events meant for its
+    /// children, e.g. a modal swallowing the clicks on the rest of the UI. Stopping the propagation
+    /// of an event stops it from reaching the target at all, and preventing its default action
+    /// works as usual. Only events that propagate are captured.
+    pub fn set_capture_handler(&mut self, index: Index, handler: Option<OnEvent>) {
+        match handler {
+            Some(handler) => {
+                self.set_metadata(index, CaptureHandler(handler));
+            }
+            None => {
+                self.remove_metadata::<CaptureHandler>(index);
+            }
+        }
+    }
+
+    /// Set the styles that win over all the others of the given widget, whatever the widget and
+    /// its parent set (see [StylePriority::Override](crate::styles::StylePriority::Override)), or
+    /// remove them if `None`
+    ///
+    /// Only the properties set in the override (those that differ from
+    /// [Style::default](crate::styles::Style::default)) are overridden. This is meant for the
+    /// user's customizations and for debugging, as styles are normally set through props.
+    pub fn set_style_override(&mut self, index: Index, styles: Option<crate::styles::Style>) {
+        self.widget_manager.set_style_override(index, styles);
+    }
+
+    /// Send a user-defined event carrying the given payload to the given widget, from which it
+    /// propagates up the tree like a click
+    ///
+    /// Handlers receive it as an [EventType::Custom] and can read its payload with
+    /// [get_custom_event_payload](Self::get_custom_event_payload), which lets widgets notify their
+    /// ancestors (e.g. a `Select` telling its form that it changed) without a shared binding.
+    /// If called while events are dispatched, it's sent once those are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use kayak_core::{EventType, Index, KayakContext, OnEvent};
+    /// #[derive(Debug, Clone)]
+    /// struct FieldChanged(String);
+    ///
+    /// let on_event = OnEvent::new(|ctx, event| match event.event_type {
+    ///     EventType::Custom(custom) => {
+    ///         if let Some(FieldChanged(field)) = ctx.get_custom_event_payload::<FieldChanged>(&custom) {
+    ///             println!("{} changed", field);
+    ///         }
+    ///     }
+    ///     _ => {}
+    /// });
+    ///
+    /// # let mut context = KayakContext::new();
+    /// # let select = Index::default();
+    /// context.dispatch_custom_event(select, FieldChanged("country".to_string()));
+    /// ```
+    pub fn dispatch_custom_event<T: Any + Send + Sync>(&mut self, target: Index, payload: T) {
+        let id = self.next_custom_event_id;
+        self.next_custom_event_id += 1;
+        self.custom_event_payloads.insert(id, Box::new(payload));
+
+        let event = Event::new(target, EventType::Custom(CustomEvent::new::<T>(id)));
+        let dispatcher = self.event_dispatcher.to_owned();
+        self.dispatch_with(dispatcher, vec![event]);
+    }
+
+    /// Get the payload of the given custom event, if it's of type `T` and still being dispatched
+    pub fn get_custom_event_payload<T: Any + Send + Sync>(
+        &self,
+        event: &CustomEvent,
+    ) -> Option<&T> {
+        self.custom_event_payloads
+            .get(&event.id)
+            .and_then(|payload| payload.downcast_ref::<T>())
+    }
+
+    /// Call the given listener with every dispatched event of the given category, once it's been
+    /// handled by the widgets it reached
+    ///
+    /// This lets game code react to the UI as a whole (e.g. playing a sound on every click, or
+    /// advancing a tutorial) without wrapping the `on_event` of each widget. Returns the ID of the
+    /// listener, which can be used to remove it.
+    pub fn add_global_listener(
+        &mut self,
+        category: EventCategory,
+        listener: GlobalListener,
+    ) -> u64 {
+        let id = self.next_global_listener_id;
+        self.next_global_listener_id += 1;
+        self.global_listeners.push((id, category, listener));
+        id
+    }
+
+    /// Remove the global listener with the given ID, returning true if it was still registered
+    pub fn remove_global_listener(&mut self, id: u64) -> bool {
+        let count = self.global_listeners.len();
+        self.global_listeners
+            .retain(|(listener_id, ..)| *listener_id != id);
+        self.global_listeners.len() != count
+    }
+
+    /// Calls the global listeners of the category of the given event
+    pub(crate) fn notify_global_listeners(&mut self, event: &Event) {
+        let category = event.event_type.event_category();
+        let listeners = self
+            .global_listeners
+            .iter()
+            .filter(|(_, listener_category, _)| *listener_category == category)
+            .map(|(.., listener)| listener.clone())
+            .collect::<Vec<_>>();
+        for listener in listeners {
+            listener.call(self, event);
+        }
+    }
+
+    /// Send the interactions with widgets (see [Interaction]) to the given sink, or stop recording
+    /// them if `None`
+    ///
+    /// Nothing is recorded by default. Labeling the menus and buttons of interest (see
+    /// [set_label](Self::set_label)) makes the recorded interactions easier to tell apart.
+    pub fn set_interaction_sink(&mut self, sink: Option<InteractionSink>) {
+        self.interaction_sink = sink;
+    }
+
+    /// Records the interaction of the given type with the given widget, if interactions are recorded
+    pub(crate) fn record_interaction(&self, index: Index, event_type: EventType) {
+        let sink = match &self.interaction_sink {
+            Some(sink) => sink,
+            None => return,
+        };
+        let widget_name = match self.widget_manager.get_name(&index) {
+            Some(name) => name,
+            None => return,
+        };
+
+        let mut label = None;
+        let mut current = Some(index);
+        while let Some(index) = current {
+            label = self.get_label(index);
+            if label.is_some() {
+                break;
+            }
+            current = self.widget_manager.tree.get_parent(index);
+        }
+
+        sink.record(&Interaction {
+            widget: index,
+            widget_name,
+            label,
+            event_type,
+            timestamp: self.clock.peek(),
+            screen: self.current_screen(),
+        });
+    }
+
+    /// Label the given widget so that it can be found with [find_by_label](Self::find_by_label)
+    pub fn set_label(&mut self, index: Index, label: impl Into<String>) {
+        self.set_metadata(index, WidgetLabel(label.into()));
+    }
+
+    /// Get the label of the given widget (if any)
+    pub fn get_label(&self, index: Index) -> Option<String> {
+        self.get_metadata::<WidgetLabel>(index).map(|label| label.0)
+    }
+
+    /// Find the first widget (in tree order) with the given label
+    pub fn find_by_label(&self, label: &str) -> Option<Index> {
+        self.find_all_by_label(label).into_iter().next()
+    }
+
+    /// Find all widgets (in tree order) with the given label
+    pub fn find_all_by_label(&self, label: &str) -> Vec<Index> {
+        self.find_all(|context, index| {
+            context
+                .get_label(index)
+                .map_or(false, |value| value == label)
+        })
+    }
+
+    /// Find all widgets (in tree order) with the given name
+    ///
+    /// The name of a widget is generally the name of its function (e.g. `"Button"`).
+    pub fn find_all_by_name(&self, name: &str) -> Vec<Index> {
+        self.find_all(|context, index| {
+            context
+                .widget_manager
+                .get_name(&index)
+                .map_or(false, |value| value == name)
+        })
+    }
+
+    /// Find all widgets (in tree order) that match the given predicate
+    pub fn find_all<F: Fn(&Self, Index) -> bool>(&self, predicate: F) -> Vec<Index> {
+        self.widget_manager
+            .tree
+            .flatten()
+            .into_iter()
+            .filter(|index| predicate(self, *index))
+            .collect()
+    }
+
+    /// Simulate a full mouse click on the given widget
+    ///
+    /// This moves the cursor to the center of the widget's layout, then presses and releases the
+    /// left mouse button. Each step is processed as its own batch of input events, just as it would
+    /// be when coming from a real device.
+    ///
+    /// Returns false if the widget has no layout to click on.
+    pub fn simulate_click(&mut self, index: Index) -> bool {
+        let layout = if let Some(layout) = self.widget_manager.find_layout(index) {
+            layout
+        } else {
+            return false;
+        };
+
+        let center = (
+            layout.posx + layout.width / 2.0,
+            layout.posy + layout.height / 2.0,
+        );
+        self.process_events(vec![InputEvent::MouseMoved(center)]);
+        self.process_events(vec![InputEvent::MouseLeftPress]);
+        self.process_events(vec![InputEvent::MouseLeftRelease]);
+
+        true
+    }
+
+    /// Simulate typing the given text into the given widget
+    ///
+    /// The widget is focused first (sending the appropriate `Focus` and `Blur` events), then each
+    /// character is sent as a separate `CharInput` event.
+    pub fn simulate_typing(&mut self, index: Index, text: &str) {
+        self.simulate_focus(index);
+        for c in text.chars() {
+            self.process_events(vec![InputEvent::CharEvent { c }]);
+        }
+    }
+
+    /// Simulate pressing and releasing the given key on the currently focused widget
+    pub fn simulate_key_press(&mut self, key: KeyCode) {
+        self.process_events(vec![InputEvent::Keyboard {
+            key,
+            physical_key: None,
+            is_pressed: true,
+        }]);
+        self.process_events(vec![InputEvent::Keyboard {
+            key,
+            physical_key: None,
+            is_pressed: false,
+        }]);
+    }
+
+    /// Move focus to the given widget, sending the appropriate `Focus` and `Blur` events
+    ///
+    /// This is the same as [set_focus](Self::set_focus), named to match the other simulated input.
+    pub fn simulate_focus(&mut self, index: Index) {
+        self.set_focus(index);
+    }
+
+    /// Move focus to the given widget, sending the appropriate `Focus` and `Blur` events
+    ///
+    /// This can be called from event handlers (e.g. to move focus between the items of a widget
+    /// with the arrow keys), as long as the widget handling the event is neither the one gaining
+    /// nor the one losing focus.
+    pub fn set_focus(&mut self, index: Index) {
+        let current_focus = self.widget_manager.focus_tree.current();
+        if current_focus == Some(index) {
+            return;
+        }
+
+        let mut events = vec![Event::new(index, EventType::Focus)];
+        if let Some(current_focus) = current_focus {
+            events.push(Event::new(current_focus, EventType::Blur));
+        }
+        self.widget_manager.focus_tree.focus(index);
+
+        let dispatcher = self.event_dispatcher.to_owned();
+        self.dispatch_with(dispatcher, events);
+    }
+
+    /// Start sending [EnterView](EventType::EnterView) and [ExitView](EventType::ExitView) events to
+    /// the given widget
+    ///
+    /// The widget is checked against the layout after every render. It's sent `EnterView` once any
+    /// part of it is within the window and not clipped by its ancestors (e.g. scrolled out of a
+    /// scroll box), and `ExitView` once it no longer is. Widgets without a layout of their own
+    /// (like those using [`RenderCommand::Empty`](crate::render_command::RenderCommand::Empty)) use
+    /// that of their first descendant with one.
+    ///
+    /// Watching an already watched widget does nothing, so this can be called on every render.
+    pub fn watch_visibility(&mut self, index: Index) {
+        self.visibility.watch(index);
+    }
+
+    /// Stop sending visibility events to the given widget
+    pub fn unwatch_visibility(&mut self, index: Index) {
+        self.visibility.unwatch(index);
+    }
+
+    /// Returns whether the given watched widget was within view after the last render
+    ///
+    /// Returns `None` if the widget isn't watched (see [watch_visibility](Self::watch_visibility))
+    /// or hasn't been checked yet.
+    pub fn is_in_view(&self, index: Index) -> Option<bool> {
+        self.visibility.is_in_view(index)
+    }
+
+    /// Push a screen onto the navigation stack
+    ///
+    /// The navigation stack is used for consistent back/cancel behavior: requesting back navigation
+    /// (see [navigate_back](Self::navigate_back)) pops the current screen unless it registered its own
+    /// handler with [on_back](Self::on_back).
+    pub fn push_screen(&mut self, screen: impl Into<String>) {
+        self.screen_stack.push(screen.into());
+    }
+
+    /// Pop the current screen off the navigation stack, returning it (if any)
+    pub fn pop_screen(&mut self) -> Option<String> {
+        self.screen_stack.pop()
+    }
+
+    /// Get the screen currently on top of the navigation stack
+    pub fn current_screen(&self) -> Option<String> {
+        self.screen_stack.current()
+    }
+
+    /// Get the binding for the entire navigation stack (bottom to top)
+    ///
+    /// Bind to this in order to re-render whenever a screen is pushed or popped.
+    pub fn screen_stack(&self) -> Binding<Vec<String>> {
+        self.screen_stack.screens()
+    }
+
+    /// Register a handler for back navigation on the current screen
+    ///
+    /// When back navigation is requested while the current screen is still on top, this handler
+    /// is called _instead_ of popping the screen. This allows screens to confirm, close a sub-panel
+    /// first, or pop themselves. If no screen has been pushed, the handler is registered for the root.
+    pub fn on_back(&mut self, handler: OnBack) {
+        self.screen_stack.set_back_handler(handler);
+    }
+
+    /// Request back navigation
+    ///
+    /// This is the default action of the `Escape` key, which is sent to the topmost modal (or the
+    /// root) when nothing is focused, and doesn't navigate back again while held. It calls the current screen's back handler
+    /// (see [on_back](Self::on_back)) or, if there isn't one, pops the current screen. A handler
+    /// requesting back navigation itself (e.g. once the user confirmed) pops the screen rather than
+    /// being called again.
+    ///
+    /// Returns true if anything handled the request.
+    pub fn navigate_back(&mut self) -> bool {
+        if let Some(handler) = self.screen_stack.get_back_handler() {
+            // The handler is locked while it runs, so this fails when it's the one asking
+            if let Ok(mut handler) = handler.0.try_write() {
+                handler(self);
+                return true;
+            }
+        }
+
+        self.pop_screen().is_some()
+    }
+
+    /// Show a transient notification (or "toast") in the `NotificationArea` widget
+    ///
+    /// Returns the ID of the notification, which can be used to dismiss it early.
+    pub fn push_notification(&mut self, notification: impl Into<Notification>) -> u64 {
+        self.notifications.push(notification.into())
+    }
+
+    /// Dismiss the notification with the given ID, returning true if it was still shown
+    pub fn dismiss_notification(&mut self, id: u64) -> bool {
+        self.notifications.dismiss(id)
+    }
+
+    /// Dismiss all notifications
+    pub fn clear_notifications(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Get the binding for the notifications currently shown (oldest first)
+    ///
+    /// Bind to this in order to re-render whenever a notification is pushed or dismissed.
+    pub fn notifications(&self) -> Binding<Vec<Notification>> {
+        self.notifications.notifications()
+    }
+
+    /// Advance the notifications by the given time (in seconds), dismissing the expired ones
+    ///
+    /// The Bevy plugin calls this every frame.
+    pub fn advance_notifications(&mut self, delta: f32) {
+        self.notifications.advance(delta);
+    }
+
+    /// Get the current time, as seen by widgets (for delays, animations, etc.)
+    ///
+    /// This is the real time, unless it's simulated (see [simulate_time](Self::simulate_time)).
+    /// Widgets should prefer this over [Instant::now] for anything time-dependent, so they can be
+    /// tested deterministically.
+    pub fn now(&mut self) -> Instant {
+        self.clock.now(self.current_id)
+    }
+
+    /// Freeze the time seen by widgets, so that it only moves with
+    /// [advance_time](Self::advance_time)
+    ///
+    /// This is meant for tests, to check time-dependent behaviors (delays, animations, etc.)
+    /// deterministically. While simulated, the Bevy plugin no longer advances the notifications.
+    pub fn simulate_time(&mut self) {
+        self.clock.simulate();
+    }
+
+    /// Move the simulated time forward (simulating it first if needed)
+    ///
+    /// The notifications are advanced along with the time, and the widgets that read the time
+    /// (see [now](Self::now)) are marked to re-render, so the next [render](Self::render) shows
+    /// them at the new time.
+    pub fn advance_time(&mut self, delta: Duration) {
+        self.notifications.advance(delta.as_secs_f32());
+        let readers = self.clock.advance(delta);
+        self.mark_dirty(readers);
+    }
+
+    /// Go back to the real time after [simulate_time](Self::simulate_time)
+    pub fn resume_real_time(&mut self) {
+        let readers = self.clock.resume();
+        self.mark_dirty(readers);
+    }
+
+    /// Returns whether the time seen by widgets is simulated
+    pub fn is_time_simulated(&self) -> bool {
+        self.clock.is_simulated()
+    }
+
+    /// Mark the given widgets (those still in the tree) to re-render
+    fn mark_dirty(&mut self, widgets: Vec<Index>) {
+        if let Ok(mut dirty_nodes) = self.widget_manager.dirty_nodes.lock() {
+            for widget in widgets {
+                if self.widget_manager.tree.contains(widget) {
+                    dirty_nodes.insert(widget);
+                }
+            }
+        }
+    }
+
+    /// Get the app's settings, which are shared with (and persisted by) the settings widgets
+    ///
+    /// Load them with [Settings::load_file] on startup and save them with [Settings::save_file]
+    /// whenever appropriate (e.g. when leaving the settings screen).
+    pub fn settings(&self) -> Settings {
+        self.settings.clone()
+    }
+
     /// Get the last calculated mouse position.
     ///
     /// Calling this from a widget will return the last mouse position at the time the widget was rendered.
@@ -458,6 +1178,40 @@ impl KayakContext {
         self.event_dispatcher.current_mouse_position()
     }
 
+    /// Ask for the cursor to show the given icon (e.g. while hovering a resize handle)
+    ///
+    /// The icon stays until it's changed again, so widgets should reset it to
+    /// [CursorIcon::Default] once they no longer need it. It's applied to the window by the
+    /// renderer (see [cursor_icon](Self::cursor_icon)).
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.cursor_icon = icon;
+    }
+
+    /// Get the icon the UI asks the cursor to show
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icon
+    }
+
+    /// Get the theme that [theme tokens](crate::theme) in styles resolve to
+    pub fn theme(&self) -> &Theme {
+        self.widget_manager.theme()
+    }
+
+    /// Switch the theme that [theme tokens](crate::theme) in styles resolve to
+    ///
+    /// Widgets using tokens are restyled without being re-rendered.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.widget_manager.set_theme(theme);
+    }
+
+    /// Get the keyboard modifiers that are currently pressed
+    ///
+    /// This is useful for events that don't carry the modifiers themselves, like mouse events (e.g.
+    /// to adjust a value more finely while Shift is held during a drag).
+    pub fn keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.event_dispatcher.keyboard_modifiers()
+    }
+
     #[cfg(feature = "bevy_renderer")]
     pub fn query_world<T: bevy::ecs::system::SystemParam, F, R>(&mut self, mut f: F) -> R
     where
@@ -509,6 +1263,97 @@ impl KayakContext {
         self.event_dispatcher.last_clicked.clone()
     }
 
+    /// Show the live value of the given binding in the `Inspector`, under the given label
+    ///
+    /// Watching another binding under the same label replaces it, so this can be called on every
+    /// render of the widget owning the binding.
+    pub fn watch_binding<T: Clone + PartialEq + std::fmt::Debug + Send + Sync + 'static>(
+        &mut self,
+        label: impl Into<String>,
+        binding: &Binding<T>,
+    ) {
+        let watched = WatchedBinding::new(label, binding);
+        let mut watched_bindings = self.watched_bindings.get();
+        if watched_bindings.contains(&watched) {
+            return;
+        }
+
+        watched_bindings.retain(|other| other.label() != watched.label());
+        watched_bindings.push(watched);
+        self.watched_bindings.set(watched_bindings);
+    }
+
+    /// Stop showing the binding watched under the given label in the `Inspector`
+    pub fn unwatch_binding(&mut self, label: &str) {
+        let mut watched_bindings = self.watched_bindings.get();
+        let count = watched_bindings.len();
+        watched_bindings.retain(|watched| watched.label() != label);
+        if watched_bindings.len() != count {
+            self.watched_bindings.set(watched_bindings);
+        }
+    }
+
+    /// Get the binding for the bindings watched in the `Inspector` (see
+    /// [watch_binding](Self::watch_binding))
+    pub fn watched_bindings(&self) -> Binding<Vec<WatchedBinding>> {
+        self.watched_bindings.clone()
+    }
+
+    /// Get the number of clicks in a row on the last clicked widget (1 for a single click, 2 for a
+    /// double click, and so on)
+    ///
+    /// Calling this while handling a [Click](crate::EventType::Click) gives the count of that click,
+    /// e.g. to select a word on a double click and a line on a triple click.
+    pub fn click_count(&self) -> u32 {
+        self.event_dispatcher.click_count()
+    }
+
+    /// Set how long the left mouse button (or a touch) is held over a widget for it to receive a
+    /// [LongPress](EventType::LongPress) (500 milliseconds by default)
+    ///
+    /// Long presses are detected when events are processed, so the events need to be processed
+    /// every frame (even without input), as `bevy_kayak_ui` does.
+    pub fn set_long_press_time(&mut self, long_press_time: Duration) {
+        self.event_dispatcher.set_long_press_time(long_press_time);
+    }
+
+    /// Set how held keys repeat their [KeyDown](EventType::KeyDown) event (and the characters
+    /// they typed) to the focused widget, or `None` to only send the repeats reported by the system
+    ///
+    /// Keys repeat after 500 milliseconds, about 30 times per second, by default. While enabled, the
+    /// repeats reported by the system are ignored so keys don't repeat twice as fast. Either way,
+    /// repeated events are flagged (see [KeyboardEvent::is_repeat](crate::KeyboardEvent::is_repeat)).
+    ///
+    /// Like long presses, repeats are detected when events are processed, so the events need to be
+    /// processed every frame (even without input).
+    pub fn set_key_repeat(&mut self, key_repeat: Option<KeyRepeat>) {
+        self.event_dispatcher.set_key_repeat(key_repeat);
+    }
+
+    /// Get the active keyboard layout, e.g. to show the key typed by a physical key in a
+    /// shortcut hint
+    ///
+    /// The layout is corrected with every key pressed whose physical key is known (see
+    /// [InputEvent::Keyboard]), so it matches the user's keyboard once they have typed the keys.
+    pub fn keyboard_layout(&self) -> &KeyboardLayout {
+        self.event_dispatcher.keyboard_layout()
+    }
+
+    /// Set the active keyboard layout (QWERTY by default)
+    ///
+    /// The physical key of a [KeyboardEvent](crate::KeyboardEvent) is found with this layout
+    /// when the input doesn't report it.
+    pub fn set_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) {
+        self.event_dispatcher.set_keyboard_layout(keyboard_layout);
+    }
+
+    /// Set the longest time between two clicks on a widget for them to count as a double click
+    /// (500 milliseconds by default)
+    pub fn set_double_click_time(&mut self, double_click_time: Duration) {
+        self.event_dispatcher
+            .set_double_click_time(double_click_time);
+    }
+
     /// Returns true if the cursor is currently over a valid widget
     ///
     /// For the purposes of this method, a valid widget is one which has the means to display a visual component on its own.
@@ -534,3 +1379,282 @@ impl KayakContext {
         self.event_dispatcher.has_cursor()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use derivative::*;
+
+    use super::{KayakContext, MAX_DISPATCH_PASSES};
+    use crate::render_command::RenderCommand;
+    use crate::styles::{Style, StyleProp, Units};
+    use crate::{
+        Children, Event, EventType, Index, InputEvent, KeyCode, OnEvent, Widget, WidgetBuilder,
+        WidgetTree,
+    };
+
+    /// A widget calling its event handler, whose children are rendered again on every render
+    #[derive(Derivative)]
+    #[derivative(Default, Debug, PartialEq, Clone)]
+    struct Listener {
+        id: Index,
+        styles: Option<Style>,
+        #[derivative(Debug = "ignore", PartialEq = "ignore")]
+        children: Children,
+        #[derivative(Debug = "ignore", PartialEq = "ignore")]
+        on_event: Option<OnEvent>,
+    }
+
+    impl Widget for Listener {
+        fn focusable(&self) -> Option<bool> {
+            Some(false)
+        }
+
+        fn get_id(&self) -> Index {
+            self.id
+        }
+
+        fn set_id(&mut self, id: Index) {
+            self.id = id;
+        }
+
+        fn get_styles(&self) -> Option<Style> {
+            self.styles.clone()
+        }
+
+        fn set_styles(&mut self, styles: Option<Style>) {
+            self.styles = styles;
+        }
+
+        fn set_children(&mut self, children: Children) {
+            self.children = children;
+        }
+
+        fn set_on_event(&mut self, on_event: Option<OnEvent>) {
+            self.on_event = on_event;
+        }
+
+        fn get_name(&self) -> String {
+            String::from("Listener")
+        }
+
+        fn on_event(&mut self, context: &mut KayakContext, event: &mut Event) {
+            if let Some(on_event) = self.on_event.as_ref() {
+                if let Ok(mut on_event) = on_event.0.write() {
+                    on_event(context, event);
+                }
+            }
+        }
+
+        fn render(&mut self, context: &mut KayakContext) {
+            let tree = WidgetTree::new();
+            tree.add(self.id, None);
+            if let Some(children) = self.children.clone() {
+                children(tree.clone(), Some(self.id), context);
+            }
+
+            let tree = tree.take();
+            let changes = context.widget_manager.tree.diff_children(&tree, self.id);
+            context.widget_manager.tree.merge(&tree, self.id, changes);
+        }
+    }
+
+    /// Renders a [Listener] as the root, calling the given handler with the custom events it gets
+    /// (along with their payload)
+    fn render_listener<F>(context: &mut KayakContext, mut handler: F) -> Index
+    where
+        F: FnMut(&mut KayakContext, &Event, u32) + Send + Sync + 'static,
+    {
+        WidgetBuilder::new::<Listener>()
+            .on_event(OnEvent::new(move |ctx, event| {
+                if let EventType::Custom(custom) = event.event_type {
+                    if let Some(payload) = ctx.get_custom_event_payload::<u32>(&custom).copied() {
+                        handler(ctx, event, payload);
+                    }
+                }
+            }))
+            .render_root(context);
+        context.widget_manager.tree.root_node.unwrap()
+    }
+
+    #[test]
+    fn events_caused_while_dispatching_should_be_dispatched_once_in_order() {
+        let mut context = KayakContext::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let root = {
+            let received = received.clone();
+            render_listener(&mut context, move |ctx, event, payload| {
+                received.lock().unwrap().push(payload);
+                if payload == 0 {
+                    ctx.dispatch_custom_event(event.target, 1u32);
+                    ctx.dispatch_custom_event(event.target, 2u32);
+                }
+            })
+        };
+
+        context.dispatch_custom_event(root, 0u32);
+        assert_eq!(vec![0, 1, 2], *received.lock().unwrap());
+        assert!(context.pending_events.is_empty());
+        assert!(context.custom_event_payloads.is_empty());
+    }
+
+    #[test]
+    fn events_causing_each_other_forever_should_be_dropped() {
+        let mut context = KayakContext::new();
+        let received = Arc::new(Mutex::new(0));
+        let root = {
+            let received = received.clone();
+            render_listener(&mut context, move |ctx, event, payload| {
+                *received.lock().unwrap() += 1;
+                ctx.dispatch_custom_event(event.target, payload + 1);
+            })
+        };
+
+        context.dispatch_custom_event(root, 0u32);
+        assert_eq!(MAX_DISPATCH_PASSES, *received.lock().unwrap());
+        assert!(context.pending_events.is_empty());
+        assert!(context.custom_event_payloads.is_empty());
+    }
+
+    #[test]
+    fn handlers_should_be_able_to_remove_their_own_widget() {
+        let mut context = KayakContext::new();
+        let show_child = Arc::new(AtomicBool::new(true));
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let child_handler = {
+            let show_child = show_child.clone();
+            let received = received.clone();
+            OnEvent::new(move |ctx, event| {
+                if let EventType::Custom(_) = event.event_type {
+                    received.lock().unwrap().push("child");
+                    // Re-render the parent without this widget
+                    show_child.store(false, Ordering::SeqCst);
+                    ctx.widget_manager.dirty(false);
+                    ctx.render();
+                }
+            })
+        };
+        let parent_handler = {
+            let received = received.clone();
+            OnEvent::new(move |_, event| {
+                if let EventType::Custom(_) = event.event_type {
+                    received.lock().unwrap().push("parent");
+                }
+            })
+        };
+        let children: Children = {
+            let show_child = show_child.clone();
+            Some(Arc::new(
+                move |tree: WidgetTree, parent_id: Option<Index>, context: &mut KayakContext| {
+                    if show_child.load(Ordering::SeqCst) {
+                        WidgetBuilder::new::<Listener>()
+                            .on_event(child_handler.clone())
+                            .render(&tree, parent_id, context);
+                    }
+                },
+            ))
+        };
+        WidgetBuilder::new::<Listener>()
+            .on_event(parent_handler)
+            .with_children(children)
+            .render_root(&mut context);
+
+        let tree = &context.widget_manager.tree;
+        let root = tree.root_node.unwrap();
+        let child = tree.children.get(&root).unwrap()[0];
+
+        context.dispatch_custom_event(child, ());
+        // The render is deferred until the event is handled, so it still bubbles to the parent
+        assert_eq!(vec!["child", "parent"], *received.lock().unwrap());
+        let children = context.widget_manager.tree.children.get(&root);
+        assert!(!children.map_or(false, |children| children.contains(&child)));
+        assert!(!context.is_dispatching);
+    }
+
+    #[test]
+    fn escape_should_navigate_back_once_without_focus() {
+        let mut context = KayakContext::new();
+        WidgetBuilder::new::<Listener>().render_root(&mut context);
+        context.push_screen("inventory");
+        context.push_screen("item");
+        context.push_screen("confirm");
+        assert_eq!(None, context.current_focus());
+
+        let escape = |is_pressed| InputEvent::Keyboard {
+            key: KeyCode::Escape,
+            physical_key: None,
+            is_pressed,
+        };
+        context.process_events(vec![escape(true)]);
+        assert_eq!(Some("item".to_string()), context.current_screen());
+
+        // Key repeats while Escape is held don't pop more screens
+        context.process_events(vec![escape(true)]);
+        context.process_events(vec![escape(true)]);
+        assert_eq!(Some("item".to_string()), context.current_screen());
+
+        context.process_events(vec![escape(false)]);
+        context.simulate_key_press(KeyCode::Escape);
+        assert_eq!(Some("inventory".to_string()), context.current_screen());
+    }
+
+    #[test]
+    fn preventing_the_default_of_escape_should_not_navigate_back() {
+        let mut context = KayakContext::new();
+        WidgetBuilder::new::<Listener>()
+            .on_event(OnEvent::new(|_, event| {
+                if let EventType::KeyDown(..) = event.event_type {
+                    event.prevent_default();
+                }
+            }))
+            .render_root(&mut context);
+        context.push_screen("inventory");
+
+        context.simulate_key_press(KeyCode::Escape);
+        assert_eq!(Some("inventory".to_string()), context.current_screen());
+    }
+
+    #[test]
+    fn labeled_widgets_should_be_found_and_receive_simulated_input() {
+        let mut context = KayakContext::new();
+        let sized = |size| Style {
+            render_command: StyleProp::Value(RenderCommand::Layout),
+            width: StyleProp::Value(Units::Pixels(size)),
+            height: StyleProp::Value(Units::Pixels(size)),
+            ..Style::default()
+        };
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let on_event = {
+            let received = received.clone();
+            OnEvent::new(move |_, event| match event.event_type {
+                EventType::Click => received.lock().unwrap().push("click".to_string()),
+                EventType::CharInput { c } => received.lock().unwrap().push(c.to_string()),
+                _ => {}
+            })
+        };
+        WidgetBuilder::new::<Listener>()
+            .styles(sized(100.0))
+            .child(WidgetBuilder::new::<Listener>().styles(sized(20.0)))
+            .child(
+                WidgetBuilder::new::<Listener>()
+                    .styles(sized(50.0))
+                    .on_event(on_event),
+            )
+            .render_root(&mut context);
+        context.render();
+
+        let root = context.widget_manager.tree.root_node.unwrap();
+        let button = context.widget_manager.tree.children.get(&root).unwrap()[1];
+        assert_eq!(None, context.find_by_label("confirm"));
+        context.set_label(button, "confirm");
+        assert_eq!(Some(button), context.find_by_label("confirm"));
+        assert_eq!(3, context.find_all_by_name("Listener").len());
+
+        assert!(context.simulate_click(button));
+        context.simulate_typing(button, "ok");
+        assert_eq!(vec!["click", "o", "k"], *received.lock().unwrap());
+    }
+}
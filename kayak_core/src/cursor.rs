@@ -16,3 +16,31 @@ impl Default for PointerEvents {
         Self::All
     }
 }
+
+/// The icon the UI asks the cursor to show (see
+/// [KayakContext::set_cursor_icon](crate::KayakContext::set_cursor_icon))
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    /// The platform's default cursor (usually an arrow)
+    Default,
+    /// A hand, for clickable content such as links
+    Pointer,
+    /// A text cursor (or "I-beam")
+    Text,
+    /// Arrows in all four directions, for moving things around
+    Move,
+    /// A horizontal double arrow, for resizing the width of something
+    ResizeHorizontal,
+    /// A vertical double arrow, for resizing the height of something
+    ResizeVertical,
+    /// A double arrow from the top-left to the bottom-right corner
+    ResizeNwSe,
+    /// A double arrow from the top-right to the bottom-left corner
+    ResizeNeSw,
+}
+
+impl Default for CursorIcon {
+    fn default() -> Self {
+        Self::Default
+    }
+}
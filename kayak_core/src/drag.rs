@@ -0,0 +1,94 @@
+use std::any::Any;
+use std::sync::Arc;
+
+/// The data carried by a drag, set by the dragged widget when it receives
+/// [DragStart](crate::EventType::DragStart) (see [KayakContext::start_drag](crate::KayakContext::start_drag))
+#[derive(Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    pub fn new<T: Any + Send + Sync>(data: T) -> Self {
+        Self(Arc::new(data))
+    }
+
+    /// Get the data of the payload, if it's of type `T`
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
+
+    /// Checks if the data of the payload is of type `T`
+    pub fn is<T: Any + Send + Sync>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+
+impl PartialEq for DragPayload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DragPayload").finish()
+    }
+}
+
+/// Decides which payloads a drop target accepts (see [KayakContext::set_drop_target](crate::KayakContext::set_drop_target))
+#[derive(Clone)]
+pub struct DropFilter(pub Arc<dyn Fn(&DragPayload) -> bool + Send + Sync>);
+
+impl DropFilter {
+    pub fn new<F: Fn(&DragPayload) -> bool + Send + Sync + 'static>(f: F) -> DropFilter {
+        DropFilter(Arc::new(f))
+    }
+
+    /// A filter accepting any payload
+    pub fn any() -> DropFilter {
+        Self::new(|_| true)
+    }
+
+    /// A filter accepting the payloads of type `T`
+    pub fn of<T: Any + Send + Sync>() -> DropFilter {
+        Self::new(|payload| payload.is::<T>())
+    }
+
+    /// Checks if the given payload can be dropped
+    pub fn accepts(&self, payload: &DragPayload) -> bool {
+        (self.0)(payload)
+    }
+}
+
+impl Default for DropFilter {
+    fn default() -> Self {
+        Self::any()
+    }
+}
+
+impl std::fmt::Debug for DropFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DropFilter").finish()
+    }
+}
+
+impl PartialEq for DropFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DragPayload, DropFilter};
+
+    #[test]
+    fn filters_should_only_accept_payloads_of_their_type() {
+        let card = DragPayload::new(7u32);
+        assert_eq!(Some(&7), card.get::<u32>());
+        assert_eq!(None, card.get::<String>());
+
+        assert!(DropFilter::of::<u32>().accepts(&card));
+        assert!(!DropFilter::of::<String>().accepts(&card));
+        assert!(DropFilter::any().accepts(&card));
+    }
+}
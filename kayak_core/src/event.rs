@@ -1,4 +1,6 @@
-use crate::{Index, KeyboardEvent};
+use std::any::{Any, TypeId};
+
+use crate::{Index, KeyboardEvent, NavigationDirection};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Event {
@@ -12,6 +14,8 @@ pub struct Event {
     pub(crate) should_propagate: bool,
     /// Indicates whether the default action of this event (if any) has been prevented
     pub(crate) default_prevented: bool,
+    /// Indicates whether the current target requested to capture the cursor
+    pub(crate) should_capture_cursor: bool,
 }
 
 impl Default for Event {
@@ -22,6 +26,7 @@ impl Default for Event {
             event_type: EventType::Click,
             should_propagate: true,
             default_prevented: false,
+            should_capture_cursor: false,
         }
     }
 }
@@ -38,6 +43,7 @@ impl Event {
             event_type,
             should_propagate: event_type.propagates(),
             default_prevented: false,
+            should_capture_cursor: false,
         }
     }
 
@@ -60,21 +66,165 @@ impl Event {
     pub fn prevent_default(&mut self) {
         self.default_prevented = true;
     }
+
+    /// Captures the cursor for the current target until the mouse is released
+    ///
+    /// While captured, the current target receives [MouseMove](EventType::MouseMove) events for
+    /// every cursor movement, even those outside its bounds. This is mainly useful for dragging.
+    pub fn capture_cursor(&mut self) {
+        self.should_capture_cursor = true;
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
     Click,
+    /// Sent to the deepest widget under the cursor on the second of two clicks on it in a row
+    /// (see [KayakContext::set_double_click_time](crate::KayakContext::set_double_click_time)),
+    /// right after its [Click](EventType::Click)
+    DoubleClick,
+    /// Sent to the deepest widget under the cursor once the left mouse button (or a touch) is held
+    /// over it (see [KayakContext::set_long_press_time](crate::KayakContext::set_long_press_time))
+    /// without the cursor moving away, in which case releasing it doesn't click
+    LongPress,
     Hover,
     MouseIn,
     MouseOut,
     MouseDown,
     MouseUp,
+    /// Sent to the widget that captured the cursor (see [Event::capture_cursor]) whenever the cursor moves
+    MouseMove,
+    /// Sent to the deepest widget under the cursor when the right mouse button is pressed
+    ContextMenu,
+    /// Sent to the widgets under the cursor when a mouse button other than the left one is pressed
+    /// (the left button sends [MouseDown](EventType::MouseDown))
+    AuxMouseDown(MouseButton),
+    /// Sent to the widgets under the cursor when a mouse button other than the left one is
+    /// released (the left button sends [MouseUp](EventType::MouseUp))
+    AuxMouseUp(MouseButton),
+    /// Sent to the deepest widget under the cursor when a mouse button other than the left one is
+    /// pressed and released over it (the left button sends [Click](EventType::Click))
+    AuxClick(MouseButton),
+    /// Sent to the deepest widget under the cursor when the mouse wheel (or touchpad) scrolls
+    Scroll(ScrollEvent),
+    /// Sent to the widget pressed with the left mouse button once the cursor moves far enough while
+    /// pressed, which starts a drag if a handler sets its payload (see
+    /// [KayakContext::start_drag](crate::KayakContext::start_drag))
+    DragStart,
+    /// Sent to the dragged widget whenever the cursor moves during its drag
+    Drag,
+    /// Sent to a drop target accepting the payload of the drag when the cursor moves onto it
+    DragEnter,
+    /// Sent to a drop target accepting the payload of the drag when the cursor moves off of it
+    DragLeave,
+    /// Sent to the drop target under the cursor when the drag is released over it, right before the
+    /// [DragEnd](EventType::DragEnd)
+    Drop,
+    /// Sent to the dragged widget when its drag ends, whether it was dropped on a drop target or not
+    DragEnd {
+        dropped: bool,
+    },
     Focus,
     Blur,
-    CharInput { c: char },
+    CharInput {
+        c: char,
+    },
     KeyUp(KeyboardEvent),
     KeyDown(KeyboardEvent),
+    /// Sent to the focused widget when directional navigation is requested (see
+    /// [InputEvent::Navigate](crate::InputEvent::Navigate)), before focus moves in that direction
+    ///
+    /// Preventing its default keeps focus from moving, e.g. for a slider adjusted with the D-pad.
+    Navigate(NavigationDirection),
+    /// Sent to every widget when the window gains (`true`) or loses (`false`) focus
+    WindowFocusChanged(bool),
+    /// Sent to a watched widget (see [KayakContext::watch_visibility](crate::KayakContext::watch_visibility))
+    /// once it's within view
+    EnterView,
+    /// Sent to a watched widget once it's no longer within view
+    ExitView,
+    /// A user-defined event sent with
+    /// [KayakContext::dispatch_custom_event](crate::KayakContext::dispatch_custom_event), which
+    /// propagates like a click
+    Custom(CustomEvent),
+}
+
+/// A button of the mouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// The unit of a [ScrollEvent]'s delta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollUnit {
+    /// The delta is in lines (e.g. a notched mouse wheel)
+    Line,
+    /// The delta is in pixels (e.g. a touchpad)
+    Pixel,
+}
+
+/// A mouse wheel (or touchpad) scroll
+///
+/// Positive deltas scroll right and down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollEvent {
+    pub delta_x: f32,
+    pub delta_y: f32,
+    pub unit: ScrollUnit,
+}
+
+impl ScrollEvent {
+    /// The number of pixels a single line scrolls
+    pub const LINE_HEIGHT: f32 = 20.0;
+
+    /// Get the scroll delta in pixels
+    pub fn pixel_delta(&self) -> (f32, f32) {
+        match self.unit {
+            ScrollUnit::Line => (
+                self.delta_x * Self::LINE_HEIGHT,
+                self.delta_y * Self::LINE_HEIGHT,
+            ),
+            ScrollUnit::Pixel => (self.delta_x, self.delta_y),
+        }
+    }
+}
+
+// Deltas are compared bitwise so that events can be used as keys
+impl Eq for ScrollEvent {}
+
+impl std::hash::Hash for ScrollEvent {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.delta_x.to_bits().hash(state);
+        self.delta_y.to_bits().hash(state);
+        self.unit.hash(state);
+    }
+}
+
+/// Identifies a user-defined event and the type of its payload
+///
+/// The payload itself is kept by the context while the event is dispatched, and can be read with
+/// [KayakContext::get_custom_event_payload](crate::KayakContext::get_custom_event_payload).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomEvent {
+    pub(crate) id: u64,
+    type_id: TypeId,
+}
+
+impl CustomEvent {
+    pub(crate) fn new<T: Any>(id: u64) -> Self {
+        Self {
+            id,
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// Checks if the payload of this event is of type `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -82,6 +232,8 @@ pub enum EventCategory {
     Mouse,
     Keyboard,
     Focus,
+    Visibility,
+    Custom,
 }
 
 impl EventType {
@@ -94,16 +246,35 @@ impl EventType {
             // Propagates
             Self::Hover => true,
             Self::Click => true,
+            Self::DoubleClick => true,
+            Self::LongPress => true,
             Self::MouseDown => true,
             Self::MouseUp => true,
+            Self::ContextMenu => true,
+            Self::AuxMouseDown(..) => true,
+            Self::AuxMouseUp(..) => true,
+            Self::AuxClick(..) => true,
+            Self::Scroll(..) => true,
+            Self::DragStart => true,
             Self::CharInput { .. } => true,
             Self::KeyUp(..) => true,
             Self::KeyDown(..) => true,
+            Self::Navigate(..) => true,
+            Self::Custom(..) => true,
             // Doesn't Propagate
             Self::MouseIn => false,
             Self::MouseOut => false,
+            Self::MouseMove => false,
+            Self::Drag => false,
+            Self::DragEnter => false,
+            Self::DragLeave => false,
+            Self::Drop => false,
+            Self::DragEnd { .. } => false,
             Self::Focus => false,
             Self::Blur => false,
+            Self::WindowFocusChanged(..) => false,
+            Self::EnterView => false,
+            Self::ExitView => false,
         }
     }
 
@@ -113,10 +284,24 @@ impl EventType {
             // Mouse
             Self::Hover => EventCategory::Mouse,
             Self::Click => EventCategory::Mouse,
+            Self::DoubleClick => EventCategory::Mouse,
+            Self::LongPress => EventCategory::Mouse,
             Self::MouseDown => EventCategory::Mouse,
             Self::MouseUp => EventCategory::Mouse,
             Self::MouseIn => EventCategory::Mouse,
             Self::MouseOut => EventCategory::Mouse,
+            Self::MouseMove => EventCategory::Mouse,
+            Self::ContextMenu => EventCategory::Mouse,
+            Self::AuxMouseDown(..) => EventCategory::Mouse,
+            Self::AuxMouseUp(..) => EventCategory::Mouse,
+            Self::AuxClick(..) => EventCategory::Mouse,
+            Self::Scroll(..) => EventCategory::Mouse,
+            Self::DragStart => EventCategory::Mouse,
+            Self::Drag => EventCategory::Mouse,
+            Self::DragEnter => EventCategory::Mouse,
+            Self::DragLeave => EventCategory::Mouse,
+            Self::Drop => EventCategory::Mouse,
+            Self::DragEnd { .. } => EventCategory::Mouse,
             // Keyboard
             Self::CharInput { .. } => EventCategory::Keyboard,
             Self::KeyUp(..) => EventCategory::Keyboard,
@@ -124,6 +309,13 @@ impl EventType {
             // Focus
             Self::Focus => EventCategory::Focus,
             Self::Blur => EventCategory::Focus,
+            Self::WindowFocusChanged(..) => EventCategory::Focus,
+            Self::Navigate(..) => EventCategory::Focus,
+            // Visibility
+            Self::EnterView => EventCategory::Visibility,
+            Self::ExitView => EventCategory::Visibility,
+            // Custom
+            Self::Custom(..) => EventCategory::Custom,
         }
     }
 }
@@ -2,12 +2,29 @@ use crate::flo_binding::{Binding, MutableBound};
 
 use crate::layout_cache::Rect;
 use crate::render_command::RenderCommand;
+use crate::styles::StyleProp;
+use crate::throttle::EventThrottler;
 use crate::widget_manager::WidgetManager;
 use crate::{
-    Event, EventType, Index, InputEvent, InputEventCategory, KayakContext, KeyCode, KeyboardEvent,
-    KeyboardModifiers, PointerEvents, Widget,
+    DragPayload, DropFilter, Event, EventThrottle, EventType, HitTest, Index, InputEvent,
+    InputEventCategory, Interaction, KayakContext, KeyCode, KeyRepeat, KeyboardEvent,
+    KeyboardLayout, KeyboardModifiers, MouseButton, NavigationDirection, OnEvent, PointerEvents,
+    TouchPhase, Widget,
 };
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// The most events dispatched at once by default (see [EventDispatcher::set_max_events])
+const DEFAULT_MAX_EVENTS: usize = 1024;
+/// Where the cursor is once it leaves the window, which no widget contains
+const OUTSIDE_WINDOW: (f32, f32) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+/// The longest time between two clicks of a double click by default
+const DEFAULT_DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+/// How far the cursor moves while pressed before the pressed widget starts a drag (in pixels),
+/// which also cancels a long press
+const DRAG_THRESHOLD: f32 = 4.0;
+/// How long a press is held before it's a long press by default
+const DEFAULT_LONG_PRESS_TIME: Duration = Duration::from_millis(500);
 
 type EventMap = HashMap<Index, HashSet<EventType>>;
 type TreeNode = (
@@ -22,6 +39,8 @@ struct EventState {
     best_z_index: f32,
     best_match: Option<Index>,
     best_depth: isize,
+    /// The number of states created before this one, to keep events of the same type in order
+    order: usize,
 }
 
 impl Default for EventState {
@@ -30,21 +49,66 @@ impl Default for EventState {
             best_z_index: f32::NEG_INFINITY,
             best_match: None,
             best_depth: -1,
+            order: 0,
         }
     }
 }
 
+/// A handler called before the widgets below its widget handle an event (see
+/// [KayakContext::set_capture_handler])
+#[derive(Clone)]
+pub(crate) struct CaptureHandler(pub OnEvent);
+
+/// A key held down, which repeats once held long enough
+#[derive(Debug, Clone)]
+struct HeldKey {
+    key: KeyCode,
+    physical_key: Option<KeyCode>,
+    /// The characters typed by the key press
+    chars: Vec<char>,
+    /// When the key repeats next
+    repeat_at: Instant,
+    /// Whether the key was pressed in the current batch of input events
+    is_new: bool,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct EventDispatcher {
     is_mouse_pressed: bool,
+    /// The mouse buttons other than the left one that are currently pressed
+    pressed_aux_buttons: HashSet<MouseButton>,
     current_mouse_position: (f32, f32),
     next_mouse_position: (f32, f32),
     previous_events: EventMap,
     keyboard_modifiers: KeyboardModifiers,
+    keyboard_layout: KeyboardLayout,
+    key_repeat: Option<KeyRepeat>,
+    /// The last key pressed, while it's held
+    held_key: Option<HeldKey>,
     pub last_clicked: Binding<Index>,
     contains_cursor: Option<bool>,
     wants_cursor: Option<bool>,
     has_cursor: Option<Index>,
+    max_events: usize,
+    double_click_time: Duration,
+    /// The widget last clicked, when it was clicked, and the number of clicks on it in a row
+    last_click: Option<(Index, Instant, u32)>,
+    /// The deepest widget pressed with the left button and where it was pressed, which may start a
+    /// drag once the cursor moves far enough
+    drag_press: Option<(Index, (f32, f32))>,
+    /// The widget being dragged (if any)
+    drag_source: Option<Index>,
+    /// The drop target under the cursor that accepts the payload of the current drag (if any)
+    drop_target: Option<Index>,
+    long_press_time: Duration,
+    /// The deepest widget pressed with the left button, when and where it was pressed, until its
+    /// press is long enough or cancelled
+    long_press: Option<(Index, Instant, (f32, f32))>,
+    /// Whether the current (or last) press was a long press, which doesn't click
+    was_long_pressed: bool,
+    /// The touch point acting as the cursor (see [InputEvent::Touch])
+    primary_touch: Option<u64>,
+    throttler: EventThrottler,
 }
 
 impl EventDispatcher {
@@ -52,16 +116,58 @@ impl EventDispatcher {
         Self {
             last_clicked: Binding::new(Index::default()),
             is_mouse_pressed: Default::default(),
+            pressed_aux_buttons: Default::default(),
             current_mouse_position: Default::default(),
             next_mouse_position: Default::default(),
             previous_events: Default::default(),
             keyboard_modifiers: Default::default(),
+            keyboard_layout: Default::default(),
+            key_repeat: Some(KeyRepeat::default()),
+            held_key: None,
             contains_cursor: None,
             wants_cursor: None,
             has_cursor: None,
+            max_events: DEFAULT_MAX_EVENTS,
+            double_click_time: DEFAULT_DOUBLE_CLICK_TIME,
+            last_click: None,
+            drag_press: None,
+            drag_source: None,
+            drop_target: None,
+            long_press_time: DEFAULT_LONG_PRESS_TIME,
+            long_press: None,
+            was_long_pressed: false,
+            primary_touch: None,
+            throttler: EventThrottler::default(),
         }
     }
 
+    /// Set the longest time between two clicks on a widget for them to count as a double click
+    pub fn set_double_click_time(&mut self, double_click_time: Duration) {
+        self.double_click_time = double_click_time;
+    }
+
+    /// Set how held keys repeat, or `None` to only send the repeats reported by the system
+    pub fn set_key_repeat(&mut self, key_repeat: Option<KeyRepeat>) {
+        self.key_repeat = key_repeat;
+    }
+
+    /// Set how long a press is held for it to be a long press
+    pub fn set_long_press_time(&mut self, long_press_time: Duration) {
+        self.long_press_time = long_press_time;
+    }
+
+    /// Gets the number of clicks in a row on the last clicked widget (1 for a single click, 2 for a
+    /// double click, and so on)
+    pub fn click_count(&self) -> u32 {
+        self.last_click.map_or(0, |(.., count)| count)
+    }
+
+    /// Set the most events dispatched at once (e.g. for a frame's input), past which the least
+    /// important pointer events are dropped
+    pub fn set_max_events(&mut self, max_events: usize) {
+        self.max_events = max_events;
+    }
+
     /// Returns whether the mouse is currently pressed or not
     #[allow(dead_code)]
     pub fn is_mouse_pressed(&self) -> bool {
@@ -74,6 +180,21 @@ impl EventDispatcher {
         self.current_mouse_position
     }
 
+    /// Gets the keyboard modifiers that are currently pressed (since last keyboard event)
+    pub fn keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.keyboard_modifiers
+    }
+
+    /// Gets the active keyboard layout
+    pub fn keyboard_layout(&self) -> &KeyboardLayout {
+        &self.keyboard_layout
+    }
+
+    /// Set the active keyboard layout
+    pub fn set_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) {
+        self.keyboard_layout = keyboard_layout;
+    }
+
     /// Returns true if the cursor is currently over a valid widget
     ///
     /// For the purposes of this method, a valid widget is one which has the means to display a visual component on its own.
@@ -105,13 +226,26 @@ impl EventDispatcher {
     /// Process and dispatch an [InputEvent](crate::InputEvent)
     #[allow(dead_code)]
     pub fn process_event(&mut self, input_event: InputEvent, context: &mut KayakContext) {
-        let events = self.build_event_stream(&[input_event], &mut context.widget_manager);
+        let now = context.clock.peek();
+        let events = self.build_event_stream(
+            &[input_event],
+            &mut context.widget_manager,
+            context.drag_payload.as_ref(),
+            now,
+        );
         self.dispatch_events(events, context);
     }
 
     /// Process and dispatch a set of [InputEvents](crate::InputEvent)
+    #[allow(dead_code)]
     pub fn process_events(&mut self, input_events: Vec<InputEvent>, context: &mut KayakContext) {
-        let events = self.build_event_stream(&input_events, &mut context.widget_manager);
+        let now = context.clock.peek();
+        let events = self.build_event_stream(
+            &input_events,
+            &mut context.widget_manager,
+            context.drag_payload.as_ref(),
+            now,
+        );
         self.dispatch_events(events, context);
     }
 
@@ -124,10 +258,57 @@ impl EventDispatcher {
     /// Dispatch a set of [Events](crate::Event)
     pub fn dispatch_events(&mut self, events: Vec<Event>, context: &mut KayakContext) {
         // === Dispatch Events === //
+        let events = self.limit_events(events);
+        let now = context.clock.peek();
         let mut next_events = HashMap::default();
         for mut event in events {
-            let mut current_target: Option<Index> = Some(event.target);
-            while let Some(index) = current_target {
+            if Interaction::is_recorded(&event.event_type) {
+                context.record_interaction(event.target, event.event_type);
+            }
+
+            // The propagation path is set before any handler runs, so that handlers changing the
+            // tree can't misroute the rest of the bubbling
+            let mut path = Self::get_propagation_path(&event, &context.widget_manager);
+
+            // --- Capture Phase --- //
+            let mut was_captured = false;
+            if event.should_propagate {
+                for index in path.iter().rev() {
+                    let handler = match context.get_metadata::<CaptureHandler>(*index) {
+                        Some(CaptureHandler(handler)) => handler,
+                        None => continue,
+                    };
+
+                    let mut node_event = Event {
+                        current_target: *index,
+                        ..event
+                    };
+                    if let Ok(mut handler) = handler.0.write() {
+                        handler(context, &mut node_event);
+                    }
+
+                    event.default_prevented |= node_event.default_prevented;
+                    if !node_event.should_propagate {
+                        was_captured = true;
+                        break;
+                    }
+                }
+            }
+            if was_captured {
+                // Intercepted before reaching the target, so no widget handles it
+                path.clear();
+            }
+
+            // --- Bubble Phase --- //
+            for index in path {
+                // The widget may have been removed by a previous event handler
+                if !matches!(
+                    context.widget_manager.current_widgets.get(index),
+                    Some(Some(_))
+                ) {
+                    continue;
+                }
+
                 // Create a copy of the event, specific for this node
                 // This is to make sure unauthorized changes to the event are not propagated
                 // (e.g., changing the event type, removing the target, etc.)
@@ -136,24 +317,55 @@ impl EventDispatcher {
                     ..event
                 };
 
+                // --- Throttle Event --- //
+                if EventThrottler::is_throttled(&node_event.event_type) {
+                    let throttle = Self::get_event_throttle(index, &context.widget_manager);
+                    match self.throttler.throttle(index, throttle, node_event, now) {
+                        Some(throttled) => node_event = throttled,
+                        // Withheld from this widget, but still sent to the next ones
+                        None => continue,
+                    }
+                }
+
                 // --- Update State --- //
                 Self::insert_event(&mut next_events, &index, node_event.event_type);
 
                 // --- Call Event --- //
+                let was_dragging = context.drag_payload.is_some();
                 let mut target_widget = context.widget_manager.take(index);
                 target_widget.on_event(context, &mut node_event);
                 context.widget_manager.repossess(target_widget);
 
+                if node_event.should_capture_cursor {
+                    self.has_cursor = Some(index);
+                }
+
+                if event.event_type == EventType::DragStart
+                    && !was_dragging
+                    && context.drag_payload.is_some()
+                {
+                    // This widget set the payload, so it's the one being dragged
+                    self.drag_source = Some(index);
+                    node_event.should_propagate = false;
+                }
+
                 event.default_prevented |= node_event.default_prevented;
 
                 // --- Propagate Event --- //
-                if node_event.should_propagate {
-                    current_target = context.widget_manager.node_tree.get_parent(index);
-                } else {
-                    current_target = None;
+                if !node_event.should_propagate {
+                    break;
                 }
             }
 
+            context.notify_global_listeners(&event);
+
+            if matches!(event.event_type, EventType::DragEnd { .. }) {
+                context.drag_payload = None;
+            }
+            if let EventType::Custom(custom) = event.event_type {
+                context.custom_event_payloads.remove(&custom.id);
+            }
+
             if !event.default_prevented {
                 self.execute_default(event, context);
             }
@@ -167,6 +379,11 @@ impl EventDispatcher {
                 // Make sure this event isn't removed while mouse is still held down
                 Self::insert_event(&mut next_events, index, EventType::MouseDown);
             }
+            for button in self.pressed_aux_buttons.iter() {
+                if events.contains(&EventType::AuxMouseDown(*button)) {
+                    Self::insert_event(&mut next_events, index, EventType::AuxMouseDown(*button));
+                }
+            }
 
             // Mouse is currently within this node
             if events.contains(&EventType::MouseIn)
@@ -181,11 +398,154 @@ impl EventDispatcher {
         self.previous_events = next_events;
     }
 
+    /// Get the widgets the given event reaches, from its target up to the root (or only its target
+    /// if it doesn't propagate)
+    fn get_propagation_path(event: &Event, widget_manager: &WidgetManager) -> Vec<Index> {
+        let mut path = vec![event.target];
+        if event.should_propagate {
+            let mut current = event.target;
+            while let Some(parent) = widget_manager.node_tree.get_parent(current) {
+                path.push(parent);
+                current = parent;
+            }
+        }
+        path
+    }
+
+    /// Coalesces and caps the given events, so that floods of pointer events (e.g. thousands of
+    /// widgets entered at once in a huge tree) can't stall the frame
+    ///
+    /// Repeated `MouseMove` events to the same widget are merged. Past the most events allowed,
+    /// the least important pointer events are dropped with a warning: `Hover` and `MouseMove`
+    /// first, then `MouseIn`, and `MouseOut` last (so no widget is left hovered). Other events are
+    /// always kept.
+    fn limit_events(&self, events: Vec<Event>) -> Vec<Event> {
+        let mut moved = HashSet::new();
+        let events = events
+            .into_iter()
+            .filter(|event| event.event_type != EventType::MouseMove || moved.insert(event.target))
+            .collect::<Vec<_>>();
+        if events.len() <= self.max_events {
+            return events;
+        }
+
+        // The number of events kept for each priority, from the most important
+        let mut counts = [0; 4];
+        for event in events.iter() {
+            counts[Self::get_event_priority(&event.event_type)] += 1;
+        }
+        let mut allowed = self.max_events.saturating_sub(counts[0]);
+        for count in counts.iter_mut().skip(1) {
+            *count = (*count).min(allowed);
+            allowed -= *count;
+        }
+
+        let total = events.len();
+        let limited = events
+            .into_iter()
+            .filter(|event| {
+                let count = &mut counts[Self::get_event_priority(&event.event_type)];
+                if *count > 0 {
+                    *count -= 1;
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect::<Vec<_>>();
+        eprintln!(
+            "Warning: dropped {} of {} events dispatched at once (the most allowed is {})",
+            total - limited.len(),
+            total,
+            self.max_events
+        );
+        limited
+    }
+
+    /// Get the priority of the given event when limiting events, from 0 (always kept) to 3 (the
+    /// first to be dropped)
+    fn get_event_priority(event_type: &EventType) -> usize {
+        match event_type {
+            EventType::MouseOut => 1,
+            EventType::MouseIn => 2,
+            EventType::Hover | EventType::MouseMove => 3,
+            _ => 0,
+        }
+    }
+
     /// Generates a stream of [Events](crate::Event) from a set of [InputEvents](crate::InputEvent)
-    fn build_event_stream(
+    ///
+    /// The given time is when the input happened, used to detect double clicks.
+    ///
+    /// Events are generated in a stable order. The events withheld from throttled widgets (see
+    /// [EventThrottle]) that they can now receive come first, so that newer events supersede them.
+    /// Then come the events with an obvious target (`MouseIn`, `MouseOut`, `MouseDown`, and
+    /// `MouseUp`), going depth-first from the root, followed by the `MouseMove` events of a
+    /// captured cursor and the keyboard events.
+    /// Then come the events sent to the best match in the whole tree: `Focus` (and the `Blur` of
+    /// the widget losing focus), `Click` (and `DoubleClick`, unless a drag is released),
+    /// `AuxClick`, `ContextMenu`, `Scroll`, and `Hover`.
+    /// Then come the drag events: `DragStart`, or `Drag`, `DragLeave` and `DragEnter` followed by
+    /// `Drop` and `DragEnd` when the drag is released, and the `LongPress` of a press held long
+    /// enough (checked whenever events are processed, even without input).
+    /// Finally, a press outside of any focusable widget blurs the focused one.
+    pub(crate) fn build_event_stream(
         &mut self,
         input_events: &[InputEvent],
         widget_manager: &mut WidgetManager,
+        drag_payload: Option<&DragPayload>,
+        now: Instant,
+    ) -> Vec<Event> {
+        self.throttler.next_frame();
+
+        // === Throttled Events === //
+        let due = self
+            .throttler
+            .take_due(|index| Self::get_event_throttle(index, widget_manager), now);
+        let mut event_stream = due
+            .into_iter()
+            .map(|(index, event)| {
+                // Only the throttled widget receives it, as the others already did
+                Event {
+                    target: index,
+                    current_target: index,
+                    should_propagate: false,
+                    ..event
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let input_stream = if input_events
+            .iter()
+            .any(|event| matches!(event, InputEvent::Touch { .. }))
+        {
+            // Each segment is processed on its own, so that a touch moves the cursor before pressing
+            let mut event_stream = Vec::<Event>::new();
+            for segment in self.convert_touch_events(input_events) {
+                event_stream.extend(self.build_input_event_stream(
+                    &segment,
+                    widget_manager,
+                    drag_payload,
+                    now,
+                ));
+            }
+            event_stream
+        } else {
+            self.build_input_event_stream(input_events, widget_manager, drag_payload, now)
+        };
+        event_stream.extend(input_stream);
+        event_stream.extend(self.process_key_repeat(widget_manager, now));
+
+        event_stream
+    }
+
+    /// Generates the events of the given input events, none of which are touch events
+    fn build_input_event_stream(
+        &mut self,
+        input_events: &[InputEvent],
+        widget_manager: &mut WidgetManager,
+        drag_payload: Option<&DragPayload>,
+        now: Instant,
     ) -> Vec<Event> {
         let mut event_stream = Vec::<Event>::new();
         let mut states: HashMap<EventType, EventState> = HashMap::new();
@@ -203,14 +563,22 @@ impl EventDispatcher {
         self.wants_cursor = None;
 
         // === Mouse Events === //
-        let mut stack: Vec<TreeNode> = vec![(root, 0)];
+        let modal = widget_manager.get_modal();
+        // The root covers the window, so anything outside of it is offscreen
+        let viewport = widget_manager.get_layout(&root).copied();
+        // Each node is paired with the clip rect its ancestors restrict it to (if any)
+        let mut stack: Vec<(TreeNode, Option<Rect>)> = vec![((root, 0), None)];
         while stack.len() > 0 {
-            let (current, depth) = stack.pop().unwrap();
+            let ((current, depth), clip) = stack.pop().unwrap();
             let mut enter_children = true;
 
+            // The root always needs processing since it maintains the global mouse state
+            let is_hittable = current == root
+                || self.is_hittable(current, clip.as_ref(), viewport.as_ref(), widget_manager);
+
             for input_event in input_events {
                 // --- Process Event --- //
-                if matches!(input_event.category(), InputEventCategory::Mouse) {
+                if is_hittable && matches!(input_event.category(), InputEventCategory::Mouse) {
                     // A widget's PointerEvents style will determine how it and its children are processed
                     let mut pointer_events = PointerEvents::default();
                     if let Some(widget) = widget_manager.current_widgets.get(current).unwrap() {
@@ -218,12 +586,21 @@ impl EventDispatcher {
                             pointer_events = styles.pointer_events.resolve();
                         }
                     }
+                    if let Some(modal) = modal {
+                        pointer_events = Self::get_modal_pointer_events(
+                            current,
+                            modal,
+                            pointer_events,
+                            widget_manager,
+                        );
+                    }
 
                     match pointer_events {
                         PointerEvents::All | PointerEvents::SelfOnly => {
                             let events = self.process_pointer_events(
                                 input_event,
                                 (current, depth),
+                                clip.as_ref(),
                                 &mut states,
                                 widget_manager,
                             );
@@ -242,26 +619,121 @@ impl EventDispatcher {
             // --- Push Children to Stack --- //
             if enter_children {
                 if let Some(children) = widget_manager.node_tree.children.get(&current) {
+                    // Children of a clip can only be interacted with inside of it
+                    let child_clip = match (Self::get_clip_layout(current, widget_manager), clip) {
+                        (Some(layout), Some(clip)) => Some(layout.intersection(&clip)),
+                        (Some(layout), None) => Some(layout),
+                        (None, clip) => clip,
+                    };
                     for child in children {
-                        stack.push((*child, depth + 1));
+                        stack.push(((*child, depth + 1), child_clip));
                     }
                 }
             }
         }
 
+        // === Captured Cursor Events === //
+        if let Some(captured) = self.has_cursor {
+            for input_event in input_events {
+                if let InputEvent::MouseMoved(..) = input_event {
+                    event_stream.push(Event::new(captured, EventType::MouseMove));
+                }
+            }
+        }
+
         // === Keyboard Events === //
-        for input_event in input_events {
+        let repeats = self.update_held_key(input_events, now);
+        for (input_event, is_repeat) in input_events.iter().zip(repeats) {
+            if is_repeat && self.key_repeat.is_some() {
+                // The held key is repeated by the dispatcher instead (see process_key_repeat)
+                continue;
+            }
+
             // Keyboard events only care about the currently focused widget so we don't need to run this over every node in the tree
-            let events = self.process_keyboard_events(input_event, &mut states, widget_manager);
+            let events =
+                self.process_keyboard_events(input_event, is_repeat, &mut states, widget_manager);
             event_stream.extend(events);
         }
 
+        // === Navigation Events === //
+        for input_event in input_events {
+            let current_focus = widget_manager.focus_tree.current();
+            match (input_event, current_focus) {
+                (InputEvent::Navigate(direction), Some(current_focus)) => {
+                    // Focus moves by default (see execute_default)
+                    event_stream.push(Event::new(current_focus, EventType::Navigate(*direction)));
+                }
+                (InputEvent::Navigate(..), None) => {
+                    // Start from the top-left focusable widget
+                    let first = Self::get_navigation_candidates(widget_manager)
+                        .into_iter()
+                        .min_by(|(_, a), (_, b)| {
+                            (a.posy, a.posx)
+                                .partial_cmp(&(b.posy, b.posx))
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        });
+                    if let Some((first, _)) = first {
+                        event_stream.push(Event::new(first, EventType::Focus));
+                        widget_manager.focus_tree.focus(first);
+                    }
+                }
+                (InputEvent::Activate, Some(current_focus)) => {
+                    event_stream.push(Event::new(current_focus, EventType::Click));
+                }
+                _ => {}
+            }
+        }
+
+        // === Window Events === //
+        for input_event in input_events {
+            if let InputEvent::WindowFocused(is_focused) = input_event {
+                if !is_focused {
+                    // Buttons and keys released while unfocused are never reported, so forget them
+                    self.is_mouse_pressed = false;
+                    self.primary_touch = None;
+                    self.pressed_aux_buttons.clear();
+                    self.has_cursor = None;
+                    self.keyboard_modifiers = KeyboardModifiers::default();
+                    self.held_key = None;
+                }
+                for widget in widget_manager.tree.flatten() {
+                    event_stream.push(Event::new(
+                        widget,
+                        EventType::WindowFocusChanged(*is_focused),
+                    ));
+                }
+            }
+        }
+
         // === Additional Events === //
         let mut had_focus_event = false;
 
         // These events are ones that require a specific target and need the tree to be evaluated before selecting the best match
+        let hovered = states
+            .get(&EventType::Hover)
+            .and_then(|state| state.best_match);
+        let mut states = states.into_iter().collect::<Vec<_>>();
+        states.sort_by_key(|(event_type, state)| (Self::get_state_order(event_type), state.order));
         for (event_type, state) in states {
             if let Some(node) = state.best_match {
+                if event_type == EventType::DragStart {
+                    // Only sent once the cursor moves far enough (see the drag events below)
+                    self.drag_press = Some((node, self.current_mouse_position));
+                    continue;
+                }
+                if event_type == EventType::LongPress {
+                    // Only sent once the press is held long enough (see the long press below)
+                    self.long_press = Some((node, now, self.current_mouse_position));
+                    self.was_long_pressed = false;
+                    continue;
+                }
+                if event_type == EventType::Click
+                    && (self.drag_source.is_some() || self.was_long_pressed)
+                {
+                    // Releasing a drag drops it, and a long press already had its own event
+                    continue;
+                }
+
                 event_stream.push(Event::new(node, event_type));
 
                 match event_type {
@@ -274,13 +746,97 @@ impl EventDispatcher {
                         }
                         widget_manager.focus_tree.focus(node);
                     }
+                    EventType::Click => {
+                        let count = match self.last_click {
+                            Some((last_node, last_time, count))
+                                if last_node == node
+                                    && now.saturating_duration_since(last_time)
+                                        <= self.double_click_time =>
+                            {
+                                count + 1
+                            }
+                            _ => 1,
+                        };
+                        self.last_click = Some((node, now, count));
+                        if count == 2 {
+                            event_stream.push(Event::new(node, EventType::DoubleClick));
+                        }
+                    }
                     _ => {}
                 }
             }
         }
 
+        // === Drag Events === //
+        let had_move = input_events
+            .iter()
+            .any(|event| matches!(event, InputEvent::MouseMoved(..) | InputEvent::CursorLeft));
+        let had_release = input_events.contains(&InputEvent::MouseLeftRelease);
+        let lost_focus = input_events.contains(&InputEvent::WindowFocused(false));
+        if let Some(source) = self.drag_source {
+            if had_move {
+                event_stream.push(Event::new(source, EventType::Drag));
+
+                // The drop target is the deepest widget under the cursor accepting the payload
+                let target = hovered.and_then(|hovered| {
+                    drag_payload.and_then(|payload| {
+                        Self::find_drop_target(hovered, payload, widget_manager)
+                    })
+                });
+                if target != self.drop_target {
+                    if let Some(previous) = self.drop_target {
+                        event_stream.push(Event::new(previous, EventType::DragLeave));
+                    }
+                    if let Some(target) = target {
+                        event_stream.push(Event::new(target, EventType::DragEnter));
+                    }
+                    self.drop_target = target;
+                }
+            }
+
+            if had_release || lost_focus {
+                let target = self.drop_target.take();
+                if let Some(target) = target {
+                    if had_release {
+                        event_stream.push(Event::new(target, EventType::Drop));
+                    } else {
+                        event_stream.push(Event::new(target, EventType::DragLeave));
+                    }
+                }
+                let dropped = had_release && target.is_some();
+                event_stream.push(Event::new(source, EventType::DragEnd { dropped }));
+                self.drag_source = None;
+            }
+        } else if let Some((pressed, origin)) = self.drag_press {
+            let (x, y) = self.next_mouse_position;
+            let distance = ((x - origin.0).powi(2) + (y - origin.1).powi(2)).sqrt();
+            if !self.is_mouse_pressed {
+                self.drag_press = None;
+            } else if had_move && distance >= DRAG_THRESHOLD {
+                // The drag only starts if a handler sets its payload
+                event_stream.push(Event::new(pressed, EventType::DragStart));
+                self.drag_press = None;
+            }
+        }
+
+        // === Long Press === //
+        if let Some((pressed, pressed_at, origin)) = self.long_press {
+            let (x, y) = self.next_mouse_position;
+            let distance = ((x - origin.0).powi(2) + (y - origin.1).powi(2)).sqrt();
+            if !self.is_mouse_pressed || distance >= DRAG_THRESHOLD || self.drag_source.is_some() {
+                self.long_press = None;
+            } else if now.saturating_duration_since(pressed_at) >= self.long_press_time {
+                event_stream.push(Event::new(pressed, EventType::LongPress));
+                self.long_press = None;
+                self.was_long_pressed = true;
+            }
+        }
+
         // --- Blur Event --- //
-        if !had_focus_event && input_events.contains(&InputEvent::MouseLeftPress) {
+        let had_press = input_events.contains(&InputEvent::MouseLeftPress)
+            || input_events.contains(&InputEvent::MouseRightPress)
+            || input_events.contains(&InputEvent::MouseMiddlePress);
+        if !had_focus_event && had_press {
             // A mouse press didn't contain a focus event -> blur
             if let Some(current_focus) = widget_manager.focus_tree.current() {
                 event_stream.push(Event::new(current_focus, EventType::Blur));
@@ -303,21 +859,99 @@ impl EventDispatcher {
         event_stream
     }
 
+    /// Converts the touch events among the given input events into the mouse events of the primary
+    /// touch point, split into segments to be processed one after the other
+    ///
+    /// A cursor press only hits what's under the cursor before the events are processed, so the
+    /// cursor is moved to a touch point in one segment and pressed in the next. Touch points other
+    /// than the primary one are ignored, so they can't move the cursor or release its press.
+    fn convert_touch_events(&mut self, input_events: &[InputEvent]) -> Vec<Vec<InputEvent>> {
+        let mut segments = vec![Vec::new()];
+        for input_event in input_events {
+            let (id, phase, position) = match input_event {
+                InputEvent::Touch {
+                    id,
+                    phase,
+                    position,
+                } => (*id, *phase, *position),
+                input_event => {
+                    segments.last_mut().unwrap().push(input_event.clone());
+                    continue;
+                }
+            };
+
+            let converted = match phase {
+                TouchPhase::Started if self.primary_touch.is_none() => {
+                    self.primary_touch = Some(id);
+                    vec![
+                        vec![InputEvent::MouseMoved(position)],
+                        vec![InputEvent::MouseLeftPress],
+                    ]
+                }
+                TouchPhase::Moved if self.primary_touch == Some(id) => {
+                    vec![vec![InputEvent::MouseMoved(position)]]
+                }
+                TouchPhase::Ended if self.primary_touch == Some(id) => {
+                    self.primary_touch = None;
+                    // Nothing stays hovered once the touch point is lifted
+                    vec![
+                        vec![InputEvent::MouseMoved(position)],
+                        vec![InputEvent::MouseLeftRelease],
+                        vec![InputEvent::CursorLeft],
+                    ]
+                }
+                TouchPhase::Cancelled if self.primary_touch == Some(id) => {
+                    self.primary_touch = None;
+                    // Releasing outside of the window neither clicks nor drops anything
+                    vec![
+                        vec![InputEvent::CursorLeft],
+                        vec![InputEvent::MouseLeftRelease],
+                    ]
+                }
+                _ => Vec::new(),
+            };
+            for segment in converted {
+                if !segments.last().unwrap().is_empty() {
+                    segments.push(Vec::new());
+                }
+                segments.last_mut().unwrap().extend(segment);
+            }
+            if !segments.last().unwrap().is_empty() {
+                segments.push(Vec::new());
+            }
+        }
+        segments.retain(|segment| !segment.is_empty());
+        segments
+    }
+
     fn process_pointer_events(
         &mut self,
         input_event: &InputEvent,
         tree_node: TreeNode,
+        clip: Option<&Rect>,
         states: &mut HashMap<EventType, EventState>,
         widget_manager: &WidgetManager,
     ) -> Vec<Event> {
         let mut event_stream = Vec::<Event>::new();
         let (node, depth) = tree_node;
+        let hit_test = Self::get_hit_test(node, widget_manager);
 
         match input_event {
+            InputEvent::CursorLeft => {
+                // Leaving the window moves the cursor out of every widget
+                return self.process_pointer_events(
+                    &InputEvent::MouseMoved(OUTSIDE_WINDOW),
+                    tree_node,
+                    clip,
+                    states,
+                    widget_manager,
+                );
+            }
             InputEvent::MouseMoved(point) => {
                 if let Some(layout) = widget_manager.get_layout(&node) {
-                    let was_contained = layout.contains(&self.current_mouse_position);
-                    let is_contained = layout.contains(point);
+                    let was_contained =
+                        Self::contains_point(layout, clip, hit_test, &self.current_mouse_position);
+                    let is_contained = Self::contains_point(layout, clip, hit_test, point);
                     if was_contained != is_contained {
                         if was_contained {
                             event_stream.push(Event::new(node, EventType::MouseOut));
@@ -356,8 +990,10 @@ impl EventDispatcher {
                 self.is_mouse_pressed = true;
 
                 if let Some(layout) = widget_manager.get_layout(&node) {
-                    if layout.contains(&self.current_mouse_position) {
+                    if Self::contains_point(layout, clip, hit_test, &self.current_mouse_position) {
                         event_stream.push(Event::new(node, EventType::MouseDown));
+                        Self::update_state(states, (node, depth), layout, EventType::DragStart);
+                        Self::update_state(states, (node, depth), layout, EventType::LongPress);
 
                         if let Some(focusable) = widget_manager.get_focusable(node) {
                             if focusable {
@@ -377,13 +1013,70 @@ impl EventDispatcher {
                     }
                 }
             }
+            InputEvent::MouseRightPress | InputEvent::MouseMiddlePress => {
+                let button = if *input_event == InputEvent::MouseRightPress {
+                    MouseButton::Right
+                } else {
+                    MouseButton::Middle
+                };
+                self.pressed_aux_buttons.insert(button);
+
+                if let Some(layout) = widget_manager.get_layout(&node) {
+                    if Self::contains_point(layout, clip, hit_test, &self.current_mouse_position) {
+                        event_stream.push(Event::new(node, EventType::AuxMouseDown(button)));
+                        if button == MouseButton::Right {
+                            Self::update_state(
+                                states,
+                                (node, depth),
+                                layout,
+                                EventType::ContextMenu,
+                            );
+                        }
+                    }
+                }
+            }
+            InputEvent::MouseRightRelease | InputEvent::MouseMiddleRelease => {
+                let button = if *input_event == InputEvent::MouseRightRelease {
+                    MouseButton::Right
+                } else {
+                    MouseButton::Middle
+                };
+                self.pressed_aux_buttons.remove(&button);
+
+                if let Some(layout) = widget_manager.get_layout(&node) {
+                    if Self::contains_point(layout, clip, hit_test, &self.current_mouse_position) {
+                        event_stream.push(Event::new(node, EventType::AuxMouseUp(button)));
+                        let down = EventType::AuxMouseDown(button);
+                        if Self::contains_event(&self.previous_events, &node, &down) {
+                            Self::update_state(
+                                states,
+                                (node, depth),
+                                layout,
+                                EventType::AuxClick(button),
+                            );
+                        }
+                    }
+                }
+            }
+            InputEvent::Scroll(scroll) => {
+                if let Some(layout) = widget_manager.get_layout(&node) {
+                    if Self::contains_point(layout, clip, hit_test, &self.current_mouse_position) {
+                        Self::update_state(
+                            states,
+                            (node, depth),
+                            layout,
+                            EventType::Scroll(*scroll),
+                        );
+                    }
+                }
+            }
             InputEvent::MouseLeftRelease => {
                 // Reset global mouse pressed
                 self.is_mouse_pressed = false;
                 self.has_cursor = None;
 
                 if let Some(layout) = widget_manager.get_layout(&node) {
-                    if layout.contains(&self.current_mouse_position) {
+                    if Self::contains_point(layout, clip, hit_test, &self.current_mouse_position) {
                         event_stream.push(Event::new(node, EventType::MouseUp));
                         self.last_clicked.set(node);
 
@@ -400,19 +1093,146 @@ impl EventDispatcher {
         event_stream
     }
 
+    /// Tracks the held key through the given input events, returning whether each one is a repeat
+    /// of the held key reported by the system
+    ///
+    /// The characters typed in the same batch as a key press are the ones it repeats. They're
+    /// attached before the keyboard events are processed, since they may come before the press.
+    fn update_held_key(&mut self, input_events: &[InputEvent], now: Instant) -> Vec<bool> {
+        let mut repeats = vec![false; input_events.len()];
+        for (input_event, is_repeat) in input_events.iter().zip(repeats.iter_mut()) {
+            match input_event {
+                InputEvent::Keyboard {
+                    key,
+                    physical_key,
+                    is_pressed: true,
+                } => {
+                    if self
+                        .held_key
+                        .as_ref()
+                        .map_or(false, |held| held.key == *key)
+                    {
+                        *is_repeat = true;
+                    } else if !matches!(
+                        key,
+                        KeyCode::LControl
+                            | KeyCode::RControl
+                            | KeyCode::LShift
+                            | KeyCode::RShift
+                            | KeyCode::LAlt
+                            | KeyCode::RAlt
+                            | KeyCode::LWin
+                            | KeyCode::RWin
+                    ) {
+                        let delay = self
+                            .key_repeat
+                            .map_or(Duration::ZERO, |repeat| repeat.delay);
+                        self.held_key = Some(HeldKey {
+                            key: *key,
+                            physical_key: *physical_key,
+                            chars: Vec::new(),
+                            repeat_at: now + delay,
+                            is_new: true,
+                        });
+                    }
+                }
+                InputEvent::Keyboard {
+                    key,
+                    is_pressed: false,
+                    ..
+                } => {
+                    if self
+                        .held_key
+                        .as_ref()
+                        .map_or(false, |held| held.key == *key)
+                    {
+                        self.held_key = None;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (input_event, is_repeat) in input_events.iter().zip(repeats.iter_mut()) {
+            if let (InputEvent::CharEvent { c }, Some(held)) = (input_event, &mut self.held_key) {
+                if held.is_new {
+                    held.chars.push(*c);
+                } else {
+                    *is_repeat = held.chars.contains(c);
+                }
+            }
+        }
+
+        repeats
+    }
+
+    /// Repeats the `KeyDown` of the held key (and the characters it typed) to the focused widget,
+    /// once it's been held long enough
+    fn process_key_repeat(&mut self, widget_manager: &WidgetManager, now: Instant) -> Vec<Event> {
+        let mut event_stream = Vec::new();
+        if let Some(held) = &mut self.held_key {
+            held.is_new = false;
+        }
+
+        let (key_repeat, held) = match (self.key_repeat, &mut self.held_key) {
+            (Some(key_repeat), Some(held)) => (key_repeat, held),
+            _ => return event_stream,
+        };
+        if now < held.repeat_at {
+            return event_stream;
+        }
+        // At most one repeat per frame, so a long frame doesn't cause a burst of them
+        held.repeat_at = (held.repeat_at + key_repeat.interval).max(now);
+
+        if let Some(current_focus) = widget_manager.focus_tree.current() {
+            let held = held.clone();
+            let physical_key = held
+                .physical_key
+                .unwrap_or_else(|| self.keyboard_layout.physical_key(held.key));
+            let keyboard_event = KeyboardEvent::new(held.key, self.keyboard_modifiers)
+                .with_physical_key(physical_key)
+                .with_repeat(true);
+            event_stream.push(Event::new(
+                current_focus,
+                EventType::KeyDown(keyboard_event),
+            ));
+            for c in held.chars {
+                event_stream.push(Event::new(current_focus, EventType::CharInput { c }));
+            }
+        }
+
+        event_stream
+    }
+
     fn process_keyboard_events(
         &mut self,
         input_event: &InputEvent,
+        is_repeat: bool,
         _states: &mut HashMap<EventType, EventState>,
         widget_manager: &WidgetManager,
     ) -> Vec<Event> {
         let mut event_stream = Vec::new();
+
+        // === Layout === //
+        if let InputEvent::Keyboard {
+            key,
+            physical_key: Some(physical_key),
+            is_pressed: true,
+        } = input_event
+        {
+            self.keyboard_layout.set_key(*physical_key, *key);
+        }
+
         if let Some(current_focus) = widget_manager.focus_tree.current() {
             match input_event {
                 InputEvent::CharEvent { c } => {
                     event_stream.push(Event::new(current_focus, EventType::CharInput { c: *c }))
                 }
-                InputEvent::Keyboard { key, is_pressed } => {
+                InputEvent::Keyboard {
+                    key,
+                    physical_key,
+                    is_pressed,
+                } => {
                     // === Modifers === //
                     match key {
                         KeyCode::LControl | KeyCode::RControl => {
@@ -431,25 +1251,58 @@ impl EventDispatcher {
                     }
 
                     // === Event === //
+                    let physical_key =
+                        physical_key.unwrap_or_else(|| self.keyboard_layout.physical_key(*key));
+                    let keyboard_event = KeyboardEvent::new(*key, self.keyboard_modifiers)
+                        .with_physical_key(physical_key)
+                        .with_repeat(is_repeat && *is_pressed);
                     if *is_pressed {
                         event_stream.push(Event::new(
                             current_focus,
-                            EventType::KeyDown(KeyboardEvent::new(*key, self.keyboard_modifiers)),
+                            EventType::KeyDown(keyboard_event),
                         ))
                     } else {
-                        event_stream.push(Event::new(
-                            current_focus,
-                            EventType::KeyUp(KeyboardEvent::new(*key, self.keyboard_modifiers)),
-                        ))
+                        event_stream
+                            .push(Event::new(current_focus, EventType::KeyUp(keyboard_event)))
                     }
                 }
                 _ => {}
             }
+        } else if let InputEvent::Keyboard {
+            key: KeyCode::Escape,
+            physical_key,
+            is_pressed: true,
+        } = input_event
+        {
+            // Nothing is focused, so the key goes to the topmost modal (or the root) for it to
+            // still navigate back by default
+            if let Some(target) = widget_manager.get_modal().or(widget_manager.tree.root_node) {
+                let physical_key = physical_key
+                    .unwrap_or_else(|| self.keyboard_layout.physical_key(KeyCode::Escape));
+                let keyboard_event = KeyboardEvent::new(KeyCode::Escape, self.keyboard_modifiers)
+                    .with_physical_key(physical_key)
+                    .with_repeat(is_repeat);
+                event_stream.push(Event::new(target, EventType::KeyDown(keyboard_event)));
+            }
         }
 
         event_stream
     }
 
+    /// Get the position of the given event type among the events that need the whole tree to find
+    /// their target (see [build_event_stream](Self::build_event_stream))
+    fn get_state_order(event_type: &EventType) -> usize {
+        match event_type {
+            EventType::Focus => 0,
+            EventType::Click => 1,
+            EventType::AuxClick(..) => 2,
+            EventType::ContextMenu => 3,
+            EventType::Scroll(..) => 4,
+            EventType::Hover => 5,
+            _ => 6,
+        }
+    }
+
     /// Updates the state data for the given event
     fn update_state(
         states: &mut HashMap<EventType, EventState>,
@@ -457,7 +1310,11 @@ impl EventDispatcher {
         layout: &Rect,
         event_type: EventType,
     ) {
-        let state = states.entry(event_type).or_insert(EventState::default());
+        let order = states.len();
+        let state = states.entry(event_type).or_insert(EventState {
+            order,
+            ..EventState::default()
+        });
 
         let (node, depth) = tree_node;
         // Node is at or above best depth and is at or above best z-level
@@ -472,6 +1329,97 @@ impl EventDispatcher {
         }
     }
 
+    /// Get the first widget from the given one up to the root that is a drop target accepting the
+    /// given payload (see [KayakContext::set_drop_target])
+    fn find_drop_target(
+        index: Index,
+        payload: &DragPayload,
+        widget_manager: &WidgetManager,
+    ) -> Option<Index> {
+        let mut current = Some(index);
+        while let Some(index) = current {
+            if let Some(filter) = widget_manager.get_metadata::<DropFilter>(index) {
+                if filter.accepts(payload) {
+                    return Some(index);
+                }
+            }
+            current = widget_manager.tree.get_parent(index);
+        }
+        None
+    }
+
+    /// Checks if the given point hits the layout (within the shape given by its `hit_test` style,
+    /// if any), as well as the clip (if any)
+    fn contains_point(
+        layout: &Rect,
+        clip: Option<&Rect>,
+        hit_test: Option<&HitTest>,
+        point: &(f32, f32),
+    ) -> bool {
+        let hits_layout = match hit_test {
+            Some(hit_test) => hit_test.contains(layout, point),
+            None => layout.contains(point),
+        };
+        hits_layout && clip.map_or(true, |clip| clip.contains(point))
+    }
+
+    /// Gets the shape the cursor hits on the given widget, if it's set
+    fn get_hit_test(index: Index, widget_manager: &WidgetManager) -> Option<&HitTest> {
+        match widget_manager.nodes.get(index) {
+            Some(Some(node)) => match &node.styles.hit_test {
+                StyleProp::Value(hit_test) => Some(hit_test),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Get the throttle of the events sent to the given widget (see [EventThrottle])
+    fn get_event_throttle(index: Index, widget_manager: &WidgetManager) -> EventThrottle {
+        match widget_manager.current_widgets.get(index) {
+            Some(Some(widget)) => widget
+                .get_styles()
+                .map(|styles| styles.event_throttle.resolve())
+                .unwrap_or_default(),
+            _ => EventThrottle::default(),
+        }
+    }
+
+    /// Checks if the given widget could be under the cursor at all
+    ///
+    /// Widgets lying entirely outside of the window or their clip region can't be hit, so they are
+    /// skipped during hit testing. Their children are still tested since they may overflow them.
+    fn is_hittable(
+        &self,
+        index: Index,
+        clip: Option<&Rect>,
+        viewport: Option<&Rect>,
+        widget_manager: &WidgetManager,
+    ) -> bool {
+        if Self::contains_event(&self.previous_events, &index, &EventType::MouseIn) {
+            // Still needs to be told that the cursor left it
+            return true;
+        }
+
+        if let Some(layout) = widget_manager.get_layout(&index) {
+            viewport.map_or(true, |viewport| viewport.intersects(layout))
+                && clip.map_or(true, |clip| clip.intersects(layout))
+        } else {
+            true
+        }
+    }
+
+    /// Gets the layout of the given widget if it clips its children
+    fn get_clip_layout(index: Index, widget_manager: &WidgetManager) -> Option<Rect> {
+        let widget = widget_manager.current_widgets.get(index)?.as_ref()?;
+        let styles = widget.get_styles()?;
+        if matches!(styles.render_command, StyleProp::Value(RenderCommand::Clip)) {
+            widget_manager.get_layout(&index).copied()
+        } else {
+            None
+        }
+    }
+
     /// Checks if the given event map contains a specific event for the given widget
     fn contains_event(events: &EventMap, widget_id: &Index, event_type: &EventType) -> bool {
         if let Some(entry) = events.get(widget_id) {
@@ -503,6 +1451,122 @@ impl EventDispatcher {
         }
     }
 
+    /// Restricts the pointer events of a node to those allowed by the current modal widget
+    ///
+    /// Nodes outside the modal are ignored entirely, except for its ancestors, which still need to
+    /// be traversed to reach it.
+    fn get_modal_pointer_events(
+        node: Index,
+        modal: Index,
+        pointer_events: PointerEvents,
+        widget_manager: &WidgetManager,
+    ) -> PointerEvents {
+        if node == modal || widget_manager.node_tree.is_descendant(node, modal) {
+            pointer_events
+        } else if widget_manager.node_tree.is_descendant(modal, node) {
+            PointerEvents::ChildrenOnly
+        } else {
+            PointerEvents::None
+        }
+    }
+
+    /// Get the widgets directional navigation can focus, with their layouts
+    ///
+    /// These are the focusable widgets with a visible layout, within the current modal (if any).
+    fn get_navigation_candidates(widget_manager: &WidgetManager) -> Vec<(Index, Rect)> {
+        let modal = widget_manager.get_modal();
+        widget_manager
+            .focus_tree
+            .tree()
+            .flatten()
+            .into_iter()
+            .filter(|index| matches!(widget_manager.get_focusable(*index), Some(true)))
+            .filter(|index| match modal {
+                Some(modal) => *index == modal || widget_manager.tree.is_descendant(*index, modal),
+                None => true,
+            })
+            .filter_map(|index| {
+                let layout = widget_manager.get_layout(&index)?;
+                if layout.width > 0.0 && layout.height > 0.0 {
+                    Some((index, *layout))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the focusable widget nearest to the given one in the given direction
+    ///
+    /// Widgets are compared by the distance between the centers of their layouts, where being off
+    /// to the side counts twice as much as being further along the direction. Only widgets whose
+    /// center is past the given widget's center in that direction are considered.
+    fn find_neighbor(
+        widget_manager: &WidgetManager,
+        from: Index,
+        direction: NavigationDirection,
+    ) -> Option<Index> {
+        let center = |layout: &Rect| {
+            (
+                layout.posx + layout.width / 2.0,
+                layout.posy + layout.height / 2.0,
+            )
+        };
+        let (from_x, from_y) = center(widget_manager.get_layout(&from)?);
+
+        let mut best: Option<(Index, f32)> = None;
+        for (index, layout) in Self::get_navigation_candidates(widget_manager) {
+            if index == from {
+                continue;
+            }
+
+            let (x, y) = center(&layout);
+            // The distance along the direction and the distance off to its side
+            let (along, aside) = match direction {
+                NavigationDirection::Up => (from_y - y, x - from_x),
+                NavigationDirection::Down => (y - from_y, x - from_x),
+                NavigationDirection::Left => (from_x - x, y - from_y),
+                NavigationDirection::Right => (x - from_x, y - from_y),
+            };
+            if along <= 0.0 {
+                continue;
+            }
+
+            let score = along + aside.abs() * 2.0;
+            if best.map_or(true, |(_, best_score)| score < best_score) {
+                best = Some((index, score));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+
+    /// Moves focus to the next (or previous) focusable widget, staying within the current modal
+    fn cycle_focus(widget_manager: &mut WidgetManager, reverse: bool) -> Option<Index> {
+        let modal = widget_manager.get_modal();
+        let current_focus = widget_manager.focus_tree.current();
+
+        // Each widget is visited at most once per cycle
+        for _ in 0..widget_manager.current_widgets.len().max(1) {
+            let index = if reverse {
+                widget_manager.focus_tree.prev()
+            } else {
+                widget_manager.focus_tree.next()
+            }?;
+
+            match modal {
+                Some(modal) if !widget_manager.tree.is_descendant(index, modal) => continue,
+                _ => return Some(index),
+            }
+        }
+
+        // Nothing within the modal can be focused
+        if let Some(current_focus) = current_focus {
+            widget_manager.focus_tree.focus(current_focus);
+        }
+        None
+    }
+
     /// Executes default actions for events
     fn execute_default(&mut self, event: Event, context: &mut KayakContext) {
         match event.event_type {
@@ -510,11 +1574,8 @@ impl EventDispatcher {
                 KeyCode::Tab => {
                     let current_focus = context.widget_manager.focus_tree.current();
 
-                    let index = if evt.is_shift_pressed() {
-                        context.widget_manager.focus_tree.prev()
-                    } else {
-                        context.widget_manager.focus_tree.next()
-                    };
+                    let index =
+                        Self::cycle_focus(&mut context.widget_manager, evt.is_shift_pressed());
 
                     if let Some(index) = index {
                         let mut events = vec![Event::new(index, EventType::Focus)];
@@ -527,9 +1588,129 @@ impl EventDispatcher {
                         self.dispatch_events(events, context);
                     }
                 }
+                // Holding the key only navigates back once
+                KeyCode::Escape if !evt.is_repeat() => {
+                    context.navigate_back();
+                }
                 _ => {}
             },
+            EventType::Navigate(direction) => {
+                let current_focus = context.widget_manager.focus_tree.current();
+                let neighbor = current_focus.and_then(|current_focus| {
+                    Self::find_neighbor(&context.widget_manager, current_focus, direction)
+                });
+
+                if let (Some(current_focus), Some(neighbor)) = (current_focus, neighbor) {
+                    let events = vec![
+                        Event::new(neighbor, EventType::Focus),
+                        Event::new(current_focus, EventType::Blur),
+                    ];
+                    context.widget_manager.focus_tree.focus(neighbor);
+                    self.dispatch_events(events, context);
+                }
+            }
             _ => {}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::EventDispatcher;
+    use crate::{Event, EventType, Index, InputEvent, KeyCode, TouchPhase};
+
+    #[test]
+    fn event_floods_should_drop_the_least_important_events() {
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.set_max_events(4);
+        let widget = |index| Index::from_raw_parts(index, 0);
+
+        let mut events = vec![Event::new(widget(0), EventType::Click)];
+        events.extend((0..3).map(|index| Event::new(widget(index), EventType::MouseIn)));
+        events.extend((0..3).map(|index| Event::new(widget(index), EventType::MouseOut)));
+        events.extend((0..3).map(|_| Event::new(widget(0), EventType::MouseMove)));
+
+        let event_types = dispatcher
+            .limit_events(events)
+            .iter()
+            .map(|event| event.event_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                EventType::Click,
+                EventType::MouseOut,
+                EventType::MouseOut,
+                EventType::MouseOut
+            ],
+            event_types
+        );
+    }
+
+    #[test]
+    fn only_the_primary_touch_should_move_the_cursor() {
+        let mut dispatcher = EventDispatcher::new();
+        let touch = |id, phase, position| InputEvent::Touch {
+            id,
+            phase,
+            position,
+        };
+
+        let segments = dispatcher.convert_touch_events(&[
+            touch(1, TouchPhase::Started, (10.0, 10.0)),
+            touch(2, TouchPhase::Started, (50.0, 50.0)),
+            touch(2, TouchPhase::Moved, (60.0, 60.0)),
+            touch(1, TouchPhase::Moved, (20.0, 20.0)),
+            touch(2, TouchPhase::Ended, (60.0, 60.0)),
+            touch(1, TouchPhase::Ended, (20.0, 20.0)),
+        ]);
+        assert_eq!(
+            vec![
+                vec![InputEvent::MouseMoved((10.0, 10.0))],
+                vec![InputEvent::MouseLeftPress],
+                vec![InputEvent::MouseMoved((20.0, 20.0))],
+                vec![InputEvent::MouseMoved((20.0, 20.0))],
+                vec![InputEvent::MouseLeftRelease],
+                vec![InputEvent::CursorLeft],
+            ],
+            segments
+        );
+    }
+
+    #[test]
+    fn system_key_repeats_should_be_detected() {
+        let mut dispatcher = EventDispatcher::new();
+        let now = Instant::now();
+        let key = |key, is_pressed| InputEvent::Keyboard {
+            key,
+            physical_key: None,
+            is_pressed,
+        };
+        let backspace = InputEvent::CharEvent { c: '\u{8}' };
+
+        let pressed = [backspace.clone(), key(KeyCode::Back, true)];
+        assert_eq!(
+            vec![false, false],
+            dispatcher.update_held_key(&pressed, now)
+        );
+        assert_eq!(
+            Some(vec!['\u{8}']),
+            dispatcher.held_key.as_ref().map(|held| held.chars.clone())
+        );
+
+        dispatcher.held_key.as_mut().unwrap().is_new = false;
+        let repeated = [
+            backspace,
+            key(KeyCode::Back, true),
+            key(KeyCode::LShift, true),
+        ];
+        assert_eq!(
+            vec![true, true, false],
+            dispatcher.update_held_key(&repeated, now + Duration::from_millis(600))
+        );
+
+        dispatcher.update_held_key(&[key(KeyCode::Back, false)], now);
+        assert!(dispatcher.held_key.is_none());
+    }
+}
@@ -146,6 +146,8 @@ impl<Value: 'static + Clone + PartialEq + Send + Sync> Changeable for Binding<Va
 impl<Value: 'static + Clone + PartialEq + Send + Sync> Bound<Value> for Binding<Value> {
     fn get(&self) -> Value {
         BindingContext::add_dependency(self.clone());
+        #[cfg(debug_assertions)]
+        crate::binding_check::record_read(self.id);
 
         self.value.lock().unwrap().get()
     }
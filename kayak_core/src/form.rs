@@ -0,0 +1,56 @@
+/// A struct whose fields can be edited through a form of bindings
+///
+/// This is usually derived with `#[derive(UiForm)]`, which generates the form (a struct named
+/// like this one with a `Form` suffix) holding a [Binding](crate::Binding) per field. Inputs edit
+/// the bindings through the form's change handlers, and the edited struct is collected back with
+/// [from_form](Self::from_form) (e.g. when an options screen is applied).
+pub trait UiForm: Sized {
+    /// The bindings of this struct's fields
+    type Form;
+
+    /// Creates a form holding this struct's values
+    fn to_form(&self) -> Self::Form;
+
+    /// Collects the current values of a form
+    fn from_form(form: &Self::Form) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bound, UiForm};
+    use kayak_render_macros::UiForm;
+
+    #[derive(Debug, Clone, PartialEq, UiForm)]
+    struct Options {
+        vsync: bool,
+        volume: f32,
+        name: String,
+    }
+
+    #[test]
+    fn forms_should_round_trip_their_struct() {
+        let options = Options {
+            vsync: true,
+            volume: 0.8,
+            name: "Ferris".to_string(),
+        };
+        let form = options.to_form();
+        assert_eq!(options, Options::from_form(&form));
+
+        form.on_vsync_change().call(false);
+        form.on_name_change().call("Corro".to_string());
+        assert!(!form.vsync.get());
+        assert_eq!(
+            Options {
+                vsync: false,
+                volume: 0.8,
+                name: "Corro".to_string(),
+            },
+            form.to_struct()
+        );
+
+        form.set_struct(&options);
+        assert_eq!(options, form.to_struct());
+        assert_eq!(form, form.clone());
+    }
+}
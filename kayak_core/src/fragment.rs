@@ -31,6 +31,18 @@ impl Widget for Fragment {
         self.styles.clone()
     }
 
+    fn set_styles(&mut self, styles: Option<Style>) {
+        self.styles = styles;
+    }
+
+    fn set_children(&mut self, children: crate::Children) {
+        self.children = children;
+    }
+
+    fn set_on_event(&mut self, on_event: Option<crate::OnEvent>) {
+        self.on_event = on_event;
+    }
+
     fn get_name(&self) -> String {
         String::from("Fragment")
     }
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use crate::layout_cache::Rect;
+
+/// The shape of a widget that the cursor hits, set with the `hit_test` style
+///
+/// Pointer events only reach a widget when the cursor is over this shape, which always lies
+/// within the widget's layout. This lets round buttons or irregular map regions ignore clicks in
+/// their transparent corners.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HitTest {
+    /// The whole layout of the widget
+    Rect,
+    /// The ellipse filling the layout of the widget (a circle for square widgets)
+    Ellipse,
+    /// A polygon, with its points relative to the layout of the widget: `(0.0, 0.0)` is its
+    /// top-left corner and `(1.0, 1.0)` its bottom-right one
+    Polygon(Vec<(f32, f32)>),
+    /// A mask stretched over the layout of the widget (e.g. the alpha channel of its image)
+    Mask(HitMask),
+    /// A custom function, given the layout of the widget and the cursor position
+    Custom(HitTestFn),
+}
+
+impl Default for HitTest {
+    fn default() -> Self {
+        Self::Rect
+    }
+}
+
+impl HitTest {
+    /// Checks if the given point hits this shape, placed on the given layout
+    pub fn contains(&self, layout: &Rect, point: &(f32, f32)) -> bool {
+        if !layout.contains(point) {
+            return false;
+        }
+
+        // Relative to the layout, from 0.0 to 1.0
+        let relative = |point: &(f32, f32)| {
+            (
+                if layout.width > 0.0 {
+                    (point.0 - layout.posx) / layout.width
+                } else {
+                    0.0
+                },
+                if layout.height > 0.0 {
+                    (point.1 - layout.posy) / layout.height
+                } else {
+                    0.0
+                },
+            )
+        };
+
+        match self {
+            Self::Rect => true,
+            Self::Ellipse => {
+                let (x, y) = relative(point);
+                let (x, y) = (x * 2.0 - 1.0, y * 2.0 - 1.0);
+                x * x + y * y <= 1.0
+            }
+            Self::Polygon(points) => polygon_contains(points, relative(point)),
+            Self::Mask(mask) => mask.contains(relative(point)),
+            Self::Custom(hit_test) => (hit_test.0)(layout, *point),
+        }
+    }
+}
+
+/// A grid of values stretched over a widget, which is hit where the values exceed a threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitMask {
+    width: usize,
+    height: usize,
+    values: Arc<Vec<u8>>,
+    threshold: u8,
+}
+
+impl HitMask {
+    /// Creates a mask from its values, given row by row from the top
+    ///
+    /// It's hit wherever the value is above zero (see [with_threshold](Self::with_threshold)).
+    /// Missing values count as zero.
+    pub fn new(width: usize, height: usize, values: Vec<u8>) -> Self {
+        Self {
+            width,
+            height,
+            values: Arc::new(values),
+            threshold: 0,
+        }
+    }
+
+    /// Creates a mask from the alpha channel of an image, given as 4 bytes per pixel (RGBA or BGRA)
+    /// row by row from the top
+    pub fn from_alpha(width: usize, height: usize, pixels: &[u8]) -> Self {
+        let values = pixels.chunks_exact(4).map(|pixel| pixel[3]).collect();
+        Self::new(width, height, values)
+    }
+
+    /// Get the number of columns and rows of the mask
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Set the value that the mask must be above to be hit
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Checks if the mask is hit at the given point, relative to the mask (from 0.0 to 1.0)
+    pub fn contains(&self, (x, y): (f32, f32)) -> bool {
+        if self.width == 0 || self.height == 0 {
+            return false;
+        }
+
+        let column = ((x * self.width as f32) as usize).min(self.width - 1);
+        let row = ((y * self.height as f32) as usize).min(self.height - 1);
+        let value = self
+            .values
+            .get(row * self.width + column)
+            .copied()
+            .unwrap_or_default();
+        value > self.threshold
+    }
+}
+
+/// A custom hit test, returning true if the cursor position hits the widget with the given layout
+#[derive(Clone)]
+pub struct HitTestFn(pub Arc<dyn Fn(&Rect, (f32, f32)) -> bool + Send + Sync>);
+
+impl HitTestFn {
+    pub fn new<F: Fn(&Rect, (f32, f32)) -> bool + Send + Sync + 'static>(f: F) -> HitTestFn {
+        HitTestFn(Arc::new(f))
+    }
+}
+
+impl std::fmt::Debug for HitTestFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("HitTestFn").finish()
+    }
+}
+
+impl PartialEq for HitTestFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Checks if the given point is within the polygon (using the even-odd rule)
+fn polygon_contains(points: &[(f32, f32)], (x, y): (f32, f32)) -> bool {
+    let mut contains = false;
+    for (index, from) in points.iter().enumerate() {
+        let to = points[(index + 1) % points.len()];
+        if (from.1 > y) != (to.1 > y) {
+            let crossing = from.0 + (y - from.1) / (to.1 - from.1) * (to.0 - from.0);
+            if x < crossing {
+                contains = !contains;
+            }
+        }
+    }
+    contains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HitMask, HitTest};
+    use crate::layout_cache::Rect;
+
+    #[test]
+    fn shapes_should_only_be_hit_within_them() {
+        let layout = Rect {
+            posx: 10.0,
+            posy: 10.0,
+            width: 100.0,
+            height: 100.0,
+            ..Rect::default()
+        };
+        let center = (60.0, 60.0);
+        let corner = (12.0, 12.0);
+        let outside = (200.0, 60.0);
+
+        assert!(HitTest::Rect.contains(&layout, &corner));
+        assert!(!HitTest::Rect.contains(&layout, &outside));
+
+        assert!(HitTest::Ellipse.contains(&layout, &center));
+        assert!(!HitTest::Ellipse.contains(&layout, &corner));
+
+        let triangle = HitTest::Polygon(vec![(0.5, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert!(triangle.contains(&layout, &center));
+        assert!(!triangle.contains(&layout, &corner));
+
+        // Only the right half of the mask is hit
+        let pixels = [0, 0, 0, 10, 255, 255, 255, 200];
+        let mask = HitTest::Mask(HitMask::from_alpha(2, 1, &pixels).with_threshold(127));
+        assert!(mask.contains(&layout, &(100.0, 60.0)));
+        assert!(!mask.contains(&layout, &corner));
+    }
+}
@@ -1,17 +1,68 @@
-use crate::KeyCode;
+use crate::{KeyCode, NavigationDirection, ScrollEvent};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InputEvent {
     MouseMoved((f32, f32)),
+    /// The cursor left the window
+    CursorLeft,
     MouseLeftPress,
     MouseLeftRelease,
-    CharEvent { c: char },
-    Keyboard { key: KeyCode, is_pressed: bool },
+    MouseRightPress,
+    MouseRightRelease,
+    MouseMiddlePress,
+    MouseMiddleRelease,
+    /// A mouse wheel (or touchpad) scroll
+    Scroll(ScrollEvent),
+    CharEvent {
+        c: char,
+    },
+    Keyboard {
+        key: KeyCode,
+        /// The key at the same position on a US QWERTY keyboard, if known (see
+        /// [KeyboardEvent::physical_key](crate::KeyboardEvent::physical_key))
+        physical_key: Option<KeyCode>,
+        is_pressed: bool,
+    },
+    /// A touch point (e.g. a finger) started, moved, or stopped touching the screen
+    ///
+    /// The first touch point down acts as the cursor with the left mouse button pressed: tapping a
+    /// widget clicks it and moving the touch point hovers and drags over widgets. Other touch
+    /// points are ignored until it's lifted.
+    Touch {
+        /// Identifies the touch point among those touching the screen at once
+        id: u64,
+        phase: TouchPhase,
+        position: (f32, f32),
+    },
+    /// Move focus to the nearest focusable widget in the given direction (e.g. with a gamepad)
+    ///
+    /// The focused widget first receives a [Navigate](crate::EventType::Navigate) event, and
+    /// focus only moves if its default isn't prevented. If nothing is focused, the first focusable
+    /// widget is.
+    Navigate(NavigationDirection),
+    /// Activate the focused widget (e.g. with a gamepad button), which clicks it
+    Activate,
+    /// The window gained (`true`) or lost (`false`) focus
+    WindowFocused(bool),
+}
+
+/// The stage of a touch point's contact with the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    Ended,
+    /// The touch was interrupted (e.g. by the system), so it shouldn't trigger anything
+    Cancelled,
 }
 
 pub enum InputEventCategory {
     Mouse,
     Keyboard,
+    /// Touch events are converted into mouse events for the first touch point down
+    Touch,
+    Navigation,
+    Window,
     // TODO: Gamepad, etc.
 }
 
@@ -20,11 +71,24 @@ impl InputEvent {
         match self {
             // Mouse events
             Self::MouseMoved(..) => InputEventCategory::Mouse,
+            Self::CursorLeft => InputEventCategory::Mouse,
             Self::MouseLeftPress => InputEventCategory::Mouse,
             Self::MouseLeftRelease => InputEventCategory::Mouse,
+            Self::MouseRightPress => InputEventCategory::Mouse,
+            Self::MouseRightRelease => InputEventCategory::Mouse,
+            Self::MouseMiddlePress => InputEventCategory::Mouse,
+            Self::MouseMiddleRelease => InputEventCategory::Mouse,
+            Self::Scroll(..) => InputEventCategory::Mouse,
             // Keyboard events
             Self::CharEvent { .. } => InputEventCategory::Keyboard,
             Self::Keyboard { .. } => InputEventCategory::Keyboard,
+            // Touch events
+            Self::Touch { .. } => InputEventCategory::Touch,
+            // Navigation events
+            Self::Navigate(..) => InputEventCategory::Navigation,
+            Self::Activate => InputEventCategory::Navigation,
+            // Window events
+            Self::WindowFocused(..) => InputEventCategory::Window,
         }
     }
 }
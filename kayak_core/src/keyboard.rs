@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::KeyCode;
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
@@ -14,22 +16,77 @@ pub struct KeyboardModifiers {
     pub is_meta_pressed: bool,
 }
 
+/// How a held key repeats its `KeyDown` event (and the characters it typed)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KeyRepeat {
+    /// How long the key is held before it starts repeating
+    pub delay: Duration,
+    /// The time between two repeats
+    pub interval: Duration,
+}
+
+impl Default for KeyRepeat {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            interval: Duration::from_millis(33),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct KeyboardEvent {
     key: KeyCode,
+    physical_key: KeyCode,
     modifiers: KeyboardModifiers,
+    is_repeat: bool,
 }
 
 impl KeyboardEvent {
     pub fn new(key: KeyCode, modifiers: KeyboardModifiers) -> Self {
-        Self { key, modifiers }
+        Self {
+            key,
+            physical_key: key,
+            modifiers,
+            is_repeat: false,
+        }
+    }
+
+    /// Set whether this event repeats a held key (see [is_repeat](Self::is_repeat))
+    pub fn with_repeat(mut self, is_repeat: bool) -> Self {
+        self.is_repeat = is_repeat;
+        self
     }
 
-    /// Returns this event's affected key
+    /// Set the key at the same position on a US QWERTY keyboard (see [physical_key](Self::physical_key))
+    pub fn with_physical_key(mut self, physical_key: KeyCode) -> Self {
+        self.physical_key = physical_key;
+        self
+    }
+
+    /// Returns this event's affected key, as typed with the user's keyboard layout
+    ///
+    /// Use this for shortcuts named after a key (e.g. `Ctrl+Z` to undo).
     pub fn key(&self) -> KeyCode {
         self.key
     }
 
+    /// Returns the key at the same position as this event's key on a US QWERTY keyboard
+    ///
+    /// Use this for bindings that depend on where the keys are rather than what they type, such as
+    /// WASD movement, which is ZQSD on AZERTY. To show such a binding to the user, get the label of
+    /// the key it types with [KeyboardLayout::key_label](crate::KeyboardLayout::key_label).
+    pub fn physical_key(&self) -> KeyCode {
+        self.physical_key
+    }
+
+    /// Returns true if this event repeats the `KeyDown` of a held key rather than a new press
+    ///
+    /// Actions that shouldn't repeat (e.g. toggling a menu) can ignore these.
+    pub fn is_repeat(&self) -> bool {
+        self.is_repeat
+    }
+
     /// Returns all modifiers for this event's key
     pub fn modifiers(&self) -> KeyboardModifiers {
         self.modifiers
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use crate::KeyCode;
+
+/// A keyboard layout, mapping each physical key to the key it types
+///
+/// Physical keys are named after the key at the same position on a US QWERTY keyboard, so
+/// [KeyCode::W] is the key right above [KeyCode::S] whatever it types. Bindings that depend on
+/// where the keys are (such as WASD movement) should use physical keys (see
+/// [KeyboardEvent::physical_key](crate::KeyboardEvent::physical_key)), while hints shown to the
+/// user should use the keys they type (see [key_label](Self::key_label)).
+///
+/// Only the keys that differ from QWERTY are stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    name: String,
+    /// The key typed by each physical key that doesn't type the same key as on QWERTY
+    keys: HashMap<KeyCode, KeyCode>,
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+impl KeyboardLayout {
+    /// Creates a layout typing the same keys as QWERTY, to be changed with [with_key](Self::with_key)
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// The US QWERTY layout
+    pub fn qwerty() -> Self {
+        Self::new("QWERTY")
+    }
+
+    /// The French AZERTY layout
+    pub fn azerty() -> Self {
+        Self::new("AZERTY")
+            .with_key(KeyCode::Q, KeyCode::A)
+            .with_key(KeyCode::A, KeyCode::Q)
+            .with_key(KeyCode::W, KeyCode::Z)
+            .with_key(KeyCode::Z, KeyCode::W)
+            .with_key(KeyCode::Semicolon, KeyCode::M)
+            .with_key(KeyCode::M, KeyCode::Comma)
+    }
+
+    /// The German QWERTZ layout
+    pub fn qwertz() -> Self {
+        Self::new("QWERTZ")
+            .with_key(KeyCode::Y, KeyCode::Z)
+            .with_key(KeyCode::Z, KeyCode::Y)
+    }
+
+    /// Set the key typed by the given physical key
+    pub fn with_key(mut self, physical_key: KeyCode, key: KeyCode) -> Self {
+        self.set_key(physical_key, key);
+        self
+    }
+
+    /// Set the key typed by the given physical key
+    pub fn set_key(&mut self, physical_key: KeyCode, key: KeyCode) {
+        if physical_key == key {
+            self.keys.remove(&physical_key);
+        } else {
+            self.keys.insert(physical_key, key);
+        }
+    }
+
+    /// Get the name of the layout (e.g. `"AZERTY"`)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the key typed by the given physical key
+    pub fn key(&self, physical_key: KeyCode) -> KeyCode {
+        self.keys
+            .get(&physical_key)
+            .copied()
+            .unwrap_or(physical_key)
+    }
+
+    /// Get the physical key typing the given key
+    ///
+    /// Keys typed by no key of the layout are assumed to be at their QWERTY position.
+    pub fn physical_key(&self, key: KeyCode) -> KeyCode {
+        self.keys
+            .iter()
+            .find(|(_, typed)| **typed == key)
+            .map_or(key, |(physical_key, _)| *physical_key)
+    }
+
+    /// Get the label of the key typed by the given physical key, for shortcut hints (e.g. `"Z"`
+    /// for [KeyCode::W] on AZERTY)
+    pub fn key_label(&self, physical_key: KeyCode) -> String {
+        Self::label(self.key(physical_key))
+    }
+
+    /// Get the label of the given key, such as `"A"`, `"1"`, `","` or `"Space"`
+    pub fn label(key: KeyCode) -> String {
+        let label = match key {
+            KeyCode::Key0 => "0",
+            KeyCode::Key1 => "1",
+            KeyCode::Key2 => "2",
+            KeyCode::Key3 => "3",
+            KeyCode::Key4 => "4",
+            KeyCode::Key5 => "5",
+            KeyCode::Key6 => "6",
+            KeyCode::Key7 => "7",
+            KeyCode::Key8 => "8",
+            KeyCode::Key9 => "9",
+            KeyCode::Apostrophe => "'",
+            KeyCode::Backslash => "\\",
+            KeyCode::Comma => ",",
+            KeyCode::Equals => "=",
+            KeyCode::Grave => "`",
+            KeyCode::LBracket => "[",
+            KeyCode::Minus => "-",
+            KeyCode::Period => ".",
+            KeyCode::RBracket => "]",
+            KeyCode::Semicolon => ";",
+            KeyCode::Slash => "/",
+            KeyCode::Return => "Enter",
+            KeyCode::Back => "Backspace",
+            KeyCode::Escape => "Esc",
+            _ => return format!("{:?}", key),
+        };
+        label.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardLayout;
+    use crate::KeyCode;
+
+    #[test]
+    fn physical_keys_should_map_to_the_keys_of_the_layout() {
+        let azerty = KeyboardLayout::azerty();
+        assert_eq!(KeyCode::Z, azerty.key(KeyCode::W));
+        assert_eq!(KeyCode::W, azerty.physical_key(KeyCode::Z));
+        assert_eq!(KeyCode::S, azerty.key(KeyCode::S));
+        assert_eq!("Z", azerty.key_label(KeyCode::W));
+        assert_eq!(",", azerty.key_label(KeyCode::M));
+
+        let qwerty = KeyboardLayout::qwerty();
+        assert_eq!("W", qwerty.key_label(KeyCode::W));
+    }
+}
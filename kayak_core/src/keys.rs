@@ -201,3 +201,193 @@ pub enum KeyCode {
     Paste,
     Cut,
 }
+
+impl KeyCode {
+    /// All of the key codes, in declaration order
+    pub const ALL: [KeyCode; 163] = [
+        KeyCode::Key1,
+        KeyCode::Key2,
+        KeyCode::Key3,
+        KeyCode::Key4,
+        KeyCode::Key5,
+        KeyCode::Key6,
+        KeyCode::Key7,
+        KeyCode::Key8,
+        KeyCode::Key9,
+        KeyCode::Key0,
+        KeyCode::A,
+        KeyCode::B,
+        KeyCode::C,
+        KeyCode::D,
+        KeyCode::E,
+        KeyCode::F,
+        KeyCode::G,
+        KeyCode::H,
+        KeyCode::I,
+        KeyCode::J,
+        KeyCode::K,
+        KeyCode::L,
+        KeyCode::M,
+        KeyCode::N,
+        KeyCode::O,
+        KeyCode::P,
+        KeyCode::Q,
+        KeyCode::R,
+        KeyCode::S,
+        KeyCode::T,
+        KeyCode::U,
+        KeyCode::V,
+        KeyCode::W,
+        KeyCode::X,
+        KeyCode::Y,
+        KeyCode::Z,
+        KeyCode::Escape,
+        KeyCode::F1,
+        KeyCode::F2,
+        KeyCode::F3,
+        KeyCode::F4,
+        KeyCode::F5,
+        KeyCode::F6,
+        KeyCode::F7,
+        KeyCode::F8,
+        KeyCode::F9,
+        KeyCode::F10,
+        KeyCode::F11,
+        KeyCode::F12,
+        KeyCode::F13,
+        KeyCode::F14,
+        KeyCode::F15,
+        KeyCode::F16,
+        KeyCode::F17,
+        KeyCode::F18,
+        KeyCode::F19,
+        KeyCode::F20,
+        KeyCode::F21,
+        KeyCode::F22,
+        KeyCode::F23,
+        KeyCode::F24,
+        KeyCode::Snapshot,
+        KeyCode::Scroll,
+        KeyCode::Pause,
+        KeyCode::Insert,
+        KeyCode::Home,
+        KeyCode::Delete,
+        KeyCode::End,
+        KeyCode::PageDown,
+        KeyCode::PageUp,
+        KeyCode::Left,
+        KeyCode::Up,
+        KeyCode::Right,
+        KeyCode::Down,
+        KeyCode::Back,
+        KeyCode::Return,
+        KeyCode::Space,
+        KeyCode::Compose,
+        KeyCode::Caret,
+        KeyCode::Numlock,
+        KeyCode::Numpad0,
+        KeyCode::Numpad1,
+        KeyCode::Numpad2,
+        KeyCode::Numpad3,
+        KeyCode::Numpad4,
+        KeyCode::Numpad5,
+        KeyCode::Numpad6,
+        KeyCode::Numpad7,
+        KeyCode::Numpad8,
+        KeyCode::Numpad9,
+        KeyCode::AbntC1,
+        KeyCode::AbntC2,
+        KeyCode::NumpadAdd,
+        KeyCode::Apostrophe,
+        KeyCode::Apps,
+        KeyCode::Asterisk,
+        KeyCode::Plus,
+        KeyCode::At,
+        KeyCode::Ax,
+        KeyCode::Backslash,
+        KeyCode::Calculator,
+        KeyCode::Capital,
+        KeyCode::Colon,
+        KeyCode::Comma,
+        KeyCode::Convert,
+        KeyCode::NumpadDecimal,
+        KeyCode::NumpadDivide,
+        KeyCode::Equals,
+        KeyCode::Grave,
+        KeyCode::Kana,
+        KeyCode::Kanji,
+        KeyCode::LAlt,
+        KeyCode::LBracket,
+        KeyCode::LControl,
+        KeyCode::LShift,
+        KeyCode::LWin,
+        KeyCode::Mail,
+        KeyCode::MediaSelect,
+        KeyCode::MediaStop,
+        KeyCode::Minus,
+        KeyCode::NumpadMultiply,
+        KeyCode::Mute,
+        KeyCode::MyComputer,
+        KeyCode::NavigateForward,
+        KeyCode::NavigateBackward,
+        KeyCode::NextTrack,
+        KeyCode::NoConvert,
+        KeyCode::NumpadComma,
+        KeyCode::NumpadEnter,
+        KeyCode::NumpadEquals,
+        KeyCode::Oem102,
+        KeyCode::Period,
+        KeyCode::PlayPause,
+        KeyCode::Power,
+        KeyCode::PrevTrack,
+        KeyCode::RAlt,
+        KeyCode::RBracket,
+        KeyCode::RControl,
+        KeyCode::RShift,
+        KeyCode::RWin,
+        KeyCode::Semicolon,
+        KeyCode::Slash,
+        KeyCode::Sleep,
+        KeyCode::Stop,
+        KeyCode::NumpadSubtract,
+        KeyCode::Sysrq,
+        KeyCode::Tab,
+        KeyCode::Underline,
+        KeyCode::Unlabeled,
+        KeyCode::VolumeDown,
+        KeyCode::VolumeUp,
+        KeyCode::Wake,
+        KeyCode::WebBack,
+        KeyCode::WebFavorites,
+        KeyCode::WebForward,
+        KeyCode::WebHome,
+        KeyCode::WebRefresh,
+        KeyCode::WebSearch,
+        KeyCode::WebStop,
+        KeyCode::Yen,
+        KeyCode::Copy,
+        KeyCode::Paste,
+        KeyCode::Cut,
+    ];
+
+    /// Get the key code with the given name (as formatted by [Debug], e.g. `"Space"` or `"F1"`)
+    pub fn from_name(name: &str) -> Option<KeyCode> {
+        Self::ALL
+            .iter()
+            .find(|key| format!("{:?}", key) == name)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyCode;
+
+    #[test]
+    fn key_codes_round_trip_through_their_names() {
+        for key in KeyCode::ALL {
+            assert_eq!(KeyCode::from_name(&format!("{:?}", key)), Some(key));
+        }
+        assert_eq!(KeyCode::from_name("NotAKey"), None);
+    }
+}
@@ -18,6 +18,31 @@ impl Rect {
         (point.0 >= self.posx && point.0 <= self.posx + self.width)
             && (point.1 >= self.posy && point.1 <= self.posy + self.height)
     }
+
+    /// Returns true if this rect and the given one overlap by a non-zero area
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.posx < other.posx + other.width
+            && other.posx < self.posx + self.width
+            && self.posy < other.posy + other.height
+            && other.posy < self.posy + self.height
+    }
+
+    /// Returns the overlapping area of this rect and the given one
+    ///
+    /// If the rects don't overlap, the returned rect has no size. The z-index of this rect is kept.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let posx = self.posx.max(other.posx);
+        let posy = self.posy.max(other.posy);
+        let right = (self.posx + self.width).min(other.posx + other.width);
+        let bottom = (self.posy + self.height).min(other.posy + other.height);
+        Rect {
+            posx,
+            posy,
+            width: (right - posx).max(0.0),
+            height: (bottom - posy).max(0.0),
+            z_index: self.z_index,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -1,45 +1,78 @@
 mod assets;
 mod binding;
+mod binding_check;
+mod builder;
+pub mod canvas;
+mod clock;
 pub mod color;
 pub mod context;
 mod cursor;
+mod drag;
 pub mod event;
 mod event_dispatcher;
 mod flo_binding;
 mod focus_tree;
+mod form;
 pub mod fragment;
 pub(crate) mod generational_arena;
+mod hit_test;
 mod input_event;
 mod keyboard;
+mod keyboard_layout;
 mod keys;
 pub mod layout_cache;
+mod listener;
 mod multi_state;
+mod navigation;
 pub mod node;
+mod notifications;
 pub mod render_command;
 pub mod render_primitive;
+mod screen_stack;
+mod settings;
+pub mod snapshot;
 pub mod styles;
+mod telemetry;
+pub mod theme;
+mod throttle;
 pub mod tree;
 mod vec;
+mod visibility;
+mod watch;
 pub mod widget;
 pub mod widget_manager;
 
 use std::sync::{Arc, RwLock};
 
 pub use binding::*;
+pub use builder::{AnyWidgetBuilder, WidgetBuilder};
 pub use color::Color;
 pub use context::*;
-pub use cursor::PointerEvents;
+pub use cursor::{CursorIcon, PointerEvents};
+pub use drag::{DragPayload, DropFilter};
 pub use event::*;
 pub use focus_tree::FocusTree;
+pub use form::UiForm;
 pub use fragment::Fragment;
 pub use generational_arena::{Arena, Index};
+pub use hit_test::{HitMask, HitTest, HitTestFn};
 pub use input_event::*;
-pub use keyboard::{KeyboardEvent, KeyboardModifiers};
+pub use keyboard::{KeyRepeat, KeyboardEvent, KeyboardModifiers};
+pub use keyboard_layout::KeyboardLayout;
 pub use keys::KeyCode;
+pub use listener::GlobalListener;
+pub use navigation::{NavigationDirection, NavigationRepeater, NavigationSettings};
+pub use notifications::{Notification, NotificationKind};
 pub use resources::Resources;
+pub use screen_stack::OnBack;
+pub use settings::Settings;
+pub use telemetry::{Interaction, InteractionSink};
+pub use theme::{Spacing, Theme, ThemeColor, ThemeToken};
+pub use throttle::EventThrottle;
 pub use tree::{Tree, WidgetTree};
 pub use vec::VecTracker;
-pub use widget::Widget;
+pub use watch::WatchedBinding;
+pub use widget::{Widget, WidgetLabel};
 
 pub mod derivative {
     pub use derivative::*;
@@ -49,6 +82,81 @@ pub type Children = Option<
     Arc<dyn Fn(WidgetTree, Option<crate::Index>, &mut crate::context::KayakContext) + Send + Sync>,
 >;
 
+/// Children rendered with data passed by their parent widget, such as the item and index of each
+/// row of a `List`
+///
+/// In `rsx!`, a closure child sets the `render_children` prop of its parent (which can't have
+/// other children). Several inputs receive the fields of a tuple:
+///
+/// ```ignore
+/// <List items={items}>
+///     {|item: String, index: usize| <Text content={format!("{}. {}", index + 1, item)} />}
+/// </List>
+/// ```
+pub struct RenderChildren<T>(
+    pub  Arc<
+        dyn Fn(T, WidgetTree, Option<crate::Index>, &mut crate::context::KayakContext)
+            + Send
+            + Sync,
+    >,
+);
+
+impl<T> RenderChildren<T> {
+    pub fn new<
+        F: Fn(T, WidgetTree, Option<crate::Index>, &mut crate::context::KayakContext)
+            + Send
+            + Sync
+            + 'static,
+    >(
+        f: F,
+    ) -> RenderChildren<T> {
+        RenderChildren(Arc::new(f))
+    }
+
+    /// Renders the children for the given data, as [Children] are rendered
+    pub fn render(
+        &self,
+        data: T,
+        tree: WidgetTree,
+        parent_id: Option<crate::Index>,
+        context: &mut crate::context::KayakContext,
+    ) {
+        (self.0)(data, tree, parent_id, context)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> RenderChildren<T> {
+    /// Get the [Children] rendered for the given data, to pass them to any widget
+    pub fn bind(&self, data: T) -> Children {
+        let render = self.0.clone();
+        Some(Arc::new(
+            move |tree: WidgetTree,
+                  parent_id: Option<crate::Index>,
+                  context: &mut crate::context::KayakContext| {
+                render(data.clone(), tree, parent_id, context)
+            },
+        ))
+    }
+}
+
+impl<T> Clone for RenderChildren<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for RenderChildren<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> std::fmt::Debug for RenderChildren<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RenderChildren").finish()
+    }
+}
+
 #[derive(Clone)]
 pub struct OnEvent(
     pub  Arc<
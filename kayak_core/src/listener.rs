@@ -0,0 +1,30 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{Event, KayakContext};
+
+/// A handler observing every dispatched event of a category (see
+/// [KayakContext::add_global_listener](crate::KayakContext::add_global_listener))
+///
+/// Listeners only observe events: they can't stop their propagation or prevent their default.
+#[derive(Clone)]
+pub struct GlobalListener(pub Arc<RwLock<dyn FnMut(&mut KayakContext, &Event) + Send + Sync>>);
+
+impl GlobalListener {
+    pub fn new<F: FnMut(&mut KayakContext, &Event) + Send + Sync + 'static>(
+        f: F,
+    ) -> GlobalListener {
+        GlobalListener(Arc::new(RwLock::new(f)))
+    }
+
+    pub fn call(&self, context: &mut KayakContext, event: &Event) {
+        if let Ok(mut listener) = self.0.write() {
+            listener(context, event);
+        }
+    }
+}
+
+impl std::fmt::Debug for GlobalListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GlobalListener").finish()
+    }
+}
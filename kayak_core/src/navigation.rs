@@ -0,0 +1,179 @@
+/// A direction used for directional (e.g. gamepad or arrow key) UI navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Tunables for directional UI navigation
+///
+/// These control how raw directional input (a stick, D-pad, or arrow keys) is turned into
+/// discrete navigation steps. See [NavigationRepeater] for how they are applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NavigationSettings {
+    /// The time (in seconds) a direction must be held before it starts repeating
+    pub initial_delay: f32,
+    /// The number of navigation steps per second while a direction is held (after the initial delay)
+    ///
+    /// A value of zero or less disables repeating.
+    pub repeat_rate: f32,
+    /// Stick tilt at or below this value is ignored
+    pub dead_zone: f32,
+    /// Bias applied to the vertical axis when resolving diagonal input
+    ///
+    /// A value of `1.0` treats both axes equally. Larger values favor vertical navigation (which
+    /// generally feels better for list-based menus) while smaller values favor horizontal navigation.
+    pub diagonal_bias: f32,
+}
+
+impl Default for NavigationSettings {
+    fn default() -> Self {
+        Self {
+            initial_delay: 0.4,
+            repeat_rate: 10.0,
+            dead_zone: 0.5,
+            diagonal_bias: 1.0,
+        }
+    }
+}
+
+impl NavigationSettings {
+    /// Resolve the given axis input into a single navigation direction
+    ///
+    /// The axis is expected to be in the range `[-1.0, 1.0]`, where positive Y points up.
+    pub fn resolve_direction(&self, axis: (f32, f32)) -> Option<NavigationDirection> {
+        let (x, y) = axis;
+        if x.abs() <= self.dead_zone && y.abs() <= self.dead_zone {
+            return None;
+        }
+
+        if y.abs() * self.diagonal_bias >= x.abs() {
+            if y > 0.0 {
+                Some(NavigationDirection::Up)
+            } else {
+                Some(NavigationDirection::Down)
+            }
+        } else if x > 0.0 {
+            Some(NavigationDirection::Right)
+        } else {
+            Some(NavigationDirection::Left)
+        }
+    }
+}
+
+/// Turns continuous directional input into discrete navigation steps
+///
+/// A step is generated as soon as a direction is entered. If that direction is held, another step
+/// is generated after [initial_delay](NavigationSettings::initial_delay) and then repeatedly at
+/// the [repeat_rate](NavigationSettings::repeat_rate).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NavigationRepeater {
+    current: Option<NavigationDirection>,
+    held_for: f32,
+    next_repeat: f32,
+}
+
+impl NavigationRepeater {
+    /// Update the repeater with the current axis input
+    ///
+    /// # Arguments
+    ///
+    /// * `settings`: The navigation settings to apply
+    /// * `axis`: The current axis input (positive Y points up)
+    /// * `delta`: The time (in seconds) since the last update
+    ///
+    /// returns: Option<NavigationDirection>
+    pub fn update(
+        &mut self,
+        settings: &NavigationSettings,
+        axis: (f32, f32),
+        delta: f32,
+    ) -> Option<NavigationDirection> {
+        let direction = settings.resolve_direction(axis);
+        if direction != self.current {
+            // Direction changed -> step immediately
+            self.current = direction;
+            self.held_for = 0.0;
+            self.next_repeat = settings.initial_delay;
+            return direction;
+        }
+
+        let direction = direction?;
+        self.held_for += delta;
+        if self.held_for >= self.next_repeat {
+            if settings.repeat_rate > 0.0 {
+                self.next_repeat += 1.0 / settings.repeat_rate;
+            } else {
+                self.next_repeat = f32::INFINITY;
+            }
+            return Some(direction);
+        }
+
+        None
+    }
+
+    /// Reset the repeater, as if no direction was held
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::navigation::{NavigationDirection, NavigationRepeater, NavigationSettings};
+
+    #[test]
+    fn should_respect_dead_zone_and_bias() {
+        let mut settings = NavigationSettings::default();
+        assert_eq!(None, settings.resolve_direction((0.3, -0.3)));
+        assert_eq!(
+            Some(NavigationDirection::Down),
+            settings.resolve_direction((0.6, -0.6))
+        );
+        assert_eq!(
+            Some(NavigationDirection::Left),
+            settings.resolve_direction((-0.9, 0.6))
+        );
+
+        settings.diagonal_bias = 2.0;
+        assert_eq!(
+            Some(NavigationDirection::Up),
+            settings.resolve_direction((-0.9, 0.6))
+        );
+    }
+
+    #[test]
+    fn should_repeat_after_initial_delay() {
+        let settings = NavigationSettings {
+            initial_delay: 0.5,
+            repeat_rate: 4.0,
+            ..Default::default()
+        };
+        let mut repeater = NavigationRepeater::default();
+        let right = (1.0, 0.0);
+
+        assert_eq!(
+            Some(NavigationDirection::Right),
+            repeater.update(&settings, right, 0.25)
+        );
+        assert_eq!(None, repeater.update(&settings, right, 0.25));
+        assert_eq!(
+            Some(NavigationDirection::Right),
+            repeater.update(&settings, right, 0.25)
+        );
+        assert_eq!(None, repeater.update(&settings, right, 0.125));
+        assert_eq!(
+            Some(NavigationDirection::Right),
+            repeater.update(&settings, right, 0.125)
+        );
+
+        // Releasing and pressing again should step immediately
+        assert_eq!(None, repeater.update(&settings, (0.0, 0.0), 0.25));
+        assert_eq!(
+            Some(NavigationDirection::Right),
+            repeater.update(&settings, right, 0.25)
+        );
+    }
+}
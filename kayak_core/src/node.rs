@@ -1,5 +1,6 @@
 use crate::{
-    styles::{Style, StyleProp},
+    render_command::RenderCommand,
+    styles::{LayoutType, Style, StyleProp, Units},
     Arena, Index,
 };
 
@@ -304,36 +305,100 @@ impl<'a> morphorm::Node<'a> for Index {
         return Some(morphorm::Units::Auto);
     }
 
-    fn row_between(&self, _store: &'_ Self::Data) -> Option<morphorm::Units> {
-        Some(morphorm::Units::Auto)
+    fn row_between(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.row_between {
+                    StyleProp::Default => Some(morphorm::Units::Auto),
+                    StyleProp::Value(prop) => Some(prop),
+                    _ => Some(morphorm::Units::Auto),
+                };
+            }
+        }
+        return Some(morphorm::Units::Auto);
     }
 
-    fn col_between(&self, _store: &'_ Self::Data) -> Option<morphorm::Units> {
-        Some(morphorm::Units::Auto)
+    fn col_between(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.col_between {
+                    StyleProp::Default => Some(morphorm::Units::Auto),
+                    StyleProp::Value(prop) => Some(prop),
+                    _ => Some(morphorm::Units::Auto),
+                };
+            }
+        }
+        return Some(morphorm::Units::Auto);
     }
 
-    fn grid_rows(&self, _store: &'_ Self::Data) -> Option<Vec<morphorm::Units>> {
-        Some(vec![])
+    fn grid_rows(&self, store: &'_ Self::Data) -> Option<Vec<morphorm::Units>> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                if let StyleProp::Value(rows) = &node.styles.grid_rows {
+                    return Some(resolve_auto_tracks(store, node, rows, Axis::Vertical));
+                }
+            }
+        }
+        return Some(vec![]);
     }
 
-    fn grid_cols(&self, _store: &'_ Self::Data) -> Option<Vec<morphorm::Units>> {
-        Some(vec![])
+    fn grid_cols(&self, store: &'_ Self::Data) -> Option<Vec<morphorm::Units>> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                if let StyleProp::Value(cols) = &node.styles.grid_cols {
+                    return Some(resolve_auto_tracks(store, node, cols, Axis::Horizontal));
+                }
+            }
+        }
+        return Some(vec![]);
     }
 
-    fn row_index(&self, _store: &'_ Self::Data) -> Option<usize> {
-        Some(0)
+    fn row_index(&self, store: &'_ Self::Data) -> Option<usize> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.row_index {
+                    StyleProp::Value(prop) => Some(prop),
+                    _ => Some(0),
+                };
+            }
+        }
+        return Some(0);
     }
 
-    fn col_index(&self, _store: &'_ Self::Data) -> Option<usize> {
-        Some(0)
+    fn col_index(&self, store: &'_ Self::Data) -> Option<usize> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.col_index {
+                    StyleProp::Value(prop) => Some(prop),
+                    _ => Some(0),
+                };
+            }
+        }
+        return Some(0);
     }
 
-    fn row_span(&self, _store: &'_ Self::Data) -> Option<usize> {
-        Some(1)
+    fn row_span(&self, store: &'_ Self::Data) -> Option<usize> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.row_span {
+                    StyleProp::Value(prop) => Some(prop.max(1)),
+                    _ => Some(1),
+                };
+            }
+        }
+        return Some(1);
     }
 
-    fn col_span(&self, _store: &'_ Self::Data) -> Option<usize> {
-        Some(1)
+    fn col_span(&self, store: &'_ Self::Data) -> Option<usize> {
+        if let Some(node) = store.get(*self) {
+            if let Some(node) = node {
+                return match node.styles.col_span {
+                    StyleProp::Value(prop) => Some(prop.max(1)),
+                    _ => Some(1),
+                };
+            }
+        }
+        return Some(1);
     }
 
     fn border_left(&self, _store: &'_ Self::Data) -> Option<morphorm::Units> {
@@ -352,3 +417,183 @@ impl<'a> morphorm::Node<'a> for Index {
         Some(morphorm::Units::Auto)
     }
 }
+
+/// The direction of the tracks of a grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Axis {
+    /// Columns, sized by the widths of their children
+    Horizontal,
+    /// Rows, sized by the heights of their children
+    Vertical,
+}
+
+/// Replaces the `Auto` tracks of a grid by the size of the largest child placed only in them
+///
+/// The layout doesn't size grid tracks to their content, so this measures the children ahead
+/// of it (see [content_size]).
+fn resolve_auto_tracks(
+    store: &Arena<Option<Node>>,
+    grid: &Node,
+    tracks: &[Units],
+    axis: Axis,
+) -> Vec<Units> {
+    if !tracks.iter().any(|track| *track == Units::Auto) {
+        return tracks.to_vec();
+    }
+
+    let children = layout_children(store, grid);
+    tracks
+        .iter()
+        .enumerate()
+        .map(|(track, units)| match units {
+            Units::Auto => {
+                let size = children
+                    .iter()
+                    .filter(|child| {
+                        let (index, span) = match axis {
+                            Axis::Horizontal => (&child.styles.col_index, &child.styles.col_span),
+                            Axis::Vertical => (&child.styles.row_index, &child.styles.row_span),
+                        };
+                        resolve_or(index, 0) == track && resolve_or(span, 1) <= 1
+                    })
+                    .map(|child| content_size(store, child, axis))
+                    .fold(0.0, f32::max);
+                Units::Pixels(size)
+            }
+            units => *units,
+        })
+        .collect()
+}
+
+/// Measures the size of a node along the given axis before layout
+///
+/// A size in pixels is used as is. Otherwise, the node is as large as its children (side by side
+/// along its layout direction, or the largest of them across it) and its padding in pixels.
+fn content_size(store: &Arena<Option<Node>>, node: &Node, axis: Axis) -> f32 {
+    let (size, padding_start, padding_end) = match axis {
+        Axis::Horizontal => (
+            &node.styles.width,
+            &node.styles.padding_left,
+            &node.styles.padding_right,
+        ),
+        Axis::Vertical => (
+            &node.styles.height,
+            &node.styles.padding_top,
+            &node.styles.padding_bottom,
+        ),
+    };
+    if let StyleProp::Value(Units::Pixels(size)) = size {
+        return *size;
+    }
+
+    let pixels = |units: &StyleProp<Units>| match units {
+        StyleProp::Value(Units::Pixels(pixels)) => *pixels,
+        _ => 0.0,
+    };
+    let is_stacked = matches!(
+        (
+            resolve_or(&node.styles.layout_type, LayoutType::default()),
+            axis
+        ),
+        (LayoutType::Row, Axis::Horizontal) | (LayoutType::Column, Axis::Vertical)
+    );
+    let children = layout_children(store, node)
+        .into_iter()
+        .map(|child| content_size(store, child, axis));
+    let content = if is_stacked {
+        children.sum()
+    } else {
+        children.fold(0.0, f32::max)
+    };
+
+    content + pixels(padding_start) + pixels(padding_end)
+}
+
+/// Gets the children of a node that are laid out within it, skipping over the ones that aren't
+/// rendered (like the widget manager does when building the node tree)
+fn layout_children<'a>(store: &'a Arena<Option<Node>>, node: &Node) -> Vec<&'a Node> {
+    let mut children = Vec::new();
+    for child in node.children.iter() {
+        if let Some(Some(child)) = store.get(*child) {
+            if child.styles.render_command.resolve() != RenderCommand::Empty {
+                children.push(child);
+            } else {
+                children.extend(layout_children(store, child));
+            }
+        }
+    }
+    children
+}
+
+fn resolve_or<T: Default + Clone>(prop: &StyleProp<T>, default: T) -> T {
+    match prop {
+        StyleProp::Value(value) => value.clone(),
+        _ => default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_auto_tracks, Axis, NodeBuilder};
+    use crate::render_command::RenderCommand;
+    use crate::styles::{Style, StyleProp, Units};
+    use crate::Arena;
+
+    #[test]
+    fn auto_tracks_should_fit_their_children() {
+        let mut store = Arena::new();
+        let mut insert = |styles: Style, children| {
+            let id = store.insert(None);
+            store[id] = Some(NodeBuilder::new(id, styles).with_children(children).build());
+            id
+        };
+        let layout = StyleProp::Value(RenderCommand::Layout);
+        let text = insert(
+            Style {
+                render_command: layout.clone(),
+                width: StyleProp::Value(Units::Pixels(40.0)),
+                ..Style::default()
+            },
+            vec![],
+        );
+        let first = insert(
+            Style {
+                render_command: layout.clone(),
+                padding_left: StyleProp::Value(Units::Pixels(5.0)),
+                ..Style::default()
+            },
+            vec![text],
+        );
+        // Spanning several columns, so it doesn't count
+        let spanning = insert(
+            Style {
+                render_command: layout.clone(),
+                width: StyleProp::Value(Units::Pixels(500.0)),
+                col_span: StyleProp::Value(2),
+                ..Style::default()
+            },
+            vec![],
+        );
+        let second = insert(
+            Style {
+                render_command: layout.clone(),
+                width: StyleProp::Value(Units::Pixels(30.0)),
+                col_index: StyleProp::Value(1),
+                ..Style::default()
+            },
+            vec![],
+        );
+        let grid = insert(Style::default(), vec![first, spanning, second]);
+
+        let grid = store[grid].clone().unwrap();
+        let tracks = [Units::Auto, Units::Auto, Units::Stretch(1.0)];
+        assert_eq!(
+            vec![
+                Units::Pixels(45.0),
+                Units::Pixels(30.0),
+                Units::Stretch(1.0)
+            ],
+            resolve_auto_tracks(&store, &grid, &tracks, Axis::Horizontal)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{bind, Binding, Bound, MutableBound};
+
+/// How long notifications are shown by default (in seconds)
+const DEFAULT_DURATION: f32 = 4.0;
+
+/// The kind of a [Notification], mainly affecting how it's displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Default for NotificationKind {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+/// A transient message (or "toast") shown by the `NotificationArea` widget
+///
+/// Push one with [KayakContext::push_notification](crate::KayakContext::push_notification).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// The ID given to this notification when it was pushed
+    pub id: u64,
+    pub message: String,
+    pub kind: NotificationKind,
+    /// How long this notification is shown (in seconds). If `None`, it stays until dismissed.
+    pub duration: Option<f32>,
+    /// How long this notification has been shown (in seconds)
+    pub elapsed: f32,
+}
+
+impl Notification {
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            id: 0,
+            message: message.into(),
+            kind: NotificationKind::default(),
+            duration: Some(DEFAULT_DURATION),
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn with_kind(mut self, kind: NotificationKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set how long the notification is shown (in seconds), or `None` to keep it until dismissed
+    pub fn with_duration(mut self, duration: Option<f32>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The portion of this notification's duration left (from 1 to 0), or `None` if it doesn't expire
+    pub fn remaining(&self) -> Option<f32> {
+        self.duration.map(|duration| {
+            if duration > 0.0 {
+                (1.0 - self.elapsed / duration).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        })
+    }
+
+    fn is_expired(&self) -> bool {
+        self.duration
+            .map_or(false, |duration| self.elapsed >= duration)
+    }
+}
+
+impl Default for Notification {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl From<&str> for Notification {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for Notification {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// The notifications currently shown, oldest first
+///
+/// These are stored in a [Binding] so that widgets can bind to it and re-render when notifications
+/// are pushed or dismissed.
+#[derive(Debug, Clone)]
+pub(crate) struct NotificationQueue {
+    notifications: Binding<Vec<Notification>>,
+    next_id: u64,
+}
+
+impl Default for NotificationQueue {
+    fn default() -> Self {
+        Self {
+            notifications: bind(Vec::new()),
+            next_id: 1,
+        }
+    }
+}
+
+impl NotificationQueue {
+    pub fn notifications(&self) -> Binding<Vec<Notification>> {
+        self.notifications.clone()
+    }
+
+    pub fn push(&mut self, mut notification: Notification) -> u64 {
+        notification.id = self.next_id;
+        notification.elapsed = 0.0;
+        self.next_id += 1;
+
+        let id = notification.id;
+        let mut notifications = self.notifications.get();
+        notifications.push(notification);
+        self.notifications.set(notifications);
+        id
+    }
+
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let mut notifications = self.notifications.get();
+        let count = notifications.len();
+        notifications.retain(|notification| notification.id != id);
+        if notifications.len() == count {
+            return false;
+        }
+
+        self.notifications.set(notifications);
+        true
+    }
+
+    pub fn clear(&mut self) {
+        if !self.notifications.get().is_empty() {
+            self.notifications.set(Vec::new());
+        }
+    }
+
+    /// Advance the notifications by the given time (in seconds), dismissing the expired ones
+    pub fn advance(&mut self, delta: f32) {
+        let mut notifications = self.notifications.get();
+        if notifications
+            .iter()
+            .all(|notification| notification.duration.is_none())
+        {
+            return;
+        }
+
+        for notification in notifications.iter_mut() {
+            notification.elapsed += delta;
+        }
+        notifications.retain(|notification| !notification.is_expired());
+        self.notifications.set(notifications);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifications::{Notification, NotificationQueue};
+    use crate::Bound;
+
+    #[test]
+    fn should_expire_after_duration() {
+        let mut queue = NotificationQueue::default();
+        queue.push(Notification::new("Saved").with_duration(Some(1.0)));
+        let sticky = queue.push(Notification::new("Disconnected").with_duration(None));
+
+        queue.advance(0.6);
+        assert_eq!(2, queue.notifications().get().len());
+        assert_eq!(
+            Some(0.4),
+            queue.notifications().get()[0]
+                .remaining()
+                .map(|remaining| (remaining * 10.0).round() / 10.0)
+        );
+
+        queue.advance(0.6);
+        let notifications = queue.notifications().get();
+        assert_eq!(1, notifications.len());
+        assert_eq!(sticky, notifications[0].id);
+    }
+
+    #[test]
+    fn should_dismiss_by_id() {
+        let mut queue = NotificationQueue::default();
+        let first = queue.push("First".into());
+        let second = queue.push("Second".into());
+        assert_ne!(first, second);
+
+        assert!(queue.dismiss(first));
+        assert!(!queue.dismiss(first));
+        let notifications = queue.notifications().get();
+        assert_eq!(1, notifications.len());
+        assert_eq!(second, notifications[0].id);
+    }
+}
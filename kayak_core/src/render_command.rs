@@ -1,3 +1,5 @@
+use crate::canvas::CanvasShape;
+use crate::color::Color;
 use crate::layout_cache::Space;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -7,6 +9,45 @@ pub enum RenderCommand {
     Layout,
     Clip,
     Quad,
+    /// A quad of which only a pie slice is drawn, sweeping clockwise from the top
+    ///
+    /// `fill` is the portion of the quad covered by the slice, from 0 (nothing) to 1 (everything).
+    RadialFill {
+        fill: f32,
+    },
+    /// A pie slice of the circle inscribed in the quad, sweeping clockwise from `start`
+    ///
+    /// `start` and `sweep` are portions of a full turn, clockwise from the top (so a sector with a
+    /// `sweep` of 1 is a full circle).
+    Sector {
+        start: f32,
+        sweep: f32,
+    },
+    /// A straight line between two points, drawn with the `color` style
+    ///
+    /// The points are relative to the top-left corner of the node's layout (in pixels), and may
+    /// be outside of it.
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+        thickness: f32,
+    },
+    /// A text caret, drawn with the `color` style
+    ///
+    /// `position` is the top of the caret, relative to the top-left corner of the node's layout
+    /// (in pixels).
+    Caret {
+        position: (f32, f32),
+        height: f32,
+        thickness: f32,
+    },
+    /// Highlighted ranges of text (e.g. a selection), drawn with the `background_color` style
+    ///
+    /// Each rect is `(x, y, width, height)`, relative to the top-left corner of the node's layout
+    /// (in pixels), so a selection spanning several lines is a single node.
+    Highlight {
+        rects: Vec<(f32, f32, f32, f32)>,
+    },
     Text {
         content: String,
         font: String,
@@ -14,15 +55,70 @@ pub enum RenderCommand {
         parent_size: (f32, f32),
         size: f32,
     },
+    /// Several runs of differently styled text, each drawn on a single line at its own position
+    ///
+    /// The runs are laid out (and wrapped) beforehand, so they can share a paragraph. Runs without
+    /// a color are drawn with the `color` style.
+    RichText {
+        runs: Vec<TextRun>,
+    },
+    /// Shapes drawn through a [Painter](crate::canvas::Painter), relative to the node's layout
+    Canvas {
+        shapes: Vec<CanvasShape>,
+    },
     Image {
         handle: u16,
     },
+    /// A region of an image, fitted within the node's layout
+    ///
+    /// `uv_min` and `uv_max` are the top-left and bottom-right corners of the region, relative to
+    /// the size of the image (so `(0.0, 0.0)` and `(1.0, 1.0)` cover all of it). The image's size is
+    /// looked up whenever it's drawn, so the image behind `handle` can change (e.g. every frame of
+    /// a video) without re-rendering the widget.
+    Texture {
+        handle: u16,
+        uv_min: (f32, f32),
+        uv_max: (f32, f32),
+        fit: ImageFit,
+    },
     NinePatch {
         border: Space,
         handle: u16,
     },
 }
 
+/// A run of text with a single style, within a [RenderCommand::RichText]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextRun {
+    pub content: String,
+    pub font: String,
+    pub size: f32,
+    pub color: Option<Color>,
+    /// The top-left corner of the run, relative to the top-left corner of the node's layout (in pixels)
+    pub position: (f32, f32),
+    /// Whether the run is emboldened (by thickening the glyphs of the font)
+    pub bold: bool,
+    /// Whether a line is drawn under the run
+    pub underline: bool,
+}
+
+/// How a [RenderCommand::Texture] is fitted within its node's layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Stretch the image to cover the layout, ignoring its aspect ratio
+    Fill,
+    /// Scale the image to fit within the layout, keeping its aspect ratio (leaving empty bars)
+    Contain,
+    /// Scale the image to cover the layout, keeping its aspect ratio (cropping the overflow)
+    Cover,
+}
+
+impl Default for ImageFit {
+    fn default() -> Self {
+        Self::Fill
+    }
+}
+
 impl Default for RenderCommand {
     fn default() -> Self {
         Self::Empty
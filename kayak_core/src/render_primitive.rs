@@ -1,7 +1,8 @@
 use crate::{
+    canvas::CanvasShape,
     color::Color,
     layout_cache::{Rect, Space},
-    render_command::RenderCommand,
+    render_command::{ImageFit, RenderCommand, TextRun},
     styles::{Style, StyleProp},
 };
 
@@ -16,6 +17,37 @@ pub enum RenderPrimitive {
         background_color: Color,
         border_radius: (f32, f32, f32, f32),
     },
+    RadialFill {
+        layout: Rect,
+        background_color: Color,
+        border_radius: (f32, f32, f32, f32),
+        fill: f32,
+    },
+    Sector {
+        layout: Rect,
+        background_color: Color,
+        start: f32,
+        sweep: f32,
+    },
+    Line {
+        layout: Rect,
+        color: Color,
+        from: (f32, f32),
+        to: (f32, f32),
+        thickness: f32,
+    },
+    Caret {
+        layout: Rect,
+        color: Color,
+        position: (f32, f32),
+        height: f32,
+        thickness: f32,
+    },
+    Highlight {
+        layout: Rect,
+        background_color: Color,
+        rects: Vec<(f32, f32, f32, f32)>,
+    },
     Text {
         color: Color,
         content: String,
@@ -25,10 +57,28 @@ pub enum RenderPrimitive {
         parent_size: (f32, f32),
         size: f32,
     },
+    /// Rich text, of which every run has a color
+    RichText {
+        layout: Rect,
+        runs: Vec<TextRun>,
+    },
+    /// Shapes drawn on a canvas, which are drawn with the other primitives (see
+    /// [CanvasShape::to_primitive])
+    Canvas {
+        layout: Rect,
+        shapes: Vec<CanvasShape>,
+    },
     Image {
         layout: Rect,
         handle: u16,
     },
+    Texture {
+        layout: Rect,
+        handle: u16,
+        uv_min: (f32, f32),
+        uv_max: (f32, f32),
+        fit: ImageFit,
+    },
     NinePatch {
         border: Space,
         layout: Rect,
@@ -41,8 +91,16 @@ impl RenderPrimitive {
         match self {
             RenderPrimitive::Clip { layout, .. } => *layout = new_layout,
             RenderPrimitive::Quad { layout, .. } => *layout = new_layout,
+            RenderPrimitive::RadialFill { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Sector { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Line { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Caret { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Highlight { layout, .. } => *layout = new_layout,
             RenderPrimitive::Text { layout, .. } => *layout = new_layout,
+            RenderPrimitive::RichText { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Canvas { layout, .. } => *layout = new_layout,
             RenderPrimitive::Image { layout, .. } => *layout = new_layout,
+            RenderPrimitive::Texture { layout, .. } => *layout = new_layout,
             RenderPrimitive::NinePatch { layout, .. } => *layout = new_layout,
             _ => (),
         }
@@ -70,6 +128,45 @@ impl From<&Style> for RenderPrimitive {
                 border_radius: style.border_radius.resolve(),
                 layout: Rect::default(),
             },
+            RenderCommand::RadialFill { fill } => Self::RadialFill {
+                background_color: background_color,
+                border_radius: style.border_radius.resolve(),
+                fill: fill.clamp(0.0, 1.0),
+                layout: Rect::default(),
+            },
+            RenderCommand::Sector { start, sweep } => Self::Sector {
+                background_color: background_color,
+                start: start.rem_euclid(1.0),
+                sweep: sweep.clamp(0.0, 1.0),
+                layout: Rect::default(),
+            },
+            RenderCommand::Line {
+                from,
+                to,
+                thickness,
+            } => Self::Line {
+                color: style.color.resolve(),
+                from,
+                to,
+                layout: Rect::default(),
+                thickness: thickness.max(0.0),
+            },
+            RenderCommand::Caret {
+                position,
+                height,
+                thickness,
+            } => Self::Caret {
+                color: style.color.resolve(),
+                position,
+                height: height.max(0.0),
+                layout: Rect::default(),
+                thickness: thickness.max(0.0),
+            },
+            RenderCommand::Highlight { rects } => Self::Highlight {
+                background_color: background_color,
+                rects,
+                layout: Rect::default(),
+            },
             RenderCommand::Text {
                 content,
                 font,
@@ -85,10 +182,39 @@ impl From<&Style> for RenderPrimitive {
                 parent_size,
                 size,
             },
+            RenderCommand::RichText { runs } => {
+                let color = style.color.resolve();
+                Self::RichText {
+                    layout: Rect::default(),
+                    runs: runs
+                        .into_iter()
+                        .map(|run| TextRun {
+                            color: Some(run.color.unwrap_or(color)),
+                            ..run
+                        })
+                        .collect(),
+                }
+            }
+            RenderCommand::Canvas { shapes } => Self::Canvas {
+                layout: Rect::default(),
+                shapes,
+            },
             RenderCommand::Image { handle } => Self::Image {
                 layout: Rect::default(),
                 handle,
             },
+            RenderCommand::Texture {
+                handle,
+                uv_min,
+                uv_max,
+                fit,
+            } => Self::Texture {
+                layout: Rect::default(),
+                handle,
+                uv_min,
+                uv_max,
+                fit,
+            },
             RenderCommand::NinePatch { handle, border } => Self::NinePatch {
                 border,
                 layout: Rect::default(),
@@ -0,0 +1,118 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{Binding, Bound, KayakContext, MutableBound};
+
+/// A handler called when back navigation is requested while its screen is on top of the stack
+#[derive(Clone)]
+pub struct OnBack(pub Arc<RwLock<dyn FnMut(&mut KayakContext) + Send + Sync + 'static>>);
+
+impl OnBack {
+    pub fn new<F: FnMut(&mut KayakContext) + Send + Sync + 'static>(f: F) -> OnBack {
+        OnBack(Arc::new(RwLock::new(f)))
+    }
+}
+
+impl PartialEq for OnBack {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for OnBack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("OnBack").finish()
+    }
+}
+
+/// A stack of screens used to implement consistent back/cancel navigation
+///
+/// Screens are identified by name. The stack itself is stored in a [Binding] so that widgets
+/// can bind to it and re-render when screens are pushed or popped. Back handlers belong to an
+/// entry of the stack rather than to a name, so a screen pushed twice has a handler per entry.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ScreenStack {
+    screens: Binding<Vec<String>>,
+    /// The back handler of each entry of the stack (if any), from bottom to top
+    back_handlers: Vec<Option<OnBack>>,
+    /// The back handler registered while no screen was pushed
+    root_back_handler: Option<OnBack>,
+}
+
+impl ScreenStack {
+    pub fn screens(&self) -> Binding<Vec<String>> {
+        self.screens.clone()
+    }
+
+    pub fn current(&self) -> Option<String> {
+        self.screens.get().last().cloned()
+    }
+
+    pub fn push(&mut self, screen: String) {
+        let mut screens = self.screens.get();
+        screens.push(screen);
+        self.back_handlers.resize(screens.len() - 1, None);
+        self.back_handlers.push(None);
+        self.screens.set(screens);
+    }
+
+    pub fn pop(&mut self) -> Option<String> {
+        let mut screens = self.screens.get();
+        let popped = screens.pop();
+        if popped.is_some() {
+            // Remove the handler of the popped entry
+            self.back_handlers.truncate(screens.len());
+            self.screens.set(screens);
+        }
+        popped
+    }
+
+    pub fn set_back_handler(&mut self, handler: OnBack) {
+        let depth = self.screens.get().len();
+        if depth == 0 {
+            self.root_back_handler = Some(handler);
+        } else {
+            self.back_handlers.resize(depth, None);
+            self.back_handlers[depth - 1] = Some(handler);
+        }
+    }
+
+    pub fn get_back_handler(&self) -> Option<OnBack> {
+        match self.screens.get().len() {
+            0 => self.root_back_handler.clone(),
+            depth => self.back_handlers.get(depth - 1).cloned().flatten(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::OnBack;
+    use crate::KayakContext;
+
+    #[test]
+    fn back_handlers_should_belong_to_their_stack_entry() {
+        let mut context = KayakContext::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        context.push_screen("settings");
+        let cloned_calls = calls.clone();
+        context.on_back(OnBack::new(move |context| {
+            cloned_calls.fetch_add(1, Ordering::SeqCst);
+            // Requesting back navigation again pops the screen instead of deadlocking
+            assert!(context.navigate_back());
+        }));
+        context.push_screen("settings");
+
+        // The top entry has no handler of its own, so it's popped
+        assert!(context.navigate_back());
+        assert_eq!(0, calls.load(Ordering::SeqCst));
+        assert_eq!(Some(String::from("settings")), context.current_screen());
+
+        assert!(context.navigate_back());
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        assert_eq!(None, context.current_screen());
+    }
+}
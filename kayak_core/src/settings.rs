@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::{bind, Binding, Bound, MutableBound};
+
+/// The persisted settings of an app (e.g. its volume, resolution, or key bindings)
+///
+/// Settings are strings stored by key, and typed values are converted with [ToString] and
+/// [FromStr]. They're saved in a plain text format, with one `key=value` pair per line, so the
+/// file can be edited by hand. Lines starting with `#` are ignored.
+///
+/// This is a cheap handle: clones share the same settings. Get the context's settings with
+/// [KayakContext::settings](crate::KayakContext::settings) and bind to [Settings::binding] to
+/// re-render whenever they change.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    values: Binding<BTreeMap<String, String>>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            values: bind(BTreeMap::new()),
+        }
+    }
+}
+
+impl Settings {
+    /// Get the binding for all of the settings
+    pub fn binding(&self) -> Binding<BTreeMap<String, String>> {
+        self.values.clone()
+    }
+
+    /// Get the setting with the given key
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.values.get().get(key.trim()).cloned()
+    }
+
+    /// Get the setting with the given key, parsed as `T`
+    ///
+    /// Returns `None` if there's no such setting or if it can't be parsed.
+    pub fn get_as<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.values
+            .get()
+            .get(key.trim())
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Set the setting with the given key
+    ///
+    /// Leading or trailing whitespace is removed from the key. Keys can't be empty, start with `#`,
+    /// or contain `=` or line breaks, as they couldn't be loaded back: the setting is ignored
+    /// instead.
+    pub fn set<V: ToString>(&self, key: &str, value: V) {
+        let key = key.trim();
+        if !is_valid_key(key) {
+            eprintln!(
+                "Warning: ignored the setting {:?}, as keys can't be empty, start with `#`, or contain `=` or line breaks",
+                key
+            );
+            return;
+        }
+
+        let value = value.to_string();
+        let mut values = self.values.get();
+        if values.get(key) != Some(&value) {
+            values.insert(key.to_string(), value);
+            self.values.set(values);
+        }
+    }
+
+    /// Remove the setting with the given key, returning its value if it was set
+    pub fn remove(&self, key: &str) -> Option<String> {
+        let mut values = self.values.get();
+        let removed = values.remove(key.trim());
+        if removed.is_some() {
+            self.values.set(values);
+        }
+        removed
+    }
+
+    /// Add the settings saved in the given text, replacing those with the same keys
+    pub fn load_str(&self, contents: &str) {
+        let mut values = self.values.get();
+        for line in contents.lines() {
+            let line = line.trim_start();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                values.insert(key.trim().to_string(), unescape(value));
+            }
+        }
+        self.values.set(values);
+    }
+
+    /// Save the settings as text, which can be loaded back with [Settings::load_str]
+    pub fn save_str(&self) -> String {
+        self.values
+            .get()
+            .iter()
+            .map(|(key, value)| format!("{}={}\n", key, escape(value)))
+            .collect()
+    }
+
+    /// Add the settings saved in the given file, replacing those with the same keys
+    pub fn load_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let contents = std::fs::read_to_string(path)?;
+        self.load_str(&contents);
+        Ok(())
+    }
+
+    /// Save the settings to the given file, replacing it if it exists
+    pub fn save_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.save_str())
+    }
+}
+
+/// Checks if the given (trimmed) key can be saved and loaded back
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && !key.starts_with('#') && !key.contains(|c| matches!(c, '=' | '\n' | '\r'))
+}
+
+/// Escape the backslashes and line breaks of a value, so it fits on a single line
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+
+    #[test]
+    fn settings_round_trip() {
+        let settings = Settings::default();
+        settings.set("audio.volume", 0.5);
+        settings.set("player.name", "Line one\nC:\\Games");
+
+        let loaded = Settings::default();
+        loaded.load_str(&settings.save_str());
+        assert_eq!(loaded.get_as::<f32>("audio.volume"), Some(0.5));
+        assert_eq!(
+            loaded.get("player.name").as_deref(),
+            Some("Line one\nC:\\Games")
+        );
+    }
+
+    #[test]
+    fn load_skips_comments_and_keeps_other_keys() {
+        let settings = Settings::default();
+        settings.set("video.fullscreen", true);
+        settings.load_str("# Audio\naudio.muted=true\n\nnot a setting\n");
+
+        assert_eq!(settings.get_as::<bool>("video.fullscreen"), Some(true));
+        assert_eq!(settings.get_as::<bool>("audio.muted"), Some(true));
+        assert_eq!(settings.get("not a setting"), None);
+        assert_eq!(settings.remove("audio.muted").as_deref(), Some("true"));
+        assert_eq!(settings.get("audio.muted"), None);
+    }
+
+    #[test]
+    fn keys_should_be_trimmed_and_rejected_if_they_cant_be_loaded() {
+        let settings = Settings::default();
+        settings.set(" audio.volume ", 0.5);
+        assert_eq!(settings.get("audio.volume").as_deref(), Some("0.5"));
+        assert_eq!(settings.remove(" audio.volume ").as_deref(), Some("0.5"));
+        assert_eq!(settings.get("audio.volume"), None);
+
+        for key in ["a=b", "a\nb", "a\rb", "# comment", "  "] {
+            settings.set(key, "value");
+        }
+        assert!(settings.binding().get().is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! Text snapshots of the widget tree, to verify how a UI renders without comparing pixels
+//!
+//! A snapshot lists every widget (indented by depth) with its name, its label (if any), its
+//! render command, and its layout, one widget per line:
+//!
+//! ```text
+//! App
+//!   Background [Quad] @ 0,0 1280x720
+//!     Text [Text "Play" 24] @ 10,10 32.5x24
+//! ```
+//!
+//! The ids of widgets are left out, so snapshots stay the same across refactors that keep what is
+//! rendered the same. Snapshots can be checked against stored files with
+//! [assert_tree_snapshot](crate::assert_tree_snapshot).
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::layout_cache::Rect;
+use crate::render_command::RenderCommand;
+use crate::widget_manager::WidgetManager;
+use crate::{Index, WidgetLabel};
+
+/// The environment variable that makes [assert_tree_snapshot](crate::assert_tree_snapshot)
+/// overwrite stored snapshots instead of comparing them
+pub const UPDATE_SNAPSHOTS_VAR: &str = "KAYAK_UPDATE_SNAPSHOTS";
+
+/// What to include in a snapshot of the widget tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotOptions {
+    /// Include the render command of each widget (e.g. `[Text "Play" 24]`)
+    pub render_commands: bool,
+    /// Include the layout of each widget (e.g. `@ 0,0 100x50`)
+    pub layout: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            render_commands: true,
+            layout: true,
+        }
+    }
+}
+
+/// Renders the widget tree (or the subtree starting at `root`) to a stable text format
+///
+/// See the [module docs](self) for the format.
+pub fn snapshot_tree(
+    widget_manager: &WidgetManager,
+    root: Option<Index>,
+    options: &SnapshotOptions,
+) -> String {
+    let mut snapshot = String::new();
+    if let Some(root) = root.or(widget_manager.tree.root_node) {
+        write_widget(widget_manager, root, 0, options, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Checks the given snapshot against the one stored at `path`, panicking with both if they differ
+///
+/// The snapshot is stored instead if there's no file at `path` yet, or if the
+/// [UPDATE_SNAPSHOTS_VAR] environment variable is set. Prefer the
+/// [assert_tree_snapshot](crate::assert_tree_snapshot) macro, which resolves `path` relative to the
+/// crate being tested.
+pub fn assert_snapshot(snapshot: &str, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some();
+    if update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap_or_else(|err| {
+                panic!("Failed to create snapshot directory {:?}: {}", parent, err)
+            });
+        }
+        std::fs::write(path, snapshot)
+            .unwrap_or_else(|err| panic!("Failed to write snapshot {:?}: {}", path, err));
+        return;
+    }
+
+    let stored = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Failed to read snapshot {:?}: {}", path, err));
+    // Line endings may have been converted by version control
+    if stored.replace("\r\n", "\n") != snapshot {
+        panic!(
+            "Widget tree doesn't match snapshot {:?} (set {} to update it)\n\n--- stored ---\n{}\n--- actual ---\n{}",
+            path, UPDATE_SNAPSHOTS_VAR, stored, snapshot
+        );
+    }
+}
+
+/// Asserts that the widget tree of a [KayakContext](crate::KayakContext) matches a stored
+/// snapshot
+///
+/// The path of the snapshot is relative to the root of the crate being tested. If it doesn't
+/// exist yet (or the `KAYAK_UPDATE_SNAPSHOTS` environment variable is set), the snapshot is
+/// stored instead. Options can be given as a third argument (see
+/// [SnapshotOptions](crate::snapshot::SnapshotOptions)).
+///
+/// # Examples
+///
+/// ```ignore
+/// context.render();
+/// assert_tree_snapshot!(context, "tests/snapshots/main_menu.txt");
+/// ```
+#[macro_export]
+macro_rules! assert_tree_snapshot {
+    ($context:expr, $path:expr) => {
+        $crate::assert_tree_snapshot!(
+            $context,
+            $path,
+            $crate::snapshot::SnapshotOptions::default()
+        )
+    };
+    ($context:expr, $path:expr, $options:expr) => {
+        $crate::snapshot::assert_snapshot(
+            &$crate::snapshot::snapshot_tree(&$context.widget_manager, None, &$options),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+fn write_widget(
+    widget_manager: &WidgetManager,
+    index: Index,
+    depth: usize,
+    options: &SnapshotOptions,
+    snapshot: &mut String,
+) {
+    let name = widget_manager
+        .current_widgets
+        .get(index)
+        .and_then(|widget| widget.as_ref())
+        .map_or_else(|| String::from("?"), |widget| widget.get_name());
+    let _ = write!(snapshot, "{}{}", "  ".repeat(depth), name);
+
+    if let Some(WidgetLabel(label)) = widget_manager.get_metadata::<WidgetLabel>(index) {
+        let _ = write!(snapshot, " #{}", label);
+    }
+
+    if options.render_commands {
+        if let Some(Some(node)) = widget_manager.nodes.get(index) {
+            if let Some(command) = describe_command(&node.styles.render_command.resolve()) {
+                let _ = write!(snapshot, " [{}]", command);
+            }
+        }
+    }
+
+    if options.layout && widget_manager.node_tree.contains(index) {
+        if let Some(layout) = widget_manager.get_layout(&index) {
+            let _ = write!(snapshot, " @ {}", describe_layout(layout));
+        }
+    }
+    snapshot.push('\n');
+
+    if let Some(children) = widget_manager.tree.children.get(&index) {
+        for child in children {
+            write_widget(widget_manager, *child, depth + 1, options, snapshot);
+        }
+    }
+}
+
+/// Summarizes a render command, leaving out the commands that don't draw anything
+fn describe_command(command: &RenderCommand) -> Option<String> {
+    let description = match command {
+        RenderCommand::Empty | RenderCommand::Layout => return None,
+        RenderCommand::Clip => String::from("Clip"),
+        RenderCommand::Quad => String::from("Quad"),
+        RenderCommand::RadialFill { fill } => format!("RadialFill {}", round(*fill)),
+        RenderCommand::Sector { start, sweep } => {
+            format!("Sector {} {}", round(*start), round(*sweep))
+        }
+        RenderCommand::Line { .. } => String::from("Line"),
+        RenderCommand::Caret { .. } => String::from("Caret"),
+        RenderCommand::Highlight { rects } => format!("Highlight {}", rects.len()),
+        RenderCommand::Text { content, size, .. } => {
+            format!("Text {:?} {}", content, round(*size))
+        }
+        RenderCommand::RichText { runs } => {
+            let content = runs
+                .iter()
+                .map(|run| run.content.as_str())
+                .collect::<String>();
+            format!("RichText {:?}", content)
+        }
+        RenderCommand::Canvas { shapes } => format!("Canvas {}", shapes.len()),
+        RenderCommand::Image { handle } => format!("Image {}", handle),
+        RenderCommand::Texture { handle, .. } => format!("Texture {}", handle),
+        RenderCommand::NinePatch { handle, .. } => format!("NinePatch {}", handle),
+    };
+    Some(description)
+}
+
+fn describe_layout(layout: &Rect) -> String {
+    format!(
+        "{},{} {}x{}",
+        round(layout.posx),
+        round(layout.posy),
+        round(layout.width),
+        round(layout.height)
+    )
+}
+
+/// Rounds to one decimal, so tiny layout differences don't change the snapshot
+fn round(value: f32) -> f32 {
+    let value = (value * 10.0).round() / 10.0;
+    // Avoid printing "-0"
+    if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snapshot_tree, SnapshotOptions};
+    use crate::layout_cache::Rect;
+    use crate::node::Node;
+    use crate::render_command::RenderCommand;
+    use crate::styles::{Style, StyleProp};
+    use crate::widget_manager::WidgetManager;
+    use crate::{Fragment, WidgetLabel};
+
+    #[test]
+    fn tree_should_render_to_text() {
+        let mut widget_manager = WidgetManager::new();
+        let (_, root) = widget_manager.create_widget(0, Fragment::default(), None);
+        let (_, child) = widget_manager.create_widget(0, Fragment::default(), Some(root));
+        widget_manager.set_metadata(child, WidgetLabel(String::from("title")));
+
+        widget_manager.node_tree.add(child, None);
+        widget_manager.nodes[child] = Some(Node {
+            children: Vec::new(),
+            id: child,
+            styles: Style {
+                render_command: StyleProp::Value(RenderCommand::Text {
+                    content: String::from("Play"),
+                    font: String::from("Roboto"),
+                    line_height: 18.0,
+                    parent_size: (100.0, 100.0),
+                    size: 14.0,
+                }),
+                ..Style::default()
+            },
+            z: 0.0,
+        });
+        widget_manager.layout_cache.rect.insert(
+            child,
+            Rect {
+                posx: 10.04,
+                posy: -0.01,
+                width: 32.5,
+                height: 18.0,
+                z_index: 0.0,
+            },
+        );
+
+        assert_eq!(
+            "Fragment\n  Fragment #title [Text \"Play\" 14] @ 10,0 32.5x18\n",
+            snapshot_tree(&widget_manager, None, &SnapshotOptions::default())
+        );
+        assert_eq!(
+            "Fragment\n  Fragment #title\n",
+            snapshot_tree(
+                &widget_manager,
+                None,
+                &SnapshotOptions {
+                    render_commands: false,
+                    layout: false,
+                }
+            )
+        );
+    }
+}
@@ -1,6 +1,9 @@
 pub use morphorm::{LayoutType, PositionType, Units};
 
 use crate::cursor::PointerEvents;
+use crate::hit_test::HitTest;
+use crate::theme::{FromThemeToken, Theme, ThemeToken};
+use crate::throttle::EventThrottle;
 use crate::{color::Color, render_command::RenderCommand};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -8,6 +11,8 @@ pub enum StyleProp<T: Default + Clone> {
     Default,
     Inherit,
     Value(T),
+    /// A value of the active theme, resolved along with the other styles of the widget
+    Theme(ThemeToken),
 }
 
 impl<T> Default for StyleProp<T>
@@ -28,8 +33,186 @@ where
             StyleProp::Default => T::default(),
             StyleProp::Value(value) => value.clone(),
             StyleProp::Inherit => panic!("All styles should be merged before resolving!"),
+            StyleProp::Theme(..) => panic!("Theme tokens should be resolved before resolving!"),
         }
     }
+
+    /// Resolves the value, looking theme tokens up in the given theme, or gets the given default
+    /// if it isn't set (or the token doesn't apply to this kind of value)
+    ///
+    /// Unlike [resolve](Self::resolve), this never panics, so widgets can read the styles they
+    /// were given while rendering, before those are merged and their tokens resolved.
+    pub fn resolve_or(&self, theme: &Theme, default: T) -> T
+    where
+        T: FromThemeToken,
+    {
+        match self {
+            StyleProp::Value(value) => value.clone(),
+            StyleProp::Theme(token) => T::from_theme_token(*token, theme).unwrap_or(default),
+            StyleProp::Default | StyleProp::Inherit => default,
+        }
+    }
+
+    /// Replaces a theme token with its value in the given theme, or with the default if the
+    /// token doesn't apply to this kind of value
+    fn resolve_token<F: Fn(ThemeToken, &Theme) -> Option<T>>(
+        &mut self,
+        theme: &Theme,
+        from_token: F,
+    ) {
+        if let StyleProp::Theme(token) = self {
+            *self = match from_token(*token, theme) {
+                Some(value) => StyleProp::Value(value),
+                None => StyleProp::Default,
+            };
+        }
+    }
+}
+
+/// The priority of a layer of styles (see [StyleLayers]), from lowest to highest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StylePriority {
+    /// The defaults of a widget
+    Default,
+    /// Styles derived from the active theme
+    Theme,
+    /// Styles shared by many widgets (e.g. a framework's look for all buttons)
+    Stylesheet,
+    /// The styles passed to a widget in its `styles` prop
+    Inline,
+    /// Styles that win over all others, such as those set with
+    /// [KayakContext::set_style_override](crate::KayakContext::set_style_override)
+    Override,
+}
+
+/// Styles made of layers with explicit priorities, so that the precedence of layered styles (e.g.
+/// widget defaults, then a theme, then the user's props) is predictable
+///
+/// Each property resolves to its value in the highest priority layer that sets it (i.e. where it
+/// differs from [Style::default]). Among layers of the same priority, the last one added wins.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_core::styles::{Style, StyleLayers, StylePriority, StyleProp, Units};
+/// # use kayak_core::Color;
+/// let defaults = Style {
+///     width: StyleProp::Value(Units::Pixels(100.0)),
+///     background_color: StyleProp::Value(Color::new(0.2, 0.2, 0.2, 1.0)),
+///     ..Style::default()
+/// };
+/// let props = Style {
+///     width: StyleProp::Value(Units::Stretch(1.0)),
+///     ..Style::default()
+/// };
+///
+/// let layers = StyleLayers::new()
+///     .with(StylePriority::Inline, props)
+///     .with(StylePriority::Default, defaults);
+/// assert_eq!(StyleProp::Value(Units::Stretch(1.0)), layers.resolve().width);
+/// assert_eq!(Some(StylePriority::Default), layers.source("background_color"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleLayers {
+    layers: Vec<(StylePriority, Style)>,
+}
+
+impl StyleLayers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer of styles with the given priority
+    pub fn with(mut self, priority: StylePriority, styles: Style) -> Self {
+        self.add(priority, styles);
+        self
+    }
+
+    /// Add a layer of styles with the given priority
+    pub fn add(&mut self, priority: StylePriority, styles: Style) {
+        self.layers.push((priority, styles));
+    }
+
+    /// Add a layer of styles with the given priority, if any (e.g. for a `styles` prop)
+    pub fn with_optional(self, priority: StylePriority, styles: Option<Style>) -> Self {
+        match styles {
+            Some(styles) => self.with(priority, styles),
+            None => self,
+        }
+    }
+
+    /// Get the layers from lowest to highest priority, in the order they apply
+    fn sorted(&self) -> Vec<&(StylePriority, Style)> {
+        let mut layers = self.layers.iter().collect::<Vec<_>>();
+        // The sort is stable, so layers of the same priority keep the order they were added in
+        layers.sort_by_key(|(priority, _)| *priority);
+        layers
+    }
+
+    /// Resolves the layers into a single set of styles
+    pub fn resolve(&self) -> Style {
+        let mut styles = Style::default();
+        for (_, layer) in self.sorted() {
+            styles.apply(layer);
+        }
+        styles
+    }
+
+    /// Get the priority of the layer the given property resolves from (by field name, such as
+    /// `"width"`), or `None` if no layer sets it
+    pub fn source(&self, property: &str) -> Option<StylePriority> {
+        self.sorted()
+            .into_iter()
+            .rev()
+            .find(|(_, layer)| layer.set_properties().contains(&property))
+            .map(|(priority, _)| *priority)
+    }
+}
+
+/// How a widget with the `Row` layout type wraps its children onto new rows when they don't fit
+/// its width
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Wrap {
+    /// The space between rows (in pixels)
+    pub row_spacing: f32,
+    /// The space between the children of a row (in pixels)
+    pub column_spacing: f32,
+    /// How the children of each row are aligned horizontally
+    pub alignment: WrapAlignment,
+}
+
+/// The horizontal alignment of the rows of a wrapping widget (see [Wrap])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapAlignment {
+    Start,
+    Center,
+    End,
+}
+
+impl Default for WrapAlignment {
+    fn default() -> Self {
+        Self::Start
+    }
+}
+
+/// The edge of its parent a widget is docked to
+///
+/// A parent with docked children places each of them in turn along the given edge of the space
+/// left by the previous ones, and its last child fills the space left by all the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dock {
+    Left,
+    Top,
+    Right,
+    Bottom,
+    /// Fills the space left by the previous children (the last child always does)
+    Fill,
+}
+
+impl Default for Dock {
+    fn default() -> Self {
+        Self::Left
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -58,7 +241,41 @@ pub struct Style {
     pub min_height: StyleProp<Units>,
     pub max_width: StyleProp<Units>,
     pub max_height: StyleProp<Units>,
+    /// The space between the children of a widget with the `Column` or `Grid` layout type
+    pub row_between: StyleProp<Units>,
+    /// The space between the children of a widget with the `Row` or `Grid` layout type
+    pub col_between: StyleProp<Units>,
     pub pointer_events: StyleProp<PointerEvents>,
+    /// The edge of its parent this widget is docked to (not docked by default)
+    ///
+    /// Once one child of a widget is docked, the others are docked to the left unless set.
+    pub dock: StyleProp<Dock>,
+    /// The shape within the layout that the cursor hits (the whole layout by default)
+    pub hit_test: StyleProp<HitTest>,
+    /// How often this widget receives `Hover`, `MouseMove`, and `Scroll` events (every time by
+    /// default)
+    pub event_throttle: StyleProp<EventThrottle>,
+    /// An offset added to the z-index of this widget and all of its descendants
+    pub z_index: StyleProp<f32>,
+    /// The sizes of the rows of a grid (with the `Grid` layout type)
+    ///
+    /// `Auto` rows are as tall as the tallest child placed only in them.
+    pub grid_rows: StyleProp<Vec<Units>>,
+    /// The sizes of the columns of a grid (with the `Grid` layout type)
+    ///
+    /// `Auto` columns are as wide as the widest child placed only in them.
+    pub grid_cols: StyleProp<Vec<Units>>,
+    /// The row of its parent grid this widget starts in (zero by default)
+    pub row_index: StyleProp<usize>,
+    /// The column of its parent grid this widget starts in (zero by default)
+    pub col_index: StyleProp<usize>,
+    /// The number of rows of its parent grid this widget spans (one by default)
+    pub row_span: StyleProp<usize>,
+    /// The number of columns of its parent grid this widget spans (one by default)
+    pub col_span: StyleProp<usize>,
+    /// Wraps the children onto new rows, for widgets with the `Row` layout type (no wrapping by
+    /// default)
+    pub wrap: StyleProp<Wrap>,
 }
 
 impl Default for Style {
@@ -88,7 +305,20 @@ impl Default for Style {
             min_height: StyleProp::Default,
             max_width: StyleProp::Default,
             max_height: StyleProp::Default,
+            row_between: StyleProp::Default,
+            col_between: StyleProp::Default,
             pointer_events: StyleProp::Default,
+            dock: StyleProp::Default,
+            hit_test: StyleProp::Default,
+            event_throttle: StyleProp::Default,
+            z_index: StyleProp::Default,
+            grid_rows: StyleProp::Default,
+            grid_cols: StyleProp::Default,
+            row_index: StyleProp::Default,
+            col_index: StyleProp::Default,
+            row_span: StyleProp::Default,
+            col_span: StyleProp::Default,
+            wrap: StyleProp::Default,
         }
     }
 }
@@ -199,5 +429,247 @@ impl Style {
             StyleProp::Inherit => self.margin_bottom = other.margin_bottom.clone(),
             _ => (),
         }
+        match self.z_index {
+            StyleProp::Inherit => self.z_index = other.z_index.clone(),
+            _ => (),
+        }
+        match self.grid_rows {
+            StyleProp::Inherit => self.grid_rows = other.grid_rows.clone(),
+            _ => (),
+        }
+        match self.grid_cols {
+            StyleProp::Inherit => self.grid_cols = other.grid_cols.clone(),
+            _ => (),
+        }
+        match self.row_index {
+            StyleProp::Inherit => self.row_index = other.row_index.clone(),
+            _ => (),
+        }
+        match self.col_index {
+            StyleProp::Inherit => self.col_index = other.col_index.clone(),
+            _ => (),
+        }
+        match self.row_span {
+            StyleProp::Inherit => self.row_span = other.row_span.clone(),
+            _ => (),
+        }
+        match self.col_span {
+            StyleProp::Inherit => self.col_span = other.col_span.clone(),
+            _ => (),
+        }
+        match self.wrap {
+            StyleProp::Inherit => self.wrap = other.wrap.clone(),
+            _ => (),
+        }
+        match self.dock {
+            StyleProp::Inherit => self.dock = other.dock.clone(),
+            _ => (),
+        }
+        match self.hit_test {
+            StyleProp::Inherit => self.hit_test = other.hit_test.clone(),
+            _ => (),
+        }
+        match self.event_throttle {
+            StyleProp::Inherit => self.event_throttle = other.event_throttle.clone(),
+            _ => (),
+        }
+        match self.row_between {
+            StyleProp::Inherit => self.row_between = other.row_between.clone(),
+            _ => (),
+        }
+        match self.col_between {
+            StyleProp::Inherit => self.col_between = other.col_between.clone(),
+            _ => (),
+        }
+    }
+
+    /// Overlays the properties set in the given styles (those that differ from
+    /// [Style::default]) on these styles
+    pub fn apply(&mut self, other: &Self) {
+        let default = Style::default();
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(
+                    if other.$field != default.$field {
+                        self.$field = other.$field.clone();
+                    }
+                )*
+            };
+        }
+        apply!(
+            background_color,
+            border_radius,
+            bottom,
+            color,
+            height,
+            layout_type,
+            left,
+            position_type,
+            render_command,
+            right,
+            top,
+            width,
+            padding_left,
+            padding_right,
+            padding_top,
+            padding_bottom,
+            margin_left,
+            margin_right,
+            margin_top,
+            margin_bottom,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            row_between,
+            col_between,
+            pointer_events,
+            dock,
+            hit_test,
+            event_throttle,
+            z_index,
+            grid_rows,
+            grid_cols,
+            row_index,
+            col_index,
+            row_span,
+            col_span,
+            wrap
+        );
+    }
+
+    /// Get the names of the properties set in these styles (those that differ from
+    /// [Style::default])
+    pub fn set_properties(&self) -> Vec<&'static str> {
+        let default = Style::default();
+        let mut properties = Vec::new();
+        macro_rules! check {
+            ($($field:ident),*) => {
+                $(
+                    if self.$field != default.$field {
+                        properties.push(stringify!($field));
+                    }
+                )*
+            };
+        }
+        check!(
+            background_color,
+            border_radius,
+            bottom,
+            color,
+            height,
+            layout_type,
+            left,
+            position_type,
+            render_command,
+            right,
+            top,
+            width,
+            padding_left,
+            padding_right,
+            padding_top,
+            padding_bottom,
+            margin_left,
+            margin_right,
+            margin_top,
+            margin_bottom,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            row_between,
+            col_between,
+            pointer_events,
+            dock,
+            hit_test,
+            event_throttle,
+            z_index,
+            grid_rows,
+            grid_cols,
+            row_index,
+            col_index,
+            row_span,
+            col_span,
+            wrap
+        );
+        properties
+    }
+
+    /// Replaces the theme tokens in these styles with their values in the given theme
+    ///
+    /// Colors can use [ThemeColor](crate::ThemeColor) tokens and sizes can use
+    /// [Spacing](crate::Spacing) tokens. Other tokens fall back to the default value.
+    pub fn resolve_theme(&mut self, theme: &Theme) {
+        let color = Color::from_theme_token;
+        let units = Units::from_theme_token;
+        self.background_color.resolve_token(theme, color);
+        self.border_radius.resolve_token(theme, |_, _| None);
+        self.bottom.resolve_token(theme, units);
+        self.color.resolve_token(theme, color);
+        self.height.resolve_token(theme, units);
+        self.layout_type.resolve_token(theme, |_, _| None);
+        self.left.resolve_token(theme, units);
+        self.position_type.resolve_token(theme, |_, _| None);
+        self.render_command.resolve_token(theme, |_, _| None);
+        self.right.resolve_token(theme, units);
+        self.top.resolve_token(theme, units);
+        self.width.resolve_token(theme, units);
+        self.padding_left.resolve_token(theme, units);
+        self.padding_right.resolve_token(theme, units);
+        self.padding_top.resolve_token(theme, units);
+        self.padding_bottom.resolve_token(theme, units);
+        self.margin_left.resolve_token(theme, units);
+        self.margin_right.resolve_token(theme, units);
+        self.margin_top.resolve_token(theme, units);
+        self.margin_bottom.resolve_token(theme, units);
+        self.min_width.resolve_token(theme, units);
+        self.min_height.resolve_token(theme, units);
+        self.max_width.resolve_token(theme, units);
+        self.max_height.resolve_token(theme, units);
+        self.row_between.resolve_token(theme, units);
+        self.col_between.resolve_token(theme, units);
+        self.pointer_events.resolve_token(theme, |_, _| None);
+        self.dock.resolve_token(theme, |_, _| None);
+        self.hit_test.resolve_token(theme, |_, _| None);
+        self.event_throttle.resolve_token(theme, |_, _| None);
+        self.z_index.resolve_token(theme, |_, _| None);
+        self.grid_rows.resolve_token(theme, |_, _| None);
+        self.grid_cols.resolve_token(theme, |_, _| None);
+        self.row_index.resolve_token(theme, |_, _| None);
+        self.col_index.resolve_token(theme, |_, _| None);
+        self.row_span.resolve_token(theme, |_, _| None);
+        self.col_span.resolve_token(theme, |_, _| None);
+        self.wrap.resolve_token(theme, |_, _| None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Style, StyleLayers, StylePriority, StyleProp, Units};
+
+    #[test]
+    fn higher_priority_layers_should_win() {
+        let width = |width| Style {
+            width: StyleProp::Value(Units::Pixels(width)),
+            ..Style::default()
+        };
+        let layers = StyleLayers::new()
+            .with(StylePriority::Override, width(30.0))
+            .with(StylePriority::Inline, width(20.0))
+            .with(StylePriority::Theme, width(10.0))
+            .with(
+                StylePriority::Theme,
+                Style {
+                    height: StyleProp::Value(Units::Pixels(5.0)),
+                    ..Style::default()
+                },
+            );
+
+        let styles = layers.resolve();
+        assert_eq!(StyleProp::Value(Units::Pixels(30.0)), styles.width);
+        assert_eq!(StyleProp::Value(Units::Pixels(5.0)), styles.height);
+        assert_eq!(Some(StylePriority::Override), layers.source("width"));
+        assert_eq!(Some(StylePriority::Theme), layers.source("height"));
+        assert_eq!(None, layers.source("top"));
     }
 }
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::{EventType, Index};
+
+/// A user interaction with a widget, recorded for telemetry (see
+/// [KayakContext::set_interaction_sink](crate::KayakContext::set_interaction_sink))
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interaction {
+    /// The widget interacted with
+    pub widget: Index,
+    /// The name of the widget's type (e.g. `"Button"`)
+    pub widget_name: String,
+    /// The label of the widget or of its closest labeled ancestor (see
+    /// [KayakContext::set_label](crate::KayakContext::set_label)), which usually names the menu
+    /// or button better than the widget itself
+    pub label: Option<String>,
+    pub event_type: EventType,
+    /// When the interaction happened (as seen by the context's clock)
+    pub timestamp: Instant,
+    /// The screen on top of the navigation stack at the time
+    pub screen: Option<String>,
+}
+
+impl Interaction {
+    /// Checks if the given event type is recorded as an interaction
+    ///
+    /// Only deliberate interactions are recorded: clicks (of any button), context menus, focus
+    /// changes, and drops. Hovering and scrolling happen too often to be useful.
+    pub fn is_recorded(event_type: &EventType) -> bool {
+        matches!(
+            event_type,
+            EventType::Click
+                | EventType::DoubleClick
+                | EventType::AuxClick(..)
+                | EventType::ContextMenu
+                | EventType::Focus
+                | EventType::Drop
+        )
+    }
+}
+
+/// Receives the [Interactions](Interaction) recorded by a context
+#[derive(Clone)]
+pub struct InteractionSink(pub Arc<dyn Fn(&Interaction) + Send + Sync>);
+
+impl InteractionSink {
+    pub fn new<F: Fn(&Interaction) + Send + Sync + 'static>(f: F) -> InteractionSink {
+        InteractionSink(Arc::new(f))
+    }
+
+    pub fn record(&self, interaction: &Interaction) {
+        (self.0)(interaction)
+    }
+}
+
+impl std::fmt::Debug for InteractionSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InteractionSink").finish()
+    }
+}
@@ -0,0 +1,174 @@
+//! Theme tokens, which style widgets with the values of the active [Theme]
+//!
+//! A token names a value of the theme (like its primary color or its medium spacing) instead of
+//! giving the value itself. Tokens can be used directly in the fields of a
+//! [Style](crate::styles::Style):
+//!
+//! ```
+//! # use kayak_core::styles::Style;
+//! # use kayak_core::{Spacing, ThemeColor};
+//! let styles = Style {
+//!     background_color: ThemeColor::Primary.into(),
+//!     padding_left: Spacing::Md.into(),
+//!     ..Style::default()
+//! };
+//! ```
+//!
+//! The tokens are resolved when the styles of the widgets are resolved, so switching themes with
+//! [KayakContext::set_theme](crate::KayakContext::set_theme) restyles the widgets using them
+//! without re-rendering them.
+
+use crate::color::Color;
+use crate::styles::{StyleProp, Units};
+
+/// A value of the theme, used in place of a style value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThemeToken {
+    Color(ThemeColor),
+    Spacing(Spacing),
+}
+
+/// A color of the theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeColor {
+    /// The main color of interactive widgets (e.g. buttons)
+    Primary,
+    /// The color of less prominent interactive widgets
+    Secondary,
+    /// The color behind everything else
+    Background,
+    /// The color of panels and other containers drawn over the background
+    Surface,
+    /// The color of text
+    Text,
+    /// The color of secondary text (e.g. hints)
+    TextMuted,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A spacing of the theme (for padding, margins, and offsets), from the smallest to the largest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spacing {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// The values that [ThemeToken]s resolve to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub primary: Color,
+    pub secondary: Color,
+    pub background: Color,
+    pub surface: Color,
+    pub text: Color,
+    pub text_muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// The spacings (in pixels), from [Spacing::Xs] to [Spacing::Xl]
+    pub spacing: [f32; 5],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: Color::new(0.0781, 0.0898, 0.101, 1.0),
+            secondary: Color::new(0.176, 0.196, 0.215, 1.0),
+            background: Color::new(0.0588, 0.0667, 0.0745, 1.0),
+            surface: Color::new(0.125, 0.144, 0.168, 1.0),
+            text: Color::WHITE,
+            text_muted: Color::new(0.6, 0.6, 0.6, 1.0),
+            success: Color::new(0.286, 0.714, 0.369, 1.0),
+            warning: Color::new(0.957, 0.706, 0.204, 1.0),
+            error: Color::new(0.863, 0.267, 0.267, 1.0),
+            spacing: [4.0, 8.0, 16.0, 24.0, 32.0],
+        }
+    }
+}
+
+impl Theme {
+    /// Get the color of the given token
+    pub fn color(&self, color: ThemeColor) -> Color {
+        match color {
+            ThemeColor::Primary => self.primary,
+            ThemeColor::Secondary => self.secondary,
+            ThemeColor::Background => self.background,
+            ThemeColor::Surface => self.surface,
+            ThemeColor::Text => self.text,
+            ThemeColor::TextMuted => self.text_muted,
+            ThemeColor::Success => self.success,
+            ThemeColor::Warning => self.warning,
+            ThemeColor::Error => self.error,
+        }
+    }
+
+    /// Get the spacing of the given token (in pixels)
+    pub fn spacing(&self, spacing: Spacing) -> f32 {
+        self.spacing[spacing as usize]
+    }
+}
+
+/// A style value that can be given by a [ThemeToken]
+pub trait FromThemeToken: Sized {
+    /// Get the value of the given token in the theme, or `None` if the token doesn't apply to
+    /// this kind of value (e.g. a spacing used as a color)
+    fn from_theme_token(token: ThemeToken, theme: &Theme) -> Option<Self>;
+}
+
+impl FromThemeToken for Color {
+    fn from_theme_token(token: ThemeToken, theme: &Theme) -> Option<Self> {
+        match token {
+            ThemeToken::Color(color) => Some(theme.color(color)),
+            _ => None,
+        }
+    }
+}
+
+impl FromThemeToken for Units {
+    fn from_theme_token(token: ThemeToken, theme: &Theme) -> Option<Self> {
+        match token {
+            ThemeToken::Spacing(spacing) => Some(Units::Pixels(theme.spacing(spacing))),
+            _ => None,
+        }
+    }
+}
+
+impl From<ThemeColor> for StyleProp<Color> {
+    fn from(color: ThemeColor) -> Self {
+        StyleProp::Theme(ThemeToken::Color(color))
+    }
+}
+
+impl From<Spacing> for StyleProp<Units> {
+    fn from(spacing: Spacing) -> Self {
+        StyleProp::Theme(ThemeToken::Spacing(spacing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Spacing, Theme, ThemeColor};
+    use crate::styles::{Style, StyleProp, Units};
+
+    #[test]
+    fn tokens_should_resolve_against_the_theme() {
+        let mut styles = Style {
+            background_color: ThemeColor::Primary.into(),
+            padding_left: Spacing::Md.into(),
+            ..Style::default()
+        };
+        let theme = Theme {
+            spacing: [1.0, 2.0, 3.0, 4.0, 5.0],
+            ..Theme::default()
+        };
+        styles.resolve_theme(&theme);
+
+        assert_eq!(StyleProp::Value(theme.primary), styles.background_color);
+        assert_eq!(StyleProp::Value(Units::Pixels(3.0)), styles.padding_left);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::mem::Discriminant;
+use std::time::{Duration, Instant};
+
+use crate::{Event, EventType, Index, ScrollEvent};
+
+/// How often a widget receives the high-frequency pointer events (`Hover`, `MouseMove`, and
+/// `Scroll`), set with the `event_throttle` style
+///
+/// This keeps expensive handlers (e.g. tooltips querying game data) from running hundreds of
+/// times per second. Withheld events aren't lost: the latest `Hover` or `MouseMove` is received
+/// once allowed, and the deltas of withheld `Scroll` events are added to the next one received.
+/// Throttling only applies to the widget with the style, so events it withholds still propagate
+/// to its ancestors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventThrottle {
+    /// Every event is received
+    None,
+    /// At most one event of each type is received per frame (i.e. per batch of input events)
+    PerFrame,
+    /// At most one event of each type is received per the given interval
+    Interval(Duration),
+}
+
+impl Default for EventThrottle {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+type ThrottleKey = (Index, Discriminant<EventType>);
+
+/// Tracks the events received by throttled widgets, and the ones withheld from them
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EventThrottler {
+    frame: u64,
+    /// When each widget last received each type of throttled event, and in which frame
+    last_received: HashMap<ThrottleKey, (Instant, u64)>,
+    /// The latest event of each type withheld from each widget
+    withheld: HashMap<ThrottleKey, Event>,
+}
+
+impl EventThrottler {
+    /// Checks if the given event type can be throttled
+    pub fn is_throttled(event_type: &EventType) -> bool {
+        matches!(
+            event_type,
+            EventType::Hover | EventType::MouseMove | EventType::Scroll(..)
+        )
+    }
+
+    /// Starts a new frame, allowing the widgets throttled per frame to receive events again
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Get the event the given widget should receive in place of the given one, or `None` if it's
+    /// withheld for now
+    pub fn throttle(
+        &mut self,
+        index: Index,
+        throttle: EventThrottle,
+        event: Event,
+        now: Instant,
+    ) -> Option<Event> {
+        if throttle == EventThrottle::None || !Self::is_throttled(&event.event_type) {
+            return Some(event);
+        }
+
+        let key = (index, std::mem::discriminant(&event.event_type));
+        let event = match (self.withheld.remove(&key), event.event_type) {
+            (Some(withheld), EventType::Scroll(scroll)) => match withheld.event_type {
+                EventType::Scroll(withheld_scroll) => Event {
+                    event_type: EventType::Scroll(Self::add_scrolls(withheld_scroll, scroll)),
+                    ..event
+                },
+                _ => event,
+            },
+            _ => event,
+        };
+
+        if self.is_allowed(&key, throttle, now) {
+            self.last_received.insert(key, (now, self.frame));
+            Some(event)
+        } else {
+            self.withheld.insert(key, event);
+            None
+        }
+    }
+
+    /// Takes the withheld events that can now be received, given the throttle of each widget
+    pub fn take_due<F: Fn(Index) -> EventThrottle>(
+        &mut self,
+        get_throttle: F,
+        now: Instant,
+    ) -> Vec<(Index, Event)> {
+        let due = self
+            .withheld
+            .keys()
+            .filter(|key| self.is_allowed(key, get_throttle(key.0), now))
+            .copied()
+            .collect::<Vec<_>>();
+        due.into_iter()
+            .filter_map(|key| self.withheld.remove(&key).map(|event| (key.0, event)))
+            .collect()
+    }
+
+    fn is_allowed(&self, key: &ThrottleKey, throttle: EventThrottle, now: Instant) -> bool {
+        match (throttle, self.last_received.get(key)) {
+            (EventThrottle::PerFrame, Some((_, frame))) => *frame != self.frame,
+            (EventThrottle::Interval(interval), Some((time, _))) => {
+                now.saturating_duration_since(*time) >= interval
+            }
+            _ => true,
+        }
+    }
+
+    fn add_scrolls(first: ScrollEvent, second: ScrollEvent) -> ScrollEvent {
+        if first.unit == second.unit {
+            ScrollEvent {
+                delta_x: first.delta_x + second.delta_x,
+                delta_y: first.delta_y + second.delta_y,
+                unit: first.unit,
+            }
+        } else {
+            let (first_x, first_y) = first.pixel_delta();
+            let (second_x, second_y) = second.pixel_delta();
+            ScrollEvent {
+                delta_x: first_x + second_x,
+                delta_y: first_y + second_y,
+                unit: crate::ScrollUnit::Pixel,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{EventThrottle, EventThrottler};
+    use crate::{Event, EventType, Index, ScrollEvent, ScrollUnit};
+
+    #[test]
+    fn throttled_widgets_should_receive_the_withheld_events_later() {
+        let mut throttler = EventThrottler::default();
+        let widget = Index::from_raw_parts(0, 0);
+        let throttle = EventThrottle::Interval(Duration::from_millis(100));
+        let start = Instant::now();
+        let scroll = |delta_y| {
+            Event::new(
+                widget,
+                EventType::Scroll(ScrollEvent {
+                    delta_x: 0.0,
+                    delta_y,
+                    unit: ScrollUnit::Line,
+                }),
+            )
+        };
+
+        assert!(throttler
+            .throttle(widget, throttle, scroll(1.0), start)
+            .is_some());
+        assert!(throttler
+            .throttle(widget, throttle, scroll(2.0), start)
+            .is_none());
+        assert!(throttler
+            .take_due(|_| throttle, start + Duration::from_millis(50))
+            .is_empty());
+
+        // The withheld scrolls add up
+        let allowed = throttler.throttle(
+            widget,
+            throttle,
+            scroll(3.0),
+            start + Duration::from_millis(100),
+        );
+        assert_eq!(Some(scroll(5.0)), allowed);
+    }
+}
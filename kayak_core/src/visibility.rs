@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::layout_cache::Rect;
+use crate::render_command::RenderCommand;
+use crate::widget_manager::WidgetManager;
+use crate::{Event, EventType, Index};
+
+/// Tracks whether watched widgets are within view, to send them
+/// [EnterView](EventType::EnterView) and [ExitView](EventType::ExitView) events
+///
+/// A widget is within view if any part of its layout lies within the window and within the clip
+/// regions of its ancestors (e.g. the visible area of a scroll box).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VisibilityTracker {
+    /// The watched widgets, mapped to whether they were within view after the last update (or
+    /// `None` if they haven't been updated yet)
+    watched: HashMap<Index, Option<bool>>,
+}
+
+impl VisibilityTracker {
+    pub fn watch(&mut self, index: Index) {
+        self.watched.entry(index).or_insert(None);
+    }
+
+    pub fn unwatch(&mut self, index: Index) {
+        self.watched.remove(&index);
+    }
+
+    /// Returns whether the given widget was within view after the last update (or `None` if it
+    /// isn't watched or hasn't been laid out yet)
+    pub fn is_in_view(&self, index: Index) -> Option<bool> {
+        self.watched.get(&index).copied().flatten()
+    }
+
+    /// Checks the watched widgets against the current layout, returning the events for those
+    /// that entered or left the view
+    ///
+    /// Widgets are only sent `ExitView` once they've been within view. Widgets that were removed
+    /// from the tree stop being watched.
+    pub fn update(&mut self, widget_manager: &WidgetManager) -> Vec<Event> {
+        self.watched
+            .retain(|index, _| widget_manager.tree.contains(*index));
+
+        let viewport = widget_manager
+            .node_tree
+            .root_node
+            .and_then(|root| widget_manager.get_layout(&root))
+            .copied();
+
+        let mut events = Vec::new();
+        for (index, was_in_view) in self.watched.iter_mut() {
+            let is_in_view = Self::is_within_view(*index, viewport.as_ref(), widget_manager);
+            match (*was_in_view, is_in_view) {
+                (None | Some(false), true) => events.push(Event::new(*index, EventType::EnterView)),
+                (Some(true), false) => events.push(Event::new(*index, EventType::ExitView)),
+                _ => {}
+            }
+            *was_in_view = Some(is_in_view);
+        }
+        events
+    }
+
+    fn is_within_view(
+        index: Index,
+        viewport: Option<&Rect>,
+        widget_manager: &WidgetManager,
+    ) -> bool {
+        let mut visible = match widget_manager.find_layout(index) {
+            Some(layout) => layout,
+            None => return false,
+        };
+        if visible.width <= 0.0 || visible.height <= 0.0 {
+            return false;
+        }
+
+        if let Some(viewport) = viewport {
+            if !viewport.intersects(&visible) {
+                return false;
+            }
+            visible = visible.intersection(viewport);
+        }
+
+        let mut current = index;
+        while let Some(parent) = widget_manager.tree.get_parent(current) {
+            if let Some(clip) = Self::get_clip_layout(parent, widget_manager) {
+                if !clip.intersects(&visible) {
+                    return false;
+                }
+                visible = visible.intersection(&clip);
+            }
+            current = parent;
+        }
+
+        true
+    }
+
+    /// Gets the layout of the given widget if it clips its children
+    fn get_clip_layout(index: Index, widget_manager: &WidgetManager) -> Option<Rect> {
+        let node = widget_manager.nodes.get(index)?.as_ref()?;
+        if matches!(node.styles.render_command.resolve(), RenderCommand::Clip) {
+            widget_manager.get_layout(&index).copied()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisibilityTracker;
+    use crate::layout_cache::Rect;
+    use crate::widget_manager::WidgetManager;
+    use crate::{EventType, Index};
+
+    fn rect(posx: f32, posy: f32) -> Rect {
+        Rect {
+            posx,
+            posy,
+            width: 50.0,
+            height: 50.0,
+            z_index: 0.0,
+        }
+    }
+
+    #[test]
+    fn widgets_should_enter_and_exit_the_view() {
+        let mut widget_manager = WidgetManager::new();
+        let root = Index::from_raw_parts(0, 0);
+        let child = Index::from_raw_parts(1, 0);
+        for tree in [&mut widget_manager.tree, &mut widget_manager.node_tree] {
+            tree.add(root, None);
+            tree.add(child, Some(root));
+        }
+        widget_manager.layout_cache.rect.insert(
+            root,
+            Rect {
+                width: 100.0,
+                height: 100.0,
+                ..rect(0.0, 0.0)
+            },
+        );
+        widget_manager
+            .layout_cache
+            .rect
+            .insert(child, rect(200.0, 0.0));
+
+        let mut tracker = VisibilityTracker::default();
+        tracker.watch(child);
+        assert!(tracker.update(&widget_manager).is_empty());
+        assert_eq!(Some(false), tracker.is_in_view(child));
+
+        widget_manager
+            .layout_cache
+            .rect
+            .insert(child, rect(75.0, 0.0));
+        let events = tracker.update(&widget_manager);
+        assert_eq!(1, events.len());
+        assert_eq!(EventType::EnterView, events[0].event_type);
+        assert!(tracker.update(&widget_manager).is_empty());
+
+        widget_manager
+            .layout_cache
+            .rect
+            .insert(child, rect(0.0, -60.0));
+        let events = tracker.update(&widget_manager);
+        assert_eq!(1, events.len());
+        assert_eq!(EventType::ExitView, events[0].event_type);
+    }
+}
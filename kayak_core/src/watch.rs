@@ -0,0 +1,80 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::flo_binding::Uuid;
+use crate::{Binding, Bound, KayakContext};
+
+/// A binding shown with its live value in the `Inspector` (see
+/// [KayakContext::watch_binding](crate::KayakContext::watch_binding))
+#[derive(Clone)]
+pub struct WatchedBinding {
+    label: String,
+    id: Uuid,
+    value: Arc<dyn Fn() -> String + Send + Sync>,
+    bind: Arc<dyn Fn(&mut KayakContext) + Send + Sync>,
+}
+
+impl WatchedBinding {
+    pub fn new<T: Clone + PartialEq + Debug + Send + Sync + 'static>(
+        label: impl Into<String>,
+        binding: &Binding<T>,
+    ) -> Self {
+        let value_binding = binding.clone();
+        let bind_binding = binding.clone();
+        Self {
+            label: label.into(),
+            id: binding.id,
+            value: Arc::new(move || format!("{:?}", value_binding.get())),
+            bind: Arc::new(move |context| context.bind(&bind_binding)),
+        }
+    }
+
+    /// Get the label the binding is shown with
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the current value of the binding, formatted with [Debug]
+    pub fn value(&self) -> String {
+        (self.value)()
+    }
+
+    /// Binds the watched binding to the widget currently rendering, so it re-renders whenever the
+    /// value changes
+    pub fn bind(&self, context: &mut KayakContext) {
+        (self.bind)(context)
+    }
+}
+
+impl PartialEq for WatchedBinding {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.id == other.id
+    }
+}
+
+impl Debug for WatchedBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchedBinding")
+            .field("label", &self.label)
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WatchedBinding;
+    use crate::{Binding, MutableBound};
+
+    #[test]
+    fn watched_bindings_should_show_the_live_value() {
+        let binding = Binding::new(3);
+        let watched = WatchedBinding::new("Lives", &binding);
+        assert_eq!("3", watched.value());
+
+        binding.set(2);
+        assert_eq!("2", watched.value());
+        assert_eq!(watched, WatchedBinding::new("Lives", &binding));
+        assert_ne!(watched, WatchedBinding::new("Lives", &Binding::new(2)));
+    }
+}
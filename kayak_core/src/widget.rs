@@ -1,6 +1,6 @@
 use as_any::AsAny;
 
-use crate::{context::KayakContext, styles::Style, Event, Index};
+use crate::{context::KayakContext, styles::Style, Children, Event, Index, OnEvent};
 
 pub trait Widget: std::fmt::Debug + AsAny + Send + Sync {
     /// Returns whether this widget can be focused or not (or unspecified if `None`)
@@ -8,6 +8,9 @@ pub trait Widget: std::fmt::Debug + AsAny + Send + Sync {
     fn get_id(&self) -> Index;
     fn set_id(&mut self, id: Index);
     fn get_styles(&self) -> Option<Style>;
+    fn set_styles(&mut self, styles: Option<Style>);
+    fn set_children(&mut self, children: Children);
+    fn set_on_event(&mut self, on_event: Option<OnEvent>);
     fn get_name(&self) -> String;
     fn on_event(&mut self, context: &mut KayakContext, event: &mut Event);
     fn render(&mut self, context: &mut KayakContext);
@@ -17,3 +20,10 @@ impl as_any::Downcast for dyn Widget {}
 impl as_any::Downcast for dyn Widget + Send {}
 impl as_any::Downcast for dyn Widget + Sync {}
 impl as_any::Downcast for dyn Widget + Send + Sync {}
+
+/// A label used to identify a specific widget
+///
+/// Labels are stored as widget metadata (see [`KayakContext::set_label`]) and can be used to
+/// find widgets from outside the tree, such as in automated tests or tutorials.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WidgetLabel(pub String);
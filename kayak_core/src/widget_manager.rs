@@ -1,5 +1,6 @@
+use derivative::*;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     sync::{Arc, Mutex},
 };
 
@@ -11,13 +12,23 @@ use crate::{
     node::{Node, NodeBuilder},
     render_command::RenderCommand,
     render_primitive::RenderPrimitive,
-    styles::Style,
+    styles::{Dock, PositionType, Style, StyleProp, Units, Wrap, WrapAlignment},
+    theme::Theme,
     tree::Tree,
     Arena, Index, Widget,
 };
 // use as_any::Downcast;
 
-#[derive(Debug)]
+/// The styles overriding those of a widget (see [WidgetManager::set_style_override])
+#[derive(Debug, Clone)]
+struct StyleOverride(Style);
+
+/// The most layouts calculated per frame to dock children (see [Dock]), as each level of nested
+/// dock panels needs the layout of the one above it
+const MAX_DOCK_PASSES: usize = 4;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
 pub struct WidgetManager {
     pub(crate) current_widgets: Arena<Option<Box<dyn Widget>>>,
     pub(crate) dirty_render_nodes: HashSet<Index>,
@@ -32,6 +43,19 @@ pub struct WidgetManager {
     pub layout_cache: LayoutCache,
     focus_tracker: FocusTracker,
     current_z: f32,
+    /// Arbitrary user data attached to widgets, keyed by widget ID.
+    ///
+    /// Unlike props or state, this data is never touched by rendering and so it survives re-renders.
+    #[derivative(Debug = "ignore")]
+    metadata: HashMap<Index, resources::Resources>,
+    /// How far the content of scrolled widgets is scrolled, keyed by widget ID
+    scroll_offsets: HashMap<Index, (f32, f32)>,
+    /// Widgets that currently block interaction with the rest of the UI, with the topmost last
+    modals: Vec<Index>,
+    /// The theme that theme tokens in styles resolve to
+    theme: Theme,
+    /// The height of the rows of each wrapping widget at the last layout
+    wrapped_heights: HashMap<Index, f32>,
 }
 
 impl WidgetManager {
@@ -47,16 +71,29 @@ impl WidgetManager {
             focus_tree: FocusTree::default(),
             focus_tracker: FocusTracker::default(),
             current_z: 0.0,
+            metadata: HashMap::new(),
+            scroll_offsets: HashMap::new(),
+            modals: Vec::new(),
+            theme: Theme::default(),
+            wrapped_heights: HashMap::new(),
         }
     }
 
     /// Re-renders from the root.
     /// If force is true sets ALL nodes to re-render.
     /// Can be slow.
+    ///
+    /// Does nothing if the tree is empty (e.g. when nothing was mounted).
     pub fn dirty(&mut self, force: bool) {
+        let root_node = if let Some(root_node) = self.tree.root_node {
+            root_node
+        } else {
+            return;
+        };
+
         // Force tree to re-render from root.
         if let Ok(mut dirty_nodes) = self.dirty_nodes.lock() {
-            dirty_nodes.insert(self.tree.root_node.unwrap());
+            dirty_nodes.insert(root_node);
 
             if force {
                 for (node_index, _) in self.current_widgets.iter() {
@@ -163,6 +200,41 @@ impl WidgetManager {
         self.layout_cache.rect.get(id)
     }
 
+    /// Get the layout of the given widget, or of its first descendant that has one
+    pub fn find_layout(&self, index: Index) -> Option<Rect> {
+        for node in self.tree.flatten_node(index) {
+            if node != index && !self.tree.is_descendant(node, index) {
+                // Left the subtree
+                break;
+            }
+            if self.node_tree.contains(node) {
+                if let Some(layout) = self.get_layout(&node) {
+                    return Some(*layout);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get the handles of all images currently rendered (by `Image`, `Texture`, or `NinePatch`
+    /// render commands)
+    pub fn get_image_handles(&self) -> HashSet<u16> {
+        self.node_tree
+            .flatten()
+            .into_iter()
+            .filter_map(|index| match self.nodes.get(index) {
+                Some(Some(node)) => match &node.styles.render_command {
+                    StyleProp::Value(RenderCommand::Image { handle })
+                    | StyleProp::Value(RenderCommand::Texture { handle, .. })
+                    | StyleProp::Value(RenderCommand::NinePatch { handle, .. }) => Some(*handle),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn get_name(&self, id: &Index) -> Option<String> {
         if let Some(widget) = &self.current_widgets[*id] {
             return Some(widget.get_name());
@@ -171,6 +243,27 @@ impl WidgetManager {
         None
     }
 
+    /// Get the theme that theme tokens in styles resolve to
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Set the theme that theme tokens in styles resolve to, restyling all widgets (without
+    /// re-rendering them)
+    pub fn set_theme(&mut self, theme: Theme) {
+        if theme == self.theme {
+            return;
+        }
+
+        self.theme = theme;
+        let widgets = self
+            .current_widgets
+            .iter()
+            .filter(|(_, widget)| widget.is_some())
+            .map(|(widget_id, _)| widget_id);
+        self.dirty_render_nodes.extend(widgets);
+    }
+
     pub fn render(&mut self) {
         let default_styles = Style {
             background_color: crate::styles::StyleProp::Default,
@@ -191,16 +284,12 @@ impl WidgetManager {
             width: crate::styles::StyleProp::Default,
             ..Style::default()
         };
-        for dirty_node_index in self.dirty_render_nodes.drain() {
-            let dirty_widget = self.current_widgets[dirty_node_index].as_ref().unwrap();
+        let dirty_render_nodes = self.dirty_render_nodes.drain().collect::<Vec<_>>();
+        for dirty_node_index in dirty_render_nodes {
             let parent_styles =
                 if let Some(parent_widget_id) = self.tree.parents.get(&dirty_node_index) {
-                    if let Some(parent) = self.current_widgets[*parent_widget_id].as_ref() {
-                        if let Some(styles) = parent.get_styles() {
-                            styles
-                        } else {
-                            default_styles.clone()
-                        }
+                    if let Some(styles) = self.get_styles(*parent_widget_id) {
+                        styles
                     } else {
                         default_styles.clone()
                     }
@@ -230,7 +319,7 @@ impl WidgetManager {
                 }
             };
 
-            let mut styles = dirty_widget.get_styles();
+            let mut styles = self.get_styles(dirty_node_index);
             if styles.is_some() {
                 styles.as_mut().unwrap().merge(&parent_styles);
             }
@@ -240,7 +329,8 @@ impl WidgetManager {
                 .get(&dirty_node_index)
                 .cloned()
                 .unwrap_or(vec![]);
-            let styles = styles.unwrap_or(default_styles.clone());
+            let mut styles = styles.unwrap_or(default_styles.clone());
+            styles.resolve_theme(&self.theme);
 
             let mut node = NodeBuilder::empty()
                 .with_id(dirty_node_index)
@@ -257,6 +347,386 @@ impl WidgetManager {
 
     pub fn calculate_layout(&mut self) {
         morphorm::layout(&mut self.layout_cache, &self.node_tree, &self.nodes);
+        self.dock_children();
+        if let Some(root) = self.node_tree.root_node {
+            self.recurse_node_tree_to_wrap(root);
+            Self::recurse_node_tree_to_update_layout(
+                &self.node_tree,
+                &mut self.layout_cache,
+                &self.nodes,
+                &self.scroll_offsets,
+                root,
+                0.0,
+                (0.0, 0.0),
+            );
+        }
+    }
+
+    /// Places the docked children of nodes (see [Dock]) and calculates the layout again
+    ///
+    /// The layout can't dock children itself, so they're placed by position and size within
+    /// their parent once its layout is known. This repeats until the docked layouts settle.
+    fn dock_children(&mut self) {
+        let mut docked = HashMap::new();
+        for _ in 0..MAX_DOCK_PASSES {
+            let next = self.get_docked_layouts();
+            if next.is_empty() || next == docked {
+                break;
+            }
+            docked = next;
+
+            let mut nodes = self.nodes.clone();
+            for (child, rect) in docked.iter() {
+                if let Some(Some(node)) = nodes.get_mut(*child) {
+                    node.styles.position_type = StyleProp::Value(PositionType::SelfDirected);
+                    node.styles.left = StyleProp::Value(Units::Pixels(rect.posx));
+                    node.styles.top = StyleProp::Value(Units::Pixels(rect.posy));
+                    node.styles.width = StyleProp::Value(Units::Pixels(rect.width));
+                    node.styles.height = StyleProp::Value(Units::Pixels(rect.height));
+                    node.styles.right = StyleProp::Default;
+                    node.styles.bottom = StyleProp::Default;
+                }
+            }
+            morphorm::layout(&mut self.layout_cache, &self.node_tree, &nodes);
+        }
+    }
+
+    /// Get the layouts of all docked children from the current layout, relative to their parent
+    fn get_docked_layouts(&self) -> HashMap<Index, Rect> {
+        let pixels = |units: &StyleProp<Units>| match units {
+            StyleProp::Value(Units::Pixels(pixels)) => Some(*pixels),
+            _ => None,
+        };
+
+        let mut docked = HashMap::new();
+        for (panel_id, children) in self.node_tree.children.iter() {
+            let children = children
+                .iter()
+                .filter_map(|child| match self.nodes.get(*child) {
+                    Some(Some(node)) => Some((*child, node)),
+                    _ => None,
+                })
+                .filter(|(_, node)| {
+                    matches!(node.styles.dock, StyleProp::Value(_))
+                        || node.styles.position_type != StyleProp::Value(PositionType::SelfDirected)
+                })
+                .collect::<Vec<_>>();
+            if !children
+                .iter()
+                .any(|(_, node)| matches!(node.styles.dock, StyleProp::Value(_)))
+            {
+                continue;
+            }
+            let (panel, panel_node) = match (
+                self.layout_cache.rect.get(panel_id),
+                self.nodes.get(*panel_id),
+            ) {
+                (Some(panel), Some(Some(panel_node))) => (*panel, panel_node),
+                _ => continue,
+            };
+
+            // The space left for the next child, relative to the panel
+            let padding = |units: &StyleProp<Units>| pixels(units).unwrap_or_default();
+            let padding_left = padding(&panel_node.styles.padding_left);
+            let padding_top = padding(&panel_node.styles.padding_top);
+            let mut space = Rect {
+                posx: padding_left,
+                posy: padding_top,
+                width: (panel.width - padding_left - padding(&panel_node.styles.padding_right))
+                    .max(0.0),
+                height: (panel.height - padding_top - padding(&panel_node.styles.padding_bottom))
+                    .max(0.0),
+                ..Rect::default()
+            };
+
+            for (position, (child, node)) in children.iter().enumerate() {
+                let measured = self
+                    .layout_cache
+                    .rect
+                    .get(child)
+                    .copied()
+                    .unwrap_or_default();
+                let width = pixels(&node.styles.width)
+                    .unwrap_or(measured.width)
+                    .clamp(0.0, space.width);
+                let height = pixels(&node.styles.height)
+                    .unwrap_or(measured.height)
+                    .clamp(0.0, space.height);
+                let dock = match node.styles.dock {
+                    _ if position == children.len() - 1 => Dock::Fill,
+                    StyleProp::Value(dock) => dock,
+                    _ => Dock::Left,
+                };
+
+                let rect = match dock {
+                    Dock::Left => {
+                        let rect = Rect { width, ..space };
+                        space.posx += width;
+                        space.width -= width;
+                        rect
+                    }
+                    Dock::Top => {
+                        let rect = Rect { height, ..space };
+                        space.posy += height;
+                        space.height -= height;
+                        rect
+                    }
+                    Dock::Right => {
+                        space.width -= width;
+                        Rect {
+                            posx: space.posx + space.width,
+                            width,
+                            ..space
+                        }
+                    }
+                    Dock::Bottom => {
+                        space.height -= height;
+                        Rect {
+                            posy: space.posy + space.height,
+                            height,
+                            ..space
+                        }
+                    }
+                    Dock::Fill => space,
+                };
+                docked.insert(*child, rect);
+            }
+        }
+        docked
+    }
+
+    /// Moves the children of wrapping nodes (see [Wrap]) onto new rows where they overflow
+    ///
+    /// The layout places these children in a single row, so each one (along with its descendants)
+    /// is moved from there.
+    fn recurse_node_tree_to_wrap(&mut self, current_node: Index) {
+        let wrap = match self.nodes.get(current_node) {
+            Some(Some(node)) => match node.styles.wrap {
+                StyleProp::Value(wrap) => Some(wrap),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(wrap) = wrap {
+            self.wrap_children(current_node, wrap);
+        }
+
+        let children = self
+            .node_tree
+            .children
+            .get(&current_node)
+            .cloned()
+            .unwrap_or_default();
+        for child in children {
+            self.recurse_node_tree_to_wrap(child);
+        }
+    }
+
+    /// Wraps the children of the given node onto rows fitting its width
+    ///
+    /// The height of the rows is stored so that the wrapping widget can fit them (see
+    /// [get_wrapped_height](Self::get_wrapped_height)), and the widget is re-rendered when it
+    /// changes.
+    fn wrap_children(&mut self, node_id: Index, wrap: Wrap) {
+        let panel = match self.layout_cache.rect.get(&node_id) {
+            Some(panel) => *panel,
+            None => return,
+        };
+        let pixels = |units: &StyleProp<Units>| match units {
+            StyleProp::Value(Units::Pixels(pixels)) => *pixels,
+            _ => 0.0,
+        };
+        let (padding_left, padding_right, padding_top, padding_bottom) =
+            match self.nodes.get(node_id) {
+                Some(Some(node)) => (
+                    pixels(&node.styles.padding_left),
+                    pixels(&node.styles.padding_right),
+                    pixels(&node.styles.padding_top),
+                    pixels(&node.styles.padding_bottom),
+                ),
+                _ => return,
+            };
+        let start = (panel.posx + padding_left, panel.posy + padding_top);
+        let available = panel.width - padding_left - padding_right;
+
+        // === Rows === //
+        let mut rows: Vec<Vec<(Index, Rect)>> = Vec::new();
+        let mut row_width = 0.0;
+        for child in self.node_tree.children.get(&node_id).into_iter().flatten() {
+            if let Some(Some(node)) = self.nodes.get(*child) {
+                if node.styles.position_type == StyleProp::Value(PositionType::SelfDirected) {
+                    continue;
+                }
+            }
+            let rect = match self.layout_cache.rect.get(child) {
+                Some(rect) => *rect,
+                None => continue,
+            };
+
+            match rows.last_mut() {
+                Some(row) if row_width + wrap.column_spacing + rect.width <= available => {
+                    row_width += wrap.column_spacing + rect.width;
+                    row.push((*child, rect));
+                }
+                _ => {
+                    rows.push(vec![(*child, rect)]);
+                    row_width = rect.width;
+                }
+            }
+        }
+
+        // === Placement === //
+        let mut y = start.1;
+        for row in rows.iter() {
+            let row_width = row.iter().map(|(_, rect)| rect.width).sum::<f32>()
+                + wrap.column_spacing * (row.len() - 1) as f32;
+            let row_height = row.iter().map(|(_, rect)| rect.height).fold(0.0, f32::max);
+            let free_space = (available - row_width).max(0.0);
+            let mut x = start.0
+                + match wrap.alignment {
+                    WrapAlignment::Start => 0.0,
+                    WrapAlignment::Center => free_space / 2.0,
+                    WrapAlignment::End => free_space,
+                };
+            for (child, rect) in row {
+                self.translate_subtree(*child, (x - rect.posx, y - rect.posy));
+                x += rect.width + wrap.column_spacing;
+            }
+            y += row_height + wrap.row_spacing;
+        }
+
+        let rows_height = if rows.is_empty() {
+            0.0
+        } else {
+            y - wrap.row_spacing - start.1
+        };
+        let height = rows_height + padding_top + padding_bottom;
+        if self.wrapped_heights.insert(node_id, height) != Some(height) {
+            if let Ok(mut dirty_nodes) = self.dirty_nodes.lock() {
+                dirty_nodes.insert(node_id);
+            }
+        }
+    }
+
+    /// Moves the layout of the given node and its descendants
+    fn translate_subtree(&mut self, node: Index, translation: (f32, f32)) {
+        if translation == (0.0, 0.0) {
+            return;
+        }
+
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            if let Some(layout) = self.layout_cache.rect.get_mut(&node) {
+                layout.posx += translation.0;
+                layout.posy += translation.1;
+            }
+            if let Some(children) = self.node_tree.children.get(&node) {
+                stack.extend(children.iter().copied());
+            }
+        }
+    }
+
+    /// Get the height of the wrapped rows of the given widget (with its vertical padding), as of
+    /// the last layout
+    ///
+    /// This is only set for widgets with the `wrap` style, which can use it to fit their rows.
+    pub fn get_wrapped_height(&self, index: Index) -> Option<f32> {
+        self.wrapped_heights.get(&index).copied()
+    }
+
+    /// Finishes the calculated layout of each node
+    ///
+    /// This stores the z-index of each node in the layout cache, matching the one used when
+    /// building render primitives (so that hit-testing agrees with what is drawn on top), and
+    /// translates each node by the scroll offsets of its ancestors.
+    fn recurse_node_tree_to_update_layout(
+        node_tree: &Tree,
+        layout_cache: &mut LayoutCache,
+        nodes: &Arena<Option<Node>>,
+        scroll_offsets: &HashMap<Index, (f32, f32)>,
+        current_node: Index,
+        main_z_index: f32,
+        translation: (f32, f32),
+    ) {
+        let z_index = if let Some(node) = nodes.get(current_node).unwrap() {
+            main_z_index + Self::get_z_index_offset(node)
+        } else {
+            main_z_index
+        };
+
+        if let Some(layout) = layout_cache.rect.get_mut(&current_node) {
+            layout.z_index = z_index;
+            layout.posx += translation.0;
+            layout.posy += translation.1;
+        }
+
+        let translation = if let Some(offset) = scroll_offsets.get(&current_node) {
+            (translation.0 - offset.0, translation.1 - offset.1)
+        } else {
+            translation
+        };
+
+        if let Some(children) = node_tree.children.get(&current_node) {
+            for child in children {
+                Self::recurse_node_tree_to_update_layout(
+                    node_tree,
+                    layout_cache,
+                    nodes,
+                    scroll_offsets,
+                    *child,
+                    z_index + 1.0,
+                    translation,
+                );
+            }
+        }
+    }
+
+    /// Set how far the given widget's content is scrolled (in pixels)
+    ///
+    /// All descendants of the widget are translated by the negated offset when calculating layout.
+    pub fn set_scroll_offset(&mut self, index: Index, offset: (f32, f32)) {
+        if offset == (0.0, 0.0) {
+            self.scroll_offsets.remove(&index);
+        } else {
+            self.scroll_offsets.insert(index, offset);
+        }
+    }
+
+    /// Get how far the given widget's content is scrolled (in pixels)
+    pub fn get_scroll_offset(&self, index: Index) -> (f32, f32) {
+        self.scroll_offsets.get(&index).copied().unwrap_or_default()
+    }
+
+    /// Make the given widget modal, placing it above any other modal widgets
+    ///
+    /// While a widget is the topmost modal, pointer events only reach it and its descendants, and
+    /// `Tab` only moves focus between its descendants.
+    pub fn push_modal(&mut self, index: Index) {
+        self.modals.retain(|modal| *modal != index);
+        self.modals.push(index);
+    }
+
+    /// Stop the given widget from being modal
+    pub fn remove_modal(&mut self, index: Index) {
+        self.modals.retain(|modal| *modal != index);
+    }
+
+    /// Get the topmost modal widget (if any)
+    pub fn get_modal(&self) -> Option<Index> {
+        // Widgets removed without removing their modal status no longer count
+        self.modals
+            .iter()
+            .rev()
+            .find(|modal| self.tree.contains(**modal))
+            .copied()
+    }
+
+    /// Gets the z-index offset set by the node's `z_index` style
+    fn get_z_index_offset(node: &Node) -> f32 {
+        match node.styles.z_index {
+            StyleProp::Value(z_index) => z_index,
+            _ => 0.0,
+        }
     }
 
     fn recurse_node_tree_to_build_primitives(
@@ -266,23 +736,47 @@ impl WidgetManager {
         current_node: Index,
         mut main_z_index: f32,
         mut prev_clip: RenderPrimitive,
+        visible_rect: Option<Rect>,
     ) -> Vec<RenderPrimitive> {
         let mut render_primitives = Vec::new();
 
         if let Some(node) = nodes.get(current_node).unwrap() {
+            main_z_index += Self::get_z_index_offset(node);
             if let Some(layout) = layout_cache.rect.get(&current_node) {
                 let mut render_primitive: RenderPrimitive = (&node.styles).into();
                 let mut layout = *layout;
-                let new_z_index = if matches!(render_primitive, RenderPrimitive::Clip { .. }) {
+                let is_clip = matches!(render_primitive, RenderPrimitive::Clip { .. });
+                let new_z_index = if is_clip {
                     main_z_index - 0.1
                 } else {
                     main_z_index
                 };
                 layout.z_index = new_z_index;
-                render_primitive.set_layout(layout);
-                render_primitives.push(render_primitive.clone());
 
-                let new_prev_clip = if matches!(render_primitive, RenderPrimitive::Clip { .. }) {
+                let mut visible_rect = visible_rect;
+                if is_clip {
+                    // Nested clips only show what their parents show
+                    let clip_layout = match visible_rect {
+                        Some(visible_rect) => layout.intersection(&visible_rect),
+                        None => layout,
+                    };
+                    if clip_layout.width <= 0.0 || clip_layout.height <= 0.0 {
+                        // Nothing within this subtree can be seen
+                        return render_primitives;
+                    }
+                    render_primitive.set_layout(clip_layout);
+                    visible_rect = Some(clip_layout);
+                    render_primitives.push(render_primitive.clone());
+                } else {
+                    render_primitive.set_layout(layout);
+                    let is_visible = visible_rect.map_or(true, |rect| rect.intersects(&layout));
+                    // Children can overflow their parent, so only this primitive is culled
+                    if is_visible && !matches!(render_primitive, RenderPrimitive::Empty) {
+                        render_primitives.push(render_primitive.clone());
+                    }
+                }
+
+                let new_prev_clip = if is_clip {
                     render_primitive.clone()
                 } else {
                     prev_clip
@@ -300,6 +794,7 @@ impl WidgetManager {
                             *child,
                             main_z_index,
                             new_prev_clip.clone(),
+                            visible_rect,
                         ));
 
                         main_z_index = layout.z_index;
@@ -323,19 +818,53 @@ impl WidgetManager {
     }
 
     pub fn build_render_primitives(&self) -> Vec<RenderPrimitive> {
+        let root_node = if let Some(root_node) = self.node_tree.root_node {
+            root_node
+        } else {
+            return Vec::new();
+        };
+
+        Self::recurse_node_tree_to_build_primitives(
+            &self.node_tree,
+            &self.layout_cache,
+            &self.nodes,
+            root_node,
+            0.0,
+            RenderPrimitive::Empty,
+            None,
+        )
+    }
+
+    /// Builds the render primitives, skipping those that can't be seen within the given viewport
+    ///
+    /// Primitives wholly outside the viewport or their clip rect are culled, as are entire subtrees
+    /// beneath clips that are hidden or have no area. Clips are shrunk to the part of them that is
+    /// actually visible.
+    pub fn build_visible_render_primitives(&self, viewport: Rect) -> Vec<RenderPrimitive> {
+        let root_node = if let Some(root_node) = self.node_tree.root_node {
+            root_node
+        } else {
+            return Vec::new();
+        };
+
         Self::recurse_node_tree_to_build_primitives(
             &self.node_tree,
             &self.layout_cache,
             &self.nodes,
-            self.node_tree.root_node.unwrap(),
+            root_node,
             0.0,
             RenderPrimitive::Empty,
+            Some(viewport),
         )
     }
 
     fn build_nodes_tree(&mut self) -> Tree {
         let mut tree = Tree::default();
-        let (root_node_id, _) = self.current_widgets.iter().next().unwrap();
+        let (root_node_id, _) = if let Some(root) = self.current_widgets.iter().next() {
+            root
+        } else {
+            return tree;
+        };
         tree.root_node = Some(root_node_id);
         tree.children.insert(
             tree.root_node.unwrap(),
@@ -409,6 +938,37 @@ impl WidgetManager {
         None
     }
 
+    /// Get the styles of the given widget, with its style override (if any) applied on top
+    fn get_styles(&self, index: Index) -> Option<Style> {
+        let styles = self.current_widgets.get(index)?.as_ref()?.get_styles();
+        match self.get_metadata::<StyleOverride>(index) {
+            Some(StyleOverride(style_override)) => {
+                let mut styles = styles.unwrap_or_default();
+                styles.apply(&style_override);
+                Some(styles)
+            }
+            None => styles,
+        }
+    }
+
+    /// Set the styles that win over all the others of the given widget (see
+    /// [StylePriority::Override](crate::styles::StylePriority::Override)), or remove them if `None`
+    ///
+    /// The widget is re-rendered to apply them.
+    pub fn set_style_override(&mut self, index: Index, styles: Option<Style>) {
+        match styles {
+            Some(styles) => {
+                self.set_metadata(index, StyleOverride(styles));
+            }
+            None => {
+                self.remove_metadata::<StyleOverride>(index);
+            }
+        }
+        if let Ok(mut dirty_nodes) = self.dirty_nodes.lock() {
+            dirty_nodes.insert(index);
+        }
+    }
+
     pub fn get_node(&self, id: &Index) -> Option<Node> {
         self.nodes[*id].clone()
     }
@@ -421,4 +981,276 @@ impl WidgetManager {
         self.focus_tracker
             .set_focusability(index, focusable, is_parent);
     }
+
+    /// Attach a piece of metadata to the given widget
+    ///
+    /// Only one value per type can be stored for a widget. Setting a value of a type that's
+    /// already present replaces (and returns) the old value.
+    pub fn set_metadata<T: resources::Resource>(&mut self, index: Index, data: T) -> Option<T> {
+        self.metadata
+            .entry(index)
+            .or_insert(resources::Resources::default())
+            .insert(data)
+    }
+
+    /// Get a copy of the metadata of type `T` attached to the given widget (if any)
+    pub fn get_metadata<T: resources::Resource + Clone>(&self, index: Index) -> Option<T> {
+        if let Some(metadata) = self.metadata.get(&index) {
+            if let Ok(data) = metadata.get::<T>() {
+                return Some(data.clone());
+            }
+        }
+
+        None
+    }
+
+    /// Checks if the given widget has metadata of type `T` attached to it
+    pub fn has_metadata<T: resources::Resource>(&self, index: Index) -> bool {
+        if let Some(metadata) = self.metadata.get(&index) {
+            metadata.contains::<T>()
+        } else {
+            false
+        }
+    }
+
+    /// Remove the metadata of type `T` from the given widget, returning it (if any)
+    pub fn remove_metadata<T: resources::Resource>(&mut self, index: Index) -> Option<T> {
+        if let Some(metadata) = self.metadata.get_mut(&index) {
+            metadata.remove::<T>()
+        } else {
+            None
+        }
+    }
+
+    /// Export the node tree in the [DOT](https://graphviz.org/doc/info/lang.html) format, to be
+    /// visualized with Graphviz (e.g. `dot -Tsvg tree.dot > tree.svg`)
+    ///
+    /// Each node is labeled with its widget name, ID, z-index, layout, and focusability, with the
+    /// focused widget drawn in bold. If `overlay_focus_tree` is true, the focus tree is drawn over
+    /// it with dashed blue edges (and dashed nodes for the focusable widgets without a node).
+    pub fn export_dot(&self, overlay_focus_tree: bool) -> String {
+        let dot_id = |index: Index| {
+            let (id, generation) = index.into_raw_parts();
+            format!("n{}_{}", id, generation)
+        };
+        let current_focus = self.focus_tree.current();
+
+        let mut dot = String::from("digraph WidgetTree {\n");
+        dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        let mut declared = HashSet::new();
+        let mut declare = |dot: &mut String, index: Index, is_node: bool| {
+            if !declared.insert(index) {
+                return;
+            }
+
+            let (id, _) = index.into_raw_parts();
+            let name = match self.current_widgets.get(index) {
+                Some(Some(widget)) => widget.get_name(),
+                _ => "?".to_string(),
+            };
+            let mut label = format!("{} ({})", name, id);
+            if let Some(Some(node)) = self.nodes.get(index) {
+                label.push_str(&format!("\\nz: {}", node.z));
+            }
+            if let Some(layout) = self.get_layout(&index) {
+                label.push_str(&format!(
+                    "\\n{}, {} {}x{}",
+                    layout.posx, layout.posy, layout.width, layout.height
+                ));
+            }
+            match self.get_focusable(index) {
+                Some(true) => label.push_str("\\nfocusable"),
+                Some(false) => label.push_str("\\nnot focusable"),
+                None => {}
+            }
+
+            let mut style = Vec::new();
+            if !is_node {
+                style.push("dashed");
+            }
+            if current_focus == Some(index) {
+                style.push("bold");
+            }
+            let style = if style.is_empty() {
+                String::new()
+            } else {
+                format!(", style=\"{}\"", style.join(","))
+            };
+            dot.push_str(&format!(
+                "    {} [label=\"{}\"{}];\n",
+                dot_id(index),
+                label.replace('"', "\\\""),
+                style
+            ));
+        };
+
+        let nodes = self.node_tree.flatten();
+        for index in nodes.iter() {
+            declare(&mut dot, *index, true);
+        }
+        for index in nodes.iter() {
+            if let Some(parent) = self.node_tree.get_parent(*index) {
+                dot.push_str(&format!("    {} -> {};\n", dot_id(parent), dot_id(*index)));
+            }
+        }
+
+        if overlay_focus_tree {
+            let focusable = self.focus_tree.tree().flatten();
+            for index in focusable.iter() {
+                declare(&mut dot, *index, self.node_tree.contains(*index));
+            }
+            for index in focusable.iter() {
+                if let Some(parent) = self.focus_tree.tree().get_parent(*index) {
+                    dot.push_str(&format!(
+                        "    {} -> {} [color=blue, style=dashed, constraint=false];\n",
+                        dot_id(parent),
+                        dot_id(*index)
+                    ));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout_cache::Rect;
+    use crate::node::NodeBuilder;
+    use crate::styles::{Dock, Style, StyleProp, Units, Wrap};
+    use crate::widget_manager::WidgetManager;
+    use crate::Index;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Tag(&'static str);
+
+    #[test]
+    fn metadata_should_be_stored_per_widget() {
+        let mut widget_manager = WidgetManager::new();
+        let a = Index::from_raw_parts(0, 0);
+        let b = Index::from_raw_parts(1, 0);
+
+        assert_eq!(None, widget_manager.set_metadata(a, Tag("a")));
+        assert_eq!(Some(Tag("a")), widget_manager.get_metadata::<Tag>(a));
+        assert!(!widget_manager.has_metadata::<Tag>(b));
+
+        assert_eq!(Some(Tag("a")), widget_manager.set_metadata(a, Tag("c")));
+        assert_eq!(Some(Tag("c")), widget_manager.remove_metadata::<Tag>(a));
+        assert_eq!(None, widget_manager.get_metadata::<Tag>(a));
+    }
+
+    #[test]
+    fn dot_export_should_contain_the_nodes_and_their_edges() {
+        let mut widget_manager = WidgetManager::new();
+        let parent = widget_manager.nodes.insert(None);
+        let child = widget_manager.nodes.insert(None);
+        widget_manager.nodes[child] = Some(NodeBuilder::new(child, Style::default()).build());
+        widget_manager.node_tree.add(parent, None);
+        widget_manager.node_tree.add(child, Some(parent));
+        widget_manager.set_focusable(Some(true), child, false);
+
+        let dot = widget_manager.export_dot(false);
+        assert!(dot.starts_with("digraph WidgetTree {"));
+        assert!(dot.contains("n0_0 [label=\"? (0)\"];"));
+        assert!(dot.contains("n1_0 [label=\"? (1)\\nz: 0\\nfocusable\"];"));
+        assert!(dot.contains("n0_0 -> n1_0;"));
+    }
+
+    #[test]
+    fn wrapping_nodes_should_move_overflowing_children_to_new_rows() {
+        let mut widget_manager = WidgetManager::new();
+        let panel = widget_manager.nodes.insert(None);
+        let styles = Style {
+            wrap: StyleProp::Value(Wrap {
+                row_spacing: 5.0,
+                column_spacing: 10.0,
+                ..Wrap::default()
+            }),
+            ..Style::default()
+        };
+        widget_manager.nodes[panel] = Some(NodeBuilder::new(panel, styles).build());
+        widget_manager.node_tree.add(panel, None);
+        widget_manager.layout_cache.rect.insert(
+            panel,
+            Rect {
+                width: 100.0,
+                height: 20.0,
+                ..Rect::default()
+            },
+        );
+
+        let children = (0..3)
+            .map(|index| {
+                let child = widget_manager.nodes.insert(None);
+                widget_manager.node_tree.add(child, Some(panel));
+                widget_manager.layout_cache.rect.insert(
+                    child,
+                    Rect {
+                        posx: index as f32 * 40.0,
+                        width: 40.0,
+                        height: 20.0,
+                        ..Rect::default()
+                    },
+                );
+                child
+            })
+            .collect::<Vec<_>>();
+
+        widget_manager.recurse_node_tree_to_wrap(panel);
+
+        let position = |child: &Index| {
+            let layout = widget_manager.layout_cache.rect.get(child).unwrap();
+            (layout.posx, layout.posy)
+        };
+        assert_eq!((0.0, 0.0), position(&children[0]));
+        assert_eq!((50.0, 0.0), position(&children[1]));
+        assert_eq!((0.0, 25.0), position(&children[2]));
+        assert_eq!(Some(45.0), widget_manager.get_wrapped_height(panel));
+    }
+
+    #[test]
+    fn docked_children_should_take_the_edges_of_their_parent_in_order() {
+        let mut widget_manager = WidgetManager::new();
+        let panel = widget_manager.nodes.insert(None);
+        widget_manager.nodes[panel] = Some(NodeBuilder::new(panel, Style::default()).build());
+        widget_manager.node_tree.add(panel, None);
+        widget_manager.layout_cache.rect.insert(
+            panel,
+            Rect {
+                width: 100.0,
+                height: 80.0,
+                ..Rect::default()
+            },
+        );
+
+        let docks = [Dock::Top, Dock::Left, Dock::Bottom, Dock::Fill];
+        let children = docks
+            .iter()
+            .map(|dock| {
+                let child = widget_manager.nodes.insert(None);
+                let styles = Style {
+                    dock: StyleProp::Value(*dock),
+                    width: StyleProp::Value(Units::Pixels(20.0)),
+                    height: StyleProp::Value(Units::Pixels(10.0)),
+                    ..Style::default()
+                };
+                widget_manager.nodes[child] = Some(NodeBuilder::new(child, styles).build());
+                widget_manager.node_tree.add(child, Some(panel));
+                child
+            })
+            .collect::<Vec<_>>();
+
+        let docked = widget_manager.get_docked_layouts();
+        let layout = |child: &Index| {
+            let rect = docked.get(child).unwrap();
+            (rect.posx, rect.posy, rect.width, rect.height)
+        };
+        assert_eq!((0.0, 0.0, 100.0, 10.0), layout(&children[0]));
+        assert_eq!((0.0, 10.0, 20.0, 70.0), layout(&children[1]));
+        assert_eq!((20.0, 70.0, 80.0, 10.0), layout(&children[2]));
+        assert_eq!((20.0, 10.0, 80.0, 60.0), layout(&children[3]));
+    }
 }
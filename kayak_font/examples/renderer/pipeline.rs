@@ -236,6 +236,7 @@ pub fn prepare_quads(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
     mut sprite_meta: ResMut<QuadMeta>,
+    mut font_texture_cache: ResMut<FontTextureCache>,
     mut extracted_quads: Query<&mut ExtractedChar>,
 ) {
     let extracted_sprite_len = extracted_quads.iter_mut().len();
@@ -251,16 +252,30 @@ pub fn prepare_quads(
     );
 
     for (i, mut extracted_sprite) in extracted_quads.iter_mut().enumerate() {
-        let sprite_rect = extracted_sprite.rect;
+        let layer = extracted_sprite
+            .font_handle
+            .as_ref()
+            .and_then(|font_handle| {
+                font_texture_cache.get_glyph_layer(font_handle, extracted_sprite.char_id)
+            });
+        let sprite_rect = if layer.is_some() {
+            extracted_sprite.rect
+        } else {
+            Rect {
+                min: extracted_sprite.rect.min,
+                max: extracted_sprite.rect.min,
+            }
+        };
+        let layer = layer.unwrap_or_default() as f32;
         let color = extracted_sprite.color.as_linear_rgba_f32();
 
         let uv_min = Vec2::ZERO;
         let uv_max = Vec2::ONE;
 
-        let bottom_left = Vec4::new(uv_min.x, uv_max.y, extracted_sprite.char_id as f32, 0.0);
-        let top_left = Vec4::new(uv_min.x, uv_min.y, extracted_sprite.char_id as f32, 0.0);
-        let top_right = Vec4::new(uv_max.x, uv_min.y, extracted_sprite.char_id as f32, 0.0);
-        let bottom_right = Vec4::new(uv_max.x, uv_max.y, extracted_sprite.char_id as f32, 0.0);
+        let bottom_left = Vec4::new(uv_min.x, uv_max.y, layer, 0.0);
+        let top_left = Vec4::new(uv_min.x, uv_min.y, layer, 0.0);
+        let top_right = Vec4::new(uv_max.x, uv_min.y, layer, 0.0);
+        let bottom_right = Vec4::new(uv_max.x, uv_max.y, layer, 0.0);
 
         let uvs: [[f32; 4]; 6] = [
             bottom_left.into(),
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
+#[cfg(feature = "shaping")]
+use std::sync::Arc;
 
 #[cfg(feature = "bevy_renderer")]
 use bevy::{prelude::Handle, reflect::TypeUuid, render::texture::Image};
 
-use crate::Sdf;
+use crate::{Glyph, Sdf};
 
 #[cfg(feature = "bevy_renderer")]
 #[derive(Debug, Clone, TypeUuid, PartialEq)]
@@ -12,6 +14,12 @@ pub struct KayakFont {
     pub sdf: Sdf,
     pub atlas_image: Handle<Image>,
     char_ids: HashMap<char, u32>,
+    kerning: HashMap<(char, char), f32>,
+    #[cfg(feature = "shaping")]
+    pub(crate) face_data: Option<Arc<Vec<u8>>>,
+    /// Maps the face's glyph ids to the atlas glyphs
+    #[cfg(feature = "shaping")]
+    pub(crate) glyph_ids: HashMap<u32, char>,
 }
 
 #[cfg(not(feature = "bevy_renderer"))]
@@ -19,6 +27,12 @@ pub struct KayakFont {
 pub struct KayakFont {
     pub sdf: Sdf,
     char_ids: HashMap<char, u32>,
+    kerning: HashMap<(char, char), f32>,
+    #[cfg(feature = "shaping")]
+    pub(crate) face_data: Option<Arc<Vec<u8>>>,
+    /// Maps the face's glyph ids to the atlas glyphs
+    #[cfg(feature = "shaping")]
+    pub(crate) glyph_ids: HashMap<u32, char>,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -41,6 +55,53 @@ pub enum Alignment {
     End,
 }
 
+/// How glyphs are aligned to the pixel grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlyphPositioning {
+    /// Glyphs are placed at their exact (fractional) positions, keeping letter spacing even
+    Subpixel,
+    /// Each glyph is snapped to the nearest pixel, keeping glyph edges sharp
+    RoundPerGlyph,
+    /// The text as a whole is snapped to the nearest pixel while glyphs keep their relative offsets
+    RoundPerRun,
+}
+
+impl Default for GlyphPositioning {
+    fn default() -> Self {
+        Self::Subpixel
+    }
+}
+
+impl GlyphPositioning {
+    /// Aligns a run of laid out glyphs to the pixel grid
+    ///
+    /// The `scale` is the number of physical pixels per layout unit (i.e. the window's DPI scale).
+    pub fn apply(&self, layout_rects: &mut [LayoutRect], scale: f32) {
+        let snap = |value: f32| (value * scale).round() / scale;
+        match self {
+            Self::Subpixel => {}
+            Self::RoundPerGlyph => {
+                for layout_rect in layout_rects.iter_mut() {
+                    layout_rect.position.0 = snap(layout_rect.position.0);
+                    layout_rect.position.1 = snap(layout_rect.position.1);
+                }
+            }
+            Self::RoundPerRun => {
+                if let Some(first) = layout_rects.first() {
+                    let offset = (
+                        snap(first.position.0) - first.position.0,
+                        snap(first.position.1) - first.position.1,
+                    );
+                    for layout_rect in layout_rects.iter_mut() {
+                        layout_rect.position.0 += offset.0;
+                        layout_rect.position.1 += offset.1;
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl KayakFont {
     pub fn new(sdf: Sdf, #[cfg(feature = "bevy_renderer")] atlas_image: Handle<Image>) -> Self {
         Self {
@@ -48,6 +109,11 @@ impl KayakFont {
             #[cfg(feature = "bevy_renderer")]
             atlas_image,
             char_ids: HashMap::default(),
+            kerning: HashMap::default(),
+            #[cfg(feature = "shaping")]
+            face_data: None,
+            #[cfg(feature = "shaping")]
+            glyph_ids: HashMap::default(),
         }
     }
 
@@ -57,6 +123,51 @@ impl KayakFont {
             self.char_ids.insert(glyph.unicode, count);
             count += 1;
         }
+
+        for kerning in self.sdf.kerning() {
+            if let (Some(first), Some(second)) = (
+                char::from_u32(kerning.unicode1),
+                char::from_u32(kerning.unicode2),
+            ) {
+                self.kerning.insert((first, second), kerning.advance);
+            }
+        }
+    }
+
+    /// Set the font file (TTF or OTF) the atlas was generated from, enabling text shaping
+    ///
+    /// Returns false if the data couldn't be parsed.
+    #[cfg(feature = "shaping")]
+    pub fn set_face_data(&mut self, face_data: Vec<u8>) -> bool {
+        let glyph_ids = if let Some(face) = rustybuzz::Face::from_slice(&face_data, 0) {
+            self.sdf
+                .glyphs
+                .iter()
+                .filter_map(|glyph| {
+                    face.glyph_index(glyph.unicode)
+                        .map(|glyph_id| (glyph_id.0 as u32, glyph.unicode))
+                })
+                .collect()
+        } else {
+            return false;
+        };
+
+        self.glyph_ids = glyph_ids;
+        self.face_data = Some(Arc::new(face_data));
+        true
+    }
+
+    pub fn get_glyph(&self, c: char) -> Option<&Glyph> {
+        self.get_char_id(c)
+            .and_then(|id| self.sdf.glyphs.get(id as usize))
+    }
+
+    /// The adjustment to the advance of `first` when followed by `second` (in em units)
+    pub fn get_kerning(&self, first: char, second: char) -> f32 {
+        self.kerning
+            .get(&(first, second))
+            .copied()
+            .unwrap_or_default()
     }
 
     pub fn get_char_id(&self, c: char) -> Option<u32> {
@@ -64,25 +175,7 @@ impl KayakFont {
     }
 
     pub fn get_word_width(&self, word: &str, font_size: f32) -> f32 {
-        let mut width = 0.0;
-        for c in word.chars() {
-            if let Some(glyph) = self.sdf.glyphs.iter().find(|glyph| glyph.unicode == c) {
-                let plane_bounds = glyph.plane_bounds.as_ref();
-                let (_, _, char_width, _) = match plane_bounds {
-                    Some(val) => (
-                        val.left,
-                        val.top,
-                        val.size().0 * font_size,
-                        val.size().1 * font_size,
-                    ),
-                    None => (0.0, 0.0, 0.0, 0.0),
-                };
-
-                width += char_width;
-            }
-        }
-
-        width
+        self.get_shaped_width(word, font_size)
     }
 
     pub fn measure(
@@ -109,29 +202,27 @@ impl KayakFont {
         let mut y = 0.0;
         let mut i = 0;
         for word in content.split(&split_chars[..]) {
-            let word_width = self.get_word_width(word, font_size);
+            let shaped_word = self.shape(word);
+            let word_width: f32 = shaped_word
+                .iter()
+                .map(|glyph| glyph.advance * font_size)
+                .sum();
             if x + word_width > max_size.0 {
                 y -= shift_sign * line_height;
                 x = 0.0;
             }
-            for c in word.chars() {
-                if c == '\n' {
+            for shaped_glyph in shaped_word {
+                if shaped_glyph.unicode == '\n' {
                     y -= shift_sign * line_height;
                     x = 0.0;
+                    continue;
                 }
-                if let Some(glyph) = self.sdf.glyphs.iter().find(|glyph| glyph.unicode == c) {
-                    x += glyph.advance * font_size;
-                    size.0 = size.0.max(x);
-                }
+                x += shaped_glyph.advance * font_size;
+                size.0 = size.0.max(x);
             }
 
             if let Some(next_missing) = missing_chars.get(i) {
-                if let Some(glyph) = self
-                    .sdf
-                    .glyphs
-                    .iter()
-                    .find(|glyph| glyph.unicode == *next_missing)
-                {
+                if let Some(glyph) = self.get_glyph(*next_missing) {
                     x += glyph.advance * font_size;
                 }
                 i += 1;
@@ -179,22 +270,27 @@ impl KayakFont {
         let mut line_starting_index = 0;
         let mut last_width = 0.0;
         for word in content.split(&split_chars[..]) {
-            let word_width = self.get_word_width(word, font_size);
+            let shaped_word = self.shape(word);
+            let word_width: f32 = shaped_word
+                .iter()
+                .map(|glyph| glyph.advance * font_size)
+                .sum();
             if x + word_width + (font_size / 2.0) > max_size.0 {
                 y -= shift_sign * line_height;
                 line_widths.push((x, line_starting_index, positions_and_size.len()));
                 line_starting_index = positions_and_size.len();
                 x = 0.0;
             }
-            for c in word.chars() {
-                if c == '\n' {
+            for shaped_glyph in shaped_word {
+                if shaped_glyph.unicode == '\n' {
                     y -= shift_sign * line_height;
                     line_widths.push((x, line_starting_index, positions_and_size.len()));
                     line_starting_index = positions_and_size.len();
                     x = 0.0;
+                    continue;
                 }
 
-                if let Some(glyph) = self.sdf.glyphs.iter().find(|glyph| glyph.unicode == c) {
+                if let Some(glyph) = self.get_glyph(shaped_glyph.unicode) {
                     let plane_bounds = glyph.plane_bounds.as_ref();
                     let (left, top, width, _height) = match plane_bounds {
                         Some(val) => (
@@ -208,26 +304,23 @@ impl KayakFont {
 
                     last_width = width;
 
-                    let position_x = x + left * font_size;
-                    let position_y =
-                        y + (shift_sign * top * font_size) + ((line_height - font_size) / 2.0);
+                    let (offset_x, offset_y) = shaped_glyph.offset;
+                    let position_x = x + (left + offset_x) * font_size;
+                    let position_y = y
+                        + (shift_sign * (top + offset_y) * font_size)
+                        + ((line_height - font_size) / 2.0);
 
                     positions_and_size.push(LayoutRect {
                         position: (position_x, position_y),
                         size: (resized_max_glyph_size.0, resized_max_glyph_size.1),
-                        content: c,
+                        content: shaped_glyph.unicode,
                     });
-
-                    x += glyph.advance * font_size;
                 }
+
+                x += shaped_glyph.advance * font_size;
             }
             if let Some(next_missing) = missing_chars.get(i) {
-                if let Some(glyph) = self
-                    .sdf
-                    .glyphs
-                    .iter()
-                    .find(|glyph| glyph.unicode == *next_missing)
-                {
+                if let Some(glyph) = self.get_glyph(*next_missing) {
                     x += glyph.advance * font_size;
                 }
                 i += 1;
@@ -257,3 +350,27 @@ impl KayakFont {
         positions_and_size
     }
 }
+
+#[test]
+fn test_glyph_positioning() {
+    let layout_rects = vec![
+        LayoutRect {
+            position: (10.3, 5.6),
+            ..Default::default()
+        },
+        LayoutRect {
+            position: (17.8, 5.6),
+            ..Default::default()
+        },
+    ];
+
+    let mut per_glyph = layout_rects.clone();
+    GlyphPositioning::RoundPerGlyph.apply(&mut per_glyph, 1.0);
+    assert_eq!(per_glyph[0].position, (10.0, 6.0));
+    assert_eq!(per_glyph[1].position, (18.0, 6.0));
+
+    let mut per_run = layout_rects.clone();
+    GlyphPositioning::RoundPerRun.apply(&mut per_run, 2.0);
+    assert_eq!(per_run[0].position, (10.5, 5.5));
+    assert!((per_run[1].position.0 - 18.0).abs() < 0.001);
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+/// The number of glyph layers a font starts out with
+pub const INITIAL_GLYPH_LAYERS: u32 = 32;
+/// The default maximum number of glyph layers per font
+///
+/// This matches the minimum array layer limit guaranteed by wgpu.
+pub const MAX_GLYPH_LAYERS: u32 = 256;
+
+/// The result of requesting a glyph from a [GlyphCache]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphRequest {
+    /// The glyph is already stored in the given layer
+    Resident(u32),
+    /// The glyph was assigned the given layer and still needs to be uploaded to it
+    Allocated(u32),
+    /// Every layer is in use by glyphs needed this frame
+    Full,
+}
+
+impl GlyphRequest {
+    /// The layer containing the glyph, if any
+    pub fn layer(&self) -> Option<u32> {
+        match self {
+            Self::Resident(layer) | Self::Allocated(layer) => Some(*layer),
+            Self::Full => None,
+        }
+    }
+}
+
+/// Usage statistics of a [GlyphCache]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GlyphCacheMetrics {
+    /// The number of glyphs currently stored
+    pub resident: u32,
+    /// The number of layers currently allocated
+    pub capacity: u32,
+    /// The number of glyphs evicted to make room for others
+    pub evictions: u64,
+    /// The number of times the layer capacity was grown
+    pub growths: u64,
+    /// The number of glyphs that couldn't be stored because the cache was full
+    pub misses: u64,
+}
+
+impl GlyphCacheMetrics {
+    /// Combines two sets of metrics (e.g. to get the totals for several fonts)
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            resident: self.resident + other.resident,
+            capacity: self.capacity + other.capacity,
+            evictions: self.evictions + other.evictions,
+            growths: self.growths + other.growths,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GlyphSlot {
+    layer: u32,
+    last_used: u64,
+}
+
+/// Assigns glyphs to the layers of a font's texture array
+///
+/// Glyphs are only stored once they are requested. When all layers are taken, the capacity doubles
+/// up to `max_capacity`, after which the least recently used glyph is evicted. Glyphs requested
+/// during the current frame are never evicted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphCache {
+    slots: HashMap<u32, GlyphSlot>,
+    free_layers: Vec<u32>,
+    capacity: u32,
+    max_capacity: u32,
+    frame: u64,
+    metrics: GlyphCacheMetrics,
+}
+
+impl Default for GlyphCache {
+    fn default() -> Self {
+        Self::new(INITIAL_GLYPH_LAYERS, MAX_GLYPH_LAYERS)
+    }
+}
+
+impl GlyphCache {
+    pub fn new(initial_capacity: u32, max_capacity: u32) -> Self {
+        let max_capacity = max_capacity.max(1);
+        let capacity = initial_capacity.clamp(1, max_capacity);
+        Self {
+            slots: HashMap::default(),
+            // Reversed so layers are handed out in ascending order
+            free_layers: (0..capacity).rev().collect(),
+            capacity,
+            max_capacity,
+            frame: 0,
+            metrics: GlyphCacheMetrics {
+                capacity,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The number of layers the texture array needs
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn metrics(&self) -> GlyphCacheMetrics {
+        self.metrics
+    }
+
+    /// Get the layer for the given glyph, assigning it one if needed
+    pub fn request(&mut self, glyph_id: u32) -> GlyphRequest {
+        if let Some(slot) = self.slots.get_mut(&glyph_id) {
+            slot.last_used = self.frame;
+            return GlyphRequest::Resident(slot.layer);
+        }
+
+        if self.free_layers.is_empty() {
+            self.grow();
+        }
+
+        let layer = if let Some(layer) = self.free_layers.pop() {
+            layer
+        } else if let Some(layer) = self.evict() {
+            layer
+        } else {
+            self.metrics.misses += 1;
+            return GlyphRequest::Full;
+        };
+
+        self.slots.insert(
+            glyph_id,
+            GlyphSlot {
+                layer,
+                last_used: self.frame,
+            },
+        );
+        self.metrics.resident = self.slots.len() as u32;
+        GlyphRequest::Allocated(layer)
+    }
+
+    /// Marks the end of a frame, allowing the glyphs used during it to be evicted
+    pub fn end_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Forget all stored glyphs (e.g. because the font itself changed)
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free_layers = (0..self.capacity).rev().collect();
+        self.metrics.resident = 0;
+    }
+
+    fn grow(&mut self) {
+        if self.capacity >= self.max_capacity {
+            return;
+        }
+
+        let capacity = (self.capacity * 2).min(self.max_capacity);
+        self.free_layers.extend((self.capacity..capacity).rev());
+        self.capacity = capacity;
+        self.metrics.capacity = capacity;
+        self.metrics.growths += 1;
+    }
+
+    fn evict(&mut self) -> Option<u32> {
+        let frame = self.frame;
+        let (glyph_id, slot) = self
+            .slots
+            .iter()
+            .filter(|(_, slot)| slot.last_used < frame)
+            .min_by_key(|(_, slot)| slot.last_used)
+            .map(|(glyph_id, slot)| (*glyph_id, *slot))?;
+
+        self.slots.remove(&glyph_id);
+        self.metrics.evictions += 1;
+        Some(slot.layer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GlyphCache, GlyphRequest};
+
+    #[test]
+    fn reuses_resident_glyphs() {
+        let mut cache = GlyphCache::new(4, 4);
+        assert_eq!(cache.request(10), GlyphRequest::Allocated(0));
+        assert_eq!(cache.request(11), GlyphRequest::Allocated(1));
+        assert_eq!(cache.request(10), GlyphRequest::Resident(0));
+        assert_eq!(cache.metrics().resident, 2);
+    }
+
+    #[test]
+    fn grows_until_max_capacity() {
+        let mut cache = GlyphCache::new(2, 5);
+        for glyph_id in 0..5 {
+            assert_eq!(cache.request(glyph_id), GlyphRequest::Allocated(glyph_id));
+        }
+        assert_eq!(cache.capacity(), 5);
+        assert_eq!(cache.metrics().growths, 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = GlyphCache::new(2, 2);
+        cache.request(0);
+        cache.end_frame();
+        cache.request(1);
+        cache.end_frame();
+        cache.request(1);
+
+        // Glyph 0 is the oldest, so its layer is reused
+        assert_eq!(cache.request(2), GlyphRequest::Allocated(0));
+        assert_eq!(cache.metrics().evictions, 1);
+        assert_eq!(cache.request(1), GlyphRequest::Resident(1));
+    }
+
+    #[test]
+    fn never_evicts_glyphs_used_this_frame() {
+        let mut cache = GlyphCache::new(1, 1);
+        cache.request(0);
+        assert_eq!(cache.request(1), GlyphRequest::Full);
+        assert_eq!(cache.metrics().misses, 1);
+
+        cache.end_frame();
+        assert_eq!(cache.request(1), GlyphRequest::Allocated(0));
+    }
+}
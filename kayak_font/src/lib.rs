@@ -1,14 +1,18 @@
 mod atlas;
 mod font;
 mod glyph;
+mod glyph_cache;
 mod metrics;
 mod sdf;
+mod shaping;
 
 pub use atlas::*;
 pub use font::*;
 pub use glyph::*;
+pub use glyph_cache::*;
 pub use metrics::*;
 pub use sdf::*;
+pub use shaping::*;
 
 #[cfg(feature = "bevy_renderer")]
 mod renderer;
@@ -33,13 +37,16 @@ pub mod bevy {
 
     impl Plugin for KayakFontPlugin {
         fn build(&self, app: &mut bevy::prelude::App) {
+            // Shared so the render world can report atlas usage back to the main world
+            let metrics = FontAtlasMetrics::default();
             app.add_asset::<KayakFont>()
                 .add_asset_loader(KayakFontLoader)
+                .insert_resource(metrics.clone())
                 .add_system(init_font_texture);
 
             let render_app = app.sub_app_mut(RenderApp);
             render_app
-                .init_resource::<FontTextureCache>()
+                .insert_resource(FontTextureCache::with_metrics(metrics))
                 .init_resource::<ExtractedFonts>()
                 .add_system_to_stage(RenderStage::Extract, extract_fonts)
                 .add_system_to_stage(RenderStage::Prepare, prepare_fonts);
@@ -169,6 +176,17 @@ pub mod bevy {
 
                 font.generate_char_ids();
 
+                // Shape text with the original font file, if it was shipped alongside the atlas
+                #[cfg(feature = "shaping")]
+                for extension in ["ttf", "otf"] {
+                    let face_path = load_context.path().with_extension(extension);
+                    if let Ok(face_data) = load_context.read_asset_bytes(face_path).await {
+                        if font.set_face_data(face_data) {
+                            break;
+                        }
+                    }
+                }
+
                 load_context
                     .set_default_asset(LoadedAsset::new(font).with_dependency(atlas_image_path));
 
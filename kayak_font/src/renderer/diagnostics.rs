@@ -0,0 +1,77 @@
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticId, Diagnostics},
+    prelude::{Plugin, Res, ResMut},
+};
+
+use super::FontAtlasMetrics;
+
+/// Adds font atlas usage to Bevy's [Diagnostics]
+///
+/// Requires the [KayakFontPlugin](crate::bevy::KayakFontPlugin).
+#[derive(Default)]
+pub struct FontAtlasDiagnosticsPlugin;
+
+impl FontAtlasDiagnosticsPlugin {
+    /// The number of glyphs stored across all font atlases
+    pub const RESIDENT_GLYPHS: DiagnosticId =
+        DiagnosticId::from_u128(262697425088297750761397977547334310170);
+    /// The number of glyph layers allocated across all font atlases
+    pub const GLYPH_CAPACITY: DiagnosticId =
+        DiagnosticId::from_u128(105174165237105060577016315064591970605);
+    /// The total number of glyphs evicted to make room for others
+    pub const GLYPH_EVICTIONS: DiagnosticId =
+        DiagnosticId::from_u128(240851345817520486204942914530917068484);
+    /// The total number of glyphs that couldn't be drawn because an atlas was full
+    pub const GLYPH_MISSES: DiagnosticId =
+        DiagnosticId::from_u128(78768347499216993180942306581159243044);
+}
+
+impl Plugin for FontAtlasDiagnosticsPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_startup_system(setup_diagnostics)
+            .add_system(update_diagnostics);
+    }
+}
+
+fn setup_diagnostics(mut diagnostics: ResMut<Diagnostics>) {
+    diagnostics.add(Diagnostic::new(
+        FontAtlasDiagnosticsPlugin::RESIDENT_GLYPHS,
+        "font_atlas_resident_glyphs",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        FontAtlasDiagnosticsPlugin::GLYPH_CAPACITY,
+        "font_atlas_glyph_capacity",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        FontAtlasDiagnosticsPlugin::GLYPH_EVICTIONS,
+        "font_atlas_glyph_evictions",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        FontAtlasDiagnosticsPlugin::GLYPH_MISSES,
+        "font_atlas_glyph_misses",
+        20,
+    ));
+}
+
+fn update_diagnostics(mut diagnostics: ResMut<Diagnostics>, metrics: Res<FontAtlasMetrics>) {
+    let metrics = metrics.get();
+    diagnostics.add_measurement(
+        FontAtlasDiagnosticsPlugin::RESIDENT_GLYPHS,
+        metrics.resident as f64,
+    );
+    diagnostics.add_measurement(
+        FontAtlasDiagnosticsPlugin::GLYPH_CAPACITY,
+        metrics.capacity as f64,
+    );
+    diagnostics.add_measurement(
+        FontAtlasDiagnosticsPlugin::GLYPH_EVICTIONS,
+        metrics.evictions as f64,
+    );
+    diagnostics.add_measurement(
+        FontAtlasDiagnosticsPlugin::GLYPH_MISSES,
+        metrics.misses as f64,
+    );
+}
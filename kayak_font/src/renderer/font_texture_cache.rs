@@ -1,4 +1,4 @@
-use crate::{KayakFont, Sdf};
+use crate::{GlyphCache, GlyphCacheMetrics, GlyphRequest, KayakFont, Sdf};
 use bevy::{
     math::{Size, Vec2},
     prelude::{Handle, Res},
@@ -15,19 +15,34 @@ use bevy::{
     },
     utils::HashMap,
 };
+use std::sync::{Arc, RwLock};
 
 pub trait FontRenderingPipeline {
     fn get_font_image_layout(&self) -> &BindGroupLayout;
 }
 
-pub const MAX_CHARACTERS: u32 = 100;
+/// The glyph cache metrics of all fonts, shared between the render world and the main world
+#[derive(Default, Clone)]
+pub struct FontAtlasMetrics(pub(crate) Arc<RwLock<GlyphCacheMetrics>>);
+
+impl FontAtlasMetrics {
+    pub fn get(&self) -> GlyphCacheMetrics {
+        self.0.read().map(|metrics| *metrics).unwrap_or_default()
+    }
+}
 
 pub struct FontTextureCache {
     images: HashMap<Handle<KayakFont>, GpuImage>,
     pub(crate) bind_groups: HashMap<Handle<KayakFont>, BindGroup>,
     fonts: HashMap<Handle<KayakFont>, KayakFont>,
+    glyph_caches: HashMap<Handle<KayakFont>, GlyphCache>,
+    /// The number of layers in each font's texture array
+    texture_layers: HashMap<Handle<KayakFont>, u32>,
+    /// Glyphs (and the layers assigned to them) that still need to be copied from the atlas
+    pending_glyphs: HashMap<Handle<KayakFont>, Vec<(u32, u32)>>,
     new_fonts: Vec<Handle<KayakFont>>,
     updated_fonts: Vec<Handle<KayakFont>>,
+    metrics: FontAtlasMetrics,
 }
 
 impl Default for FontTextureCache {
@@ -38,18 +53,29 @@ impl Default for FontTextureCache {
 
 impl FontTextureCache {
     pub fn new() -> Self {
+        Self::with_metrics(FontAtlasMetrics::default())
+    }
+
+    /// Create a cache that reports its usage to the given metrics
+    pub fn with_metrics(metrics: FontAtlasMetrics) -> Self {
         Self {
             images: HashMap::default(),
             bind_groups: HashMap::default(),
             fonts: HashMap::default(),
+            glyph_caches: HashMap::default(),
+            texture_layers: HashMap::default(),
+            pending_glyphs: HashMap::default(),
             new_fonts: Vec::new(),
             updated_fonts: Vec::new(),
+            metrics,
         }
     }
 
     pub fn add(&mut self, kayak_font_handle: Handle<KayakFont>, font: KayakFont) {
         if !self.fonts.contains_key(&kayak_font_handle) {
             self.fonts.insert(kayak_font_handle.clone(), font);
+            self.glyph_caches
+                .insert(kayak_font_handle.clone(), GlyphCache::default());
             self.new_fonts.push(kayak_font_handle);
         } else {
             if let Some(old_font) = self.fonts.get_mut(&kayak_font_handle) {
@@ -63,6 +89,27 @@ impl FontTextureCache {
         self.bind_groups.get(handle)
     }
 
+    /// Get the texture array layer containing the given glyph of a font
+    ///
+    /// Glyphs are stored on demand, so this should be called (during the prepare stage) for every
+    /// glyph drawn in a frame. Returns `None` if the font is unknown or if its cache is full, in
+    /// which case the glyph shouldn't be drawn this frame.
+    pub fn get_glyph_layer(&mut self, handle: &Handle<KayakFont>, char_id: u32) -> Option<u32> {
+        let glyph_cache = self.glyph_caches.get_mut(handle)?;
+        let request = glyph_cache.request(char_id);
+        if let GlyphRequest::Allocated(layer) = request {
+            self.pending_glyphs
+                .entry(handle.clone_weak())
+                .or_insert_with(Vec::new)
+                .push((char_id, layer));
+        }
+        request.layer()
+    }
+
+    /// Creates, grows, and fills the texture arrays of all fonts
+    ///
+    /// This should run once per frame, after all glyphs have been requested with
+    /// [FontTextureCache::get_glyph_layer].
     pub fn process_new<T: FontRenderingPipeline>(
         &mut self,
         device: &RenderDevice,
@@ -70,22 +117,38 @@ impl FontTextureCache {
         pipeline: &T,
         render_images: &Res<RenderAssets<Image>>,
     ) {
+        // Changed fonts may have entirely different glyphs, so start over
+        let updated_fonts: Vec<_> = self.updated_fonts.drain(..).collect();
+        for kayak_font_handle in updated_fonts {
+            if let Some(glyph_cache) = self.glyph_caches.get_mut(&kayak_font_handle) {
+                glyph_cache.clear();
+            }
+            self.pending_glyphs.remove(&kayak_font_handle);
+        }
+
         let new_fonts: Vec<_> = self.new_fonts.drain(..).collect();
         for kayak_font_handle in new_fonts {
             let mut was_processed = true;
             if let Some(font) = self.fonts.get(&kayak_font_handle) {
-                if let Some(atlas_texture) = render_images.get(&font.atlas_image) {
-                    Self::create_from_atlas(
+                if render_images.get(&font.atlas_image).is_some() {
+                    let layers = self.glyph_caches[&kayak_font_handle].capacity();
+                    Self::create_texture(
                         &mut self.images,
+                        kayak_font_handle.clone_weak(),
+                        font.sdf.max_glyph_size().into(),
+                        layers,
+                        device,
+                        TextureFormat::Rgba8Unorm,
+                    );
+                    Self::create_bind_group(
+                        &self.images,
                         &mut self.bind_groups,
-                        &font.sdf,
                         kayak_font_handle.clone_weak(),
                         device,
-                        queue,
                         pipeline,
-                        atlas_texture,
-                        font.sdf.max_glyph_size().into(),
                     );
+                    self.texture_layers
+                        .insert(kayak_font_handle.clone_weak(), layers);
                 } else {
                     was_processed = false;
                 }
@@ -94,27 +157,62 @@ impl FontTextureCache {
                 self.new_fonts.push(kayak_font_handle.clone_weak());
             }
         }
+
+        let handles: Vec<_> = self.texture_layers.keys().cloned().collect();
+        for kayak_font_handle in handles {
+            let capacity = self.glyph_caches[&kayak_font_handle].capacity();
+            if self.texture_layers[&kayak_font_handle] < capacity {
+                self.grow_texture(&kayak_font_handle, capacity, device, queue, pipeline);
+            }
+
+            let font = &self.fonts[&kayak_font_handle];
+            if let (Some(atlas_texture), Some(pending)) = (
+                render_images.get(&font.atlas_image),
+                self.pending_glyphs.remove(&kayak_font_handle),
+            ) {
+                Self::copy_glyphs(
+                    &self.images[&kayak_font_handle],
+                    &font.sdf,
+                    &pending,
+                    device,
+                    queue,
+                    atlas_texture,
+                );
+            }
+        }
+
+        let mut metrics = GlyphCacheMetrics::default();
+        for glyph_cache in self.glyph_caches.values_mut() {
+            metrics = metrics.merge(&glyph_cache.metrics());
+            glyph_cache.end_frame();
+        }
+        if let Ok(mut shared_metrics) = self.metrics.0.write() {
+            *shared_metrics = metrics;
+        }
     }
 
     fn create_texture(
         images: &mut HashMap<Handle<KayakFont>, GpuImage>,
         font_handle: Handle<KayakFont>,
-        size: (u32, u32),
+        size: Vec2,
+        layers: u32,
         device: &RenderDevice,
         format: TextureFormat,
     ) {
         let texture_descriptor = TextureDescriptor {
             label: Some("font_texture_array"),
             size: Extent3d {
-                width: size.0,
-                height: size.1,
-                depth_or_array_layers: MAX_CHARACTERS,
+                width: size.x as u32,
+                height: size.y as u32,
+                depth_or_array_layers: layers,
             },
             mip_level_count: 1,
             sample_count: 1,
             dimension: TextureDimension::D2,
             format,
-            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::COPY_SRC,
         };
 
         let sampler_descriptor = SamplerDescriptor {
@@ -143,7 +241,7 @@ impl FontTextureCache {
             base_mip_level: 0,
             base_array_layer: 0,
             mip_level_count: None,
-            array_layer_count: std::num::NonZeroU32::new(MAX_CHARACTERS),
+            array_layer_count: std::num::NonZeroU32::new(layers),
         });
 
         let image = GpuImage {
@@ -151,21 +249,106 @@ impl FontTextureCache {
             sampler,
             texture_view,
             size: Size {
-                width: size.0 as f32,
-                height: size.1 as f32,
+                width: size.x,
+                height: size.y,
             },
         };
 
         images.insert(font_handle, image);
     }
 
+    fn create_bind_group<T: FontRenderingPipeline>(
+        images: &HashMap<Handle<KayakFont>, GpuImage>,
+        bind_groups: &mut HashMap<Handle<KayakFont>, BindGroup>,
+        font_handle: Handle<KayakFont>,
+        device: &RenderDevice,
+        pipeline: &T,
+    ) {
+        let gpu_image = images.get(&font_handle).unwrap();
+        let binding = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("text_image_bind_group"),
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&gpu_image.texture_view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&gpu_image.sampler),
+                },
+            ],
+            layout: pipeline.get_font_image_layout(),
+        });
+
+        bind_groups.insert(font_handle, binding);
+    }
+
+    /// Replaces a font's texture array with a larger one, keeping the glyphs already stored
+    fn grow_texture<T: FontRenderingPipeline>(
+        &mut self,
+        font_handle: &Handle<KayakFont>,
+        layers: u32,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        pipeline: &T,
+    ) {
+        let old_image = if let Some(old_image) = self.images.remove(font_handle) {
+            old_image
+        } else {
+            return;
+        };
+        let old_layers = self.texture_layers[font_handle];
+
+        Self::create_texture(
+            &mut self.images,
+            font_handle.clone_weak(),
+            Vec2::new(old_image.size.width, old_image.size.height),
+            layers,
+            device,
+            TextureFormat::Rgba8Unorm,
+        );
+
+        let mut command_encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("grow_font_texture_array_encoder"),
+        });
+        command_encoder.copy_texture_to_texture(
+            ImageCopyTexture {
+                texture: &old_image.texture,
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            ImageCopyTexture {
+                texture: &self.images[font_handle].texture,
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            Extent3d {
+                width: old_image.size.width as u32,
+                height: old_image.size.height as u32,
+                depth_or_array_layers: old_layers,
+            },
+        );
+        queue.submit(vec![command_encoder.finish()]);
+
+        Self::create_bind_group(
+            &self.images,
+            &mut self.bind_groups,
+            font_handle.clone_weak(),
+            device,
+            pipeline,
+        );
+        self.texture_layers.insert(font_handle.clone_weak(), layers);
+    }
+
     pub fn get_empty(device: &RenderDevice, layout: &BindGroupLayout) -> (GpuImage, BindGroup) {
         let texture_descriptor = TextureDescriptor {
             label: Some("font_texture_array"),
             size: Extent3d {
                 width: 1,
                 height: 1,
-                depth_or_array_layers: MAX_CHARACTERS,
+                depth_or_array_layers: 1,
             },
             mip_level_count: 1,
             sample_count: 1,
@@ -187,7 +370,7 @@ impl FontTextureCache {
             base_mip_level: 0,
             base_array_layer: 0,
             mip_level_count: None,
-            array_layer_count: std::num::NonZeroU32::new(MAX_CHARACTERS),
+            array_layer_count: std::num::NonZeroU32::new(1),
         });
 
         let image = GpuImage {
@@ -218,56 +401,27 @@ impl FontTextureCache {
         (image, binding)
     }
 
-    pub fn create_from_atlas<T: FontRenderingPipeline>(
-        images: &mut HashMap<Handle<KayakFont>, GpuImage>,
-        bind_groups: &mut HashMap<Handle<KayakFont>, BindGroup>,
+    /// Copies glyphs from a font's atlas into their assigned texture array layers
+    fn copy_glyphs(
+        gpu_image: &GpuImage,
         sdf: &Sdf,
-        font_handle: Handle<KayakFont>,
+        glyphs: &[(u32, u32)],
         device: &RenderDevice,
         queue: &RenderQueue,
-        pipeline: &T,
         atlas_texture: &GpuImage,
-        size: Vec2,
     ) {
-        Self::create_texture(
-            images,
-            font_handle.clone_weak(),
-            (size.x as u32, size.y as u32),
-            device,
-            TextureFormat::Rgba8Unorm,
-        );
-
         let mut command_encoder = device.create_command_encoder(&CommandEncoderDescriptor {
-            label: Some("create_sdf_from_atlas_encoder"),
-        });
-
-        let gpu_image = images.get(&font_handle).unwrap();
-
-        // create bind group
-        let binding = device.create_bind_group(&BindGroupDescriptor {
-            label: Some("text_image_bind_group"),
-            entries: &[
-                BindGroupEntry {
-                    binding: 0,
-                    resource: BindingResource::TextureView(&gpu_image.texture_view),
-                },
-                BindGroupEntry {
-                    binding: 1,
-                    resource: BindingResource::Sampler(&gpu_image.sampler),
-                },
-            ],
-            layout: &pipeline.get_font_image_layout(),
+            label: Some("copy_glyphs_from_atlas_encoder"),
         });
 
-        bind_groups.insert(font_handle.clone_weak(), binding);
-
-        // Now fill the texture data.
-
-        let _atlas_width = sdf.atlas.width;
         let atlas_height = sdf.atlas.height;
 
-        for (i, glyph) in sdf.glyphs.iter().enumerate() {
-            if let Some(atlas_bounds) = glyph.atlas_bounds {
+        for (char_id, layer) in glyphs {
+            let atlas_bounds = sdf
+                .glyphs
+                .get(*char_id as usize)
+                .and_then(|glyph| glyph.atlas_bounds);
+            if let Some(atlas_bounds) = atlas_bounds {
                 let glyph_size = atlas_bounds.size();
                 command_encoder.copy_texture_to_texture(
                     ImageCopyTexture {
@@ -286,7 +440,7 @@ impl FontTextureCache {
                         origin: Origin3d {
                             x: 0,
                             y: 0,
-                            z: i as u32,
+                            z: *layer,
                         },
                         aspect: TextureAspect::All,
                     },
@@ -1,2 +1,4 @@
+mod diagnostics;
 mod font_texture_cache;
+pub use diagnostics::*;
 pub use font_texture_cache::*;
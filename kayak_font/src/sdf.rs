@@ -47,6 +47,10 @@ impl Sdf {
         value
     }
 
+    pub fn kerning(&self) -> &[KerningData] {
+        &self.kerning
+    }
+
     pub fn max_glyph_size(&self) -> (f32, f32) {
         let mut size = (0.0, 0.0);
         self.glyphs.iter().for_each(|glyph| {
@@ -0,0 +1,143 @@
+use crate::KayakFont;
+
+/// A glyph positioned by the shaper
+///
+/// All values are in em units and need to be multiplied by the font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapedGlyph {
+    /// The atlas glyph to draw (or `'\n'` for a line break)
+    pub unicode: char,
+    /// How far to move the pen after this glyph
+    pub advance: f32,
+    /// The offset of the glyph from the pen position, with positive y pointing up
+    pub offset: (f32, f32),
+}
+
+impl KayakFont {
+    /// Shapes a run of text into positioned glyphs
+    ///
+    /// When the font has face data (see [KayakFont::set_face_data]) and the `shaping` feature is
+    /// enabled, the text is shaped with rustybuzz, which applies kerning, ligatures, and the rules
+    /// of complex scripts. Glyphs the shaper produces which aren't in the atlas (e.g. ligatures the
+    /// atlas wasn't generated with) fall back to the characters they were made from.
+    ///
+    /// Otherwise, glyphs are placed by their advances, adjusted by the atlas's kerning table.
+    pub fn shape(&self, text: &str) -> Vec<ShapedGlyph> {
+        #[cfg(feature = "shaping")]
+        if let Some(shaped) = self.shape_with_face(text) {
+            return shaped;
+        }
+
+        self.shape_by_advance(text)
+    }
+
+    /// The width of the shaped text (in pixels)
+    pub fn get_shaped_width(&self, text: &str, font_size: f32) -> f32 {
+        self.shape(text)
+            .iter()
+            .map(|glyph| glyph.advance * font_size)
+            .sum()
+    }
+
+    fn shape_by_advance(&self, text: &str) -> Vec<ShapedGlyph> {
+        let mut shaped: Vec<ShapedGlyph> = Vec::new();
+        let mut previous: Option<char> = None;
+        for c in text.chars() {
+            if c == '\n' {
+                shaped.push(ShapedGlyph {
+                    unicode: c,
+                    advance: 0.0,
+                    offset: (0.0, 0.0),
+                });
+                previous = None;
+                continue;
+            }
+
+            if let Some(glyph) = self.get_glyph(c) {
+                if let (Some(previous), Some(last)) = (previous, shaped.last_mut()) {
+                    last.advance += self.get_kerning(previous, c);
+                }
+                shaped.push(ShapedGlyph {
+                    unicode: c,
+                    advance: glyph.advance,
+                    offset: (0.0, 0.0),
+                });
+                previous = Some(c);
+            }
+        }
+
+        shaped
+    }
+
+    #[cfg(feature = "shaping")]
+    fn shape_with_face(&self, text: &str) -> Option<Vec<ShapedGlyph>> {
+        let face_data = self.face_data.as_ref()?;
+        let face = rustybuzz::Face::from_slice(face_data, 0)?;
+        let units_per_em = face.units_per_em() as f32;
+
+        // Shape each line separately so line breaks survive
+        let mut shaped = Vec::new();
+        for (line_index, line) in text.split('\n').enumerate() {
+            if line_index > 0 {
+                shaped.push(ShapedGlyph {
+                    unicode: '\n',
+                    advance: 0.0,
+                    offset: (0.0, 0.0),
+                });
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut buffer = rustybuzz::UnicodeBuffer::new();
+            buffer.push_str(line);
+            let output = rustybuzz::shape(&face, &[], buffer);
+
+            let infos = output.glyph_infos();
+            let positions = output.glyph_positions();
+            let mut fallback_cluster = None;
+            for (info, position) in infos.iter().zip(positions) {
+                if let Some(unicode) = self.glyph_ids.get(&info.glyph_id) {
+                    shaped.push(ShapedGlyph {
+                        unicode: *unicode,
+                        advance: position.x_advance as f32 / units_per_em,
+                        offset: (
+                            position.x_offset as f32 / units_per_em,
+                            position.y_offset as f32 / units_per_em,
+                        ),
+                    });
+                } else if fallback_cluster != Some(info.cluster) {
+                    // Not in the atlas, so draw the cluster's characters individually instead
+                    fallback_cluster = Some(info.cluster);
+                    let start = info.cluster as usize;
+                    let end = infos
+                        .iter()
+                        .map(|info| info.cluster as usize)
+                        .filter(|cluster| *cluster > start)
+                        .min()
+                        .unwrap_or(line.len());
+                    shaped.extend(self.shape_by_advance(&line[start..end]));
+                }
+            }
+        }
+
+        Some(shaped)
+    }
+}
+
+#[test]
+fn test_shape_by_advance() {
+    use crate::Sdf;
+    let sdf = Sdf::from_string(include_str!("../assets/roboto.kayak_font").to_string());
+    #[cfg(feature = "bevy_renderer")]
+    let mut font = KayakFont::new(sdf, Default::default());
+    #[cfg(not(feature = "bevy_renderer"))]
+    let mut font = KayakFont::new(sdf);
+    font.generate_char_ids();
+
+    let shaped = font.shape_by_advance("AV\nA");
+    let unicodes: Vec<char> = shaped.iter().map(|glyph| glyph.unicode).collect();
+    assert_eq!(unicodes, vec!['A', 'V', '\n', 'A']);
+    assert_eq!(shaped[0].advance, font.get_glyph('A').unwrap().advance);
+    assert_eq!(shaped[2].advance, 0.0);
+}
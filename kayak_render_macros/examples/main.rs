@@ -33,6 +33,18 @@ impl Widget for Test {
         todo!()
     }
 
+    fn set_styles(&mut self, _styles: Option<Style>) {
+        todo!()
+    }
+
+    fn set_children(&mut self, _children: Children) {
+        todo!()
+    }
+
+    fn set_on_event(&mut self, _on_event: Option<kayak_core::OnEvent>) {
+        todo!()
+    }
+
     fn get_name(&self) -> String {
         todo!()
     }
@@ -147,7 +147,7 @@ pub fn create_function_widget(f: syn::ItemFn, widget_arguments: WidgetArguments)
 
     if !input_names
         .iter()
-        .any(|item_name| item_name.to_string().contains("children"))
+        .any(|item_name| item_name.to_string() == "children")
     {
         input_names.push(quote! {
             children
@@ -192,6 +192,18 @@ pub fn create_function_widget(f: syn::ItemFn, widget_arguments: WidgetArguments)
                 self.styles.clone()
             }
 
+            fn set_styles(&mut self, styles: Option<#kayak_core::styles::Style>) {
+                self.styles = styles;
+            }
+
+            fn set_children(&mut self, children: #kayak_core::Children) {
+                self.children = children;
+            }
+
+            fn set_on_event(&mut self, on_event: Option<#kayak_core::OnEvent>) {
+                self.on_event = on_event;
+            }
+
             fn get_name(&self) -> String {
                 String::from(stringify!(#struct_name))
             }
@@ -206,6 +218,7 @@ pub fn create_function_widget(f: syn::ItemFn, widget_arguments: WidgetArguments)
 
             fn render(&mut self, context: &mut #kayak_core::context::KayakContext) {
                 let parent_id = self.get_id();
+                let render_depth = context.begin_widget_render();
                 context.set_current_id(parent_id);
                 let parent_id = Some(parent_id);
                 #inputs_reading_ref
@@ -227,6 +240,8 @@ pub fn create_function_widget(f: syn::ItemFn, widget_arguments: WidgetArguments)
                     .widget_manager
                     .tree
                     .merge(&tree, self.get_id(), changes);
+
+                context.end_widget_render(render_depth, self.get_id(), &self.get_name());
             }
         }
     })
@@ -1,27 +1,19 @@
 extern crate proc_macro;
 
 mod function_component;
-mod tags;
-
-mod arc_function;
-mod attribute;
-mod child;
-mod children;
 mod partial_eq;
+mod ui_form;
 mod use_effect;
-mod widget;
-mod widget_attributes;
 
 use function_component::WidgetArguments;
+use kayak_rsx::{ConstructedWidget, Widget};
 use partial_eq::impl_dyn_partial_eq;
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 use quote::quote;
 use syn::{parse_macro_input, parse_quote};
+use ui_form::impl_ui_form;
 use use_effect::UseEffect;
-use widget::ConstructedWidget;
-
-use crate::widget::Widget;
 
 #[proc_macro]
 #[proc_macro_error]
@@ -90,6 +82,46 @@ pub fn dyn_partial_eq_macro_derive(input: TokenStream) -> TokenStream {
     impl_dyn_partial_eq(&ast)
 }
 
+/// Generates a form of bindings for a struct, one per field
+///
+/// For a struct `Options`, this generates an `OptionsForm` struct holding a
+/// [Binding](kayak_core::Binding) for each field, with:
+///
+/// * `from_struct` and `to_struct`, to create the bindings from a struct and collect their values
+///   back into one
+/// * `set_struct`, to load a struct into existing bindings (e.g. to reset an options screen)
+/// * `bind`, to bind all of them to the current widget
+/// * An `on_<field>_change` handler per field, which sets its binding and can be passed to inputs
+///   like `Checkbox` and `Slider`
+///
+/// It also implements [UiForm](kayak_core::UiForm) for the struct. The fields must implement
+/// `Clone` and `PartialEq`.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_core::Bound;
+/// # use kayak_render_macros::UiForm;
+///
+/// #[derive(Clone, PartialEq, UiForm)]
+/// struct Options {
+///     vsync: bool,
+///     volume: f32,
+/// }
+///
+/// let form = OptionsForm::from_struct(&Options { vsync: true, volume: 0.8 });
+/// form.on_volume_change().call(0.5);
+/// assert_eq!(0.5, form.volume.get());
+/// assert_eq!(0.5, form.to_struct().volume);
+/// ```
+#[proc_macro_derive(UiForm)]
+#[proc_macro_error]
+pub fn ui_form_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+
+    impl_ui_form(&ast)
+}
+
 #[proc_macro_attribute]
 pub fn dyn_partial_eq(_: TokenStream, input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as syn::ItemTrait);
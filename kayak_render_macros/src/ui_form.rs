@@ -0,0 +1,146 @@
+use proc_macro::TokenStream;
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+pub fn impl_ui_form(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let form_name = format_ident!("{}Form", name);
+
+    if !ast.generics.params.is_empty() {
+        abort!(
+            ast.generics.span(),
+            "UiForm can't be derived for generic structs"
+        );
+    }
+    let fields = match &ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => abort!(
+            ast.ident.span(),
+            "UiForm can only be derived for structs with named fields"
+        ),
+    };
+
+    let found_crate = proc_macro_crate::crate_name("kayak_core");
+    let kayak_core = if let Ok(found_crate) = found_crate {
+        match found_crate {
+            proc_macro_crate::FoundCrate::Itself => quote! { crate },
+            proc_macro_crate::FoundCrate::Name(name) => {
+                let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+                quote!(#ident)
+            }
+        }
+    } else {
+        quote!(kayak_ui::core)
+    };
+
+    let field_names = fields
+        .iter()
+        .map(|field| field.ident.clone().unwrap())
+        .collect::<Vec<_>>();
+    let field_visibilities = fields.iter().map(|field| &field.vis).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let change_handlers = field_names
+        .iter()
+        .map(|field| format_ident!("on_{}_change", field))
+        .collect::<Vec<_>>();
+
+    let form_doc = format!(
+        "The bindings of a [{}], one per field, generated by `#[derive(UiForm)]`",
+        name
+    );
+    let handler_docs = field_names
+        .iter()
+        .map(|field| format!("A change handler that sets the `{}` binding", field))
+        .collect::<Vec<_>>();
+
+    let gen = quote! {
+        #[doc = #form_doc]
+        #[derive(Clone)]
+        #vis struct #form_name {
+            #(
+                #field_visibilities #field_names: #kayak_core::Binding<#field_types>,
+            )*
+        }
+
+        impl #form_name {
+            /// Creates bindings holding the values of the given struct
+            pub fn from_struct(value: &#name) -> Self {
+                Self {
+                    #(
+                        #field_names: #kayak_core::bind(::core::clone::Clone::clone(&value.#field_names)),
+                    )*
+                }
+            }
+
+            /// Collects the current values of the bindings
+            pub fn to_struct(&self) -> #name {
+                #name {
+                    #(
+                        #field_names: #kayak_core::Bound::get(&self.#field_names),
+                    )*
+                }
+            }
+
+            /// Sets the bindings to the values of the given struct
+            ///
+            /// Only the bindings whose value changed notify their widgets.
+            pub fn set_struct(&self, value: &#name) {
+                #(
+                    #kayak_core::MutableBound::set(
+                        &self.#field_names,
+                        ::core::clone::Clone::clone(&value.#field_names),
+                    );
+                )*
+            }
+
+            /// Binds all the bindings to the current widget, so it re-renders when any of them
+            /// change
+            pub fn bind(&self, context: &mut #kayak_core::KayakContext) {
+                #(
+                    context.bind(&self.#field_names);
+                )*
+            }
+
+            #(
+                #[doc = #handler_docs]
+                pub fn #change_handlers(&self) -> #kayak_core::Handler<#field_types> {
+                    let binding = ::core::clone::Clone::clone(&self.#field_names);
+                    #kayak_core::Handler::new(move |value| {
+                        #kayak_core::MutableBound::set(&binding, value);
+                    })
+                }
+            )*
+        }
+
+        // Forms are the same if they hold the same bindings
+        impl ::core::cmp::PartialEq for #form_name {
+            fn eq(&self, other: &Self) -> bool {
+                true #( && self.#field_names.id == other.#field_names.id )*
+            }
+        }
+
+        impl ::core::fmt::Debug for #form_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!(#form_name)).finish()
+            }
+        }
+
+        impl #kayak_core::UiForm for #name {
+            type Form = #form_name;
+
+            fn to_form(&self) -> Self::Form {
+                #form_name::from_struct(self)
+            }
+
+            fn from_form(form: &Self::Form) -> Self {
+                form.to_struct()
+            }
+        }
+    };
+    gen.into()
+}
@@ -0,0 +1,18 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use quote::ToTokens;
+
+// Run with `cargo fuzz run parse_rsx` from the `kayak_rsx` directory
+fuzz_target!(|data: &[u8]| {
+    let tokens = match std::str::from_utf8(data).map(str::parse::<proc_macro2::TokenStream>) {
+        Ok(Ok(tokens)) => tokens,
+        _ => return,
+    };
+    // Malformed rsx should be reported as an error, never panic
+    if let Ok(widget) = kayak_rsx::parse_rsx(tokens.clone()) {
+        let _ = widget.to_token_stream();
+    }
+    if let Ok(constructed) = kayak_rsx::parse_constructor(tokens) {
+        let _ = constructed.widget.to_token_stream();
+    }
+});
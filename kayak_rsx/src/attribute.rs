@@ -22,20 +22,6 @@ impl Attribute {
         }
     }
 
-    pub fn value_tokens(&self) -> proc_macro2::TokenStream {
-        match self {
-            Self::WithValue(_, value) => {
-                if value.stmts.len() == 1 {
-                    let first = &value.stmts[0];
-                    quote!(#first)
-                } else {
-                    quote!(#value)
-                }
-            }
-            Self::Punned(ident) => quote!(#ident),
-        }
-    }
-
     pub fn idents(&self) -> Vec<&syn::Ident> {
         self.ident().iter().collect::<Vec<_>>()
     }
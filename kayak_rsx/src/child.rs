@@ -0,0 +1,103 @@
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::spanned::Spanned;
+
+use crate::ir::{block_to_expr, ChildDescriptor};
+use crate::widget::Widget;
+
+#[derive(Debug, Clone)]
+pub enum Child {
+    Widget(Widget),
+    RawBlock(syn::Block),
+    /// A closure receiving data from the parent widget: `{|item: String, index: usize| <Text ... />}`
+    RenderClosure(RenderClosure),
+}
+
+/// Children rendered with data passed by their parent widget, which set its `render_children` prop
+#[derive(Debug, Clone)]
+pub struct RenderClosure {
+    /// The typed inputs of the closure
+    pub inputs: Vec<syn::PatType>,
+    /// The children of the closure's body
+    pub nodes: Vec<Child>,
+}
+
+impl Child {
+    pub fn to_descriptor(&self) -> ChildDescriptor {
+        match self {
+            Self::Widget(widget) => ChildDescriptor::Widget(widget.to_descriptor()),
+            Self::RawBlock(block) => ChildDescriptor::Expr(block_to_expr(block.clone())),
+            Self::RenderClosure(closure) => ChildDescriptor::Render {
+                inputs: closure.inputs.clone(),
+                children: closure.nodes.iter().map(Child::to_descriptor).collect(),
+            },
+        }
+    }
+}
+
+impl ToTokens for Child {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.to_descriptor().to_tokens(tokens);
+    }
+}
+
+impl Parse for Child {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Checking the first token (rather than falling back to a block when the widget fails to
+        // parse) keeps the widget's error instead of reporting a missing block
+        if input.peek(syn::Token![<]) {
+            Ok(Self::Widget(Widget::custom_parse(input, true)?))
+        } else if starts_with_closure(input) {
+            Ok(Self::RenderClosure(input.parse::<RenderClosure>()?))
+        } else {
+            Ok(Self::RawBlock(input.parse::<syn::Block>()?))
+        }
+    }
+}
+
+/// Checks if the next child is a block holding a closure (`{|item: String| ...}`)
+fn starts_with_closure(input: ParseStream) -> bool {
+    input
+        .cursor()
+        .group(proc_macro2::Delimiter::Brace)
+        .and_then(|(content, _, _)| content.punct())
+        .map_or(false, |(punct, _)| punct.as_char() == '|')
+}
+
+impl Parse for RenderClosure {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        syn::braced!(content in input);
+
+        content.parse::<syn::Token![|]>()?;
+        let mut inputs = Vec::new();
+        while !content.peek(syn::Token![|]) {
+            let pat = content.parse::<syn::Pat>()?;
+            if !content.peek(syn::Token![:]) {
+                return Err(syn::Error::new(
+                    pat.span(),
+                    "The inputs of a render closure need a type (e.g. `|item: String|`)",
+                ));
+            }
+            inputs.push(syn::PatType {
+                attrs: Vec::new(),
+                pat: Box::new(pat),
+                colon_token: content.parse()?,
+                ty: Box::new(content.parse()?),
+            });
+
+            if !content.peek(syn::Token![,]) {
+                break;
+            }
+            content.parse::<syn::Token![,]>()?;
+        }
+        content.parse::<syn::Token![|]>()?;
+
+        let mut nodes = Vec::new();
+        while !content.is_empty() {
+            nodes.push(content.parse::<Child>()?);
+        }
+
+        Ok(Self { inputs, nodes })
+    }
+}
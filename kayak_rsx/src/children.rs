@@ -0,0 +1,40 @@
+use crate::{
+    child::Child,
+    ir::{children_tokens, ChildDescriptor},
+};
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+
+#[derive(Debug, Clone)]
+pub struct Children {
+    pub nodes: Vec<Child>,
+}
+
+impl Children {
+    pub fn new(nodes: Vec<Child>) -> Self {
+        Children { nodes }
+    }
+
+    pub fn to_descriptors(&self) -> Vec<ChildDescriptor> {
+        self.nodes.iter().map(Child::to_descriptor).collect()
+    }
+}
+
+impl Parse for Children {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut nodes = vec![];
+
+        while !input.peek(syn::Token![<]) || !input.peek2(syn::Token![/]) {
+            let child = input.parse::<Child>()?;
+            nodes.push(child);
+        }
+
+        Ok(Self::new(nodes))
+    }
+}
+
+impl ToTokens for Children {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        children_tokens(&self.to_descriptors()).to_tokens(tokens);
+    }
+}
@@ -0,0 +1,416 @@
+//! The widget descriptor tree that `rsx!` lowers to before generating code
+//!
+//! A [WidgetDescriptor] describes a widget by its type, its props, and its children, without any
+//! of the rsx syntax. Frontends other than `rsx!` (e.g. a builder API, a templating engine, or
+//! widgets loaded from YAML) can build descriptors themselves and generate the same code as the
+//! macros do, through [WidgetDescriptor::to_render_tokens] and
+//! [WidgetDescriptor::to_constructor_tokens].
+//!
+//! The descriptors are marked `#[non_exhaustive]` so they can grow without breaking frontends:
+//! build them with [WidgetDescriptor::new] and its builder methods.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::arc_function::build_arc_function;
+
+/// A widget to create, with its props and children
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct WidgetDescriptor {
+    /// The type of the widget (e.g. `Button` or `kayak_ui::widgets::Button`)
+    pub name: syn::Path,
+    /// The props set on the widget, in order. The others keep their default value.
+    pub props: Vec<PropDescriptor>,
+    /// The children of the widget, in order
+    pub children: Vec<ChildDescriptor>,
+}
+
+/// A prop set on a widget
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct PropDescriptor {
+    pub name: syn::Ident,
+    pub value: syn::Expr,
+}
+
+/// A child of a widget
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ChildDescriptor {
+    /// A nested widget
+    Widget(WidgetDescriptor),
+    /// An expression evaluating to children, like the `children` of the enclosing widget
+    Expr(syn::Expr),
+    /// Children rendered with data passed by the widget (its `render_children` prop), as a closure
+    /// taking the given inputs. A widget with a render closure can't have other children.
+    Render {
+        inputs: Vec<syn::PatType>,
+        children: Vec<ChildDescriptor>,
+    },
+}
+
+impl WidgetDescriptor {
+    /// Describes a widget of the given type, without any props or children
+    pub fn new(name: syn::Path) -> Self {
+        Self {
+            name,
+            props: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets a prop, replacing its previous value (if any)
+    pub fn with_prop(mut self, name: syn::Ident, value: syn::Expr) -> Self {
+        match self.props.iter_mut().find(|prop| prop.name == name) {
+            Some(prop) => prop.value = value,
+            None => self.props.push(PropDescriptor::new(name, value)),
+        }
+        self
+    }
+
+    /// Appends a child
+    pub fn with_child(mut self, child: impl Into<ChildDescriptor>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Generates the code creating and rendering this widget within its parent, as `rsx!` does
+    ///
+    /// The code expects `context`, `parent_id`, `tree` and `children` to be in scope, as they are
+    /// within the render function of a widget.
+    pub fn to_render_tokens(&self) -> TokenStream {
+        build_arc_function(
+            quote! { built_widget },
+            self.to_constructor_tokens(),
+            true,
+            0,
+            true,
+        )
+    }
+
+    /// Generates the expression constructing this widget (without rendering it), as
+    /// `constructor!` does
+    pub fn to_constructor_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        let mut props = self
+            .props
+            .iter()
+            .map(|prop| {
+                let PropDescriptor { name, value } = prop;
+                quote! { #name: #value }
+            })
+            .collect::<Vec<_>>();
+
+        match self.render_closure() {
+            Some(render) if self.children.len() == 1 => {
+                props.push(quote! { children: None });
+                props.push(quote! { render_children: #render });
+            }
+            Some(_) => props.push(quote! {
+                children: compile_error!("A render closure must be the only child of its widget")
+            }),
+            None => {
+                let children = children_tokens(&self.children);
+                props.push(quote! { children: #children });
+            }
+        }
+
+        for missing in ["styles", "on_event"] {
+            if !self.props.iter().any(|prop| prop.name == missing) {
+                let missing = syn::Ident::new(missing, proc_macro2::Span::call_site());
+                props.push(quote! { #missing: None });
+            }
+        }
+
+        quote! { #name { #(#props),*, ..Default::default() } }
+    }
+
+    /// The render closure among the children of this widget, if any
+    fn render_closure(&self) -> Option<&ChildDescriptor> {
+        self.children
+            .iter()
+            .find(|child| matches!(child, ChildDescriptor::Render { .. }))
+    }
+
+    /// The variables used by the props of this widget and its descendants, which the generated
+    /// closures clone
+    fn clonable_variables(&self) -> Vec<TokenStream> {
+        let mut tokens = self
+            .props
+            .iter()
+            .filter_map(|prop| variable_of(&prop.value))
+            .collect::<Vec<_>>();
+
+        for child in self.children.iter() {
+            tokens.extend(clonable_variables(child));
+        }
+
+        tokens.dedup_by(|a, b| a.to_string().eq(&b.to_string()));
+        tokens
+    }
+}
+
+impl PropDescriptor {
+    pub fn new(name: syn::Ident, value: syn::Expr) -> Self {
+        Self { name, value }
+    }
+}
+
+impl From<WidgetDescriptor> for ChildDescriptor {
+    fn from(widget: WidgetDescriptor) -> Self {
+        Self::Widget(widget)
+    }
+}
+
+impl From<syn::Expr> for ChildDescriptor {
+    fn from(expr: syn::Expr) -> Self {
+        Self::Expr(expr)
+    }
+}
+
+impl ToTokens for ChildDescriptor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Widget(widget) => widget.to_constructor_tokens().to_tokens(tokens),
+            Self::Expr(expr) => expr.to_tokens(tokens),
+            Self::Render { inputs, children } => {
+                render_children_tokens(inputs, children).to_tokens(tokens)
+            }
+        }
+    }
+}
+
+/// Converts the block of an rsx attribute or child (e.g. `{Some(styles)}`) into the expression it
+/// holds
+pub(crate) fn block_to_expr(block: syn::Block) -> syn::Expr {
+    if let [syn::Stmt::Expr(expr)] = block.stmts.as_slice() {
+        return expr.clone();
+    }
+
+    syn::Expr::Block(syn::ExprBlock {
+        attrs: Vec::new(),
+        label: None,
+        block,
+    })
+}
+
+/// Finds the variable used by the given prop value, if it's a variable (`styles`), a call on
+/// variables (`Some(styles)`), or a block starting with one of those
+fn variable_of(expr: &syn::Expr) -> Option<TokenStream> {
+    match expr {
+        syn::Expr::Call(call) => Some(call.args.to_token_stream()),
+        syn::Expr::Path(path) => Some(path.to_token_stream()),
+        syn::Expr::Block(block) => match block.block.stmts.first() {
+            Some(syn::Stmt::Expr(syn::Expr::Call(call))) => Some(call.args.to_token_stream()),
+            Some(syn::Stmt::Expr(syn::Expr::Path(path))) => Some(path.to_token_stream()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The path of the `kayak_core` crate, as seen by the crate using the macros
+fn kayak_core_path() -> TokenStream {
+    let found_crate = proc_macro_crate::crate_name("kayak_core");
+    if let Ok(found_crate) = found_crate {
+        match found_crate {
+            proc_macro_crate::FoundCrate::Itself => quote! { crate },
+            proc_macro_crate::FoundCrate::Name(name) => {
+                let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+                quote!(#ident)
+            }
+        }
+    } else {
+        quote!(kayak_ui::core)
+    }
+}
+
+/// Generates the `children` prop of a widget: `None`, or a closure creating the children
+pub(crate) fn children_tokens(children: &[ChildDescriptor]) -> TokenStream {
+    let kayak_core = kayak_core_path();
+
+    let children_quotes: Vec<_> = children.iter().map(|child| quote! { #child }).collect();
+
+    match children_quotes.len() {
+        0 => return quote! { None },
+        1 => {
+            if children_quotes[0].to_string() == "{ }" {
+                return quote! { None };
+            }
+            if children_quotes[0].to_string() == "children" {
+                return quote! {
+                    #(#children_quotes)*.clone()
+                };
+            }
+        }
+        _ => {}
+    }
+
+    let body = children_body(children);
+    quote! {
+        Some(std::sync::Arc::new(move |tree: #kayak_core::WidgetTree, parent_id: Option<#kayak_core::Index>, context: &mut #kayak_core::context::KayakContext| {
+            #body
+        }))
+    }
+}
+
+/// Generates the `render_children` prop of a widget: a closure creating the children from the
+/// data passed by the widget
+///
+/// Several inputs receive the fields of a tuple, so `|item: String, index: usize|` takes a
+/// `(String, usize)`.
+fn render_children_tokens(inputs: &[syn::PatType], children: &[ChildDescriptor]) -> TokenStream {
+    let kayak_core = kayak_core_path();
+
+    let data = match inputs {
+        [] => quote! { _: () },
+        [input] => quote! { #input },
+        inputs => {
+            let pats = inputs.iter().map(|input| &input.pat);
+            let tys = inputs.iter().map(|input| &input.ty);
+            quote! { (#(#pats),*): (#(#tys),*) }
+        }
+    };
+
+    let body = if children.is_empty() {
+        quote! {}
+    } else {
+        children_body(children)
+    };
+
+    quote! {
+        Some(#kayak_core::RenderChildren::new(move |#data, tree: #kayak_core::WidgetTree, parent_id: Option<#kayak_core::Index>, context: &mut #kayak_core::context::KayakContext| {
+            #body
+        }))
+    }
+}
+
+/// Generates the body of the closure creating the given children, which expects `tree`,
+/// `parent_id` and `context` to be in scope
+fn children_body(children: &[ChildDescriptor]) -> TokenStream {
+    let children_quotes: Vec<_> = children.iter().map(|child| quote! { #child }).collect();
+
+    if children_quotes.len() == 1 {
+        // I think this is correct.. It needs more testing though..
+        let clonable_children = clonable_variables(&children[0])
+            .into_iter()
+            .filter(|ts| syn::parse_str::<syn::Path>(&ts.to_string()).is_ok())
+            .collect::<Vec<_>>();
+
+        let cloned_attrs = quote! {
+            #(let #clonable_children = #clonable_children.clone();)*;
+        };
+        let children_builder = build_arc_function(
+            quote! { child_widget },
+            quote! { #(#children_quotes),* },
+            true,
+            0,
+            true,
+        );
+
+        return quote! {
+            #cloned_attrs
+            #children_builder
+        };
+    }
+
+    // First get shared and non-shared attributes..
+    let mut all_attributes = HashSet::new();
+    for child in children {
+        // I think this is correct.. It needs more testing though..
+        for ts in clonable_variables(child) {
+            if syn::parse_str::<syn::Path>(&ts.to_string()).is_ok() {
+                all_attributes.insert(ts.to_string());
+            }
+        }
+    }
+
+    all_attributes.insert("children".to_string());
+
+    let base_matching: Vec<TokenStream> = all_attributes
+        .iter()
+        .map(|a| format!("base_{}", a).parse().unwrap())
+        .collect();
+
+    let all_attributes: Vec<TokenStream> =
+        all_attributes.iter().map(|a| a.parse().unwrap()).collect();
+
+    let base_clone = quote! {
+        #(let #base_matching = #all_attributes.clone();)*
+    };
+
+    let base_clones_inner = quote! {
+        #(let #all_attributes = #base_matching.clone();)*
+    };
+
+    let mut output = Vec::new();
+    output.push(quote! { #base_clone });
+    for (i, child) in children_quotes.iter().enumerate() {
+        output.push(quote! { #base_clones_inner });
+        let name: TokenStream = format!("child{}", i).parse().unwrap();
+        let child = build_arc_function(quote! { #name }, child.clone(), true, i, true);
+        output.push(quote! { #child });
+    }
+
+    quote! {
+        #(#output)*
+    }
+}
+
+fn clonable_variables(child: &ChildDescriptor) -> Vec<TokenStream> {
+    match child {
+        ChildDescriptor::Widget(widget) => widget.clonable_variables(),
+        ChildDescriptor::Expr(_) => Vec::new(),
+        // The inputs of the closure are its own, rather than variables to clone into it
+        ChildDescriptor::Render { inputs, children } => {
+            let inputs = inputs
+                .iter()
+                .map(|input| input.pat.to_token_stream().to_string())
+                .collect::<Vec<_>>();
+            children
+                .iter()
+                .flat_map(clonable_variables)
+                .filter(|variable| !inputs.contains(&variable.to_string()))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{ChildDescriptor, WidgetDescriptor};
+    use crate::parse_rsx;
+
+    #[test]
+    fn built_descriptors_should_match_parsed_rsx() {
+        let tokens = r#"
+            <Background styles={Some(styles)}>
+                <Text content={"Hello".to_string()} size={16.0} />
+                {children}
+            </Background>
+        "#
+        .parse()
+        .unwrap();
+        let parsed = parse_rsx(tokens).unwrap().to_descriptor();
+
+        let built = WidgetDescriptor::new(parse_quote!(Background))
+            .with_prop(parse_quote!(styles), parse_quote!(Some(styles)))
+            .with_child(
+                WidgetDescriptor::new(parse_quote!(Text))
+                    .with_prop(parse_quote!(content), parse_quote!("Hello".to_string()))
+                    .with_prop(parse_quote!(size), parse_quote!(16.0)),
+            )
+            .with_child(ChildDescriptor::Expr(parse_quote!(children)));
+
+        assert_eq!(built, parsed);
+        assert_eq!(
+            built.to_render_tokens().to_string(),
+            parsed.to_render_tokens().to_string()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! The parser behind the `rsx!` and `constructor!` macros of `kayak_render_macros`
+//!
+//! Proc-macro crates can only export macros, so the parser lives here, where it can be called on
+//! any token stream. This makes it possible to unit-test and fuzz it (see the `fuzz` directory)
+//! outside of a macro expansion. Malformed input is reported as a [syn::Error] rather than a
+//! panic, which the macros turn into a compile error.
+//!
+//! Parsed rsx is lowered to a tree of [WidgetDescriptor]s, which code is generated from. Other
+//! frontends can build descriptors directly to generate the same code as the macros (see the
+//! [ir] module).
+
+mod arc_function;
+mod attribute;
+mod child;
+mod children;
+pub mod ir;
+mod tags;
+mod widget;
+mod widget_attributes;
+
+use proc_macro2::TokenStream;
+
+pub use ir::{ChildDescriptor, PropDescriptor, WidgetDescriptor};
+pub use widget::{ConstructedWidget, Widget};
+
+/// Parses the contents of an `rsx!` macro
+///
+/// The returned widget expands into the code creating the widget tree (through
+/// [ToTokens](quote::ToTokens)).
+pub fn parse_rsx(tokens: TokenStream) -> syn::Result<Widget> {
+    syn::parse2(tokens)
+}
+
+/// Parses the contents of a `constructor!` macro
+pub fn parse_constructor(tokens: TokenStream) -> syn::Result<ConstructedWidget> {
+    syn::parse2(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::{parse_rsx, ChildDescriptor};
+
+    fn parse_error(source: &str) -> String {
+        let tokens = source.parse().unwrap();
+        parse_rsx(tokens).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn valid_rsx_should_parse() {
+        let tokens = r#"
+            <Background styles={Some(styles)}>
+                <Text content={"Hello".to_string()} size={16.0} />
+                {children}
+            </Background>
+        "#
+        .parse()
+        .unwrap();
+        let widget = parse_rsx(tokens).unwrap();
+        assert_eq!(2, widget.children.nodes.len());
+        assert!(!widget.to_token_stream().is_empty());
+    }
+
+    #[test]
+    fn render_closures_should_set_the_render_children() {
+        let tokens = r#"
+            <List items={items}>
+                {|item: String, index: usize| <Text content={item} />}
+            </List>
+        "#
+        .parse()
+        .unwrap();
+        let descriptor = parse_rsx(tokens).unwrap().to_descriptor();
+        assert!(matches!(
+            descriptor.children.as_slice(),
+            [ChildDescriptor::Render { inputs, children }] if inputs.len() == 2 && children.len() == 1
+        ));
+
+        let tokens = descriptor.to_constructor_tokens().to_string();
+        assert!(tokens.contains("render_children"));
+        assert!(tokens.contains("children : None"));
+
+        assert!(parse_error("<List>{|item| <Text />}</List>").contains("need a type"));
+    }
+
+    #[test]
+    fn malformed_rsx_should_return_errors() {
+        assert_eq!(
+            "Expected closing tag for: <Background>",
+            parse_error("<Background></Element>")
+        );
+        assert_eq!(
+            "There is a previous definition of the size attribute",
+            parse_error("<Text size={1.0} size={2.0} />")
+        );
+        assert!(parse_error("<Text font-size={1.0} />").starts_with("Invalid attribute"));
+        assert!(parse_error("<div />").contains("lowercase"));
+        assert!(!parse_error("<Background><Text />").is_empty());
+        assert!(!parse_error("<Background><Text /></").is_empty());
+        assert!(!parse_error("").is_empty());
+    }
+}
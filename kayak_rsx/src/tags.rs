@@ -1,5 +1,4 @@
 use crate::widget_attributes::WidgetAttributes;
-use proc_macro_error::abort;
 use quote::quote;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::spanned::Spanned;
@@ -26,18 +25,22 @@ fn name_or_fragment(maybe_name: Result<syn::Path>) -> syn::Path {
     };
 
     maybe_name.unwrap_or_else(|_| {
-        syn::parse_str::<syn::Path>(&format!("::{}::Fragment", kayak_core)).unwrap()
+        if kayak_core.to_string() == "crate" {
+            syn::parse_quote!(crate::Fragment)
+        } else {
+            syn::parse_quote!(::#kayak_core::Fragment)
+        }
     })
 }
 
-fn is_custom_element_name(path: &syn::Path) -> bool {
+pub(crate) fn is_custom_element_name(path: &syn::Path) -> bool {
     match path.get_ident() {
         None => true,
-        Some(ident) => {
-            let name = ident.to_string();
-            let first_letter = name.get(0..1).unwrap();
-            first_letter.to_uppercase() == first_letter
-        }
+        Some(ident) => ident
+            .to_string()
+            .chars()
+            .next()
+            .map_or(true, |first_letter| !first_letter.is_lowercase()),
     }
 }
 
@@ -65,18 +68,18 @@ pub struct ClosingTag {
 }
 
 impl ClosingTag {
-    pub fn validate(&self, open_tag: &OpenTag) {
+    pub fn validate(&self, open_tag: &OpenTag) -> Result<()> {
         let open_tag_path = &open_tag.name;
         let open_tag_path_str = quote!(#open_tag_path).to_string();
         let self_path = &self.name;
         let self_path_str = quote!(#self_path).to_string();
         if self_path_str != open_tag_path_str {
-            abort!(
+            return Err(syn::Error::new(
                 self.name.span(),
-                "Expected closing tag for: <{}>",
-                &open_tag_path_str
-            );
+                format!("Expected closing tag for: <{}>", &open_tag_path_str),
+            ));
         }
+        Ok(())
     }
 }
 
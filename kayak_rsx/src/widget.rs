@@ -0,0 +1,95 @@
+use quote::quote;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::spanned::Spanned;
+
+use crate::children::Children;
+use crate::ir::WidgetDescriptor;
+use crate::tags::{is_custom_element_name, ClosingTag};
+use crate::{tags::OpenTag, widget_attributes::WidgetAttributes};
+
+#[derive(Debug, Clone)]
+pub struct Widget {
+    pub name: syn::Path,
+    pub attributes: WidgetAttributes,
+    pub children: Children,
+    /// Whether the widget is only constructed (as a child or by `constructor!`) rather than
+    /// rendered
+    as_prop: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstructedWidget {
+    pub widget: Widget,
+}
+
+impl Parse for ConstructedWidget {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            widget: Widget::custom_parse(input, true)?,
+        })
+    }
+}
+
+impl Parse for Widget {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Self::custom_parse(input, false)
+    }
+}
+
+impl Widget {
+    pub fn is_custom_element(name: &syn::Path) -> bool {
+        is_custom_element_name(name)
+    }
+
+    pub fn custom_parse(input: ParseStream, as_prop: bool) -> Result<Widget> {
+        let open_tag = input.parse::<OpenTag>()?;
+
+        let children = if open_tag.self_closing {
+            Children::new(vec![])
+        } else {
+            let children = input.parse::<Children>()?;
+            let closing_tag = input.parse::<ClosingTag>()?;
+            closing_tag.validate(&open_tag)?;
+            children
+        };
+
+        let name = open_tag.name;
+        if !Self::is_custom_element(&name) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!(
+                    "Expected a widget, but `{}` starts with a lowercase letter",
+                    quote!(#name)
+                ),
+            ));
+        }
+
+        Ok(Widget {
+            name,
+            attributes: open_tag.attributes,
+            children,
+            as_prop,
+        })
+    }
+
+    /// Lowers this widget (and its children) to the descriptor tree that code is generated from
+    pub fn to_descriptor(&self) -> WidgetDescriptor {
+        WidgetDescriptor {
+            name: self.name.clone(),
+            props: self.attributes.to_descriptors(),
+            children: self.children.to_descriptors(),
+        }
+    }
+}
+
+impl ToTokens for Widget {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let descriptor = self.to_descriptor();
+        if self.as_prop {
+            descriptor.to_constructor_tokens().to_tokens(tokens);
+        } else {
+            descriptor.to_render_tokens().to_tokens(tokens);
+        }
+    }
+}
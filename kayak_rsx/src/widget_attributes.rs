@@ -0,0 +1,82 @@
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream, Result},
+    spanned::Spanned,
+};
+
+use crate::attribute::Attribute;
+use crate::ir::{block_to_expr, PropDescriptor};
+
+#[derive(Debug, Clone)]
+pub struct WidgetAttributes {
+    pub attributes: Vec<Attribute>,
+}
+
+impl WidgetAttributes {
+    pub fn new(attributes: Vec<Attribute>) -> Self {
+        Self { attributes }
+    }
+
+    /// Lowers these attributes to the props they set, in order
+    pub fn to_descriptors(&self) -> Vec<PropDescriptor> {
+        self.attributes
+            .iter()
+            .map(|attribute| match attribute {
+                Attribute::Punned(key) => {
+                    let name = key.first().unwrap().clone();
+                    PropDescriptor::new(name.clone(), syn::parse_quote!(#name))
+                }
+                Attribute::WithValue(key, block) => {
+                    PropDescriptor::new(key.first().unwrap().clone(), block_to_expr(block.clone()))
+                }
+            })
+            .collect()
+    }
+
+    pub fn custom_parse(input: ParseStream) -> Result<Self> {
+        let mut parsed_self = input.parse::<Self>()?;
+        let mut new_attributes = Vec::new();
+        let mut error: Option<syn::Error> = None;
+        for attribute in parsed_self.attributes.drain(..) {
+            match attribute.validate() {
+                Ok(attribute) => {
+                    new_attributes.push(attribute);
+                }
+                Err(err) => {
+                    let err = syn::Error::new(err.span(), format!("Invalid attribute: {}", err));
+                    match error.as_mut() {
+                        Some(error) => error.combine(err),
+                        None => error = Some(err),
+                    }
+                }
+            }
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(WidgetAttributes::new(new_attributes)),
+        }
+    }
+}
+
+impl Parse for WidgetAttributes {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut attributes: Vec<Attribute> = Vec::new();
+        while input.peek(syn::Ident::peek_any) {
+            let attribute = input.parse::<Attribute>()?;
+            let ident = attribute.ident();
+            if attributes.contains(&attribute) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "There is a previous definition of the {} attribute",
+                        quote!(#ident)
+                    ),
+                ));
+            }
+            attributes.push(attribute);
+        }
+        Ok(WidgetAttributes::new(attributes))
+    }
+}
@@ -1,6 +1,8 @@
 pub mod core {
     pub use kayak_core::*;
-    pub use kayak_render_macros::{constructor, render, rsx, use_effect, use_state, widget};
+    pub use kayak_render_macros::{
+        constructor, render, rsx, use_effect, use_state, widget, UiForm,
+    };
 }
 
 #[cfg(feature = "bevy_renderer")]
@@ -0,0 +1,172 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    widget, Bound, Children, Handler, MutableBound,
+};
+
+use crate::widgets::Fold;
+
+/// The state an [Accordion] shares with its [AccordionSection] children
+#[derive(Debug, Default, Clone, PartialEq)]
+struct AccordionState {
+    /// The keys of the expanded sections, in the order they were expanded
+    open: Vec<String>,
+    allow_multiple: bool,
+    on_section_toggled: Option<Handler<(String, bool)>>,
+}
+
+impl AccordionState {
+    /// Expands or collapses a section, returning every section whose state changed
+    fn toggle(&mut self, key: &str, is_open: bool) -> Vec<(String, bool)> {
+        let mut changed = Vec::new();
+        if is_open {
+            if self.open.iter().any(|open| open == key) {
+                return changed;
+            }
+            if !self.allow_multiple {
+                changed.extend(self.open.drain(..).map(|closed| (closed, false)));
+            }
+            self.open.push(key.to_string());
+        } else {
+            let len = self.open.len();
+            self.open.retain(|open| open != key);
+            if self.open.len() == len {
+                return changed;
+            }
+        }
+
+        changed.push((key.to_string(), is_open));
+        changed
+    }
+}
+
+/// A container of [AccordionSection] widgets, where only one section can be expanded at a time
+///
+/// Expanding a section collapses the one that was previously expanded, unless `allow_multiple`
+/// is set. Sections are identified by their `key`, so keys should be unique within an accordion.
+///
+/// # Arguments
+///
+/// * `children`: The accordion's sections
+/// * `allow_multiple`: If true, any number of sections can be expanded at once
+/// * `default_open`: The keys of the sections to expand initially
+/// * `on_section_toggled`: Called for every section the user expands or collapses. Contains the
+///   section's key and whether it's now expanded.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the accordion container.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Accordion, AccordionSection, Text};
+///
+/// rsx! {
+///     <Accordion default_open={vec!["audio".to_string()]}>
+///         <AccordionSection key={"audio".to_string()} label={"Audio".to_string()}>
+///             <Text content={"Volume".to_string()} size={14.0} />
+///         </AccordionSection>
+///         <AccordionSection key={"video".to_string()} label={"Video".to_string()}>
+///             <Text content={"Resolution".to_string()} size={14.0} />
+///         </AccordionSection>
+///     </Accordion>
+/// }
+/// ```
+#[widget]
+pub fn Accordion(
+    children: Children,
+    allow_multiple: bool,
+    default_open: Vec<String>,
+    on_section_toggled: Option<Handler<(String, bool)>>,
+) {
+    // === State === //
+    let mut initial_open = default_open.clone();
+    if !allow_multiple {
+        initial_open.truncate(1);
+    }
+    let state = context.create_provider(AccordionState {
+        open: initial_open,
+        allow_multiple,
+        on_section_toggled: on_section_toggled.clone(),
+    });
+
+    let current = state.get();
+    if current.allow_multiple != allow_multiple {
+        let mut open = current.open.clone();
+        if !allow_multiple {
+            // Keep the most recently expanded section
+            open = open.split_off(open.len().saturating_sub(1));
+        }
+        state.set(AccordionState {
+            open,
+            allow_multiple,
+            on_section_toggled,
+        });
+    }
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Auto),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// A section of an [Accordion], showing its content when expanded
+///
+/// # Arguments
+///
+/// * `key`: Identifies the section within its accordion
+/// * `label`: The text of the section's header
+/// * `children`: The section's content
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the section's container.
+#[widget]
+pub fn AccordionSection(key: String, label: String, children: Children) {
+    let state = context
+        .create_consumer::<AccordionState>()
+        .expect("AccordionSection requires an Accordion as an ancestor");
+    context.bind(&state);
+
+    let is_open = state.get().open.contains(&key);
+
+    let on_change = Handler::new(move |is_open: bool| {
+        let mut next = state.get();
+        let changed = next.toggle(&key, is_open);
+        if changed.is_empty() {
+            return;
+        }
+
+        let on_section_toggled = next.on_section_toggled.clone();
+        state.set(next);
+        if let Some(ref on_section_toggled) = on_section_toggled {
+            for change in changed {
+                on_section_toggled.call(change);
+            }
+        }
+    });
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Auto),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <Fold label={label} open={Some(is_open)} on_change={Some(on_change)}>
+            {children}
+        </Fold>
+    }
+}
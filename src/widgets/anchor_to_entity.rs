@@ -0,0 +1,152 @@
+use crate::bevy::{EntityAnchor, ScreenProjection};
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Children, Fragment, Index, KayakContext, VecTracker,
+};
+use bevy::{math::Vec3, prelude::Entity};
+
+use crate::widgets::If;
+
+/// Keeps its children at the screen position of a Bevy entity
+///
+/// Every frame, the entity's position is projected onto the screen and the children are centered
+/// on it, which is handy for quest markers and nameplates that belong to the UI rather than the
+/// world. The UI is expected to cover the window (e.g. by being within an [App](crate::widgets::App)).
+///
+/// While the entity is off-screen (or behind the camera), the `offscreen_indicator` is shown in
+/// place of the children, kept at the edge of the window closest to the entity. Without one, the
+/// children are either kept at the edge too (if `clamp_to_edges` is set) or hidden.
+///
+/// # Arguments
+///
+/// * `children`: The widgets to keep at the entity's position
+/// * `entity`: The entity to follow. If `None`, nothing is shown.
+/// * `world_offset`: An offset (in world units) added to the entity's position, e.g. to show a
+///   nameplate above a character's head
+/// * `clamp_to_edges`: Whether to keep the children within the window, even while the entity is
+///   off-screen
+/// * `edge_padding`: The space kept between the edges of the window and anything clamped to them
+///   (in pixels)
+/// * `offscreen_indicator`: The content shown at the edge of the window while the entity is off-screen
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of its children.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{AnchorToEntity, Text};
+///
+/// rsx! {
+///     <AnchorToEntity entity={Some(npc)} world_offset={Vec3::new(0.0, 2.0, 0.0)} clamp_to_edges={true}>
+///         <Text content={"Blacksmith".to_string()} size={14.0} />
+///     </AnchorToEntity>
+/// }
+/// ```
+#[widget]
+pub fn AnchorToEntity(
+    children: Children,
+    entity: Option<Entity>,
+    world_offset: Vec3,
+    clamp_to_edges: bool,
+    edge_padding: f32,
+    offscreen_indicator: Option<Fragment>,
+) {
+    // === State === //
+    let (_, _, projection_state) = use_state!(None::<ScreenProjection>);
+    let projection = track_entity(context, self.id, entity, world_offset, projection_state);
+
+    // === Layout === //
+    let size = context
+        .widget_manager
+        .get_layout(&self.id)
+        .map_or((0.0, 0.0), |layout| (layout.width, layout.height));
+    let parent_origin = context
+        .widget_manager
+        .get_valid_parent(self.id)
+        .and_then(|parent_id| context.widget_manager.get_layout(&parent_id))
+        .map_or((0.0, 0.0), |layout| (layout.posx, layout.posy));
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+
+    let is_visible = projection.map_or(false, |projection| projection.is_visible);
+    let has_indicator = offscreen_indicator.is_some();
+    let shows_indicator = projection.is_some() && !is_visible && has_indicator;
+    let shows_children = is_visible || (projection.is_some() && clamp_to_edges && !has_indicator);
+
+    let mut position = projection.map_or((0.0, 0.0), |projection| {
+        (
+            projection.position.0 - size.0 / 2.0,
+            projection.position.1 - size.1 / 2.0,
+        )
+    });
+    if clamp_to_edges || shows_indicator {
+        let min = (bounds.posx + edge_padding, bounds.posy + edge_padding);
+        let max = (
+            bounds.posx + bounds.width - edge_padding - size.0,
+            bounds.posy + bounds.height - edge_padding - size.1,
+        );
+        position = (
+            position.0.min(max.0).max(min.0),
+            position.1.min(max.1).max(min.1),
+        );
+    }
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(position.0 - parent_origin.0)),
+        top: StyleProp::Value(Units::Pixels(position.1 - parent_origin.1)),
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Auto),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let indicator = offscreen_indicator.into_iter().collect::<Vec<_>>();
+    rsx! {
+        <>
+            <If condition={shows_children}>
+                {children}
+            </If>
+            <If condition={shows_indicator}>
+                <VecTracker data={indicator} />
+            </If>
+        </>
+    }
+}
+
+/// Have the given entity's screen projection stored in `projection` every frame
+///
+/// Returns the current projection, or `None` if there isn't any yet (or `entity` is `None`).
+pub(crate) fn track_entity(
+    context: &mut KayakContext,
+    id: Index,
+    entity: Option<Entity>,
+    world_offset: Vec3,
+    projection: Binding<Option<ScreenProjection>>,
+) -> Option<ScreenProjection> {
+    context.bind(&projection);
+    if let Some(entity) = entity {
+        context.set_metadata(
+            id,
+            EntityAnchor {
+                entity,
+                offset: world_offset,
+                projection: projection.clone(),
+            },
+        );
+        projection.get()
+    } else {
+        context.remove_metadata::<EntityAnchor>(id);
+        None
+    }
+}
@@ -0,0 +1,291 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, KeyCode, OnEvent, VecTracker,
+};
+
+use crate::widgets::text_box::is_backspace;
+use crate::widgets::{Background, Clip, If, Text};
+
+/// The height of the input and of each suggestion (in pixels)
+const ITEM_HEIGHT: f32 = 26.0;
+/// The z-index offset of the suggestion list, placing it above its surrounding widgets
+const LIST_Z_INDEX: f32 = 1000.0;
+/// The most suggestions shown at once by default
+const DEFAULT_MAX_SUGGESTIONS: usize = 8;
+
+/// A text input with a dropdown of suggestions matching what the user typed
+///
+/// The dropdown opens as the user types and lists the suggestions containing the typed text
+/// (ignoring case). `Up` and `Down` highlight a suggestion, and `Enter` commits it (or the typed
+/// text if none is highlighted), replacing the typed text. Clicking a suggestion commits it too.
+/// The dropdown closes when `Escape` is pressed or when the input loses focus.
+///
+/// Suggestions are bound, so they can be populated asynchronously (e.g. from a search request
+/// started in `on_query_changed`). When they're already filtered (or sorted by relevance)
+/// elsewhere, set `skip_filtering` to list them as they are.
+///
+/// # Arguments
+///
+/// * `suggestions`: The suggestions to pick from
+/// * `on_query_changed`: Called with the typed text whenever the user edits it
+/// * `on_commit`: Called with the committed suggestion (or typed text)
+/// * `placeholder`: The text shown while nothing is typed
+/// * `max_suggestions`: The most suggestions listed at once. Defaults to 8.
+/// * `skip_filtering`: If true, all of the suggestions are listed, whatever the typed text
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the input container. The `background_color` and
+/// `color` styles apply to both the input and the suggestion list.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, Handler, rsx};
+/// # use kayak_ui::widgets::AutoComplete;
+///
+/// let suggestions = bind(vec!["Iron Sword".to_string(), "Iron Shield".to_string()]);
+/// let on_commit = Handler::new(|item: String| println!("Picked {}", item));
+///
+/// rsx! {
+///     <AutoComplete suggestions={suggestions} on_commit={Some(on_commit)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn AutoComplete(
+    suggestions: Binding<Vec<String>>,
+    on_query_changed: Option<Handler<String>>,
+    on_commit: Option<Handler<String>>,
+    placeholder: Option<String>,
+    max_suggestions: Option<usize>,
+    skip_filtering: bool,
+) {
+    context.bind(&suggestions);
+
+    // === State === //
+    let (query, set_query, ..) = use_state!(String::new());
+    let (is_open, set_is_open, ..) = use_state!(false);
+    let (has_focus, set_has_focus, ..) = use_state!(false);
+    let (highlighted, set_highlighted, ..) = use_state!(None::<usize>);
+
+    let lowercase_query = query.to_lowercase();
+    let matches = suggestions
+        .get()
+        .into_iter()
+        .filter(|suggestion| skip_filtering || suggestion.to_lowercase().contains(&lowercase_query))
+        .take(max_suggestions.unwrap_or(DEFAULT_MAX_SUGGESTIONS))
+        .collect::<Vec<_>>();
+    let highlighted = highlighted.filter(|index| *index < matches.len());
+    let is_list_shown = is_open && !matches.is_empty();
+
+    // Sets the typed text to the committed value and closes the list
+    let commit = {
+        let set_query = set_query.clone();
+        let set_is_open = set_is_open.clone();
+        let set_highlighted = set_highlighted.clone();
+        move |value: String, on_commit: &Option<Handler<String>>| {
+            set_query(value.clone());
+            set_is_open(false);
+            set_highlighted(None);
+            if let Some(on_commit) = on_commit {
+                on_commit.call(value);
+            }
+        }
+    };
+
+    // === Events === //
+    let mut current_query = query.clone();
+    let cloned_matches = matches.clone();
+    let cloned_commit = commit.clone();
+    let cloned_on_commit = on_commit.clone();
+    let cloned_set_is_open = set_is_open.clone();
+    let cloned_set_highlighted = set_highlighted.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::Focus => set_has_focus(true),
+        EventType::Blur => {
+            set_has_focus(false);
+            cloned_set_is_open(false);
+        }
+        EventType::CharInput { c } => {
+            if !has_focus {
+                return;
+            }
+            if is_backspace(c) {
+                if current_query.pop().is_none() {
+                    return;
+                }
+            } else if !c.is_control() {
+                current_query.push(c);
+            } else {
+                return;
+            }
+            set_query(current_query.clone());
+            cloned_set_is_open(true);
+            cloned_set_highlighted(None);
+            if let Some(ref on_query_changed) = on_query_changed {
+                on_query_changed.call(current_query.clone());
+            }
+        }
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Down | KeyCode::Up if !cloned_matches.is_empty() => {
+                let last = cloned_matches.len() - 1;
+                let next = match (evt.key(), highlighted) {
+                    (KeyCode::Down, Some(index)) => (index + 1).min(last),
+                    (KeyCode::Down, None) => 0,
+                    (_, Some(index)) => index.saturating_sub(1),
+                    (_, None) => last,
+                };
+                cloned_set_is_open(true);
+                cloned_set_highlighted(Some(next));
+                event.stop_propagation();
+            }
+            KeyCode::Return => {
+                let value = highlighted
+                    .filter(|_| is_list_shown)
+                    .and_then(|index| cloned_matches.get(index).cloned())
+                    .unwrap_or_else(|| current_query.clone());
+                cloned_commit(value, &cloned_on_commit);
+                event.stop_propagation();
+            }
+            KeyCode::Escape if is_list_shown => {
+                cloned_set_is_open(false);
+                // Closing the list takes precedence over back navigation
+                event.prevent_default();
+            }
+            _ => {}
+        },
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        ..base_styles.clone()
+    });
+
+    let background_color = if matches!(base_styles.background_color, StyleProp::Default) {
+        StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+    } else {
+        base_styles.background_color.clone()
+    };
+
+    let input_styles = Style {
+        background_color: background_color.clone(),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..Style::default()
+    };
+
+    let list_styles = Style {
+        background_color,
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(ITEM_HEIGHT + 2.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        z_index: StyleProp::Value(LIST_Z_INDEX),
+        ..Style::default()
+    };
+
+    let text_styles = if query.is_empty() {
+        Style {
+            color: StyleProp::Value(Color::new(0.5, 0.5, 0.5, 1.0)),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            color: base_styles.color.clone(),
+            ..Style::default()
+        }
+    };
+    let content = if query.is_empty() {
+        placeholder.unwrap_or_default()
+    } else {
+        query
+    };
+
+    let items = matches
+        .into_iter()
+        .enumerate()
+        .map(|(index, suggestion)| {
+            let commit = commit.clone();
+            let on_commit = on_commit.clone();
+            let on_chosen = Handler::new(move |value: String| commit(value, &on_commit));
+            constructor! {
+                <AutoCompleteSuggestion
+                    content={suggestion}
+                    is_highlighted={highlighted == Some(index)}
+                    on_chosen={Some(on_chosen)}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <Background styles={Some(input_styles)}>
+                <Clip>
+                    <Text content={content} size={14.0} line_height={Some(22.0)} styles={Some(text_styles)} />
+                </Clip>
+            </Background>
+            <If condition={is_list_shown}>
+                <Background styles={Some(list_styles)}>
+                    <VecTracker data={items} />
+                </Background>
+            </If>
+        </>
+    }
+}
+
+/// A single suggestion within an open [AutoComplete]
+#[widget]
+fn AutoCompleteSuggestion(
+    content: String,
+    is_highlighted: bool,
+    on_chosen: Option<Handler<String>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    let cloned_content = content.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        // Choose on press since the press also blurs (and closes) the list
+        EventType::MouseDown => {
+            if let Some(ref on_chosen) = on_chosen {
+                on_chosen.call(cloned_content.clone());
+            }
+            event.stop_propagation();
+        }
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        _ => {}
+    }));
+
+    let background_color = if is_hovered || is_highlighted {
+        StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0))
+    } else {
+        StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.0))
+    };
+
+    let background_styles = Style {
+        background_color,
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(background_styles)}>
+            <Text content={content} size={14.0} line_height={Some(22.0)} />
+        </Background>
+    }
+}
@@ -0,0 +1,37 @@
+use crate::bevy::EmbeddedEntity;
+use crate::core::{
+    render_command::RenderCommand,
+    styles::{Style, StyleProp},
+    widget,
+};
+use bevy::prelude::Entity;
+
+/// Reserves layout space for a Bevy entity rendered outside of Kayak
+///
+/// Every frame, the given entity is moved (and, for bevy_ui nodes, resized) to match this widget's
+/// layout. This allows existing bevy_ui nodes, or any entity with a `Transform`, to be embedded in
+/// a Kayak UI, which is handy when migrating an existing UI piece by piece.
+///
+/// bevy_ui nodes should be root nodes (i.e. without a parent node) since they are positioned
+/// absolutely. Entities with a `Transform` are positioned assuming a 2D camera centered on the window.
+///
+/// # Arguments
+///
+/// * `entity`: The entity to position. If `None`, the space is reserved but nothing is positioned.
+///
+/// # Styles
+///
+/// This widget accepts all styles, though it should generally be given a size.
+#[widget]
+pub fn BevyEmbed(entity: Option<Entity>) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    if let Some(entity) = entity {
+        context.set_metadata(self.id, EmbeddedEntity(entity));
+    } else {
+        context.remove_metadata::<EmbeddedEntity>(self.id);
+    }
+}
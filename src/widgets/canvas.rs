@@ -0,0 +1,124 @@
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use crate::core::{
+    canvas::{CanvasShape, Painter},
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget,
+};
+
+/// Draws the contents of a [Canvas] through a [Painter]
+#[derive(Clone)]
+pub struct OnDraw(pub Arc<RwLock<dyn FnMut(&mut Painter) + Send + Sync + 'static>>);
+
+impl OnDraw {
+    pub fn new<F: FnMut(&mut Painter) + Send + Sync + 'static>(f: F) -> OnDraw {
+        OnDraw(Arc::new(RwLock::new(f)))
+    }
+}
+
+impl PartialEq for OnDraw {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for OnDraw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("OnDraw").finish()
+    }
+}
+
+/// An area drawn in immediate mode
+///
+/// Whenever the canvas renders, `on_draw` is called with a fresh [Painter] sized like the canvas,
+/// and the shapes it draws (lines, rects, circles, and text) replace those of the previous render.
+/// Shapes are positioned relative to the top-left corner of the canvas and clipped to it.
+///
+/// # Arguments
+///
+/// * `on_draw`: Draws the contents of the canvas
+/// * `continuous`: If true, the canvas is redrawn every frame (e.g. for animations). Otherwise, it's
+///   only redrawn when it re-renders, like when it's resized.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the canvas. It stretches to fill its parent by
+/// default.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Color, rsx};
+/// # use kayak_ui::widgets::{Canvas, OnDraw};
+///
+/// let on_draw = OnDraw::new(|painter| {
+///     let (width, height) = painter.size();
+///     painter.draw_rect((0.0, 0.0), (width, height), Color::new(0.1, 0.1, 0.1, 1.0));
+///     painter.draw_line((0.0, height), (width, 0.0), 2.0, Color::WHITE);
+///     painter.draw_circle((width / 2.0, height / 2.0), 8.0, Color::new(1.0, 0.3, 0.3, 1.0));
+///     painter.draw_text((4.0, 4.0), "Profit", 14.0, Color::WHITE);
+/// });
+///
+/// rsx! {
+///     <Canvas on_draw={Some(on_draw)} />
+/// }
+/// ```
+#[widget]
+pub fn Canvas(on_draw: Option<OnDraw>, continuous: bool) {
+    // === Drawing === //
+    let size = context
+        .widget_manager
+        .get_layout(&self.id)
+        .map_or((0.0, 0.0), |layout| (layout.width, layout.height));
+    // Changing this re-renders the canvas once it has been laid out, so it's drawn at its size
+    let (_, set_size, ..) = use_state!((0.0f32, 0.0f32));
+    set_size(size);
+    let (_, set_last_frame, ..) = use_state!(None::<Instant>);
+    if continuous {
+        // Changing this state re-renders the canvas next frame, so it keeps being redrawn
+        set_last_frame(Some(context.now()));
+    }
+
+    let mut painter = Painter::new(size);
+    if let Some(on_draw) = on_draw {
+        if let Ok(mut on_draw) = on_draw.0.write() {
+            on_draw(&mut painter);
+        }
+    }
+    let shapes = painter.into_shapes();
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Clip),
+        width: if matches!(base_styles.width, StyleProp::Value(..)) {
+            base_styles.width.clone()
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        },
+        height: if matches!(base_styles.height, StyleProp::Value(..)) {
+            base_styles.height.clone()
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        },
+        ..base_styles
+    });
+
+    rsx! {
+        <CanvasSurface shapes={shapes} />
+    }
+}
+
+/// The shapes of a [Canvas], drawn within its clip
+#[widget]
+fn CanvasSurface(shapes: Vec<CanvasShape>) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Canvas { shapes }),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    });
+}
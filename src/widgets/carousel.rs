@@ -0,0 +1,411 @@
+use std::time::Instant;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Fragment, Handler, KeyCode, MutableBound,
+    OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// How long the pages take to slide by default (in seconds)
+const DEFAULT_DURATION: f32 = 0.35;
+/// The width and height of the previous and next buttons (in pixels)
+const BUTTON_SIZE: f32 = 32.0;
+/// The width and height of the page indicator dots (in pixels)
+const DOT_SIZE: f32 = 8.0;
+/// The space between the page indicator dots (in pixels)
+const DOT_GAP: f32 = 6.0;
+/// The space between the controls and the edges of the carousel (in pixels)
+const CONTROL_MARGIN: f32 = 8.0;
+
+/// A slide from one page of a [Carousel] to the current one
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CarouselTransition {
+    /// The page sliding out
+    from: usize,
+    /// Whether the pages slide to the left (moving forward) or to the right
+    forward: bool,
+    /// How far along the slide is, from 0 to 1
+    progress: f32,
+}
+
+/// A container showing one page at a time, sliding between them
+///
+/// Previous and next buttons sit on the sides of the carousel, and a row of dots at its bottom
+/// shows the current page (pressing a dot moves to its page). Moving past the last page wraps
+/// around to the first one. While focused, the `Left` and `Right` keys move to the previous and
+/// next pages. The carousel re-renders every frame while sliding or auto-advancing.
+///
+/// # Arguments
+///
+/// * `pages`: The content of each page
+/// * `page`: The page currently shown, from 0. Moving to another page updates it. If `None`, the
+///   widget manages the page itself, starting at the first one.
+/// * `on_page_changed`: Called when the user (or the timer) moves to another page. Contains the
+///   page (starting at 0).
+/// * `auto_advance`: If set, moves to the next page after this long on the current one (in
+///   seconds). The timer is paused while the cursor is over the carousel.
+/// * `duration`: How long the pages take to slide (in seconds). Defaults to 0.35.
+/// * `hide_controls`: Whether to hide the previous and next buttons and the page indicator dots
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the carousel, which clips its pages. The `color`
+/// style applies to the controls.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{constructor, rsx};
+/// # use kayak_ui::widgets::{Carousel, Text};
+///
+/// let pages = vec![
+///     constructor! { <><Text content={"New season".to_string()} size={24.0} /></> },
+///     constructor! { <><Text content={"Double XP weekend".to_string()} size={24.0} /></> },
+/// ];
+///
+/// rsx! {
+///     <Carousel pages={pages} auto_advance={Some(5.0)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Carousel(
+    pages: Vec<Fragment>,
+    page: Option<Binding<usize>>,
+    on_page_changed: Option<Handler<usize>>,
+    auto_advance: Option<f32>,
+    duration: Option<f32>,
+    hide_controls: bool,
+) {
+    let page_count = pages.len().max(1);
+
+    // === State === //
+    let (_, _, internal_page) = use_state!(0usize);
+    let page_state = page.unwrap_or(internal_page);
+    context.bind(&page_state);
+    let current_page = page_state.get().min(page_count - 1);
+    let (shown_page, set_shown_page, ..) = use_state!(current_page);
+    let (transition, set_transition, ..) = use_state!(None::<CarouselTransition>);
+    let (last_frame, set_last_frame, ..) = use_state!(None::<Instant>);
+    let (page_started_at, set_page_started_at, ..) = use_state!(None::<Instant>);
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    let now = context.now();
+
+    let cloned_page_state = page_state.clone();
+    let go_to = Handler::new(move |page: usize| {
+        if page != cloned_page_state.get() {
+            cloned_page_state.set(page);
+            if let Some(ref on_page_changed) = on_page_changed {
+                on_page_changed.call(page);
+            }
+        }
+    });
+    let previous_page = (current_page + page_count - 1) % page_count;
+    let next_page = (current_page + 1) % page_count;
+
+    // === Events === //
+    let cloned_go_to = go_to.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::KeyDown(evt) => {
+            let page = match evt.key() {
+                KeyCode::Left => Some(previous_page),
+                KeyCode::Right => Some(next_page),
+                _ => None,
+            };
+            if let Some(page) = page {
+                event.stop_propagation();
+                cloned_go_to.call(page);
+            }
+        }
+        _ => {}
+    }));
+
+    // === Animation === //
+    let duration = duration.unwrap_or(DEFAULT_DURATION);
+    let elapsed = last_frame.map_or(0.0, |last_frame| {
+        now.saturating_duration_since(last_frame).as_secs_f32()
+    });
+    let next_transition = if current_page != shown_page {
+        set_shown_page(current_page);
+        set_page_started_at(Some(now));
+        // Wrapping around slides as if the first and last pages were next to each other
+        let forward = if page_count > 2 && shown_page == page_count - 1 && current_page == 0 {
+            true
+        } else if page_count > 2 && shown_page == 0 && current_page == page_count - 1 {
+            false
+        } else {
+            current_page > shown_page
+        };
+        Some(CarouselTransition {
+            from: shown_page,
+            forward,
+            progress: 0.0,
+        })
+    } else {
+        transition
+            .map(|transition| CarouselTransition {
+                progress: if duration > 0.0 {
+                    transition.progress + elapsed / duration
+                } else {
+                    1.0
+                },
+                ..transition
+            })
+            .filter(|transition| transition.progress < 1.0)
+    };
+    if next_transition != transition {
+        set_transition(next_transition);
+    }
+
+    // === Auto-advance === //
+    let auto_advance = auto_advance.filter(|interval| *interval > 0.0 && page_count > 1);
+    if let Some(interval) = auto_advance {
+        if is_hovered {
+            if page_started_at.is_some() {
+                set_page_started_at(None);
+            }
+        } else {
+            match page_started_at {
+                Some(started_at)
+                    if now.saturating_duration_since(started_at).as_secs_f32() >= interval =>
+                {
+                    set_page_started_at(Some(now));
+                    go_to.call(next_page);
+                }
+                Some(_) => {}
+                None => set_page_started_at(Some(now)),
+            }
+        }
+    }
+    if next_transition.is_some() || (auto_advance.is_some() && !is_hovered) {
+        // Changing this state re-renders the carousel next frame
+        set_last_frame(Some(now));
+    } else if last_frame.is_some() {
+        set_last_frame(None);
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Clip),
+        ..base_styles
+    });
+
+    let layout = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let (current_left, sliding_out) = match next_transition {
+        Some(transition) => {
+            // Ease the movement in and out
+            let progress = transition.progress;
+            let eased = progress * progress * (3.0 - 2.0 * progress);
+            let direction = if transition.forward { 1.0 } else { -1.0 };
+            (
+                direction * layout.width * (1.0 - eased),
+                Some((transition.from, -direction * layout.width * eased)),
+            )
+        }
+        None => (0.0, None),
+    };
+    let page_styles = |left: f32| Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(left)),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        width: StyleProp::Value(Units::Pixels(layout.width)),
+        height: StyleProp::Value(Units::Pixels(layout.height)),
+        ..Style::default()
+    };
+    let current_styles = page_styles(current_left);
+    let sliding_out_styles = page_styles(sliding_out.map_or(0.0, |(_, left)| left));
+    let current_content = pages
+        .get(current_page)
+        .cloned()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let sliding_out_content = sliding_out
+        .and_then(|(page, _)| pages.get(page).cloned())
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    // === Controls === //
+    let show_controls = !hide_controls && pages.len() > 1;
+    let button_styles = |is_previous: bool| Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(if is_previous {
+            Units::Pixels(CONTROL_MARGIN)
+        } else {
+            Units::Stretch(1.0)
+        }),
+        right: StyleProp::Value(if is_previous {
+            Units::Stretch(1.0)
+        } else {
+            Units::Pixels(CONTROL_MARGIN)
+        }),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let previous_button_styles = button_styles(true);
+    let next_button_styles = button_styles(false);
+    let previous_go_to = Some(go_to.clone());
+    let next_go_to = Some(go_to.clone());
+
+    let dots_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Row),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Pixels(CONTROL_MARGIN)),
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+        ..Style::default()
+    };
+    let dots = (0..pages.len())
+        .map(|page| {
+            let go_to = Some(go_to.clone());
+            constructor! {
+                <CarouselDot
+                    page={page}
+                    is_current={page == current_page}
+                    color={color}
+                    go_to={go_to}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <Element styles={Some(sliding_out_styles)}>
+                <VecTracker data={sliding_out_content} />
+            </Element>
+            <Element styles={Some(current_styles)}>
+                <VecTracker data={current_content} />
+            </Element>
+            <If condition={show_controls}>
+                <CarouselButton
+                    label={"<".to_string()}
+                    page={previous_page}
+                    color={color}
+                    go_to={previous_go_to}
+                    styles={Some(previous_button_styles)}
+                />
+                <CarouselButton
+                    label={">".to_string()}
+                    page={next_page}
+                    color={color}
+                    go_to={next_go_to}
+                    styles={Some(next_button_styles)}
+                />
+                <Element styles={Some(dots_styles)}>
+                    <VecTracker data={dots} />
+                </Element>
+            </If>
+        </>
+    }
+}
+
+/// The previous or next button of a [Carousel], placed by the given styles
+#[widget]
+fn CarouselButton(label: String, page: usize, color: Color, go_to: Option<Handler<usize>>) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Click => {
+            event.stop_propagation();
+            if let Some(ref go_to) = go_to {
+                go_to.call(page);
+            }
+        }
+        _ => {}
+    }));
+
+    let button_styles = Style {
+        background_color: StyleProp::Value(Color::new(
+            0.0,
+            0.0,
+            0.0,
+            if is_hovered { 0.6 } else { 0.35 },
+        )),
+        border_radius: StyleProp::Value((
+            BUTTON_SIZE / 2.0,
+            BUTTON_SIZE / 2.0,
+            BUTTON_SIZE / 2.0,
+            BUTTON_SIZE / 2.0,
+        )),
+        width: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        height: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..styles.clone().unwrap_or_default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(color),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(button_styles)}>
+            <Text content={label} size={16.0} line_height={Some(BUTTON_SIZE)} styles={Some(text_styles)} />
+        </Background>
+    }
+}
+
+/// A page indicator dot of a [Carousel]
+#[widget]
+fn CarouselDot(page: usize, is_current: bool, color: Color, go_to: Option<Handler<usize>>) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Click => {
+            event.stop_propagation();
+            if let Some(ref go_to) = go_to {
+                go_to.call(page);
+            }
+        }
+        _ => {}
+    }));
+
+    let alpha = if is_current {
+        1.0
+    } else if is_hovered {
+        0.7
+    } else {
+        0.4
+    };
+    let dot_styles = Style {
+        background_color: StyleProp::Value(Color {
+            a: color.a * alpha,
+            ..color
+        }),
+        border_radius: StyleProp::Value((
+            DOT_SIZE / 2.0,
+            DOT_SIZE / 2.0,
+            DOT_SIZE / 2.0,
+            DOT_SIZE / 2.0,
+        )),
+        left: StyleProp::Value(Units::Pixels(if page == 0 { 0.0 } else { DOT_GAP })),
+        width: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+        height: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(dot_styles)} />
+    }
+}
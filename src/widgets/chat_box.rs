@@ -0,0 +1,317 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, KeyCode, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Element, If, OnChange, ScrollBox, ScrollDirection, Text, TextBox};
+
+/// The most sent messages kept for recall with `Up` by default
+const DEFAULT_MAX_HISTORY: usize = 50;
+/// The font size of the messages (in pixels)
+const MESSAGE_SIZE: f32 = 14.0;
+
+/// A single message of a [ChatBox]
+///
+/// Each part of the message is styled separately: the timestamp is dimmed, the author is shown in
+/// `author_color`, and the text in `color` (e.g. to set whispers or system messages apart).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatMessage {
+    /// The name of the player who sent the message. If `None`, it's shown as a system message.
+    pub author: Option<String>,
+    pub author_color: Color,
+    pub text: String,
+    /// The color of the text. If `None`, the chat box's `color` style is used.
+    pub color: Option<Color>,
+    /// When the message was sent, already formatted (e.g. `"12:04"`)
+    pub timestamp: Option<String>,
+}
+
+impl ChatMessage {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            author: None,
+            author_color: Color::new(0.45, 0.7, 1.0, 1.0),
+            text: text.into(),
+            color: None,
+            timestamp: None,
+        }
+    }
+
+    pub fn with_author<S: Into<String>>(mut self, author: S, author_color: Color) -> Self {
+        self.author = Some(author.into());
+        self.author_color = author_color;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_timestamp<S: Into<String>>(mut self, timestamp: S) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+}
+
+impl Default for ChatMessage {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+/// A chat panel, with a scrollable message history and an input line
+///
+/// The history stays scrolled to the newest message unless the user scrolls up to read older
+/// ones, and sticks again once scrolled back down. Pressing `Return` in the input line sends the
+/// typed text through `on_send` and clears the input. `Up` and `Down` recall the previously sent
+/// messages, like a terminal.
+///
+/// The chat box doesn't add sent messages to `messages` itself, since multiplayer games usually
+/// wait for the server to echo them back.
+///
+/// # Arguments
+///
+/// * `messages`: The messages to show (oldest first)
+/// * `on_send`: Called with the typed text when the user sends it
+/// * `placeholder`: The text shown while the input line is empty
+/// * `max_history`: The most sent messages kept for recall. Defaults to 50.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the chat box container. The `background_color`
+/// style applies to the panel and the `color` style applies to the messages.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, Color, Handler, rsx};
+/// # use kayak_ui::widgets::{ChatBox, ChatMessage};
+///
+/// let messages = bind(vec![
+///     ChatMessage::new("Welcome to the server!"),
+///     ChatMessage::new("hi all").with_author("Ferris", Color::new(1.0, 0.5, 0.2, 1.0)),
+/// ]);
+/// let on_send = Handler::new(|text: String| println!("Sending {}", text));
+///
+/// rsx! {
+///     <ChatBox messages={messages} on_send={Some(on_send)} />
+/// }
+/// ```
+#[widget]
+pub fn ChatBox(
+    messages: Binding<Vec<ChatMessage>>,
+    on_send: Option<Handler<String>>,
+    placeholder: Option<String>,
+    max_history: Option<usize>,
+) {
+    context.bind(&messages);
+    let messages = messages.get();
+
+    // === State === //
+    let (draft, set_draft, ..) = use_state!(String::new());
+    let (history, set_history, ..) = use_state!(Vec::<String>::new());
+    // The index of the recalled message in `history` and the draft it replaced
+    let (recalled, set_recalled, ..) = use_state!(None::<(usize, String)>);
+    // Changing this re-renders the chat box once new messages have been laid out, so the
+    // history can scroll down to them
+    let (_, set_message_count, ..) = use_state!(0usize);
+    set_message_count(messages.len());
+
+    // === Events === //
+    let max_history = max_history.unwrap_or(DEFAULT_MAX_HISTORY);
+    let cloned_draft = draft.clone();
+    let cloned_set_draft = set_draft.clone();
+    let cloned_set_recalled = set_recalled.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Return | KeyCode::NumpadEnter => {
+                let text = cloned_draft.trim().to_string();
+                if text.is_empty() {
+                    return;
+                }
+                let mut history = history.clone();
+                if history.last() != Some(&text) {
+                    history.push(text.clone());
+                }
+                let overflow = history.len().saturating_sub(max_history);
+                history.drain(..overflow);
+                set_history(history);
+                cloned_set_recalled(None);
+                cloned_set_draft(String::new());
+                if let Some(ref on_send) = on_send {
+                    on_send.call(text);
+                }
+                event.stop_propagation();
+            }
+            KeyCode::Up if !history.is_empty() => {
+                let (index, saved_draft) = match &recalled {
+                    Some((index, saved_draft)) => (index.saturating_sub(1), saved_draft.clone()),
+                    None => (history.len() - 1, cloned_draft.clone()),
+                };
+                cloned_set_draft(history[index].clone());
+                cloned_set_recalled(Some((index, saved_draft)));
+                event.stop_propagation();
+            }
+            KeyCode::Down => {
+                if let Some((index, saved_draft)) = &recalled {
+                    if index + 1 < history.len() {
+                        cloned_set_draft(history[index + 1].clone());
+                        cloned_set_recalled(Some((index + 1, saved_draft.clone())));
+                    } else {
+                        // Past the newest message, so restore what was being typed
+                        cloned_set_draft(saved_draft.clone());
+                        cloned_set_recalled(None);
+                    }
+                    event.stop_propagation();
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }));
+
+    let current_draft = draft.clone();
+    let on_change = OnChange::new(move |event| {
+        // Control characters (like the one typed with `Return`) are reported with the value as it
+        // was, which shouldn't overwrite a sent or recalled message
+        if event.value != current_draft {
+            set_draft(event.value);
+            set_recalled(None);
+        }
+    });
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 0.85))
+        } else {
+            base_styles.background_color.clone()
+        },
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        padding_left: StyleProp::Value(Units::Pixels(6.0)),
+        padding_right: StyleProp::Value(Units::Pixels(6.0)),
+        padding_top: StyleProp::Value(Units::Pixels(6.0)),
+        padding_bottom: StyleProp::Value(Units::Pixels(6.0)),
+        ..base_styles
+    });
+
+    let history_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let messages_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+    let input_styles = Style {
+        top: StyleProp::Value(Units::Pixels(6.0)),
+        ..Style::default()
+    };
+
+    let rows = messages
+        .into_iter()
+        .map(|message| {
+            constructor! {
+                <ChatMessageRow message={message} text_color={text_color} />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <ScrollBox
+                direction={ScrollDirection::Vertical}
+                stick_to_bottom={true}
+                styles={Some(history_styles)}
+            >
+                <Element styles={Some(messages_styles)}>
+                    <VecTracker data={rows} />
+                </Element>
+            </ScrollBox>
+            <TextBox
+                value={draft}
+                on_change={Some(on_change)}
+                placeholder={placeholder}
+                styles={Some(input_styles)}
+            />
+        </>
+    }
+}
+
+/// A single message of a [ChatBox]
+#[widget]
+fn ChatMessageRow(message: ChatMessage, text_color: Color) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        top: StyleProp::Value(Units::Pixels(2.0)),
+        ..Style::default()
+    });
+
+    let has_timestamp = message.timestamp.is_some();
+    let timestamp = format!("[{}] ", message.timestamp.unwrap_or_default());
+    let timestamp_styles = Style {
+        color: StyleProp::Value(Color {
+            a: text_color.a * 0.5,
+            ..text_color
+        }),
+        ..Style::default()
+    };
+
+    let has_author = message.author.is_some();
+    let author = format!("{}: ", message.author.unwrap_or_default());
+    let author_styles = Style {
+        color: StyleProp::Value(message.author_color),
+        ..Style::default()
+    };
+
+    // System messages are dimmed unless they have their own color
+    let color = message.color.unwrap_or(if has_author {
+        text_color
+    } else {
+        Color {
+            a: text_color.a * 0.75,
+            ..text_color
+        }
+    });
+    let text = message.text;
+    let text_styles = Style {
+        color: StyleProp::Value(color),
+        ..Style::default()
+    };
+    // The text wraps within its parent, so it needs one spanning the rest of the row
+    let text_container_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <If condition={has_timestamp}>
+                <Text content={timestamp} size={MESSAGE_SIZE} styles={Some(timestamp_styles)} />
+            </If>
+            <If condition={has_author}>
+                <Text content={author} size={MESSAGE_SIZE} styles={Some(author_styles)} />
+            </If>
+            <Element styles={Some(text_container_styles)}>
+                <Text content={text} size={MESSAGE_SIZE} styles={Some(text_styles)} />
+            </Element>
+        </>
+    }
+}
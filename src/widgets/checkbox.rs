@@ -0,0 +1,196 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, Children, EventType, Handler, KeyCode, OnEvent,
+};
+
+use crate::widgets::{Background, Element, If};
+
+/// The size of the checkbox's box (in pixels)
+const BOX_SIZE: f32 = 18.0;
+
+/// A toggleable checkbox
+///
+/// The checkbox toggles when clicked or, when focused, when `Space` or `Return` is pressed. An
+/// indeterminate checkbox (e.g. a "select all" with only some items selected) becomes checked
+/// when toggled.
+///
+/// # Arguments
+///
+/// * `children`: The checkbox's label, placed to the right of the box
+/// * `checked`: Whether the checkbox is checked
+/// * `indeterminate`: If true, displays the indeterminate visual regardless of `checked`
+/// * `on_change`: Called when the user toggles the checkbox. Contains the next desired checked state.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the checkbox container. The `background_color` style
+/// applies to the box and the `color` style applies to the check mark.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::{Checkbox, Text};
+///
+/// let (checked, set_checked, ..) = use_state!(false);
+/// let on_change = Handler::new(move |value| {
+///     set_checked(value);
+/// });
+///
+/// rsx! {
+///     <Checkbox checked={checked} on_change={Some(on_change)}>
+///         <Text content={"Enable VSync".to_string()} size={14.0} />
+///     </Checkbox>
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Checkbox(
+    children: Children,
+    checked: bool,
+    indeterminate: bool,
+    on_change: Option<Handler<bool>>,
+) {
+    // === Events === //
+    let next_checked = indeterminate || !checked;
+    self.on_event = Some(OnEvent::new(move |_, event| {
+        let should_toggle = match event.event_type {
+            EventType::Click => true,
+            EventType::KeyDown(evt) => {
+                !evt.is_repeat() && matches!(evt.key(), KeyCode::Space | KeyCode::Return)
+            }
+            _ => false,
+        };
+
+        if should_toggle {
+            if let Some(ref on_change) = on_change {
+                on_change.call(next_checked);
+            }
+        }
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(BOX_SIZE))
+        } else {
+            base_styles.height.clone()
+        },
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.width.clone()
+        },
+        ..base_styles.clone()
+    });
+
+    let box_styles = Style {
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+        } else {
+            base_styles.background_color.clone()
+        },
+        border_radius: StyleProp::Value((3.0, 3.0, 3.0, 3.0)),
+        width: StyleProp::Value(Units::Pixels(BOX_SIZE)),
+        height: StyleProp::Value(Units::Pixels(BOX_SIZE)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let mark_color = base_styles
+        .color
+        .resolve_or(context.theme(), Color::new(0.933, 0.745, 0.745, 1.0));
+    let mark_styles = if indeterminate {
+        // A horizontal bar
+        Style {
+            height: StyleProp::Value(Units::Pixels(BOX_SIZE / 6.0)),
+            ..base_mark_styles(mark_color)
+        }
+    } else if checked {
+        // A filled square
+        Style {
+            height: StyleProp::Value(Units::Pixels(BOX_SIZE / 2.0)),
+            ..base_mark_styles(mark_color)
+        }
+    } else {
+        Style::default()
+    };
+    let has_mark = indeterminate || checked;
+
+    let label_styles = Style {
+        left: StyleProp::Value(Units::Pixels(8.0)),
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Auto),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <Background styles={Some(box_styles)}>
+                <If condition={has_mark}>
+                    <Background styles={Some(mark_styles)} />
+                </If>
+            </Background>
+            <Element styles={Some(label_styles)}>
+                {children}
+            </Element>
+        </>
+    }
+}
+
+/// The base styles for the check mark, centered within the box
+fn base_mark_styles(color: Color) -> Style {
+    let radius = BOX_SIZE / 12.0;
+    Style {
+        background_color: StyleProp::Value(color),
+        border_radius: StyleProp::Value((radius, radius, radius, radius)),
+        width: StyleProp::Value(Units::Pixels(BOX_SIZE / 2.0)),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Checkbox;
+    use crate::core::{
+        render_primitive::RenderPrimitive,
+        styles::{Style, StyleProp, Units},
+        KayakContext, ThemeColor, WidgetBuilder,
+    };
+
+    #[test]
+    fn theme_tokens_should_color_the_check_mark() {
+        let mut context = KayakContext::new();
+        WidgetBuilder::new::<Checkbox>()
+            .prop(|checkbox| checkbox.checked = true)
+            .styles(Style {
+                color: ThemeColor::Primary.into(),
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                ..Style::default()
+            })
+            .render_root(&mut context);
+        context.render();
+
+        let primary = context.theme().color(ThemeColor::Primary);
+        let has_mark = context
+            .widget_manager
+            .build_render_primitives()
+            .iter()
+            .any(|primitive| {
+                matches!(primitive, RenderPrimitive::Quad { background_color, .. } if *background_color == primary)
+            });
+        assert!(has_mark);
+    }
+}
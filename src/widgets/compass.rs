@@ -0,0 +1,261 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Binding, Bound, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Image, Text};
+
+/// The range of headings shown by default (in degrees)
+const DEFAULT_FIELD_OF_VIEW: f32 = 90.0;
+/// The heading between two tick marks (in degrees)
+const TICK_STEP: usize = 5;
+/// The heading between two labelled tick marks (in degrees)
+const LABEL_STEP: usize = 15;
+/// The width of the container each label is centered in (in pixels)
+const LABEL_WIDTH: f32 = 40.0;
+/// The width and height of the markers by default (in pixels)
+const DEFAULT_MARKER_SIZE: f32 = 16.0;
+
+/// A point of interest shown on a [Compass]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CompassMarker {
+    /// The heading of the marker (in degrees), clockwise from north
+    pub heading: f32,
+    /// The image ID of the marker's icon (see `ImageManager`). If `None`, a dot is shown.
+    pub icon: Option<u16>,
+    /// The color of the dot shown without an icon (or the tint of the icon)
+    pub color: Color,
+}
+
+impl CompassMarker {
+    pub fn new(heading: f32, icon: Option<u16>, color: Color) -> Self {
+        Self {
+            heading,
+            icon,
+            color,
+        }
+    }
+}
+
+/// A horizontal strip showing the headings around the current yaw, like the compass of many HUDs
+///
+/// The heading the player faces is in the middle of the strip, with tick marks every 5 degrees
+/// and labels every 15 (using the cardinal and intercardinal directions where there is one). The
+/// markings and markers wrap around at 360 degrees and are clipped to the strip.
+///
+/// # Arguments
+///
+/// * `yaw`: The heading the player faces (in degrees), clockwise from north. Any value is
+///   accepted, so it doesn't need to be wrapped beforehand.
+/// * `field_of_view`: The range of headings shown across the strip (in degrees). Defaults to 90.
+/// * `markers`: The points of interest shown on the strip while their heading is in view
+/// * `marker_size`: The width and height of the markers (in pixels). Defaults to 16.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the strip. The `color` style applies to the
+/// markings and defaults to white.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, color::Color, rsx, styles::{Style, StyleProp, Units}};
+/// # use kayak_ui::widgets::{Compass, CompassMarker};
+///
+/// let yaw = bind(30.0);
+/// let markers = vec![CompassMarker::new(75.0, None, Color::new(1.0, 0.8, 0.2, 1.0))];
+/// let styles = Style {
+///     width: StyleProp::Value(Units::Pixels(400.0)),
+///     height: StyleProp::Value(Units::Pixels(40.0)),
+///     ..Style::default()
+/// };
+///
+/// rsx! {
+///     <Compass yaw={yaw} markers={markers} styles={Some(styles)} />
+/// }
+/// ```
+#[widget]
+pub fn Compass(
+    yaw: Binding<f32>,
+    field_of_view: Option<f32>,
+    markers: Vec<CompassMarker>,
+    marker_size: Option<f32>,
+) {
+    context.bind(&yaw);
+    let yaw = yaw.get().rem_euclid(360.0);
+    let field_of_view = field_of_view
+        .unwrap_or(DEFAULT_FIELD_OF_VIEW)
+        .clamp(1.0, 360.0);
+    let marker_size = marker_size.unwrap_or(DEFAULT_MARKER_SIZE);
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Clip),
+        ..base_styles
+    });
+
+    let layout = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let pixels_per_degree = layout.width / field_of_view;
+    // The position (relative to the strip) of the given heading, if it's in view. Headings just
+    // outside of the view are kept, so markings are clipped at the edges instead of popping out.
+    let get_position = |heading: f32, margin: f32| {
+        let delta = get_heading_delta(yaw, heading);
+        let x = layout.width / 2.0 + delta * pixels_per_degree;
+        if x < -margin || x > layout.width + margin {
+            None
+        } else {
+            Some(x)
+        }
+    };
+
+    let markings = (0..360)
+        .step_by(TICK_STEP)
+        .filter_map(|heading| {
+            let has_label = heading % LABEL_STEP == 0;
+            let margin = if has_label { LABEL_WIDTH / 2.0 } else { 1.0 };
+            get_position(heading as f32, margin).map(|x| (heading, has_label, x))
+        })
+        .map(|(heading, has_label, x)| {
+            let label = get_heading_label(heading);
+            let is_cardinal = heading % 90 == 0;
+            let tick_height = if has_label { 8.0 } else { 4.0 };
+            let marking_styles = Style {
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Pixels(x - LABEL_WIDTH / 2.0)),
+                width: StyleProp::Value(Units::Pixels(LABEL_WIDTH)),
+                height: StyleProp::Value(Units::Stretch(1.0)),
+                ..Style::default()
+            };
+            let tick_styles = Style {
+                background_color: StyleProp::Value(color),
+                left: StyleProp::Value(Units::Pixels(LABEL_WIDTH / 2.0 - 0.5)),
+                width: StyleProp::Value(Units::Pixels(1.0)),
+                height: StyleProp::Value(Units::Pixels(tick_height)),
+                ..Style::default()
+            };
+            let label_styles = Style {
+                padding_left: StyleProp::Value(Units::Stretch(1.0)),
+                padding_right: StyleProp::Value(Units::Stretch(1.0)),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Auto),
+                ..Style::default()
+            };
+            let text_styles = Style {
+                color: StyleProp::Value(color),
+                ..Style::default()
+            };
+            let size = if is_cardinal { 16.0 } else { 12.0 };
+            constructor! {
+                <Element styles={Some(marking_styles)}>
+                    <Background styles={Some(tick_styles)} />
+                    <If condition={has_label}>
+                        <Element styles={Some(label_styles)}>
+                            <Text content={label} size={size} styles={Some(text_styles)} />
+                        </Element>
+                    </If>
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let markers = markers
+        .into_iter()
+        .filter_map(|marker| get_position(marker.heading, marker_size / 2.0).map(|x| (marker, x)))
+        .map(|(marker, x)| {
+            let marker_styles = Style {
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Pixels(x - marker_size / 2.0)),
+                bottom: StyleProp::Value(Units::Pixels(2.0)),
+                top: StyleProp::Value(Units::Stretch(1.0)),
+                width: StyleProp::Value(Units::Pixels(marker_size)),
+                height: StyleProp::Value(Units::Pixels(marker_size)),
+                ..Style::default()
+            };
+            let has_icon = marker.icon.is_some();
+            let icon = marker.icon.unwrap_or_default();
+            let icon_styles = Style {
+                color: StyleProp::Value(marker.color),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Stretch(1.0)),
+                ..Style::default()
+            };
+            let dot_size = marker_size / 2.0;
+            let dot_styles = Style {
+                background_color: StyleProp::Value(marker.color),
+                border_radius: StyleProp::Value((
+                    dot_size / 2.0,
+                    dot_size / 2.0,
+                    dot_size / 2.0,
+                    dot_size / 2.0,
+                )),
+                left: StyleProp::Value(Units::Stretch(1.0)),
+                right: StyleProp::Value(Units::Stretch(1.0)),
+                top: StyleProp::Value(Units::Stretch(1.0)),
+                bottom: StyleProp::Value(Units::Stretch(1.0)),
+                width: StyleProp::Value(Units::Pixels(dot_size)),
+                height: StyleProp::Value(Units::Pixels(dot_size)),
+                ..Style::default()
+            };
+            constructor! {
+                <Element styles={Some(marker_styles)}>
+                    <If condition={has_icon}>
+                        <Image handle={icon} styles={Some(icon_styles)} />
+                    </If>
+                    <If condition={!has_icon}>
+                        <Background styles={Some(dot_styles)} />
+                    </If>
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Marks the heading the player faces
+    let center_styles = Style {
+        background_color: StyleProp::Value(color),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(layout.width / 2.0 - 1.0)),
+        bottom: StyleProp::Value(Units::Pixels(0.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Pixels(2.0)),
+        height: StyleProp::Value(Units::Pixels(6.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <VecTracker data={markings} />
+            <VecTracker data={markers} />
+            <Background styles={Some(center_styles)} />
+        </>
+    }
+}
+
+/// Get the signed difference between two headings (in degrees), from -180 to 180
+fn get_heading_delta(from: f32, to: f32) -> f32 {
+    (to - from + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Get the label of a heading (in whole degrees), naming the cardinal and intercardinal directions
+fn get_heading_label(heading: usize) -> String {
+    match heading {
+        0 => "N".to_string(),
+        45 => "NE".to_string(),
+        90 => "E".to_string(),
+        135 => "SE".to_string(),
+        180 => "S".to_string(),
+        225 => "SW".to_string(),
+        270 => "W".to_string(),
+        315 => "NW".to_string(),
+        _ => heading.to_string(),
+    }
+}
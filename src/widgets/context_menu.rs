@@ -0,0 +1,464 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    layout_cache::Rect,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Children, EventType, Handler, MutableBound, OnEvent,
+    VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// The width of each menu panel (in pixels)
+const MENU_WIDTH: f32 = 180.0;
+/// The height of each menu item (in pixels)
+const ITEM_HEIGHT: f32 = 24.0;
+/// The height of each separator (in pixels)
+const SEPARATOR_HEIGHT: f32 = 9.0;
+/// The space above the first and below the last item of each panel (in pixels)
+const MENU_PADDING: f32 = 4.0;
+/// The z-index offset of the menu, placing it above its surrounding widgets
+const MENU_Z_INDEX: f32 = 3000.0;
+
+/// An entry in a [ContextMenu]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextMenuItem {
+    /// A selectable item, identified by its `id`
+    Action {
+        id: String,
+        label: String,
+        disabled: bool,
+    },
+    /// A horizontal line between groups of items
+    Separator,
+    /// An item that opens a nested menu when hovered
+    Submenu {
+        label: String,
+        items: Vec<ContextMenuItem>,
+    },
+}
+
+impl Default for ContextMenuItem {
+    fn default() -> Self {
+        Self::Separator
+    }
+}
+
+impl ContextMenuItem {
+    pub fn action(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::Action {
+            id: id.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+
+    pub fn submenu(label: impl Into<String>, items: Vec<ContextMenuItem>) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+
+    fn height(&self) -> f32 {
+        match self {
+            Self::Separator => SEPARATOR_HEIGHT,
+            _ => ITEM_HEIGHT,
+        }
+    }
+}
+
+/// A menu that opens at the cursor when its children are right-clicked
+///
+/// The menu closes when an item is selected or when pressing anywhere outside of it. While open,
+/// the rest of the UI doesn't receive pointer events. Submenus open when their item is hovered.
+///
+/// # Arguments
+///
+/// * `children`: The area that opens the menu when right-clicked
+/// * `items`: The entries of the menu
+/// * `on_select`: Called when the user selects an item. Contains the item's `id`.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of its children. The
+/// `background_color` and `color` styles apply to the menu panels.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::{ContextMenu, ContextMenuItem, Text};
+///
+/// let items = vec![
+///     ContextMenuItem::action("rename", "Rename"),
+///     ContextMenuItem::separator(),
+///     ContextMenuItem::submenu("Sort By", vec![
+///         ContextMenuItem::action("sort_name", "Name"),
+///         ContextMenuItem::action("sort_date", "Date"),
+///     ]),
+/// ];
+/// let on_select = Handler::new(|id: String| {
+///     println!("Selected {}", id);
+/// });
+///
+/// rsx! {
+///     <ContextMenu items={items} on_select={Some(on_select)}>
+///         <Text content={"Right-click me".to_string()} size={16.0} />
+///     </ContextMenu>
+/// }
+/// ```
+#[widget]
+pub fn ContextMenu(
+    children: Children,
+    items: Vec<ContextMenuItem>,
+    on_select: Option<Handler<String>>,
+) {
+    // === State === //
+    let (open_at, _, open_state) = use_state!(None::<(f32, f32)>);
+    let (open_path, _, open_path_state) = use_state!(Vec::<usize>::new());
+    if open_at.is_some() {
+        context.push_modal(self.id);
+    } else {
+        context.remove_modal(self.id);
+    }
+
+    // === Events === //
+    let cloned_open_state = open_state.clone();
+    let cloned_open_path_state = open_path_state.clone();
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::ContextMenu => {
+            cloned_open_path_state.set(Vec::new());
+            cloned_open_state.set(Some(ctx.last_mouse_position()));
+            // Only open the innermost menu
+            event.stop_propagation();
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..base_styles.clone()
+    });
+
+    let origin = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+
+    let panels = open_at
+        .map(|position| get_panels(&items, &open_path, position, &bounds))
+        .unwrap_or_default();
+    let panel_rects = panels.iter().map(|(_, rect)| *rect).collect::<Vec<_>>();
+
+    // Covers the whole UI in order to close the menu when pressing outside of it
+    let catcher_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(bounds.posx - origin.posx)),
+        top: StyleProp::Value(Units::Pixels(bounds.posy - origin.posy)),
+        width: StyleProp::Value(Units::Pixels(bounds.width)),
+        height: StyleProp::Value(Units::Pixels(bounds.height)),
+        z_index: StyleProp::Value(MENU_Z_INDEX),
+        ..Style::default()
+    };
+    let cloned_open_state = open_state.clone();
+    let on_catcher_event = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseDown | EventType::ContextMenu => {
+            let position = ctx.last_mouse_position();
+            if !panel_rects.iter().any(|rect| rect.contains(&position)) {
+                cloned_open_state.set(None);
+            }
+        }
+        _ => {}
+    });
+
+    let background_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+
+    let panels = panels
+        .into_iter()
+        .enumerate()
+        .map(|(depth, (items, rect))| {
+            let open_path_state = open_path_state.clone();
+            let open_state = open_state.clone();
+            let on_select = on_select.clone();
+            constructor! {
+                <ContextMenuPanel
+                    items={items}
+                    depth={depth}
+                    position={(rect.posx - bounds.posx, rect.posy - bounds.posy)}
+                    background_color={background_color}
+                    text_color={text_color}
+                    open_path={open_path_state}
+                    open_state={open_state}
+                    on_select={on_select}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let is_open = open_at.is_some();
+    rsx! {
+        <>
+            {children}
+            <If condition={is_open}>
+                <Element styles={Some(catcher_styles)} on_event={Some(on_catcher_event)}>
+                    <VecTracker data={panels} />
+                </Element>
+            </If>
+        </>
+    }
+}
+
+/// Get the items and bounds of each open panel, starting with the root menu
+///
+/// Panels are kept within the given bounds, opening to the left or upwards when needed.
+fn get_panels(
+    items: &[ContextMenuItem],
+    open_path: &[usize],
+    position: (f32, f32),
+    bounds: &Rect,
+) -> Vec<(Vec<ContextMenuItem>, Rect)> {
+    let mut panels = Vec::new();
+    let mut items = items.to_vec();
+    let mut position = position;
+    let mut depth = 0;
+    loop {
+        let height = MENU_PADDING * 2.0 + items.iter().map(|item| item.height()).sum::<f32>();
+        let mut rect = Rect {
+            posx: position.0,
+            posy: position.1,
+            width: MENU_WIDTH,
+            height,
+            z_index: 0.0,
+        };
+        if rect.posx + rect.width > bounds.posx + bounds.width {
+            rect.posx = if depth > 0 {
+                // Open submenus on the other side of their parent
+                position.0 - MENU_WIDTH * 2.0
+            } else {
+                position.0 - MENU_WIDTH
+            };
+        }
+        if rect.posy + rect.height > bounds.posy + bounds.height {
+            rect.posy = bounds.posy + bounds.height - rect.height;
+        }
+        rect.posx = rect.posx.max(bounds.posx);
+        rect.posy = rect.posy.max(bounds.posy);
+
+        let submenu = open_path.get(depth).and_then(|index| {
+            let offset = MENU_PADDING
+                + items[..(*index).min(items.len())]
+                    .iter()
+                    .map(|item| item.height())
+                    .sum::<f32>();
+            match items.get(*index) {
+                Some(ContextMenuItem::Submenu { items, .. }) => {
+                    Some((items.clone(), (rect.posx + MENU_WIDTH, rect.posy + offset)))
+                }
+                _ => None,
+            }
+        });
+
+        panels.push((items, rect));
+        if let Some((submenu_items, submenu_position)) = submenu {
+            items = submenu_items;
+            position = submenu_position;
+            depth += 1;
+        } else {
+            break;
+        }
+    }
+
+    panels
+}
+
+/// A single (sub)menu within an open [ContextMenu]
+#[widget]
+fn ContextMenuPanel(
+    items: Vec<ContextMenuItem>,
+    depth: usize,
+    position: (f32, f32),
+    background_color: Color,
+    text_color: Color,
+    open_path: Binding<Vec<usize>>,
+    open_state: Binding<Option<(f32, f32)>>,
+    on_select: Option<Handler<String>>,
+) {
+    context.bind(&open_path);
+    let selected_path = open_path.get();
+
+    let panel_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(position.0)),
+        top: StyleProp::Value(Units::Pixels(position.1)),
+        width: StyleProp::Value(Units::Pixels(MENU_WIDTH)),
+        height: StyleProp::Value(Units::Auto),
+        padding_top: StyleProp::Value(Units::Pixels(MENU_PADDING)),
+        padding_bottom: StyleProp::Value(Units::Pixels(MENU_PADDING)),
+        ..Style::default()
+    };
+
+    let entries = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let item = item.clone();
+            let is_open = selected_path.get(depth) == Some(&index);
+            let open_path = open_path.clone();
+            let open_state = open_state.clone();
+            let on_select = on_select.clone();
+            constructor! {
+                <ContextMenuEntry
+                    item={item}
+                    depth={depth}
+                    index={index}
+                    is_open={is_open}
+                    text_color={text_color}
+                    open_path={open_path}
+                    open_state={open_state}
+                    on_select={on_select}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <Background styles={Some(panel_styles)}>
+            <VecTracker data={entries} />
+        </Background>
+    }
+}
+
+/// A single item within a [ContextMenuPanel]
+#[widget]
+fn ContextMenuEntry(
+    item: ContextMenuItem,
+    depth: usize,
+    index: usize,
+    is_open: bool,
+    text_color: Color,
+    open_path: Binding<Vec<usize>>,
+    open_state: Binding<Option<(f32, f32)>>,
+    on_select: Option<Handler<String>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    let cloned_item = item.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => {
+            set_is_hovered(true);
+            // Hovering an item closes the submenus of its siblings (and opens its own)
+            let mut path = open_path.get();
+            path.truncate(depth);
+            if matches!(cloned_item, ContextMenuItem::Submenu { .. }) {
+                path.push(index);
+            }
+            if path != open_path.get() {
+                open_path.set(path);
+            }
+        }
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Click => {
+            if let ContextMenuItem::Action {
+                ref id,
+                disabled: false,
+                ..
+            } = cloned_item
+            {
+                if let Some(ref on_select) = on_select {
+                    on_select.call(id.clone());
+                }
+                open_state.set(None);
+            }
+        }
+        _ => {}
+    }));
+
+    match item {
+        ContextMenuItem::Separator => {
+            let line_styles = Style {
+                background_color: StyleProp::Value(Color::new(
+                    text_color.r,
+                    text_color.g,
+                    text_color.b,
+                    0.2,
+                )),
+                height: StyleProp::Value(Units::Pixels(1.0)),
+                top: StyleProp::Value(Units::Pixels((SEPARATOR_HEIGHT - 1.0) / 2.0)),
+                bottom: StyleProp::Value(Units::Pixels((SEPARATOR_HEIGHT - 1.0) / 2.0)),
+                left: StyleProp::Value(Units::Pixels(8.0)),
+                right: StyleProp::Value(Units::Pixels(8.0)),
+                ..Style::default()
+            };
+            rsx! {
+                <Background styles={Some(line_styles)} />
+            }
+        }
+        ContextMenuItem::Action {
+            label, disabled, ..
+        } => {
+            let is_highlighted = is_hovered && !disabled;
+            let item_styles = entry_styles(is_highlighted);
+            let text_styles = Style {
+                color: StyleProp::Value(if disabled {
+                    Color::new(text_color.r, text_color.g, text_color.b, 0.4)
+                } else {
+                    text_color
+                }),
+                ..Style::default()
+            };
+            rsx! {
+                <Background styles={Some(item_styles)}>
+                    <Text content={label} size={14.0} line_height={Some(ITEM_HEIGHT)} styles={Some(text_styles)} />
+                </Background>
+            }
+        }
+        ContextMenuItem::Submenu { label, .. } => {
+            let item_styles = entry_styles(is_hovered || is_open);
+            let text_styles = Style {
+                color: StyleProp::Value(text_color),
+                ..Style::default()
+            };
+            let content = format!("{}  >", label);
+            rsx! {
+                <Background styles={Some(item_styles)}>
+                    <Text content={content} size={14.0} line_height={Some(ITEM_HEIGHT)} styles={Some(text_styles)} />
+                </Background>
+            }
+        }
+    }
+}
+
+fn entry_styles(is_highlighted: bool) -> Style {
+    Style {
+        background_color: StyleProp::Value(if is_highlighted {
+            Color::new(0.0781, 0.0898, 0.101, 1.0)
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        }),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(10.0)),
+        padding_right: StyleProp::Value(Units::Pixels(10.0)),
+        ..Style::default()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Children, EventType, Handler, OnEvent,
+};
+
+use crate::widgets::{Element, If, RadialFill, Text};
+
+/// A button that can't be clicked while it's on cooldown
+///
+/// While on cooldown, a dark radial overlay covers the portion of the cooldown that remains and
+/// the remaining time is shown on top of the button's content. The button doesn't track time
+/// itself: the app should count `remaining` down (in seconds), usually from a system.
+///
+/// # Arguments
+///
+/// * `children`: The button's content
+/// * `duration`: The full length of the cooldown (in seconds)
+/// * `remaining`: The time left until the button can be clicked again (in seconds)
+/// * `show_remaining`: If true, the remaining time is shown while on cooldown
+/// * `on_ready`: Called when the cooldown finishes
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the button itself. The `color` style applies to the
+/// remaining time text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, EventType, OnEvent};
+/// # use kayak_ui::widgets::{CooldownButton, Text};
+///
+/// let on_event = OnEvent::new(|_, event| match event.event_type {
+///     EventType::Click => println!("Fireball!"),
+///     _ => {}
+/// });
+/// let on_ready = Handler::new(|_| println!("Fireball is ready"));
+///
+/// rsx! {
+///     <CooldownButton duration={8.0} remaining={3.5} show_remaining={true} on_event={Some(on_event)} on_ready={Some(on_ready)}>
+///         <Text content={"Fireball".to_string()} size={16.0} />
+///     </CooldownButton>
+/// }
+/// ```
+#[widget(focusable)]
+pub fn CooldownButton(
+    children: Children,
+    duration: f32,
+    remaining: f32,
+    show_remaining: bool,
+    on_ready: Option<Handler<()>>,
+) {
+    let is_cooling_down = remaining > 0.0;
+
+    // === State === //
+    let (was_cooling_down, set_was_cooling_down, ..) = use_state!(is_cooling_down);
+    if was_cooling_down != is_cooling_down {
+        set_was_cooling_down(is_cooling_down);
+        if !is_cooling_down {
+            if let Some(ref on_ready) = on_ready {
+                on_ready.call(());
+            }
+        }
+    }
+
+    // === Events === //
+    // Clicks are stopped before they reach the button (and its `on_event`)
+    let on_content_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click if is_cooling_down => event.stop_propagation(),
+        _ => {}
+    });
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    let border_radius = if matches!(base_styles.border_radius, StyleProp::Value(..)) {
+        base_styles.border_radius.resolve()
+    } else {
+        (5.0, 5.0, 5.0, 5.0)
+    };
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        border_radius: StyleProp::Value(border_radius),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(45.0))
+        } else {
+            base_styles.height.clone()
+        },
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0))
+        } else {
+            base_styles.background_color.clone()
+        },
+        ..base_styles
+    });
+
+    let content_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let overlay_styles = Style {
+        background_color: StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.6)),
+        border_radius: StyleProp::Value(border_radius),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(0.0)),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let remaining_text_styles = Style {
+        color: StyleProp::Value(text_color),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let fill = if duration > 0.0 {
+        (remaining / duration).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    rsx! {
+        <Element styles={Some(content_styles)} on_event={Some(on_content_event)}>
+            {children}
+            <If condition={is_cooling_down}>
+                <RadialFill styles={Some(overlay_styles)} fill={fill} />
+                <If condition={show_remaining}>
+                    <Text
+                        styles={Some(remaining_text_styles)}
+                        content={format_remaining(remaining)}
+                        size={18.0}
+                    />
+                </If>
+            </If>
+        </Element>
+    }
+}
+
+/// Formats the remaining time, showing tenths of a second once it's below one second
+fn format_remaining(remaining: f32) -> String {
+    if remaining < 1.0 {
+        format!("{:.1}", remaining)
+    } else {
+        format!("{}", remaining.ceil())
+    }
+}
@@ -0,0 +1,672 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, Index, KayakContext, KeyCode,
+    MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, Popover, Text};
+
+/// The height of the date picker button (in pixels)
+const BUTTON_HEIGHT: f32 = 26.0;
+/// The width and height of each day of the calendar (in pixels)
+const DAY_SIZE: f32 = 32.0;
+/// The height of the month/year header of the calendar (in pixels)
+const HEADER_HEIGHT: f32 = 28.0;
+/// The height of the row of weekday names (in pixels)
+const WEEKDAYS_HEIGHT: f32 = 24.0;
+/// The space around the calendar (in pixels)
+const CALENDAR_PADDING: f32 = 8.0;
+/// The calendar always shows six weeks, so its size doesn't change between months
+const WEEKS: usize = 6;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A day of the (proleptic Gregorian) calendar
+///
+/// Dates are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    /// The month, from 1 (January) to 12 (December)
+    pub month: u32,
+    /// The day of the month, starting at 1
+    pub day: u32,
+}
+
+impl Default for CalendarDate {
+    fn default() -> Self {
+        Self::new(1970, 1, 1)
+    }
+}
+
+impl std::fmt::Display for CalendarDate {
+    /// Formats the date as `YYYY-MM-DD`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl CalendarDate {
+    /// Creates a date, clamping the month and day to valid values
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        Self {
+            year,
+            month,
+            day: day.clamp(1, Self::days_in_month(year, month)),
+        }
+    }
+
+    /// The current date (in UTC)
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// The day of the week, from 0 (Monday) to 6 (Sunday)
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    /// The first day of this date's month
+    pub fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// Moves the date by the given number of days (backward if negative)
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Moves the date by the given number of months (backward if negative), keeping the day
+    /// within the resulting month (e.g. January 31 plus one month is the end of February)
+    pub fn add_months(&self, months: i32) -> Self {
+        let months = self.year * 12 + self.month as i32 - 1 + months;
+        Self::new(
+            months.div_euclid(12),
+            months.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// Clamps the date within the given bounds (if any)
+    pub fn clamp_within(&self, min: Option<Self>, max: Option<Self>) -> Self {
+        let date = min.map_or(*self, |min| (*self).max(min));
+        max.map_or(date, |max| date.min(max))
+    }
+
+    /// Returns whether the date lies within the given bounds (if any)
+    pub fn is_within(&self, min: Option<Self>, max: Option<Self>) -> bool {
+        min.map_or(true, |min| *self >= min) && max.map_or(true, |max| *self <= max)
+    }
+
+    /// The number of days since 1970-01-01
+    fn to_days(&self) -> i64 {
+        // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year as i64 - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date the given number of days after 1970-01-01
+    fn from_days(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+}
+
+/// Identifies a day of a [DatePicker] calendar to its keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DaySlot {
+    calendar: Index,
+    /// The position of the day within the calendar, from the top-left corner
+    slot: usize,
+}
+
+/// A button that opens a calendar for picking a date
+///
+/// Clicking the button (or pressing `Return`, `Space`, or `Down` while it's focused) opens a
+/// popover with the calendar of a month, starting on the selected date's month. The header moves
+/// to the previous or next month (`<` and `>`) and year (`<<` and `>>`), and weeks start on
+/// Monday. Dates outside of `min_date` and `max_date` can't be picked. Picking a date closes the
+/// calendar, as does pressing `Escape` or anywhere outside of it.
+///
+/// The days of the calendar are focusable, so `Tab` moves focus into it. While a day is focused,
+/// the arrow keys move focus to the neighboring days (switching months when needed), `Page Up`
+/// and `Page Down` to the same day of the previous and next months, and `Return` or `Space` pick
+/// the focused day.
+///
+/// # Arguments
+///
+/// * `selected`: The selected date (if any)
+/// * `min_date`: The earliest date that can be picked (if any)
+/// * `max_date`: The latest date that can be picked (if any)
+/// * `placeholder`: The text displayed while no date is selected
+/// * `on_date_selected`: Called when the user picks a date. Contains the date.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the button. The `background_color` and `color`
+/// styles apply to both the button and the calendar.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::{CalendarDate, DatePicker};
+///
+/// let (selected, set_selected, ..) = use_state!(None);
+/// let on_date_selected = Handler::new(move |date| {
+///     set_selected(Some(date));
+/// });
+///
+/// rsx! {
+///     <DatePicker
+///         selected={selected}
+///         min_date={Some(CalendarDate::today())}
+///         placeholder={Some("Pick a date".to_string())}
+///         on_date_selected={Some(on_date_selected)}
+///     />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn DatePicker(
+    selected: Option<CalendarDate>,
+    min_date: Option<CalendarDate>,
+    max_date: Option<CalendarDate>,
+    placeholder: Option<String>,
+    on_date_selected: Option<Handler<CalendarDate>>,
+) {
+    // === State === //
+    let initial_month = selected
+        .unwrap_or_else(CalendarDate::today)
+        .clamp_within(min_date, max_date)
+        .first_of_month();
+    let (is_open, _, open_state) = use_state!(false);
+    let (_, _, shown_month) = use_state!(initial_month);
+
+    // === Events === //
+    let picker_id = self.id;
+    let cloned_open_state = open_state.clone();
+    let cloned_shown_month = shown_month.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        // The days of the calendar bubble their keys up to here, so only open on our own
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Escape if is_open => {
+                cloned_open_state.set(false);
+                // Closing the calendar takes precedence over back navigation
+                event.prevent_default();
+            }
+            KeyCode::Return | KeyCode::Space | KeyCode::Down
+                if !is_open && event.target == picker_id =>
+            {
+                cloned_shown_month.set(initial_month);
+                cloned_open_state.set(true);
+            }
+            _ => {}
+        },
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Pixels(BUTTON_HEIGHT)),
+        ..base_styles.clone()
+    });
+
+    let background_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+
+    let button_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(BUTTON_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(if selected.is_some() {
+            text_color
+        } else {
+            Color::new(0.5, 0.5, 0.5, 1.0)
+        }),
+        ..Style::default()
+    };
+    let cloned_open_state = open_state.clone();
+    let cloned_shown_month = shown_month.clone();
+    let on_button_event = OnEvent::new(move |_, event| {
+        if event.event_type == EventType::Click {
+            if !is_open {
+                cloned_shown_month.set(initial_month);
+            }
+            cloned_open_state.set(!is_open);
+        }
+    });
+    let popover_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        ..Style::default()
+    };
+
+    let content = selected
+        .map(|date| date.to_string())
+        .or(placeholder)
+        .unwrap_or_default();
+
+    let cloned_open_state = open_state.clone();
+    let on_pick = Handler::new(move |date| {
+        if let Some(ref on_date_selected) = on_date_selected {
+            on_date_selected.call(date);
+        }
+        cloned_open_state.set(false);
+    });
+    let calendar = constructor! {
+        <DatePickerCalendar
+            shown_month={shown_month}
+            selected={selected}
+            min_date={min_date}
+            max_date={max_date}
+            text_color={text_color}
+            on_pick={on_pick}
+        />
+    };
+    let calendar_size = (
+        DAY_SIZE * 7.0 + CALENDAR_PADDING * 2.0,
+        HEADER_HEIGHT + WEEKDAYS_HEIGHT + DAY_SIZE * WEEKS as f32 + CALENDAR_PADDING * 2.0,
+    );
+
+    rsx! {
+        <Popover
+            content={calendar}
+            is_open={open_state}
+            size={calendar_size}
+            gap={4.0}
+            styles={Some(popover_styles)}
+        >
+            <Background styles={Some(button_styles)} on_event={Some(on_button_event)}>
+                <Text content={content} size={14.0} line_height={Some(22.0)} styles={Some(text_styles)} />
+            </Background>
+        </Popover>
+    }
+}
+
+/// The calendar of a month, within the popover of a [DatePicker]
+#[widget]
+fn DatePickerCalendar(
+    shown_month: Binding<CalendarDate>,
+    selected: Option<CalendarDate>,
+    min_date: Option<CalendarDate>,
+    max_date: Option<CalendarDate>,
+    text_color: Color,
+    on_pick: Handler<CalendarDate>,
+) {
+    context.bind(&shown_month);
+    let month = shown_month.get();
+    let grid_start = get_grid_start(month);
+
+    // === Events === //
+    let calendar_id = self.id;
+    let cloned_shown_month = shown_month.clone();
+    self.on_event = Some(OnEvent::new(move |ctx, event| {
+        if let EventType::KeyDown(evt) = event.event_type {
+            let slot = match ctx.get_metadata::<DaySlot>(event.target) {
+                Some(slot) if slot.calendar == calendar_id => slot.slot,
+                _ => return,
+            };
+            let date = grid_start.add_days(slot as i64);
+            let target = match evt.key() {
+                KeyCode::Left => date.add_days(-1),
+                KeyCode::Right => date.add_days(1),
+                KeyCode::Up => date.add_days(-7),
+                KeyCode::Down => date.add_days(7),
+                KeyCode::PageUp => date.add_months(-1),
+                KeyCode::PageDown => date.add_months(1),
+                _ => return,
+            };
+            event.stop_propagation();
+            if target.is_within(min_date, max_date) {
+                move_focus(ctx, calendar_id, month, target, &cloned_shown_month);
+            }
+        }
+    }));
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        padding: StyleProp::Value(Units::Pixels(CALENDAR_PADDING)),
+        ..Style::default()
+    });
+
+    let header_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(HEADER_HEIGHT)),
+        ..Style::default()
+    };
+    let title_styles = Style {
+        color: StyleProp::Value(text_color),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let row_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    let title = format!("{} {}", MONTH_NAMES[month.month as usize - 1], month.year);
+    let nav_button = |label: &str, months: i32| {
+        let target = month.add_months(months);
+        // The month can be shown if any of its days can be picked
+        let last_day = target.add_months(1).add_days(-1);
+        let disabled = min_date.map_or(false, |min| last_day < min)
+            || max_date.map_or(false, |max| target > max);
+        let label = label.to_string();
+        let shown_month = shown_month.clone();
+        constructor! {
+            <DatePickerNavButton
+                label={label}
+                target={target}
+                disabled={disabled}
+                text_color={text_color}
+                shown_month={shown_month}
+            />
+        }
+    };
+    let previous_buttons = vec![nav_button("<<", -12), nav_button("<", -1)];
+    let next_buttons = vec![nav_button(">", 1), nav_button(">>", 12)];
+
+    let weekday_styles = Style {
+        width: StyleProp::Value(Units::Pixels(DAY_SIZE)),
+        height: StyleProp::Value(Units::Pixels(WEEKDAYS_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let weekday_text_styles = Style {
+        color: StyleProp::Value(Color::new(text_color.r, text_color.g, text_color.b, 0.6)),
+        ..Style::default()
+    };
+    let weekdays = WEEKDAY_NAMES
+        .iter()
+        .map(|name| {
+            let name = name.to_string();
+            let weekday_styles = weekday_styles.clone();
+            let weekday_text_styles = weekday_text_styles.clone();
+            constructor! {
+                <Element styles={Some(weekday_styles)}>
+                    <Text content={name} size={12.0} line_height={Some(WEEKDAYS_HEIGHT)} styles={Some(weekday_text_styles)} />
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let today = CalendarDate::today();
+    let weeks = (0..WEEKS)
+        .map(|week| {
+            let days = (0..7)
+                .map(|weekday| {
+                    let slot = week * 7 + weekday;
+                    let date = grid_start.add_days(slot as i64);
+                    let on_pick = on_pick.clone();
+                    constructor! {
+                        <DatePickerDay
+                            date={date}
+                            calendar={calendar_id}
+                            slot={slot}
+                            is_outside={date.month != month.month}
+                            is_selected={selected == Some(date)}
+                            is_today={date == today}
+                            disabled={!date.is_within(min_date, max_date)}
+                            text_color={text_color}
+                            on_pick={on_pick}
+                        />
+                    }
+                })
+                .collect::<Vec<_>>();
+            let row_styles = row_styles.clone();
+            constructor! {
+                <Element styles={Some(row_styles)}>
+                    <VecTracker data={days} />
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <Element styles={Some(header_styles)}>
+                <VecTracker data={previous_buttons} />
+                <Text content={title} size={14.0} line_height={Some(HEADER_HEIGHT)} styles={Some(title_styles)} />
+                <VecTracker data={next_buttons} />
+            </Element>
+            <Element styles={Some(row_styles)}>
+                <VecTracker data={weekdays} />
+            </Element>
+            <VecTracker data={weeks} />
+        </>
+    }
+}
+
+/// A button of a [DatePicker] calendar's header, showing another month
+#[widget]
+fn DatePickerNavButton(
+    label: String,
+    target: CalendarDate,
+    disabled: bool,
+    text_color: Color,
+    shown_month: Binding<CalendarDate>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Click if !disabled => shown_month.set(target),
+        _ => {}
+    }));
+
+    let button_styles = Style {
+        background_color: StyleProp::Value(if is_hovered && !disabled {
+            Color::new(0.0781, 0.0898, 0.101, 1.0)
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        }),
+        border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+        width: StyleProp::Value(Units::Pixels(DAY_SIZE)),
+        height: StyleProp::Value(Units::Pixels(HEADER_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(Color::new(
+            text_color.r,
+            text_color.g,
+            text_color.b,
+            if disabled { 0.3 } else { 1.0 },
+        )),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(button_styles)}>
+            <Text content={label} size={14.0} line_height={Some(HEADER_HEIGHT)} styles={Some(text_styles)} />
+        </Background>
+    }
+}
+
+/// A single day of a [DatePicker] calendar
+#[widget(focusable)]
+fn DatePickerDay(
+    date: CalendarDate,
+    calendar: Index,
+    slot: usize,
+    is_outside: bool,
+    is_selected: bool,
+    is_today: bool,
+    disabled: bool,
+    text_color: Color,
+    on_pick: Handler<CalendarDate>,
+) {
+    context.set_metadata(self.id, DaySlot { calendar, slot });
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    let (has_focus, set_has_focus, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Focus => set_has_focus(true),
+        EventType::Blur => set_has_focus(false),
+        EventType::Click if !disabled => on_pick.call(date),
+        EventType::KeyDown(evt) if !disabled => {
+            if matches!(evt.key(), KeyCode::Return | KeyCode::Space) {
+                on_pick.call(date);
+                event.stop_propagation();
+            }
+        }
+        _ => {}
+    }));
+
+    let background_color = if is_selected {
+        Color::new(0.2, 0.35, 0.6, 1.0)
+    } else if (is_hovered || has_focus) && !disabled {
+        Color::new(0.0781, 0.0898, 0.101, 1.0)
+    } else {
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    };
+    let day_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+        border: StyleProp::Value(if has_focus || is_today {
+            (1.0, 1.0, 1.0, 1.0)
+        } else {
+            (0.0, 0.0, 0.0, 0.0)
+        }),
+        border_color: StyleProp::Value(if has_focus {
+            text_color
+        } else {
+            Color::new(text_color.r, text_color.g, text_color.b, 0.4)
+        }),
+        width: StyleProp::Value(Units::Pixels(DAY_SIZE)),
+        height: StyleProp::Value(Units::Pixels(DAY_SIZE)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let alpha = if disabled {
+        0.2
+    } else if is_outside {
+        0.5
+    } else {
+        1.0
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(Color::new(text_color.r, text_color.g, text_color.b, alpha)),
+        ..Style::default()
+    };
+
+    let content = date.day.to_string();
+    rsx! {
+        <Background styles={Some(day_styles)}>
+            <Text content={content} size={14.0} line_height={Some(DAY_SIZE)} styles={Some(text_styles)} />
+        </Background>
+    }
+}
+
+/// Get the first day shown by the calendar of the given month (the Monday of its first week)
+fn get_grid_start(month: CalendarDate) -> CalendarDate {
+    let first = month.first_of_month();
+    first.add_days(-(first.weekday() as i64))
+}
+
+/// Moves focus to the day of a [DatePicker] calendar showing the given date
+///
+/// If the date is in another month, that month is shown. The day is found by its position, since
+/// each position keeps its widget when the month changes.
+fn move_focus(
+    context: &mut KayakContext,
+    calendar: Index,
+    month: CalendarDate,
+    target: CalendarDate,
+    shown_month: &Binding<CalendarDate>,
+) {
+    let target_month = if target.year == month.year && target.month == month.month {
+        month
+    } else {
+        let target_month = target.first_of_month();
+        shown_month.set(target_month);
+        target_month
+    };
+
+    let slot = (target.to_days() - get_grid_start(target_month).to_days()) as usize;
+    let day = context
+        .find_all(|context, index| {
+            context.get_metadata::<DaySlot>(index) == Some(DaySlot { calendar, slot })
+        })
+        .into_iter()
+        .next();
+    if let Some(day) = day {
+        context.set_focus(day);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, EventType, Handler, KeyCode, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Image, Text};
+
+/// The width and height of the portrait (in pixels)
+const PORTRAIT_SIZE: f32 = 96.0;
+/// The height of each choice (in pixels)
+const CHOICE_HEIGHT: f32 = 26.0;
+
+/// A conversation box showing what a character says, with an optional portrait and choices
+///
+/// The text is revealed one character at a time as `revealed` grows, like a typewriter. The box
+/// doesn't track time itself, so the app should increase `revealed` (usually from a system) until
+/// it reaches the length of the text.
+///
+/// Pressing `Return` or `Space` (or clicking the box) while the text is still being revealed calls
+/// `on_advance`, which usually reveals the rest at once. Once revealed, the choices are shown and
+/// can be highlighted with `Up` and `Down` and picked with `Return`, `Space`, or a click. Without
+/// choices, advancing calls `on_advance` again, usually moving on to the next line.
+///
+/// # Arguments
+///
+/// * `speaker`: The name shown above the text
+/// * `portrait`: The image ID of the speaker's portrait (see `ImageManager`)
+/// * `content`: The text being said
+/// * `revealed`: The number of characters of `content` shown so far. If `None`, all of it is shown.
+/// * `choices`: The responses the player can pick from once the text is revealed
+/// * `on_advance`: Called when the player wants to skip the reveal or move past a line without choices
+/// * `on_choice`: Called when the player picks a choice. Contains the choice's index.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the box. The `color` style applies to the text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::DialogueBox;
+///
+/// let on_choice = Handler::new(|index: usize| println!("Picked {}", index));
+///
+/// rsx! {
+///     <DialogueBox
+///         speaker={Some("Innkeeper".to_string())}
+///         content={"Need a room for the night?".to_string()}
+///         choices={vec!["Yes".to_string(), "No thanks".to_string()]}
+///         on_choice={Some(on_choice)}
+///     />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn DialogueBox(
+    speaker: Option<String>,
+    portrait: Option<u16>,
+    content: String,
+    revealed: Option<usize>,
+    choices: Vec<String>,
+    on_advance: Option<Handler<()>>,
+    on_choice: Option<Handler<usize>>,
+) {
+    let content_len = content.chars().count();
+    let is_revealed = revealed.map_or(true, |revealed| revealed >= content_len);
+    let shows_choices = is_revealed && !choices.is_empty();
+
+    // === State === //
+    let (highlighted, set_highlighted, ..) = use_state!(0usize);
+    let highlighted = if highlighted < choices.len() {
+        highlighted
+    } else {
+        0
+    };
+
+    // === Events === //
+    let choice_count = choices.len();
+    let cloned_on_advance = on_advance.clone();
+    let cloned_on_choice = on_choice.clone();
+    let cloned_set_highlighted = set_highlighted.clone();
+    let advance = move || {
+        if shows_choices {
+            if let Some(ref on_choice) = cloned_on_choice {
+                on_choice.call(highlighted);
+            }
+            cloned_set_highlighted(0);
+        } else if let Some(ref on_advance) = cloned_on_advance {
+            on_advance.call(());
+        }
+    };
+    let cloned_set_highlighted = set_highlighted.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Up if shows_choices => {
+                cloned_set_highlighted((highlighted + choice_count - 1) % choice_count);
+                event.stop_propagation();
+            }
+            KeyCode::Down if shows_choices => {
+                cloned_set_highlighted((highlighted + 1) % choice_count);
+                event.stop_propagation();
+            }
+            KeyCode::Return | KeyCode::Space if !evt.is_repeat() => {
+                advance();
+                event.stop_propagation();
+            }
+            _ => {}
+        },
+        // Clicking a choice picks it directly, so only clicks elsewhere advance
+        EventType::Click if !shows_choices => advance(),
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 0.95))
+        } else {
+            base_styles.background_color.clone()
+        },
+        border_radius: if matches!(base_styles.border_radius, StyleProp::Default) {
+            StyleProp::Value((8.0, 8.0, 8.0, 8.0))
+        } else {
+            base_styles.border_radius.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.height.clone()
+        },
+        padding_left: StyleProp::Value(Units::Pixels(12.0)),
+        padding_right: StyleProp::Value(Units::Pixels(12.0)),
+        padding_top: StyleProp::Value(Units::Pixels(12.0)),
+        padding_bottom: StyleProp::Value(Units::Pixels(12.0)),
+        ..base_styles
+    });
+
+    let portrait_styles = Style {
+        width: StyleProp::Value(Units::Pixels(PORTRAIT_SIZE)),
+        height: StyleProp::Value(Units::Pixels(PORTRAIT_SIZE)),
+        border_radius: StyleProp::Value((6.0, 6.0, 6.0, 6.0)),
+        right: StyleProp::Value(Units::Pixels(12.0)),
+        ..Style::default()
+    };
+    let body_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+    let speaker_styles = Style {
+        color: StyleProp::Value(Color::new(0.933, 0.745, 0.745, 1.0)),
+        ..Style::default()
+    };
+    let content_styles = Style {
+        color: StyleProp::Value(text_color),
+        top: StyleProp::Value(Units::Pixels(if speaker.is_some() { 6.0 } else { 0.0 })),
+        ..Style::default()
+    };
+    let choices_styles = Style {
+        top: StyleProp::Value(Units::Pixels(6.0)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    let has_portrait = portrait.is_some();
+    let portrait = portrait.unwrap_or_default();
+    let has_speaker = speaker.is_some();
+    let speaker = speaker.unwrap_or_default();
+    let visible_content = match revealed {
+        Some(revealed) => content.chars().take(revealed).collect::<String>(),
+        None => content,
+    };
+
+    let choices = choices
+        .into_iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            let on_choice = on_choice.clone();
+            let set_highlighted = set_highlighted.clone();
+            let on_hover = Handler::new(move |index: usize| set_highlighted(index));
+            constructor! {
+                <DialogueChoice
+                    content={choice}
+                    index={index}
+                    is_highlighted={index == highlighted}
+                    text_color={text_color}
+                    on_hover={Some(on_hover)}
+                    on_choice={on_choice}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <If condition={has_portrait}>
+                <Image handle={portrait} styles={Some(portrait_styles)} />
+            </If>
+            <Element styles={Some(body_styles)}>
+                <If condition={has_speaker}>
+                    <Text content={speaker} size={16.0} styles={Some(speaker_styles)} />
+                </If>
+                <Text content={visible_content} size={16.0} styles={Some(content_styles)} />
+                <If condition={shows_choices}>
+                    <Element styles={Some(choices_styles)}>
+                        <VecTracker data={choices} />
+                    </Element>
+                </If>
+            </Element>
+        </>
+    }
+}
+
+/// A single choice of a [DialogueBox]
+#[widget]
+fn DialogueChoice(
+    content: String,
+    index: usize,
+    is_highlighted: bool,
+    text_color: Color,
+    on_hover: Option<Handler<usize>>,
+    on_choice: Option<Handler<usize>>,
+) {
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => {
+            if let Some(ref on_hover) = on_hover {
+                on_hover.call(index);
+            }
+        }
+        EventType::Click => {
+            if let Some(ref on_choice) = on_choice {
+                on_choice.call(index);
+            }
+        }
+        _ => {}
+    }));
+
+    let choice_styles = Style {
+        background_color: StyleProp::Value(if is_highlighted {
+            Color::new(0.2, 0.35, 0.6, 1.0)
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        }),
+        border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+        height: StyleProp::Value(Units::Pixels(CHOICE_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(8.0)),
+        padding_right: StyleProp::Value(Units::Pixels(8.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(text_color),
+        ..Style::default()
+    };
+    let content = format!("{}. {}", index + 1, content);
+
+    rsx! {
+        <Background styles={Some(choice_styles)}>
+            <Text content={content} size={14.0} line_height={Some(CHOICE_HEIGHT)} styles={Some(text_styles)} />
+        </Background>
+    }
+}
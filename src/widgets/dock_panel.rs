@@ -0,0 +1,94 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Dock, Style, StyleProp, Units},
+    widget, Children,
+};
+
+/// A container docking its children to its edges, with the last child filling the space left
+///
+/// Each child is docked to an edge by wrapping it in a [DockItem], in order: a child docked to
+/// the top takes the top of the space left by the children before it. Children that aren't
+/// wrapped are docked to the left.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the panel, which stretches to its parent by
+/// default.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::core::styles::Dock;
+///
+/// rsx! {
+///     <DockPanel>
+///         <DockItem dock={Dock::Top}>
+///             <Text content={"Toolbar".to_string()} size={16.0} />
+///         </DockItem>
+///         <DockItem dock={Dock::Left}>
+///             <Text content={"Sidebar".to_string()} size={16.0} />
+///         </DockItem>
+///         <DockItem>
+///             <Text content={"Content".to_string()} size={16.0} />
+///         </DockItem>
+///     </DockPanel>
+/// }
+/// ```
+#[widget]
+pub fn DockPanel(children: Children) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Docks its children to an edge of the parent [DockPanel]
+///
+/// # Arguments
+///
+/// * `dock`: The edge the item is docked to (ignored for the last item, which fills the space
+///   left)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the item. Items docked to the top or bottom fit the
+/// height of their children unless given one (and the same goes for the width of items docked to
+/// the left or right), while their other side always fills the space left.
+#[widget]
+pub fn DockItem(children: Children, dock: Dock) {
+    let base_styles = styles.clone().unwrap_or_default();
+    let fit = |prop: &StyleProp<Units>| {
+        if matches!(prop, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            prop.clone()
+        }
+    };
+    let (width, height) = match dock {
+        Dock::Left | Dock::Right => (fit(&base_styles.width), base_styles.height.clone()),
+        Dock::Top | Dock::Bottom => (base_styles.width.clone(), fit(&base_styles.height)),
+        Dock::Fill => (base_styles.width.clone(), base_styles.height.clone()),
+    };
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        dock: StyleProp::Value(dock),
+        width,
+        height,
+        ..base_styles
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
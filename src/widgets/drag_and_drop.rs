@@ -0,0 +1,138 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    use_state, widget, Children, CursorIcon, DragPayload, DropFilter, EventType, Handler, OnEvent,
+};
+
+/// Lets its children be dragged onto a [DropTarget], carrying the given payload
+///
+/// The drag starts once the cursor moves a few pixels with the left mouse button pressed over the
+/// children. Drop targets that accept the payload are highlighted while the drag is over them.
+///
+/// # Arguments
+///
+/// * `payload`: The data carried by the drag. Nothing can be dragged if `None`.
+/// * `on_drag_end`: Called when the drag ends. Contains whether it was dropped on a target.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the children.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, DragPayload, DropFilter, Handler};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Card(u32);
+///
+/// let on_drop = Handler::new(|payload: DragPayload| {
+///     if let Some(card) = payload.get::<Card>() {
+///         println!("Played card {}", card.0);
+///     }
+/// });
+///
+/// rsx! {
+///     <>
+///         <Draggable payload={Some(DragPayload::new(Card(7)))}>
+///             <Text content={"Card 7".to_string()} size={16.0} />
+///         </Draggable>
+///         <DropTarget accepts={Some(DropFilter::of::<Card>())} on_drop={Some(on_drop)}>
+///             <Text content={"Play a card here".to_string()} size={16.0} />
+///         </DropTarget>
+///     </>
+/// }
+/// ```
+#[widget]
+pub fn Draggable(
+    children: Children,
+    payload: Option<DragPayload>,
+    on_drag_end: Option<Handler<bool>>,
+) {
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseIn => ctx.set_cursor_icon(CursorIcon::Move),
+        EventType::MouseOut => {
+            if !ctx.is_dragging() {
+                ctx.set_cursor_icon(CursorIcon::Default);
+            }
+        }
+        EventType::DragStart => {
+            if let Some(payload) = payload.clone() {
+                ctx.start_drag(payload);
+            }
+        }
+        EventType::DragEnd { dropped } => {
+            ctx.set_cursor_icon(CursorIcon::Default);
+            if let Some(ref on_drag_end) = on_drag_end {
+                on_drag_end.call(dropped);
+            }
+        }
+        _ => {}
+    }));
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// A place that drags from [Draggable] widgets can be dropped on
+///
+/// # Arguments
+///
+/// * `accepts`: Which payloads can be dropped. If `None`, any payload can.
+/// * `on_drop`: Called when an accepted drag is dropped. Contains the payload of the drag.
+/// * `highlight_color`: The color of the target while an accepted drag is over it. If `None`, a
+///   translucent blue is used.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the target, with its `background_color` shown
+/// while no accepted drag is over it.
+#[widget]
+pub fn DropTarget(
+    children: Children,
+    accepts: Option<DropFilter>,
+    on_drop: Option<Handler<DragPayload>>,
+    highlight_color: Option<Color>,
+) {
+    let (is_over, set_is_over, ..) = use_state!(false);
+    context.set_drop_target(self.id, Some(accepts.unwrap_or_default()));
+
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::DragEnter => set_is_over(true),
+        EventType::DragLeave => set_is_over(false),
+        EventType::Drop => {
+            set_is_over(false);
+            if let (Some(on_drop), Some(payload)) = (&on_drop, ctx.drag_payload()) {
+                on_drop.call(payload);
+            }
+        }
+        _ => {}
+    }));
+
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        background_color: if is_over {
+            StyleProp::Value(highlight_color.unwrap_or(Color::new(0.27, 0.53, 0.93, 0.35)))
+        } else {
+            base_styles.background_color.clone()
+        },
+        ..base_styles
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
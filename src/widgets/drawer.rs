@@ -0,0 +1,219 @@
+use std::time::Instant;
+
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Children, EventType, Fragment, MutableBound, OnEvent,
+    VecTracker,
+};
+
+use crate::widgets::{Background, Clip, If};
+
+/// How long the drawer takes to open or close by default (in seconds)
+const DEFAULT_DURATION: f32 = 0.25;
+
+/// The edge a [Drawer] slides in from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawerSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Default for DrawerSide {
+    fn default() -> Self {
+        Self::Left
+    }
+}
+
+/// How an open [Drawer] affects the rest of the content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawerMode {
+    /// The drawer is drawn over the content
+    Overlay,
+    /// The content is pushed aside to make room for the drawer
+    Push,
+}
+
+impl Default for DrawerMode {
+    fn default() -> Self {
+        Self::Overlay
+    }
+}
+
+/// A panel that slides in from an edge over (or alongside) its children
+///
+/// While open, a backdrop covers the children and pressing it closes the drawer. The drawer
+/// animates between its open and closed positions, re-rendering every frame until it's done.
+///
+/// # Arguments
+///
+/// * `children`: The content the drawer slides in over
+/// * `content`: The content of the drawer itself
+/// * `open`: Whether the drawer is open. Pressing the backdrop sets this to false.
+/// * `side`: The edge the drawer slides in from
+/// * `mode`: Whether the drawer is drawn over the children or pushes them aside
+/// * `size`: The width (or height, for the top and bottom edges) of the drawer (in pixels)
+/// * `duration`: How long the drawer takes to open or close (in seconds). Defaults to 0.25.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the children. The
+/// `background_color` style applies to the drawer.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, constructor, rsx};
+/// # use kayak_ui::widgets::{Drawer, DrawerSide, Text};
+///
+/// let is_open = bind(false);
+/// let content = constructor! { <><Text content={"Settings".to_string()} size={16.0} /></> };
+///
+/// rsx! {
+///     <Drawer content={content} open={is_open} side={DrawerSide::Right} size={300.0}>
+///         <Text content={"Main content".to_string()} size={16.0} />
+///     </Drawer>
+/// }
+/// ```
+#[widget]
+pub fn Drawer(
+    children: Children,
+    content: Fragment,
+    open: Binding<bool>,
+    side: DrawerSide,
+    mode: DrawerMode,
+    size: f32,
+    duration: Option<f32>,
+) {
+    context.bind(&open);
+    let is_open = open.get();
+
+    // === Animation === //
+    let target = if is_open { 1.0 } else { 0.0 };
+    let (progress, set_progress, ..) = use_state!(target);
+    let (last_frame, set_last_frame, ..) = use_state!(None::<Instant>);
+    let duration = duration.unwrap_or(DEFAULT_DURATION);
+    let progress = if progress == target {
+        if last_frame.is_some() {
+            set_last_frame(None);
+        }
+        progress
+    } else {
+        // Changing either state re-renders the drawer next frame, until it reaches its target
+        let now = context.now();
+        let elapsed = last_frame.map_or(0.0, |last_frame| {
+            now.saturating_duration_since(last_frame).as_secs_f32()
+        });
+        let step = if duration > 0.0 {
+            elapsed / duration
+        } else {
+            1.0
+        };
+        let next_progress = if target > progress {
+            (progress + step).min(target)
+        } else {
+            (progress - step).max(target)
+        };
+        set_last_frame(Some(now));
+        set_progress(next_progress);
+        next_progress
+    };
+    let is_shown = progress > 0.0;
+    // Ease the movement in and out
+    let offset = size * progress * progress * (3.0 - 2.0 * progress);
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Clip),
+        ..base_styles.clone()
+    });
+
+    let layout = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let is_horizontal = matches!(side, DrawerSide::Left | DrawerSide::Right);
+    let push = if mode == DrawerMode::Push {
+        offset
+    } else {
+        0.0
+    };
+    let (content_left, content_top) = match side {
+        DrawerSide::Left => (push, 0.0),
+        DrawerSide::Right => (-push, 0.0),
+        DrawerSide::Top => (0.0, push),
+        DrawerSide::Bottom => (0.0, -push),
+    };
+    let content_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(content_left)),
+        top: StyleProp::Value(Units::Pixels(content_top)),
+        width: StyleProp::Value(Units::Pixels(layout.width)),
+        height: StyleProp::Value(Units::Pixels(layout.height)),
+        ..Style::default()
+    };
+
+    let backdrop_styles = Style {
+        background_color: StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.4 * progress)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let on_backdrop_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseDown => {
+            open.set(false);
+            event.stop_propagation();
+        }
+        _ => {}
+    });
+
+    let (drawer_left, drawer_top) = match side {
+        DrawerSide::Left => (offset - size, 0.0),
+        DrawerSide::Right => (layout.width - offset, 0.0),
+        DrawerSide::Top => (0.0, offset - size),
+        DrawerSide::Bottom => (0.0, layout.height - offset),
+    };
+    let drawer_styles = Style {
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+        } else {
+            base_styles.background_color
+        },
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(drawer_left)),
+        top: StyleProp::Value(Units::Pixels(drawer_top)),
+        width: StyleProp::Value(if is_horizontal {
+            Units::Pixels(size)
+        } else {
+            Units::Stretch(1.0)
+        }),
+        height: StyleProp::Value(if is_horizontal {
+            Units::Stretch(1.0)
+        } else {
+            Units::Pixels(size)
+        }),
+        ..Style::default()
+    };
+
+    let content = vec![content];
+    rsx! {
+        <>
+            <Clip styles={Some(content_styles)}>
+                {children}
+            </Clip>
+            <If condition={is_shown}>
+                <Background styles={Some(backdrop_styles)} on_event={Some(on_backdrop_event)} />
+                <Background styles={Some(drawer_styles)}>
+                    <VecTracker data={content} />
+                </Background>
+            </If>
+        </>
+    }
+}
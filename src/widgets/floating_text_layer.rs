@@ -0,0 +1,83 @@
+use crate::bevy::{FloatingTextLabel, FloatingTexts};
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Binding, Bound, PointerEvents, VecTracker,
+};
+use crate::font::{CoordinateSystem, KayakFont};
+
+use crate::widgets::Text;
+
+/// Shows the floating texts (e.g. damage numbers) spawned with the `FloatingTexts` resource
+///
+/// Each label is centered on the screen projection of its world position. The layer covers its
+/// parent without blocking the cursor, so it's usually placed last in the [App](crate::widgets::App)
+/// to draw on top of the rest of the UI.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the layer.
+#[widget]
+pub fn FloatingTextLayer() {
+    let labels = if let Ok(world) = context.get_global_state::<bevy::prelude::World>() {
+        if let Some(floating_texts) = world.get_resource::<FloatingTexts>() {
+            floating_texts.labels()
+        } else {
+            return;
+        }
+    } else {
+        return;
+    };
+    context.bind(&labels);
+
+    let font: Binding<Option<KayakFont>> = context.get_asset("Roboto".to_string());
+    context.bind(&font);
+    let font = font.get();
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let labels = labels
+        .get()
+        .into_iter()
+        .map(|label| {
+            let FloatingTextLabel {
+                content,
+                position,
+                color,
+                size,
+            } = label;
+            let (width, height) = font.as_ref().map_or((0.0, 0.0), |font| {
+                font.measure(
+                    CoordinateSystem::PositiveYDown,
+                    &content,
+                    size,
+                    size * 1.2,
+                    (f32::MAX, f32::MAX),
+                )
+            });
+            let label_styles = Style {
+                color: StyleProp::Value(color),
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Pixels(position.0 - width / 2.0)),
+                top: StyleProp::Value(Units::Pixels(position.1 - height / 2.0)),
+                ..Style::default()
+            };
+            constructor! {
+                <Text content={content} size={size} styles={Some(label_styles)} />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={labels} />
+    }
+}
@@ -0,0 +1,128 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, Children,
+};
+
+/// The size of a row or column of a [Grid]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// A size in pixels
+    Fixed(f32),
+    /// A share of the space left by the other tracks, relative to the other stretching tracks
+    Stretch(f32),
+    /// As large as the largest item placed only in this track
+    Auto,
+}
+
+impl Default for GridTrack {
+    fn default() -> Self {
+        Self::Stretch(1.0)
+    }
+}
+
+impl From<GridTrack> for Units {
+    fn from(track: GridTrack) -> Self {
+        match track {
+            GridTrack::Fixed(pixels) => Units::Pixels(pixels),
+            GridTrack::Stretch(factor) => Units::Stretch(factor),
+            GridTrack::Auto => Units::Auto,
+        }
+    }
+}
+
+/// A container arranging its children into rows and columns
+///
+/// Each child is placed in a cell by wrapping it in a [GridItem]. Children that aren't wrapped
+/// are placed in the first cell.
+///
+/// # Arguments
+///
+/// * `rows`: The sizes of the rows, from top to bottom (a single stretching row if empty)
+/// * `columns`: The sizes of the columns, from left to right (a single stretching column if
+///   empty)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the grid, which stretches to its parent by
+/// default. Its `layout_type`, `grid_rows`, and `grid_cols` styles are set from its props.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::GridTrack;
+///
+/// let rows = vec![GridTrack::Fixed(32.0), GridTrack::Stretch(1.0)];
+/// let columns = vec![GridTrack::Auto, GridTrack::Stretch(1.0)];
+///
+/// rsx! {
+///     <Grid rows={rows} columns={columns}>
+///         <GridItem col_span={2}>
+///             <Text content={"Header".to_string()} size={16.0} />
+///         </GridItem>
+///         <GridItem row={1}>
+///             <Text content={"Sidebar".to_string()} size={16.0} />
+///         </GridItem>
+///         <GridItem row={1} column={1}>
+///             <Text content={"Content".to_string()} size={16.0} />
+///         </GridItem>
+///     </Grid>
+/// }
+/// ```
+#[widget]
+pub fn Grid(children: Children, rows: Vec<GridTrack>, columns: Vec<GridTrack>) {
+    let tracks = |tracks: &Vec<GridTrack>| -> Vec<Units> {
+        if tracks.is_empty() {
+            vec![GridTrack::default().into()]
+        } else {
+            tracks.iter().map(|track| (*track).into()).collect()
+        }
+    };
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Grid),
+        grid_rows: StyleProp::Value(tracks(&rows)),
+        grid_cols: StyleProp::Value(tracks(&columns)),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Places its children in a cell of the parent [Grid]
+///
+/// # Arguments
+///
+/// * `row`: The row the cell starts in, from zero
+/// * `column`: The column the cell starts in, from zero
+/// * `row_span`: The number of rows the cell spans (1 if zero)
+/// * `col_span`: The number of columns the cell spans (1 if zero)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the cell, which fills the rows and columns it
+/// spans. Its `row_index`, `col_index`, `row_span`, and `col_span` styles are set from its props.
+#[widget]
+pub fn GridItem(children: Children, row: usize, column: usize, row_span: usize, col_span: usize) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        row_index: StyleProp::Value(row),
+        col_index: StyleProp::Value(column),
+        row_span: StyleProp::Value(row_span.max(1)),
+        col_span: StyleProp::Value(col_span.max(1)),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
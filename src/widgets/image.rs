@@ -1,16 +1,200 @@
 use crate::core::{
+    layout_cache::Space,
     render_command::RenderCommand,
     rsx,
     styles::{Style, StyleProp},
-    widget, Children,
+    widget, Children, KayakContext,
 };
 
+/// The load state of an image, as far as widgets are concerned
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ImageStatus {
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// Gets the load state of the given image, re-rendering the current widget whenever it changes
+#[allow(unused_variables)]
+pub(crate) fn bind_image_status(context: &mut KayakContext, handle: u16) -> ImageStatus {
+    #[cfg(feature = "bevy_renderer")]
+    {
+        use crate::bevy::ImageManager;
+        use crate::core::Bound;
+        use bevy::asset::LoadState;
+
+        let load_state = if let Ok(world) = context.get_global_state::<bevy::prelude::World>() {
+            world
+                .get_resource::<ImageManager>()
+                .and_then(|image_manager| image_manager.get_load_state(&handle))
+        } else {
+            None
+        };
+
+        if let Some(load_state) = load_state {
+            context.bind(&load_state);
+            return match load_state.get() {
+                LoadState::Loaded => ImageStatus::Loaded,
+                LoadState::Failed => ImageStatus::Failed,
+                _ => ImageStatus::Loading,
+            };
+        }
+    }
+
+    ImageStatus::Loaded
+}
+
+/// Picks the styles to render an image with, based on its load state
+///
+/// While loading or failed, the given replacement styles (if any) are applied over the styles and
+/// rendered as a quad instead, so the image keeps its layout.
+pub(crate) fn image_status_styles(
+    status: ImageStatus,
+    render_command: RenderCommand,
+    styles: Style,
+    loading_style: Option<Style>,
+    error_style: Option<Style>,
+) -> Style {
+    let replacement = match status {
+        ImageStatus::Loading => loading_style,
+        ImageStatus::Failed => error_style,
+        ImageStatus::Loaded => None,
+    };
+
+    if let Some(replacement) = replacement {
+        let mut styles = styles;
+        styles.apply(&replacement);
+        styles.render_command = StyleProp::Value(RenderCommand::Quad);
+        styles
+    } else {
+        Style {
+            render_command: StyleProp::Value(render_command),
+            ..styles
+        }
+    }
+}
+
+/// Sets the shape hit by the cursor to the pixels of the given image with an alpha above the
+/// threshold, stretched over the layout (or as a nine-patch with the given border)
+///
+/// The hit shape set in the styles takes precedence. The whole layout is hit until the image's
+/// pixels can be read (see `ImageManager::get_hit_mask`).
+#[allow(unused_variables)]
+pub(crate) fn set_alpha_hit_test(
+    context: &mut KayakContext,
+    styles: &mut Style,
+    handle: u16,
+    threshold: u8,
+    border: Option<Space>,
+) {
+    if !matches!(styles.hit_test, StyleProp::Default) {
+        return;
+    }
+
+    #[cfg(feature = "bevy_renderer")]
+    {
+        use crate::bevy::ImageManager;
+        use crate::core::{HitTest, HitTestFn};
+        use bevy::{prelude::Assets, render::texture::Image};
+
+        let hit_mask = if let Ok(mut world) = context.get_global_state::<bevy::prelude::World>() {
+            let world = world.cell();
+            let hit_mask = match (
+                world.get_resource_mut::<ImageManager>(),
+                world.get_resource::<Assets<Image>>(),
+            ) {
+                (Some(mut image_manager), Some(images)) => {
+                    image_manager.get_hit_mask(handle, &images)
+                }
+                _ => None,
+            };
+            hit_mask
+        } else {
+            None
+        };
+
+        if let Some(hit_mask) = hit_mask {
+            let hit_mask = hit_mask.with_threshold(threshold);
+            let hit_test = match border {
+                None => HitTest::Mask(hit_mask),
+                Some(border) => HitTest::Custom(HitTestFn::new(move |layout, point| {
+                    let (width, height) = hit_mask.size();
+                    let x = nine_patch_offset(
+                        point.0 - layout.posx,
+                        layout.width,
+                        (border.left, border.right),
+                        width as f32,
+                    );
+                    let y = nine_patch_offset(
+                        point.1 - layout.posy,
+                        layout.height,
+                        (border.top, border.bottom),
+                        height as f32,
+                    );
+                    hit_mask.contains((x, y))
+                })),
+            };
+            styles.hit_test = StyleProp::Value(hit_test);
+        }
+    }
+}
+
+/// Maps an offset along a nine-patch to its image (from 0.0 to 1.0), where the borders keep their
+/// size in pixels and the middle stretches over the rest
+#[cfg(feature = "bevy_renderer")]
+fn nine_patch_offset(offset: f32, length: f32, border: (f32, f32), image_length: f32) -> f32 {
+    let (start, end) = border;
+    let image_offset = if offset < start {
+        offset
+    } else if offset > length - end {
+        image_length - (length - offset)
+    } else {
+        let middle = length - start - end;
+        if middle > 0.0 {
+            start + (offset - start) / middle * (image_length - start - end)
+        } else {
+            start
+        }
+    };
+    if image_length > 0.0 {
+        image_offset / image_length
+    } else {
+        0.0
+    }
+}
+
+/// A widget that renders an image
+///
+/// The widget re-renders automatically once its image finishes loading.
+///
+/// # Arguments
+///
+/// * `handle`: The image's ID (see `ImageManager`)
+/// * `children`: The widget's children
+/// * `loading_style`: If set, these styles are applied over `styles` and rendered as a quad while the image loads
+/// * `error_style`: If set, these styles are applied over `styles` and rendered as a quad if the image fails to load
+/// * `alpha_hit_threshold`: If set, the cursor only hits the image where its alpha is above this
+///   value, which needs the pixels of the image to be read once it loads (8-bit RGBA images only)
 #[widget]
-pub fn Image(handle: u16, children: Children) {
-    *styles = Some(Style {
-        render_command: StyleProp::Value(RenderCommand::Image { handle }),
-        ..styles.clone().unwrap_or_default()
-    });
+pub fn Image(
+    handle: u16,
+    children: Children,
+    loading_style: Option<Style>,
+    error_style: Option<Style>,
+    alpha_hit_threshold: Option<u8>,
+) {
+    let status = bind_image_status(context, handle);
+    let mut image_styles = image_status_styles(
+        status,
+        RenderCommand::Image { handle },
+        styles.clone().unwrap_or_default(),
+        loading_style,
+        error_style,
+    );
+    if let (ImageStatus::Loaded, Some(threshold)) = (status, alpha_hit_threshold) {
+        set_alpha_hit_test(context, &mut image_styles, handle, threshold, None);
+    }
+    *styles = Some(image_styles);
 
     rsx! {
         <>
@@ -18,3 +202,45 @@ pub fn Image(handle: u16, children: Children) {
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{image_status_styles, ImageStatus};
+    use crate::core::{
+        render_command::RenderCommand,
+        styles::{PositionType, Style, StyleProp, Units},
+        Color,
+    };
+
+    #[test]
+    fn loading_images_should_keep_their_layout() {
+        let styles = Style {
+            width: StyleProp::Value(Units::Pixels(64.0)),
+            height: StyleProp::Value(Units::Pixels(32.0)),
+            left: StyleProp::Value(Units::Pixels(10.0)),
+            position_type: StyleProp::Value(PositionType::SelfDirected),
+            ..Style::default()
+        };
+        let loading_style = Style {
+            background_color: StyleProp::Value(Color::new(0.2, 0.2, 0.2, 1.0)),
+            ..Style::default()
+        };
+
+        let loading = image_status_styles(
+            ImageStatus::Loading,
+            RenderCommand::Layout,
+            styles.clone(),
+            Some(loading_style.clone()),
+            None,
+        );
+        assert_eq!(
+            StyleProp::Value(RenderCommand::Quad),
+            loading.render_command
+        );
+        assert_eq!(loading_style.background_color, loading.background_color);
+        assert_eq!(styles.width, loading.width);
+        assert_eq!(styles.height, loading.height);
+        assert_eq!(styles.left, loading.left);
+        assert_eq!(styles.position_type, loading.position_type);
+    }
+}
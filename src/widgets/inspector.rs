@@ -13,6 +13,19 @@ pub enum InspectData {
     Data(Vec<String>),
 }
 
+/// A panel for debugging the UI
+///
+/// It shows:
+///
+/// * The layout of the last clicked widget and of its parent (the "Go Up" button inspects the
+///   parent instead)
+/// * The path from the root to the focused widget
+/// * Whether the cursor is contained, wanted, or held by a widget (see
+///   [KayakContext::contains_cursor](crate::core::KayakContext::contains_cursor) and friends)
+/// * The live values of the bindings watched with
+///   [KayakContext::watch_binding](crate::core::KayakContext::watch_binding)
+///
+/// The panel is refreshed whenever a widget is clicked or a watched binding changes.
 #[widget]
 pub fn Inspector() {
     let (inspect_data, set_inspect_data, _) = use_state!(Vec::<String>::new());
@@ -31,14 +44,20 @@ pub fn Inspector() {
     let last_clicked = context.get_last_clicked_widget();
     context.bind(&last_clicked);
 
+    let watched_bindings = context.watched_bindings();
+    context.bind(&watched_bindings);
+    let watched_bindings = watched_bindings.get();
+    for watched in watched_bindings.iter() {
+        watched.bind(context);
+    }
+
     let last_clicked_value = last_clicked.get();
     let (id, _) = last_clicked_value.into_raw_parts();
 
+    let mut data = Vec::new();
     let mut parent_id_move = None;
     if let Some(layout) = context.widget_manager.get_layout(&last_clicked_value) {
         if let Some(node) = context.widget_manager.get_node(&last_clicked_value) {
-            let mut data = Vec::new();
-
             if let Some(name) = context.widget_manager.get_name(&last_clicked_value) {
                 data.push(format!("Name: {}", name));
             }
@@ -67,12 +86,50 @@ pub fn Inspector() {
                     data.push(format!("Height: {}", layout.height));
                 }
             }
-            set_inspect_data(data);
         }
     }
 
+    data.push(format!("_________Focus_________"));
+    if let Some(focus) = context.current_focus() {
+        let mut path = Vec::new();
+        let mut current = Some(focus);
+        while let Some(index) = current {
+            let (id, _) = index.into_raw_parts();
+            let name = context
+                .widget_manager
+                .get_name(&index)
+                .unwrap_or_else(|| "?".to_string());
+            path.push(match context.get_label(index) {
+                Some(label) => format!("{} \"{}\" ({})", name, label, id),
+                None => format!("{} ({})", name, id),
+            });
+            current = context.widget_manager.tree.get_parent(index);
+        }
+        path.reverse();
+        data.push(path.join("\n> "));
+    } else {
+        data.push(format!("None"));
+    }
+
+    data.push(format!("_________Cursor_________"));
+    data.push(format!("Contains: {}", context.contains_cursor()));
+    data.push(format!("Wants: {}", context.wants_cursor()));
+    data.push(format!("Has: {}", context.has_cursor()));
+
+    if !watched_bindings.is_empty() {
+        data.push(format!("_________Watch_________"));
+        for watched in watched_bindings.iter() {
+            data.push(format!("{}: {}", watched.label(), watched.value()));
+        }
+    }
+    set_inspect_data(data);
+
     let handle_button_events = Some(OnEvent::new(move |_, event| match event.event_type {
-        EventType::Click => last_clicked.set(parent_id_move.unwrap()),
+        EventType::Click => {
+            if let Some(parent_id) = parent_id_move {
+                last_clicked.set(parent_id);
+            }
+        }
         _ => {}
     }));
 
@@ -0,0 +1,302 @@
+use std::f32::consts::PI;
+
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, Bound, EventType, Handler, Index, KayakContext, KeyCode, MutableBound,
+    OnEvent,
+};
+
+use crate::widgets::{Canvas, OnDraw};
+
+/// The default size of the knob (in pixels)
+const KNOB_SIZE: f32 = 48.0;
+/// The angle of the minimum value, clockwise from the right (in radians)
+const START_ANGLE: f32 = 0.75 * PI;
+/// The angle covered by the indicator arc from the minimum to the maximum value (in radians)
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+/// The distance to drag vertically to go from the minimum to the maximum value (in pixels)
+const DRAG_DISTANCE: f32 = 200.0;
+/// How much slower the value changes while Shift is held
+const FINE_FACTOR: f32 = 0.1;
+/// The thickness of the indicator arc (in pixels)
+const ARC_THICKNESS: f32 = 3.0;
+/// The number of line segments drawn for the full indicator arc
+const ARC_SEGMENTS: usize = 48;
+
+/// How dragging a [Knob] changes its value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KnobMode {
+    /// Dragging up increases the value and dragging down decreases it
+    Vertical,
+    /// Turning the cursor clockwise around the knob increases the value
+    Circular,
+}
+
+impl Default for KnobMode {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+/// The state of a drag on a [Knob]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct KnobDrag {
+    /// The cursor position at the last move
+    cursor: (f32, f32),
+    /// The value reached so far, before snapping (so fine movements accumulate)
+    value: f32,
+}
+
+/// A rotary dial for selecting a value within a range
+///
+/// The knob captures the cursor when pressed, and dragging it changes the value relative to where
+/// the drag started (so pressing it doesn't make the value jump). Holding Shift slows the change
+/// down for fine adjustment, even in the middle of a drag. When focused, the arrow keys move the
+/// value by `step` (or by 1% of the range if `step` is zero, and 0.1% while Shift is held) and
+/// `Home`/`End` jump to `min`/`max`. Values always snap to `step`, so Shift only slows dragging
+/// down for stepped knobs.
+///
+/// The value is shown by an arc around the knob, from the bottom left (`min`) clockwise to the
+/// bottom right (`max`), and by a line pointing at the current value.
+///
+/// # Arguments
+///
+/// * `value`: The current value
+/// * `min`: The minimum value
+/// * `max`: The maximum value (must be greater than `min`)
+/// * `step`: The increment values snap to. Set to zero for a continuous knob.
+/// * `mode`: Whether dragging vertically or around the knob changes the value
+/// * `on_change`: Called with the next desired value when the user turns the knob
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the knob. The `background_color` style applies to
+/// the dial and the `color` style to the indicator. It is 48 by 48 pixels by default.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::KnobMode;
+///
+/// let (gain, set_gain, ..) = use_state!(0.0);
+/// let on_change = Handler::new(move |value| {
+///     set_gain(value);
+/// });
+///
+/// rsx! {
+///     <Knob value={gain} min={-12.0} max={12.0} mode={KnobMode::Circular} on_change={Some(on_change)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Knob(
+    value: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    mode: KnobMode,
+    on_change: Option<Handler<f32>>,
+) {
+    // === State === //
+    // Kept in state as the knob re-renders (with a new event handler) whenever its value changes
+    let (_, _, drag) = use_state!(None::<KnobDrag>);
+
+    // === Events === //
+    let mut current_value = value;
+    self.on_event = Some(OnEvent::new(move |ctx, event| {
+        let next_value = match event.event_type {
+            EventType::MouseDown => {
+                event.capture_cursor();
+                drag.set(Some(KnobDrag {
+                    cursor: ctx.last_mouse_position(),
+                    value: current_value,
+                }));
+                None
+            }
+            EventType::MouseMove => match drag.get() {
+                Some(last_drag) => {
+                    let next_drag = drag_to(ctx, &event.current_target, last_drag, min, max, mode);
+                    drag.set(Some(next_drag));
+                    Some(next_drag.value)
+                }
+                None => None,
+            },
+            EventType::MouseUp => {
+                drag.set(None);
+                None
+            }
+            EventType::KeyDown(evt) => {
+                let increment = if step > 0.0 {
+                    step
+                } else if evt.is_shift_pressed() {
+                    (max - min) / 1000.0
+                } else {
+                    (max - min) / 100.0
+                };
+                match evt.key() {
+                    KeyCode::Right | KeyCode::Up => Some(current_value + increment),
+                    KeyCode::Left | KeyCode::Down => Some(current_value - increment),
+                    KeyCode::Home => Some(min),
+                    KeyCode::End => Some(max),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(next_value) = next_value {
+            let next_value = snap(next_value, min, max, step);
+            if next_value != current_value {
+                current_value = next_value;
+                if let Some(ref on_change) = on_change {
+                    on_change.call(next_value);
+                }
+            }
+        }
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let dial_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    let indicator_color = base_styles
+        .color
+        .resolve_or(context.theme(), Color::new(0.933, 0.745, 0.745, 1.0));
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(KNOB_SIZE))
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(KNOB_SIZE))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    let t = normalize(value, min, max);
+    let on_draw = OnDraw::new(move |painter| {
+        let (width, height) = painter.size();
+        let center = (width / 2.0, height / 2.0);
+        let arc_radius = width.min(height) / 2.0 - ARC_THICKNESS;
+        let dial_radius = arc_radius - ARC_THICKNESS * 2.0;
+        if dial_radius <= 0.0 {
+            return;
+        }
+
+        // === Indicator Arc === //
+        let track_color = Color {
+            a: indicator_color.a * 0.25,
+            ..indicator_color
+        };
+        let filled_segments = (t * ARC_SEGMENTS as f32).round() as usize;
+        for segment in 0..ARC_SEGMENTS {
+            let from = point_at(center, arc_radius, segment as f32 / ARC_SEGMENTS as f32);
+            let to = point_at(
+                center,
+                arc_radius,
+                (segment + 1) as f32 / ARC_SEGMENTS as f32,
+            );
+            let color = if segment < filled_segments {
+                indicator_color
+            } else {
+                track_color
+            };
+            painter.draw_line(from, to, ARC_THICKNESS, color);
+        }
+
+        // === Dial === //
+        painter.draw_circle(center, dial_radius, dial_color);
+        painter.draw_line(
+            point_at(center, dial_radius * 0.3, t),
+            point_at(center, dial_radius * 0.9, t),
+            ARC_THICKNESS,
+            indicator_color,
+        );
+    });
+
+    rsx! {
+        <Canvas on_draw={Some(on_draw)} />
+    }
+}
+
+/// Continues a drag to the current cursor position over the given knob
+fn drag_to(
+    context: &KayakContext,
+    knob: &Index,
+    last_drag: KnobDrag,
+    min: f32,
+    max: f32,
+    mode: KnobMode,
+) -> KnobDrag {
+    let cursor = context.last_mouse_position();
+    let turned = match mode {
+        // Up is towards negative y
+        KnobMode::Vertical => (last_drag.cursor.1 - cursor.1) / DRAG_DISTANCE,
+        KnobMode::Circular => match context.widget_manager.get_layout(knob) {
+            Some(layout) => {
+                let center = (
+                    layout.posx + layout.width / 2.0,
+                    layout.posy + layout.height / 2.0,
+                );
+                let angle = |(x, y): (f32, f32)| (y - center.1).atan2(x - center.0);
+                // Wrap around, so crossing the left side doesn't turn the knob all the way back
+                let delta = angle(cursor) - angle(last_drag.cursor);
+                let delta = (delta + PI).rem_euclid(2.0 * PI) - PI;
+                delta / SWEEP_ANGLE
+            }
+            None => 0.0,
+        },
+    };
+    let factor = if context.keyboard_modifiers().is_shift_pressed {
+        FINE_FACTOR
+    } else {
+        1.0
+    };
+
+    KnobDrag {
+        cursor,
+        value: (last_drag.value + turned * factor * (max - min))
+            .max(min)
+            .min(max),
+    }
+}
+
+/// Gets the point on the indicator arc at the given fraction of the range
+fn point_at(center: (f32, f32), radius: f32, t: f32) -> (f32, f32) {
+    let angle = START_ANGLE + t * SWEEP_ANGLE;
+    (
+        center.0 + radius * angle.cos(),
+        center.1 + radius * angle.sin(),
+    )
+}
+
+/// Maps the given value to the range `[0, 1]`
+fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Clamps the given value to the range and snaps it to the nearest step (if any)
+fn snap(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    if max <= min {
+        return min;
+    }
+
+    let value = value.clamp(min, max);
+    if step > 0.0 {
+        (min + ((value - min) / step).round() * step).min(max)
+    } else {
+        value
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children,
+};
+
+/// A widget that draws a straight line between two points
+///
+/// The points are relative to the top-left corner of the widget (in pixels) and may be outside of
+/// it, so the line doesn't depend on the widget's size.
+///
+/// # Arguments
+///
+/// * `from`: The point the line starts at
+/// * `to`: The point the line ends at
+/// * `thickness`: The thickness of the line (in pixels). Its ends are rounded.
+/// * `children`: The widget's children (they aren't affected by the line)
+///
+/// # Styles
+///
+/// This widget accepts all styles. The `color` style applies to the line.
+#[widget]
+pub fn Line(children: Children, from: (f32, f32), to: (f32, f32), thickness: f32) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Line {
+            from,
+            to,
+            thickness,
+        }),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Debug;
+
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, RenderChildren, VecTracker,
+};
+
+/// A widget rendering its children once per item
+///
+/// The children are a closure receiving each item and its index (see [RenderChildren]), so the
+/// list decides which data each row is rendered with. A row only re-renders when its item or index
+/// changes.
+///
+/// # Arguments
+///
+/// * `items`: The items to list
+/// * `render_children`: Renders the row of an item, given the item and its index. It's set by
+///   the closure child of the list in `rsx!`.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the rows (which are laid out in a
+/// column by default).
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{List, Text};
+///
+/// let items = vec!["Iron Sword".to_string(), "Iron Shield".to_string()];
+///
+/// rsx! {
+///     <List items={items}>
+///         {|item: String, index: usize| <Text content={format!("{}. {}", index + 1, item)} size={16.0} />}
+///     </List>
+/// }
+/// ```
+#[widget]
+pub fn List<T: Clone + Default + PartialEq + Debug + Send + Sync + 'static>(
+    items: Vec<T>,
+    render_children: Option<RenderChildren<(T, usize)>>,
+) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {VecTracker::from(items.iter().cloned().enumerate().map(|(index, item)| {
+                constructor! {
+                    <ListItem item={item} index={index} render_children={render_children.clone()} />
+                }
+            }))}
+        </>
+    }
+}
+
+/// A row of a [List], rendering the children for its item
+#[widget]
+fn ListItem<T: Clone + Default + PartialEq + Debug + Send + Sync + 'static>(
+    item: T,
+    index: usize,
+    render_children: Option<RenderChildren<(T, usize)>>,
+) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let children = render_children.and_then(|render_children| render_children.bind((item, index)));
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
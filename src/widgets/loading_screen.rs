@@ -0,0 +1,196 @@
+use std::time::Instant;
+
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, KayakContext,
+};
+
+use crate::widgets::{Background, If, Image, Text};
+
+/// How long each tip is shown by default (in seconds)
+const DEFAULT_TIP_DURATION: f32 = 5.0;
+/// How long tips take to fade in and out (in seconds)
+const TIP_FADE_DURATION: f32 = 0.5;
+/// The height of the progress bar (in pixels)
+const PROGRESS_BAR_HEIGHT: f32 = 8.0;
+
+/// A full-screen loading screen, with a progress bar and rotating tips
+///
+/// Without a `progress` binding, the progress bar shows the progress of the assets tracked by the
+/// `AssetLoadingProgress` resource (when using the Bevy renderer). Tips are shown one after the
+/// other, fading in and out, and the screen re-renders every frame while there's more than one.
+///
+/// # Arguments
+///
+/// * `progress`: The loading progress, from 0 to 1. Defaults to the `AssetLoadingProgress`.
+/// * `title`: The text shown above the progress bar
+/// * `tips`: The tips to rotate through below the progress bar
+/// * `tip_duration`: How long each tip is shown (in seconds). Defaults to 5.
+/// * `background_image`: The image ID of the background (see `ImageManager`), covering the screen
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the screen. The `background_color` style applies
+/// to the screen (behind the image, if there's one) and the `color` style to the text and the
+/// progress bar.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::LoadingScreen;
+///
+/// let tips = vec![
+///     "Press Tab to open the map".to_string(),
+///     "Potions heal over time".to_string(),
+/// ];
+///
+/// rsx! {
+///     <LoadingScreen title={Some("Loading...".to_string())} tips={tips} />
+/// }
+/// ```
+#[widget]
+pub fn LoadingScreen(
+    progress: Option<Binding<f32>>,
+    title: Option<String>,
+    tips: Vec<String>,
+    tip_duration: Option<f32>,
+    background_image: Option<u16>,
+) {
+    let progress = progress.or_else(|| get_asset_loading_progress(context));
+    let progress = progress.map_or(0.0, |progress| {
+        context.bind(&progress);
+        progress.get().clamp(0.0, 1.0)
+    });
+
+    // === Tips === //
+    let (started_at, set_started_at, ..) = use_state!(None::<Instant>);
+    let (_, set_last_frame, ..) = use_state!(None::<Instant>);
+    let tip_duration = tip_duration.unwrap_or(DEFAULT_TIP_DURATION).max(0.1);
+    let (tip, tip_alpha) = if tips.len() > 1 {
+        let now = context.now();
+        let started_at = started_at.unwrap_or_else(|| {
+            set_started_at(Some(now));
+            now
+        });
+        // Changing this state re-renders the screen next frame, so the tips keep rotating
+        set_last_frame(Some(now));
+        let elapsed = now.saturating_duration_since(started_at).as_secs_f32();
+        let index = (elapsed / tip_duration) as usize % tips.len();
+        let shown_for = elapsed % tip_duration;
+        let fade_in = shown_for / TIP_FADE_DURATION;
+        let fade_out = (tip_duration - shown_for) / TIP_FADE_DURATION;
+        (tips[index].clone(), fade_in.min(fade_out).clamp(0.0, 1.0))
+    } else {
+        (tips.first().cloned().unwrap_or_default(), 1.0)
+    };
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.05, 0.05, 0.07, 1.0))
+        } else {
+            base_styles.background_color.clone()
+        },
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding_top: StyleProp::Value(Units::Stretch(1.0)),
+        padding_bottom: StyleProp::Value(Units::Percentage(15.0)),
+        ..base_styles
+    });
+
+    let has_background_image = background_image.is_some();
+    let background_image = background_image.unwrap_or_default();
+    let background_image_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let has_title = title.is_some();
+    let title = title.unwrap_or_default();
+    let title_styles = Style {
+        color: StyleProp::Value(color),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let track_styles = Style {
+        background_color: StyleProp::Value(Color { a: 0.2, ..color }),
+        border_radius: StyleProp::Value((
+            PROGRESS_BAR_HEIGHT / 2.0,
+            PROGRESS_BAR_HEIGHT / 2.0,
+            PROGRESS_BAR_HEIGHT / 2.0,
+            PROGRESS_BAR_HEIGHT / 2.0,
+        )),
+        left: StyleProp::Value(Units::Percentage(20.0)),
+        right: StyleProp::Value(Units::Percentage(20.0)),
+        top: StyleProp::Value(Units::Pixels(16.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Pixels(PROGRESS_BAR_HEIGHT)),
+        ..Style::default()
+    };
+    let fill_styles = Style {
+        background_color: StyleProp::Value(color),
+        border_radius: track_styles.border_radius.clone(),
+        width: StyleProp::Value(Units::Percentage(progress * 100.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let tip_styles = Style {
+        color: StyleProp::Value(Color {
+            a: color.a * 0.8 * tip_alpha,
+            ..color
+        }),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Pixels(24.0)),
+        ..Style::default()
+    };
+    let has_tip = !tip.is_empty();
+
+    rsx! {
+        <>
+            <If condition={has_background_image}>
+                <Image handle={background_image} styles={Some(background_image_styles)} />
+            </If>
+            <If condition={has_title}>
+                <Text content={title} size={28.0} styles={Some(title_styles)} />
+            </If>
+            <Background styles={Some(track_styles)}>
+                <Background styles={Some(fill_styles)} />
+            </Background>
+            <If condition={has_tip}>
+                <Text content={tip} size={16.0} styles={Some(tip_styles)} />
+            </If>
+        </>
+    }
+}
+
+/// Gets the progress of the assets tracked by the `AssetLoadingProgress` resource, if there's one
+#[allow(unused_variables)]
+fn get_asset_loading_progress(context: &mut KayakContext) -> Option<Binding<f32>> {
+    #[cfg(feature = "bevy_renderer")]
+    {
+        use crate::bevy::AssetLoadingProgress;
+
+        if let Ok(world) = context.get_global_state::<bevy::prelude::World>() {
+            return world
+                .get_resource::<AssetLoadingProgress>()
+                .map(|loading_progress| loading_progress.progress());
+        }
+    }
+
+    None
+}
@@ -0,0 +1,746 @@
+use std::collections::HashMap;
+
+use kayak_font::KayakFont;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, OnEvent, VecTracker,
+};
+
+use crate::widgets::Text;
+
+/// The font size of paragraphs and lists (in pixels)
+const BODY_SIZE: f32 = 14.0;
+/// The font sizes of headings, from `#` to `######` (in pixels)
+const HEADING_SIZES: [f32; 6] = [28.0, 24.0, 20.0, 18.0, 16.0, 14.0];
+/// The space between blocks (in pixels)
+const BLOCK_GAP: f32 = 8.0;
+/// How far each level of list is indented (in pixels)
+const LIST_INDENT: f32 = 20.0;
+/// The padding around code blocks (in pixels)
+const CODE_PADDING: f32 = 6.0;
+/// Extra width given to each run of text, so rounding never makes it wrap (in pixels)
+const RUN_SLACK: f32 = 1.0;
+
+/// Renders a markdown string
+///
+/// The supported syntax is:
+///
+/// * Headings (`#` to `######`)
+/// * Bold (`**bold**` or `__bold__`), italic (`*italic*` or `_italic_`), and inline code
+/// * Bulleted (`-`, `*`, or `+`) and numbered (`1.`) lists, nested by indenting them
+/// * Fenced code blocks and horizontal rules (`---`)
+/// * Links (`[label](url)`), which call `on_link_clicked` with the URL when clicked
+///
+/// Paragraphs are wrapped to the widget's width, which is measured from the last layout, so the
+/// markdown re-renders once more whenever its width changes. Since bold and italic text need
+/// their own fonts, they fall back to the regular font unless `bold_font` and `italic_font` are
+/// set.
+///
+/// # Arguments
+///
+/// * `content`: The markdown to render
+/// * `on_link_clicked`: Called with the URL of a link when it's clicked
+/// * `font`: The name of the regular font. Defaults to Roboto.
+/// * `bold_font`: The name of the font used for bold text and headings
+/// * `italic_font`: The name of the font used for italic text
+/// * `code_font`: The name of the font used for code (ideally monospaced)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the markdown container. The `color` style applies
+/// to the text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::Markdown;
+///
+/// let content = "# Patch notes\n\n* Fixed **crashes** on startup\n* See [the forum](https://example.com)".to_string();
+/// let on_link_clicked = Handler::new(|url: String| println!("Opening {}", url));
+///
+/// rsx! {
+///     <Markdown content={content} on_link_clicked={Some(on_link_clicked)} />
+/// }
+/// ```
+#[widget]
+pub fn Markdown(
+    content: String,
+    on_link_clicked: Option<Handler<String>>,
+    font: Option<String>,
+    bold_font: Option<String>,
+    italic_font: Option<String>,
+    code_font: Option<String>,
+) {
+    // === Fonts === //
+    let regular = font.unwrap_or_else(|| "Roboto".to_string());
+    let mut fonts = MarkdownFonts {
+        bold: bold_font.unwrap_or_else(|| regular.clone()),
+        italic: italic_font.unwrap_or_else(|| regular.clone()),
+        code: code_font.unwrap_or_else(|| regular.clone()),
+        regular,
+        loaded: HashMap::new(),
+    };
+    for name in fonts.names() {
+        if !fonts.loaded.contains_key(&name) {
+            let font: Binding<Option<KayakFont>> = context.get_asset(name.clone());
+            context.bind(&font);
+            fonts.loaded.insert(name, font.get());
+        }
+    }
+
+    // === Width === //
+    let width = context
+        .widget_manager
+        .get_layout(&self.id)
+        .map_or(0.0, |layout| layout.width);
+    // Changing this re-renders the markdown next frame, so it's wrapped to its new width
+    let (_, set_measured_width, ..) = use_state!(-1.0f32);
+    set_measured_width(width);
+
+    let rows = layout_blocks(&parse_blocks(&content), &fonts, width);
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Stretch(1.0))
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    let rows = rows
+        .into_iter()
+        .map(|row| {
+            constructor! {
+                <MarkdownRow
+                    row={row}
+                    text_color={text_color}
+                    code_font={fonts.code.clone()}
+                    on_link_clicked={on_link_clicked.clone()}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={rows} />
+    }
+}
+
+/// A single line (or code block) of a [Markdown]
+#[widget]
+fn MarkdownRow(
+    row: Row,
+    text_color: Color,
+    code_font: String,
+    on_link_clicked: Option<Handler<String>>,
+) {
+    match row {
+        Row::Line(line) => {
+            *styles = Some(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                top: StyleProp::Value(Units::Pixels(line.gap)),
+                padding_left: StyleProp::Value(Units::Pixels(line.indent)),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Pixels(line.size * 1.2)),
+                ..Style::default()
+            });
+
+            let size = line.size;
+            let runs = line
+                .runs
+                .into_iter()
+                .map(|run| {
+                    constructor! {
+                        <MarkdownRun
+                            run={run}
+                            size={size}
+                            text_color={text_color}
+                            on_link_clicked={on_link_clicked.clone()}
+                        />
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            rsx! {
+                <VecTracker data={runs} />
+            }
+        }
+        Row::Code { lines, gap } => {
+            *styles = Some(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                background_color: StyleProp::Value(Color::new(1.0, 1.0, 1.0, 0.08)),
+                border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+                top: StyleProp::Value(Units::Pixels(gap)),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Auto),
+                padding_left: StyleProp::Value(Units::Pixels(CODE_PADDING)),
+                padding_right: StyleProp::Value(Units::Pixels(CODE_PADDING)),
+                padding_top: StyleProp::Value(Units::Pixels(CODE_PADDING)),
+                padding_bottom: StyleProp::Value(Units::Pixels(CODE_PADDING)),
+                ..Style::default()
+            });
+
+            let text_styles = Style {
+                color: StyleProp::Value(text_color),
+                ..Style::default()
+            };
+            let content = lines.join("\n");
+
+            rsx! {
+                <Text
+                    content={content}
+                    size={BODY_SIZE}
+                    font={Some(code_font)}
+                    styles={Some(text_styles)}
+                />
+            }
+        }
+        Row::Rule { gap } => {
+            *styles = Some(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                background_color: StyleProp::Value(Color {
+                    a: text_color.a * 0.25,
+                    ..text_color
+                }),
+                top: StyleProp::Value(Units::Pixels(gap)),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Pixels(1.0)),
+                ..Style::default()
+            });
+        }
+    }
+}
+
+/// A run of identically styled text within a line of a [Markdown]
+#[widget]
+fn MarkdownRun(
+    run: TextRun,
+    size: f32,
+    text_color: Color,
+    on_link_clicked: Option<Handler<String>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    if let Some(url) = run.link.clone() {
+        self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+            EventType::Click => {
+                if let Some(ref on_link_clicked) = on_link_clicked {
+                    on_link_clicked.call(url.clone());
+                }
+                event.stop_propagation();
+            }
+            EventType::MouseIn => set_is_hovered(true),
+            EventType::MouseOut => set_is_hovered(false),
+            _ => {}
+        }));
+    }
+
+    let color = match (&run.link, is_hovered) {
+        (Some(_), true) => Color::new(0.6, 0.8, 1.0, 1.0),
+        (Some(_), false) => Color::new(0.4, 0.65, 1.0, 1.0),
+        (None, _) => text_color,
+    };
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        background_color: StyleProp::Value(if run.style.code {
+            Color::new(1.0, 1.0, 1.0, 0.08)
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        }),
+        border_radius: StyleProp::Value((3.0, 3.0, 3.0, 3.0)),
+        width: StyleProp::Value(Units::Pixels(run.width + RUN_SLACK)),
+        height: StyleProp::Value(Units::Pixels(size * 1.2)),
+        ..Style::default()
+    });
+
+    let text_styles = Style {
+        color: StyleProp::Value(color),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Text content={run.content} size={size} font={Some(run.font)} styles={Some(text_styles)} />
+    }
+}
+
+/// A block of markdown, before its inline syntax is parsed
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(usize, String),
+    Paragraph(String),
+    ListItem {
+        depth: usize,
+        marker: String,
+        text: String,
+    },
+    Code(Vec<String>),
+    Rule,
+}
+
+/// How a span of text is emphasized
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+/// A span of text with the same style (and link)
+#[derive(Debug, Clone, PartialEq)]
+struct Span {
+    text: String,
+    style: InlineStyle,
+    link: Option<String>,
+}
+
+/// A span of text laid out on a single line, with its font and measured width
+#[derive(Debug, Default, Clone, PartialEq)]
+struct TextRun {
+    content: String,
+    font: String,
+    style: InlineStyle,
+    link: Option<String>,
+    width: f32,
+}
+
+/// A wrapped line of text
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Line {
+    runs: Vec<TextRun>,
+    size: f32,
+    /// The space before the line (in pixels)
+    gap: f32,
+    indent: f32,
+}
+
+/// A row of a [Markdown], which is either a line of text or a whole block
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    Line(Line),
+    Code { lines: Vec<String>, gap: f32 },
+    Rule { gap: f32 },
+}
+
+impl Default for Row {
+    fn default() -> Self {
+        Self::Line(Line::default())
+    }
+}
+
+/// The fonts of a [Markdown] and, for measuring, their loaded assets
+struct MarkdownFonts {
+    regular: String,
+    bold: String,
+    italic: String,
+    code: String,
+    loaded: HashMap<String, Option<KayakFont>>,
+}
+
+impl MarkdownFonts {
+    fn names(&self) -> Vec<String> {
+        vec![
+            self.regular.clone(),
+            self.bold.clone(),
+            self.italic.clone(),
+            self.code.clone(),
+        ]
+    }
+
+    fn get_name(&self, style: InlineStyle) -> &String {
+        if style.code {
+            &self.code
+        } else if style.bold {
+            &self.bold
+        } else if style.italic {
+            &self.italic
+        } else {
+            &self.regular
+        }
+    }
+
+    /// Measures the width of the given text, estimating it if the font isn't loaded yet
+    fn measure(&self, text: &str, font: &str, size: f32) -> f32 {
+        match self.loaded.get(font) {
+            Some(Some(font)) => text
+                .chars()
+                .map(|c| font.get_glyph(c).map_or(0.0, |glyph| glyph.advance * size))
+                .sum(),
+            _ => text.chars().count() as f32 * size * 0.5,
+        }
+    }
+}
+
+fn parse_blocks(content: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph = String::new();
+    let mut lines = content.lines();
+
+    fn flush(blocks: &mut Vec<Block>, paragraph: &mut String) {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(paragraph)));
+        }
+    }
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            flush(&mut blocks, &mut paragraph);
+            let code = lines
+                .by_ref()
+                .take_while(|line| !line.trim_start().starts_with("```"))
+                .map(|line| line.to_string())
+                .collect();
+            blocks.push(Block::Code(code));
+        } else if trimmed.is_empty() {
+            flush(&mut blocks, &mut paragraph);
+        } else if is_rule(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Rule);
+        } else if let Some((level, text)) = parse_heading(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Heading(level, text.to_string()));
+        } else if let Some((marker, text)) = parse_list_marker(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            let indentation: usize = line
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .map(|c| if c == '\t' { 4 } else { 1 })
+                .sum();
+            blocks.push(Block::ListItem {
+                depth: indentation / 2,
+                marker,
+                text: text.to_string(),
+            });
+        } else {
+            // Indented lines continue the list item above them
+            if paragraph.is_empty() && line.starts_with(char::is_whitespace) {
+                if let Some(Block::ListItem { text, .. }) = blocks.last_mut() {
+                    text.push(' ');
+                    text.push_str(trimmed);
+                    continue;
+                }
+            }
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(trimmed);
+        }
+    }
+    flush(&mut blocks, &mut paragraph);
+
+    blocks
+}
+
+/// Checks if the line is a horizontal rule (like `---` or `* * *`)
+fn is_rule(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    match chars.next() {
+        Some(first @ ('-' | '*' | '_')) => {
+            let rest = chars.collect::<Vec<_>>();
+            rest.len() >= 2 && rest.iter().all(|c| *c == first)
+        }
+        _ => false,
+    }
+}
+
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some((level, rest.trim()))
+    } else {
+        None
+    }
+}
+
+/// Parses the marker of a list item, returning how it's displayed and the rest of the line
+fn parse_list_marker(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some(("•".to_string(), text.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    let rest = &line[digits..];
+    if digits > 0 && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        Some((format!("{}.", &line[..digits]), rest[2..].trim_start()))
+    } else {
+        None
+    }
+}
+
+fn parse_inline(text: &str) -> Vec<Span> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut spans = Vec::new();
+    let mut style = InlineStyle::default();
+    let mut current = String::new();
+
+    fn push(spans: &mut Vec<Span>, current: &mut String, style: InlineStyle) {
+        if !current.is_empty() {
+            spans.push(Span {
+                text: std::mem::take(current),
+                style,
+                link: None,
+            });
+        }
+    }
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\\' if next.map_or(false, |next| next.is_ascii_punctuation()) => {
+                current.extend(next);
+                i += 2;
+            }
+            '`' => {
+                push(&mut spans, &mut current, style);
+                style.code = !style.code;
+                i += 1;
+            }
+            _ if style.code => {
+                current.push(c);
+                i += 1;
+            }
+            '*' | '_' => {
+                let previous = i.checked_sub(1).map(|index| chars[index]);
+                let is_within_word = c == '_'
+                    && previous.map_or(false, char::is_alphanumeric)
+                    && next.map_or(false, char::is_alphanumeric);
+                if is_within_word {
+                    // Like in snake_case identifiers
+                    current.push(c);
+                    i += 1;
+                    continue;
+                }
+
+                push(&mut spans, &mut current, style);
+                if next == Some(c) {
+                    style.bold = !style.bold;
+                    i += 2;
+                } else {
+                    style.italic = !style.italic;
+                    i += 1;
+                }
+            }
+            '[' => {
+                if let Some((label, url, end)) = parse_link(&chars, i) {
+                    push(&mut spans, &mut current, style);
+                    for span in parse_inline(&label) {
+                        spans.push(Span {
+                            text: span.text,
+                            style: InlineStyle {
+                                bold: style.bold || span.style.bold,
+                                italic: style.italic || span.style.italic,
+                                code: span.style.code,
+                            },
+                            link: Some(url.clone()),
+                        });
+                    }
+                    i = end;
+                } else {
+                    current.push(c);
+                    i += 1;
+                }
+            }
+            _ => {
+                current.push(c);
+                i += 1;
+            }
+        }
+    }
+    push(&mut spans, &mut current, style);
+
+    spans
+}
+
+/// Parses a link starting at the given `[`, returning its label, its URL, and the index after it
+fn parse_link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    let label_end = start + chars[start..].iter().position(|c| *c == ']')?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = label_end + chars[label_end..].iter().position(|c| *c == ')')?;
+    let label = chars[start + 1..label_end].iter().collect();
+    let url = chars[label_end + 2..url_end]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string();
+    Some((label, url, url_end + 1))
+}
+
+fn layout_blocks(blocks: &[Block], fonts: &MarkdownFonts, width: f32) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut previous: Option<&Block> = None;
+    for block in blocks {
+        let gap = match (previous, block) {
+            (None, _) => 0.0,
+            (Some(Block::ListItem { .. }), Block::ListItem { .. }) => 2.0,
+            (_, Block::Heading(..)) => BLOCK_GAP * 1.5,
+            _ => BLOCK_GAP,
+        };
+        previous = Some(block);
+
+        match block {
+            Block::Heading(level, text) => {
+                let size = HEADING_SIZES[level - 1];
+                let spans = parse_inline(text)
+                    .into_iter()
+                    .map(|span| Span {
+                        style: InlineStyle {
+                            bold: true,
+                            ..span.style
+                        },
+                        ..span
+                    })
+                    .collect::<Vec<_>>();
+                let lines = wrap_spans(&spans, fonts, size, width, None);
+                push_lines(&mut rows, lines, size, gap, 0.0, 0.0);
+            }
+            Block::Paragraph(text) => {
+                let lines = wrap_spans(&parse_inline(text), fonts, BODY_SIZE, width, None);
+                push_lines(&mut rows, lines, BODY_SIZE, gap, 0.0, 0.0);
+            }
+            Block::ListItem {
+                depth,
+                marker,
+                text,
+            } => {
+                let indent = LIST_INDENT * *depth as f32;
+                let marker = TextRun {
+                    width: LIST_INDENT - RUN_SLACK,
+                    font: fonts.regular.clone(),
+                    content: marker.clone(),
+                    ..Default::default()
+                };
+                let lines = wrap_spans(
+                    &parse_inline(text),
+                    fonts,
+                    BODY_SIZE,
+                    width - indent,
+                    Some(marker),
+                );
+                // Wrapped lines line up with the text rather than the marker
+                push_lines(
+                    &mut rows,
+                    lines,
+                    BODY_SIZE,
+                    gap,
+                    indent,
+                    indent + LIST_INDENT,
+                );
+            }
+            Block::Code(lines) => rows.push(Row::Code {
+                lines: lines.clone(),
+                gap,
+            }),
+            Block::Rule => rows.push(Row::Rule { gap: gap + 4.0 }),
+        }
+    }
+
+    rows
+}
+
+fn push_lines(
+    rows: &mut Vec<Row>,
+    lines: Vec<Vec<TextRun>>,
+    size: f32,
+    gap: f32,
+    indent: f32,
+    wrapped_indent: f32,
+) {
+    for (index, runs) in lines.into_iter().enumerate() {
+        rows.push(Row::Line(Line {
+            runs,
+            size,
+            gap: if index == 0 { gap } else { 0.0 },
+            indent: if index == 0 { indent } else { wrapped_indent },
+        }));
+    }
+}
+
+/// Breaks the spans into lines no wider than `max_width`, starting with the given prefix
+///
+/// If `max_width` isn't known yet (zero), the spans aren't wrapped.
+fn wrap_spans(
+    spans: &[Span],
+    fonts: &MarkdownFonts,
+    size: f32,
+    max_width: f32,
+    prefix: Option<TextRun>,
+) -> Vec<Vec<TextRun>> {
+    let prefix_width = prefix
+        .as_ref()
+        .map_or(0.0, |prefix| prefix.width + RUN_SLACK);
+    let mut lines = Vec::new();
+    // Wrapped lines are indented (by the caller) to line up with the text after the prefix
+    let line_start = prefix_width;
+    let mut line = prefix.into_iter().collect::<Vec<_>>();
+    let mut x = line_start;
+
+    for span in spans {
+        let font = fonts.get_name(span.style);
+        for piece in span.text.split_inclusive(' ') {
+            let word = piece.trim_end_matches(' ');
+            let word_width = fonts.measure(word, font, size);
+            let piece_width = fonts.measure(piece, font, size);
+            if max_width > 0.0 && x > line_start && x + word_width > max_width {
+                // Leave the trailing spaces of the last line behind
+                if let Some(last) = line.last_mut() {
+                    let trimmed = last.content.trim_end().to_string();
+                    last.width = fonts.measure(&trimmed, &last.font, size);
+                    last.content = trimmed;
+                }
+                lines.push(std::mem::take(&mut line));
+                x = line_start;
+            }
+            if x == line_start && word.is_empty() {
+                // Don't start a line with spaces
+                continue;
+            }
+
+            match line.last_mut() {
+                Some(last)
+                    if last.style == span.style && last.link == span.link && x > line_start =>
+                {
+                    last.content.push_str(piece);
+                    last.width += piece_width;
+                }
+                _ => line.push(TextRun {
+                    content: piece.to_string(),
+                    font: font.clone(),
+                    style: span.style,
+                    link: span.link.clone(),
+                    width: piece_width,
+                }),
+            }
+            x += piece_width;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
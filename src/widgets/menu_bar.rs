@@ -0,0 +1,584 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Children, EventType, Handler, Index, KayakContext, KeyCode,
+    MutableBound, OnEvent,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// The height of the menu bar (in pixels)
+const BAR_HEIGHT: f32 = 26.0;
+/// The width of each menu panel (in pixels)
+const MENU_WIDTH: f32 = 200.0;
+/// The height of each menu item (in pixels)
+const ITEM_HEIGHT: f32 = 24.0;
+/// The space above the first and below the last item of each panel (in pixels)
+const MENU_PADDING: f32 = 4.0;
+/// The z-index offset of open menus, placing them above all other widgets
+const MENU_Z_INDEX: f32 = 3000.0;
+
+/// The state a [MenuBar] shares with its menus and items
+#[derive(Debug, Default, Clone, PartialEq)]
+struct MenuBarState {
+    /// The open menus, from the top-level menu to the innermost submenu
+    open: Vec<Index>,
+    /// The item (or submenu) highlighted by the cursor or the keyboard
+    highlighted: Option<Index>,
+}
+
+/// The menu (or menu bar) that the widgets below it are entries of
+#[derive(Debug, Default, Clone, PartialEq)]
+struct MenuLevel {
+    menu: Index,
+    /// The number of menus above this one (the menu bar itself is at depth 0)
+    depth: usize,
+    state: Binding<MenuBarState>,
+    /// The background color of the menu panels
+    panel_color: Color,
+}
+
+/// Identifies a [Menu] or [MenuItem] to the keyboard navigation of its [MenuBar]
+#[derive(Clone)]
+struct MenuEntry {
+    /// The menu (or menu bar) this is an entry of
+    parent: Index,
+    /// Whether this entry is a submenu
+    is_menu: bool,
+    disabled: bool,
+    on_select: Option<Handler<()>>,
+}
+
+/// A horizontal bar of [Menu] widgets, like the top menus of a desktop application
+///
+/// Clicking a menu opens it. While a menu is open, hovering another menu of the bar opens that one
+/// instead, and the rest of the UI doesn't receive pointer events. Pressing outside of the menus,
+/// selecting an item, or pressing `Escape` closes them.
+///
+/// When focused and a menu is open, `Up` and `Down` move between items, `Right` and `Left` open
+/// and close submenus (or move between the menus of the bar), and `Return` selects the highlighted
+/// item.
+///
+/// # Arguments
+///
+/// * `children`: The top-level [Menu] widgets
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the bar. The `background_color` style also applies
+/// to the menu panels.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::{Menu, MenuBar, MenuItem};
+///
+/// let on_open = Handler::new(|_| println!("Open"));
+/// let on_quit = Handler::new(|_| println!("Quit"));
+///
+/// rsx! {
+///     <MenuBar>
+///         <Menu label={"File".to_string()}>
+///             <MenuItem label={"Open".to_string()} shortcut={Some("Ctrl+O".to_string())} on_select={Some(on_open)} />
+///             <Menu label={"Recent".to_string()}>
+///                 <MenuItem label={"notes.txt".to_string()} />
+///             </Menu>
+///             <MenuItem label={"Quit".to_string()} on_select={Some(on_quit)} />
+///         </Menu>
+///     </MenuBar>
+/// }
+/// ```
+#[widget(focusable)]
+pub fn MenuBar(children: Children) {
+    // === State === //
+    let (_, _, state) = use_state!(MenuBarState::default());
+    context.bind(&state);
+    let is_open = !state.get().open.is_empty();
+    if is_open {
+        context.push_modal(self.id);
+    } else {
+        context.remove_modal(self.id);
+    }
+
+    // === Events === //
+    let bar_id = self.id;
+    let cloned_state = state.clone();
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::KeyDown(evt) => {
+            if navigate(ctx, bar_id, &cloned_state, evt.key()) {
+                event.stop_propagation();
+            }
+        }
+        EventType::Blur => close(&cloned_state),
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let background_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    context.create_provider(MenuLevel {
+        menu: self.id,
+        depth: 0,
+        state: state.clone(),
+        panel_color: background_color,
+    });
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        background_color: StyleProp::Value(background_color),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(BAR_HEIGHT))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    // Covers the whole UI in order to close the menus when pressing outside of them
+    let origin = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+    let catcher_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(bounds.posx - origin.posx)),
+        top: StyleProp::Value(Units::Pixels(bounds.posy - origin.posy)),
+        width: StyleProp::Value(Units::Pixels(bounds.width)),
+        height: StyleProp::Value(Units::Pixels(bounds.height)),
+        z_index: StyleProp::Value(MENU_Z_INDEX - 1.0),
+        ..Style::default()
+    };
+    let cloned_state = state.clone();
+    let on_catcher_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseDown | EventType::ContextMenu => close(&cloned_state),
+        _ => {}
+    });
+
+    rsx! {
+        <>
+            <If condition={is_open}>
+                <Element styles={Some(catcher_styles)} on_event={Some(on_catcher_event)} />
+            </If>
+            {children}
+        </>
+    }
+}
+
+/// A menu of [MenuItem] widgets (and nested menus), placed within a [MenuBar]
+///
+/// Within a menu bar, the menu is shown as a header that opens it when clicked. Within another
+/// menu, it's shown as an item that opens it (as a submenu) when hovered.
+///
+/// # Arguments
+///
+/// * `label`: The text of the menu's header
+/// * `children`: The menu's items
+/// * `disabled`: If true, the menu can't be opened
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the menu's header.
+#[widget]
+pub fn Menu(label: String, children: Children, disabled: bool) {
+    let level = context
+        .create_consumer::<MenuLevel>()
+        .expect("Menu requires a MenuBar as an ancestor")
+        .get();
+    let state = level.state.clone();
+    context.bind(&state);
+    context.create_provider(MenuLevel {
+        menu: self.id,
+        depth: level.depth + 1,
+        state: state.clone(),
+        panel_color: level.panel_color,
+    });
+    register_entry(
+        context,
+        self.id,
+        MenuEntry {
+            parent: level.menu,
+            is_menu: true,
+            disabled,
+            on_select: None,
+        },
+    );
+
+    let current = state.get();
+    let is_open = current.open.get(level.depth) == Some(&self.id);
+    let is_highlighted = current.highlighted == Some(self.id);
+    let is_top_level = level.depth == 0;
+
+    // === Events === //
+    let id = self.id;
+    let depth = level.depth;
+    let cloned_state = state.clone();
+    let on_header_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn if !disabled => {
+            let mut next = cloned_state.get();
+            if is_top_level && next.open.is_empty() {
+                // Menus of the bar only open on hover once one of them is open
+                return;
+            }
+            next.open.truncate(depth);
+            next.open.push(id);
+            next.highlighted = if is_top_level { None } else { Some(id) };
+            if next != cloned_state.get() {
+                cloned_state.set(next);
+            }
+        }
+        EventType::Click if !disabled => {
+            let mut next = cloned_state.get();
+            if is_top_level && next.open.first() == Some(&id) {
+                next = MenuBarState::default();
+            } else {
+                next.open.truncate(depth);
+                next.open.push(id);
+            }
+            cloned_state.set(next);
+        }
+        _ => {}
+    });
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    let text_color = if disabled {
+        Color::new(text_color.r, text_color.g, text_color.b, 0.4)
+    } else {
+        text_color
+    };
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: if is_top_level {
+            StyleProp::Value(Units::Auto)
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        },
+        height: StyleProp::Value(Units::Pixels(if is_top_level {
+            BAR_HEIGHT
+        } else {
+            ITEM_HEIGHT
+        })),
+        // Keep the menus of the bar reachable while the closing area covers the UI
+        z_index: if is_top_level {
+            StyleProp::Value(MENU_Z_INDEX)
+        } else {
+            base_styles.z_index.clone()
+        },
+        ..base_styles
+    });
+
+    let header_styles = Style {
+        background_color: StyleProp::Value(highlight_color(is_open || is_highlighted)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(if is_top_level {
+            Units::Auto
+        } else {
+            Units::Stretch(1.0)
+        }),
+        padding_left: StyleProp::Value(Units::Pixels(10.0)),
+        padding_right: StyleProp::Value(Units::Pixels(10.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(text_color),
+        ..Style::default()
+    };
+    let content = if is_top_level {
+        label
+    } else {
+        format!("{}  >", label)
+    };
+    let line_height = if is_top_level {
+        BAR_HEIGHT
+    } else {
+        ITEM_HEIGHT
+    };
+
+    // Top-level menus open below their header and submenus open to the side of their item
+    let panel_styles = Style {
+        background_color: StyleProp::Value(level.panel_color),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(if is_top_level { 0.0 } else { MENU_WIDTH })),
+        top: StyleProp::Value(Units::Pixels(if is_top_level {
+            BAR_HEIGHT
+        } else {
+            -MENU_PADDING
+        })),
+        width: StyleProp::Value(Units::Pixels(MENU_WIDTH)),
+        height: StyleProp::Value(Units::Auto),
+        padding_top: StyleProp::Value(Units::Pixels(MENU_PADDING)),
+        padding_bottom: StyleProp::Value(Units::Pixels(MENU_PADDING)),
+        z_index: StyleProp::Value(if is_top_level { 0.0 } else { MENU_Z_INDEX }),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <Background styles={Some(header_styles)} on_event={Some(on_header_event)}>
+                <Text content={content} size={14.0} line_height={Some(line_height)} styles={Some(text_styles)} />
+            </Background>
+            <If condition={is_open}>
+                <Background styles={Some(panel_styles)}>
+                    {children}
+                </Background>
+            </If>
+        </>
+    }
+}
+
+/// A selectable item of a [Menu]
+///
+/// # Arguments
+///
+/// * `label`: The text of the item
+/// * `shortcut`: Text shown at the end of the item, such as a keyboard shortcut
+/// * `disabled`: If true, the item can't be selected
+/// * `on_select`: Called when the user selects the item
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the item.
+#[widget]
+pub fn MenuItem(
+    label: String,
+    shortcut: Option<String>,
+    disabled: bool,
+    on_select: Option<Handler<()>>,
+) {
+    let level = context
+        .create_consumer::<MenuLevel>()
+        .expect("MenuItem requires a Menu as an ancestor")
+        .get();
+    let state = level.state.clone();
+    context.bind(&state);
+    register_entry(
+        context,
+        self.id,
+        MenuEntry {
+            parent: level.menu,
+            is_menu: false,
+            disabled,
+            on_select: on_select.clone(),
+        },
+    );
+
+    let is_highlighted = state.get().highlighted == Some(self.id) && !disabled;
+
+    // === Events === //
+    let id = self.id;
+    let depth = level.depth;
+    let cloned_state = state.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => {
+            // Hovering an item closes the submenus of its siblings
+            let mut next = cloned_state.get();
+            next.open.truncate(depth);
+            next.highlighted = Some(id);
+            if next != cloned_state.get() {
+                cloned_state.set(next);
+            }
+        }
+        EventType::Click if !disabled => {
+            if let Some(ref on_select) = on_select {
+                on_select.call(());
+            }
+            close(&cloned_state);
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    let text_color = if disabled {
+        Color::new(text_color.r, text_color.g, text_color.b, 0.4)
+    } else {
+        text_color
+    };
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        background_color: StyleProp::Value(highlight_color(is_highlighted)),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(10.0)),
+        padding_right: StyleProp::Value(Units::Pixels(10.0)),
+        ..base_styles
+    });
+
+    let text_styles = Style {
+        color: StyleProp::Value(text_color),
+        ..Style::default()
+    };
+    let shortcut_styles = Style {
+        color: StyleProp::Value(Color::new(text_color.r, text_color.g, text_color.b, 0.6)),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let has_shortcut = shortcut.is_some();
+    let shortcut = shortcut.unwrap_or_default();
+
+    rsx! {
+        <>
+            <Text content={label} size={14.0} line_height={Some(ITEM_HEIGHT)} styles={Some(text_styles)} />
+            <If condition={has_shortcut}>
+                <Text content={shortcut} size={12.0} line_height={Some(ITEM_HEIGHT)} styles={Some(shortcut_styles)} />
+            </If>
+        </>
+    }
+}
+
+fn register_entry(context: &mut KayakContext, id: Index, entry: MenuEntry) {
+    context.set_metadata(id, entry);
+}
+
+fn close(state: &Binding<MenuBarState>) {
+    if state.get() != MenuBarState::default() {
+        state.set(MenuBarState::default());
+    }
+}
+
+fn highlight_color(is_highlighted: bool) -> Color {
+    if is_highlighted {
+        Color::new(0.0781, 0.0898, 0.101, 1.0)
+    } else {
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+/// Gets the entries of the given menu (or menu bar), in the order they appear
+fn get_entries(context: &KayakContext, menu: Index) -> Vec<(Index, MenuEntry)> {
+    context
+        .widget_manager
+        .tree
+        .flatten_node(menu)
+        .into_iter()
+        .filter_map(|index| {
+            context
+                .get_metadata::<MenuEntry>(index)
+                .filter(|entry| entry.parent == menu)
+                .map(|entry| (index, entry))
+        })
+        .collect()
+}
+
+/// Handles a key press within a [MenuBar], returning true if the key was used
+fn navigate(
+    context: &mut KayakContext,
+    bar_id: Index,
+    state: &Binding<MenuBarState>,
+    key: KeyCode,
+) -> bool {
+    let mut next = state.get();
+    let top_level = get_entries(context, bar_id);
+    let innermost = next.open.last().copied();
+
+    match key {
+        KeyCode::Escape if innermost.is_some() => next = MenuBarState::default(),
+        KeyCode::Down if innermost.is_none() => {
+            // Open the first menu of the bar
+            match top_level.iter().find(|(_, entry)| !entry.disabled) {
+                Some((first, _)) => next.open = vec![*first],
+                None => return false,
+            }
+        }
+        KeyCode::Up | KeyCode::Down => {
+            let entries = get_entries(context, innermost.unwrap())
+                .into_iter()
+                .filter(|(_, entry)| !entry.disabled)
+                .collect::<Vec<_>>();
+            if entries.is_empty() {
+                return true;
+            }
+            let current = next
+                .highlighted
+                .and_then(|highlighted| entries.iter().position(|(id, _)| *id == highlighted));
+            let index = match (current, key) {
+                (None, KeyCode::Up) => entries.len() - 1,
+                (None, _) => 0,
+                (Some(current), KeyCode::Up) => (current + entries.len() - 1) % entries.len(),
+                (Some(current), _) => (current + 1) % entries.len(),
+            };
+            next.highlighted = Some(entries[index].0);
+        }
+        KeyCode::Return | KeyCode::Right if innermost.is_some() => {
+            let highlighted = next.highlighted.and_then(|highlighted| {
+                context
+                    .get_metadata::<MenuEntry>(highlighted)
+                    .map(|entry| (highlighted, entry))
+            });
+            match highlighted {
+                Some((id, entry)) if entry.is_menu && !next.open.contains(&id) => {
+                    next.open.push(id);
+                    next.highlighted = get_entries(context, id)
+                        .into_iter()
+                        .find(|(_, entry)| !entry.disabled)
+                        .map(|(id, _)| id);
+                }
+                Some((_, entry)) if key == KeyCode::Return && !entry.is_menu => {
+                    if let Some(ref on_select) = entry.on_select {
+                        on_select.call(());
+                    }
+                    next = MenuBarState::default();
+                }
+                _ if key == KeyCode::Right => next = step_top_level(&top_level, &next, true),
+                _ => return true,
+            }
+        }
+        KeyCode::Left if next.open.len() > 1 => {
+            // Close the innermost submenu, highlighting the item that opened it
+            next.highlighted = next.open.pop();
+        }
+        KeyCode::Left if innermost.is_some() => next = step_top_level(&top_level, &next, false),
+        _ => return false,
+    }
+
+    if next != state.get() {
+        state.set(next);
+    }
+    true
+}
+
+/// Opens the next (or previous) menu of the bar
+fn step_top_level(
+    top_level: &[(Index, MenuEntry)],
+    current: &MenuBarState,
+    forward: bool,
+) -> MenuBarState {
+    let menus = top_level
+        .iter()
+        .filter(|(_, entry)| !entry.disabled)
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+    let position = current
+        .open
+        .first()
+        .and_then(|open| menus.iter().position(|id| id == open));
+    match position {
+        Some(position) if !menus.is_empty() => {
+            let index = if forward {
+                (position + 1) % menus.len()
+            } else {
+                (position + menus.len() - 1) % menus.len()
+            };
+            MenuBarState {
+                open: vec![menus[index]],
+                highlighted: None,
+            }
+        }
+        _ => current.clone(),
+    }
+}
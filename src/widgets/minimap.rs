@@ -0,0 +1,270 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Binding, Bound, EventType, Index, KayakContext, MutableBound, OnEvent,
+};
+
+use crate::widgets::{Canvas, OnDraw, ScrollPosition};
+
+/// The color of the boxes of images in the thumbnail
+const IMAGE_COLOR: Color = Color {
+    r: 0.5,
+    g: 0.5,
+    b: 0.5,
+    a: 1.0,
+};
+/// The thickness of the outline of the viewport indicator (in pixels)
+const OUTLINE_THICKNESS: f32 = 1.0;
+
+/// A scaled-down view of the content of a [ScrollBox](crate::widgets::ScrollBox), which scrolls
+/// it when clicked or dragged
+///
+/// The minimap shares the scroll box's `position` binding. It draws a thumbnail of the content
+/// (the boxes of its quads, texts, and images, in their colors) with an indicator over the visible
+/// area. Pressing the mouse centers the visible area on the cursor, and dragging keeps it there.
+/// The thumbnail is redrawn whenever the scroll position or the size of the content changes.
+///
+/// # Arguments
+///
+/// * `position`: The scroll position of the scroll box to follow
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the minimap. The `background_color` style applies
+/// to the minimap and the `color` style to the viewport indicator. It is 160 by 120 pixels by
+/// default.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, rsx};
+/// # use kayak_ui::widgets::{Minimap, ScrollBox, ScrollPosition};
+///
+/// let position = bind(ScrollPosition::default());
+///
+/// rsx! {
+///     <>
+///         <ScrollBox position={Some(position.clone())}>
+///             {children}
+///         </ScrollBox>
+///         <Minimap position={position} />
+///     </>
+/// }
+/// ```
+#[widget]
+pub fn Minimap(position: Binding<ScrollPosition>) {
+    context.bind(&position);
+    let current_position = position.get();
+    let boxes = current_position
+        .scroll_box
+        .map_or_else(Vec::new, |scroll_box| {
+            collect_boxes(context, scroll_box, current_position.offset)
+        });
+
+    // === Events === //
+    let cloned_position = position.clone();
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseDown | EventType::MouseMove => {
+            if matches!(event.event_type, EventType::MouseDown) {
+                event.capture_cursor();
+            }
+            let layout = match ctx.widget_manager.get_layout(&event.current_target) {
+                Some(layout) => *layout,
+                None => return,
+            };
+            let current_position = cloned_position.get();
+            let geometry =
+                MinimapGeometry::new((layout.width, layout.height), current_position.content_size);
+            let cursor = ctx.last_mouse_position();
+            let target = geometry.to_content((cursor.0 - layout.posx, cursor.1 - layout.posy));
+            // The scroll box clamps the offset to its content
+            let offset = (
+                target.0 - current_position.viewport_size.0 / 2.0,
+                target.1 - current_position.viewport_size.1 / 2.0,
+            );
+            if offset != current_position.offset {
+                cloned_position.set(ScrollPosition {
+                    offset,
+                    ..current_position
+                });
+            }
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let indicator_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 0.85))
+        } else {
+            base_styles.background_color.clone()
+        },
+        width: if matches!(base_styles.width, StyleProp::Value(..)) {
+            base_styles.width.clone()
+        } else {
+            StyleProp::Value(Units::Pixels(160.0))
+        },
+        height: if matches!(base_styles.height, StyleProp::Value(..)) {
+            base_styles.height.clone()
+        } else {
+            StyleProp::Value(Units::Pixels(120.0))
+        },
+        ..base_styles
+    });
+
+    let on_draw = OnDraw::new(move |painter| {
+        let geometry = MinimapGeometry::new(painter.size(), current_position.content_size);
+        for (position, size, color) in boxes.iter() {
+            let (x, y) = geometry.to_minimap(*position);
+            painter.draw_rect(
+                (x, y),
+                (size.0 * geometry.scale, size.1 * geometry.scale),
+                *color,
+            );
+        }
+
+        // === Viewport Indicator === //
+        let min = geometry.to_minimap(current_position.offset);
+        let size = (
+            current_position.viewport_size.0 * geometry.scale,
+            current_position.viewport_size.1 * geometry.scale,
+        );
+        let max = (min.0 + size.0, min.1 + size.1);
+        painter.draw_rect(
+            min,
+            size,
+            Color {
+                a: indicator_color.a * 0.15,
+                ..indicator_color
+            },
+        );
+        for (from, to) in [
+            (min, (max.0, min.1)),
+            ((max.0, min.1), max),
+            (max, (min.0, max.1)),
+            ((min.0, max.1), min),
+        ] {
+            painter.draw_line(from, to, OUTLINE_THICKNESS, indicator_color);
+        }
+    });
+
+    rsx! {
+        <Canvas on_draw={Some(on_draw)} />
+    }
+}
+
+/// Maps the content of a scroll box onto a minimap, scaled to fit and centered
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MinimapGeometry {
+    scale: f32,
+    /// The position of the top-left corner of the content within the minimap
+    origin: (f32, f32),
+}
+
+impl MinimapGeometry {
+    fn new(minimap_size: (f32, f32), content_size: (f32, f32)) -> Self {
+        if content_size.0 <= 0.0 || content_size.1 <= 0.0 {
+            return Self {
+                scale: 1.0,
+                origin: (0.0, 0.0),
+            };
+        }
+        let scale = (minimap_size.0 / content_size.0).min(minimap_size.1 / content_size.1);
+        Self {
+            scale,
+            origin: (
+                (minimap_size.0 - content_size.0 * scale) / 2.0,
+                (minimap_size.1 - content_size.1 * scale) / 2.0,
+            ),
+        }
+    }
+
+    fn to_minimap(&self, point: (f32, f32)) -> (f32, f32) {
+        (
+            self.origin.0 + point.0 * self.scale,
+            self.origin.1 + point.1 * self.scale,
+        )
+    }
+
+    fn to_content(&self, point: (f32, f32)) -> (f32, f32) {
+        (
+            (point.0 - self.origin.0) / self.scale,
+            (point.1 - self.origin.1) / self.scale,
+        )
+    }
+}
+
+/// Collects the boxes drawn within a scroll box, as (position, size, color), relative to its
+/// unscrolled content
+fn collect_boxes(
+    context: &KayakContext,
+    scroll_box: Index,
+    offset: (f32, f32),
+) -> Vec<((f32, f32), (f32, f32), Color)> {
+    let widget_manager = &context.widget_manager;
+    let viewport = match widget_manager.get_layout(&scroll_box) {
+        Some(viewport) => *viewport,
+        None => return Vec::new(),
+    };
+
+    let mut boxes = Vec::new();
+    for node_id in widget_manager.node_tree.flatten_node(scroll_box) {
+        if node_id == scroll_box {
+            continue;
+        }
+        if !widget_manager.node_tree.is_descendant(node_id, scroll_box) {
+            // Left the subtree
+            break;
+        }
+        let (node, layout) = match (
+            widget_manager.get_node(&node_id),
+            widget_manager.get_layout(&node_id),
+        ) {
+            (Some(node), Some(layout)) => (node, *layout),
+            _ => continue,
+        };
+        let styles = &node.styles;
+        // The scrollbar thumbs are placed directly within the scroll box
+        if matches!(
+            styles.position_type,
+            StyleProp::Value(PositionType::SelfDirected)
+        ) && widget_manager.node_tree.get_parent(node_id) == Some(scroll_box)
+        {
+            continue;
+        }
+
+        let color = match styles.render_command.resolve() {
+            RenderCommand::Quad if matches!(styles.background_color, StyleProp::Value(..)) => {
+                styles.background_color.resolve()
+            }
+            RenderCommand::Text { .. } | RenderCommand::RichText { .. } => {
+                let color = styles.color.resolve_or(context.theme(), Color::WHITE);
+                // Text is mostly empty space, so it's drawn lighter than solid boxes
+                Color {
+                    a: color.a * 0.5,
+                    ..color
+                }
+            }
+            RenderCommand::Image { .. }
+            | RenderCommand::Texture { .. }
+            | RenderCommand::NinePatch { .. } => IMAGE_COLOR,
+            _ => continue,
+        };
+        if color.a <= 0.0 {
+            continue;
+        }
+
+        // Undo the scroll offset applied to the layout
+        let position = (
+            layout.posx + offset.0 - viewport.posx,
+            layout.posy + offset.1 - viewport.posy,
+        );
+        boxes.push((position, (layout.width, layout.height), color));
+    }
+    boxes
+}
@@ -1,29 +1,147 @@
+mod accordion;
+#[cfg(feature = "bevy_renderer")]
+mod anchor_to_entity;
 mod app;
+mod auto_complete;
 mod background;
+#[cfg(feature = "bevy_renderer")]
+mod bevy_embed;
 mod button;
+mod canvas;
+mod carousel;
+mod chat_box;
+mod checkbox;
 mod clip;
+mod compass;
+mod context_menu;
+mod cooldown_button;
+mod date_picker;
+mod dialogue_box;
+mod dock_panel;
+mod drag_and_drop;
+mod drawer;
 mod element;
+#[cfg(feature = "bevy_renderer")]
+mod floating_text_layer;
 mod fold;
+mod grid;
 mod if_element;
 mod image;
 mod inspector;
+mod knob;
+mod line;
+mod list;
+mod loading_screen;
+mod markdown;
+mod menu_bar;
+mod minimap;
+mod modal;
+#[cfg(feature = "bevy_renderer")]
+mod nameplate_layer;
 mod nine_patch;
+mod notification_area;
+mod numeric_input;
+#[cfg(feature = "bevy_renderer")]
+mod offscreen_indicator;
+mod pagination;
+mod password_box;
+mod popover;
+mod radar;
+mod radial_fill;
+mod rating;
+mod reticle;
+mod rich_text;
+mod router;
+mod scroll_box;
+mod sector;
+mod select;
+mod settings;
+mod slider;
+mod slot_grid;
+mod split_pane;
+mod stack_panel;
+mod table;
 mod text;
+mod text_area;
 mod text_box;
+mod textured;
 mod tooltip;
+mod tree_view;
+mod video;
+mod view_observer;
 mod window;
+mod wrap_panel;
 
+pub use accordion::*;
+#[cfg(feature = "bevy_renderer")]
+pub use anchor_to_entity::*;
 pub use app::*;
+pub use auto_complete::*;
 pub use background::*;
+#[cfg(feature = "bevy_renderer")]
+pub use bevy_embed::*;
 pub use button::*;
+pub use canvas::*;
+pub use carousel::*;
+pub use chat_box::*;
+pub use checkbox::*;
 pub use clip::*;
+pub use compass::*;
+pub use context_menu::*;
+pub use cooldown_button::*;
+pub use date_picker::*;
+pub use dialogue_box::*;
+pub use dock_panel::*;
+pub use drag_and_drop::*;
+pub use drawer::*;
 pub use element::*;
+#[cfg(feature = "bevy_renderer")]
+pub use floating_text_layer::*;
 pub use fold::*;
+pub use grid::*;
 pub use if_element::*;
 pub use image::*;
 pub use inspector::*;
+pub use knob::*;
+pub use line::*;
+pub use list::*;
+pub use loading_screen::*;
+pub use markdown::*;
+pub use menu_bar::*;
+pub use minimap::*;
+pub use modal::*;
+#[cfg(feature = "bevy_renderer")]
+pub use nameplate_layer::*;
 pub use nine_patch::*;
+pub use notification_area::*;
+pub use numeric_input::*;
+#[cfg(feature = "bevy_renderer")]
+pub use offscreen_indicator::*;
+pub use pagination::*;
+pub use password_box::*;
+pub use popover::*;
+pub use radar::*;
+pub use radial_fill::*;
+pub use rating::*;
+pub use reticle::*;
+pub use rich_text::*;
+pub use router::*;
+pub use scroll_box::*;
+pub use sector::*;
+pub use select::*;
+pub use settings::*;
+pub use slider::*;
+pub use slot_grid::*;
+pub use split_pane::*;
+pub use stack_panel::*;
+pub use table::*;
 pub use text::*;
+pub use text_area::*;
 pub use text_box::*;
+pub use textured::*;
 pub use tooltip::*;
+pub use tree_view::*;
+pub use video::*;
+pub use view_observer::*;
 pub use window::*;
+pub use wrap_panel::*;
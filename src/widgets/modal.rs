@@ -0,0 +1,156 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Binding, Bound, Children, EventType, Handler, KeyCode, OnEvent,
+};
+
+use crate::widgets::{Background, Element};
+
+/// The z-index offset of the modal, placing it above its surrounding widgets
+const MODAL_Z_INDEX: f32 = 2000.0;
+
+/// A dialog displayed above a dimmed backdrop
+///
+/// While visible, the modal blocks all pointer events from reaching the widgets underneath it and
+/// keeps `Tab` focus within its children. Clicking the backdrop or pressing `Escape` (while
+/// focused within the modal) calls `on_dismiss`. Hiding the modal is left to the caller, usually
+/// by setting `visible` to false from `on_dismiss`.
+///
+/// The modal covers its parent, so it should be placed directly within the [App](crate::widgets::App)
+/// to cover the whole UI.
+///
+/// # Arguments
+///
+/// * `children`: The content of the dialog, centered above the backdrop
+/// * `visible`: Whether the modal is displayed
+/// * `on_dismiss`: Called when the user asks to dismiss the modal
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the dialog container. The `background_color` style
+/// applies to the backdrop.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, Handler, MutableBound, rsx};
+/// # use kayak_ui::widgets::{Modal, Text};
+///
+/// let visible = bind(true);
+/// let cloned_visible = visible.clone();
+/// let on_dismiss = Handler::new(move |_| {
+///     cloned_visible.set(false);
+/// });
+///
+/// rsx! {
+///     <Modal visible={visible} on_dismiss={Some(on_dismiss)}>
+///         <Text content={"Are you sure?".to_string()} size={16.0} />
+///     </Modal>
+/// }
+/// ```
+#[widget]
+pub fn Modal(children: Children, visible: Binding<bool>, on_dismiss: Option<Handler<()>>) {
+    // === State === //
+    context.bind(&visible);
+    let is_visible = visible.get();
+    if is_visible {
+        context.push_modal(self.id);
+    } else {
+        context.remove_modal(self.id);
+    }
+
+    // === Events === //
+    let cloned_on_dismiss = on_dismiss.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::KeyDown(evt) if matches!(evt.key(), KeyCode::Escape) => {
+            if let Some(ref on_dismiss) = cloned_on_dismiss {
+                on_dismiss.call(());
+            }
+            // Dismissing the modal takes precedence over back navigation
+            event.prevent_default();
+        }
+        _ => {}
+    }));
+
+    let on_backdrop_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => {
+            if let Some(ref on_dismiss) = on_dismiss {
+                on_dismiss.call(());
+            }
+        }
+        _ => {}
+    });
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(0.0)),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        z_index: StyleProp::Value(MODAL_Z_INDEX),
+        ..Style::default()
+    });
+
+    let backdrop_styles = Style {
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.5))
+        } else {
+            base_styles.background_color.clone()
+        },
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let dialog_styles = Style {
+        left: if matches!(base_styles.left, StyleProp::Default) {
+            StyleProp::Value(Units::Stretch(1.0))
+        } else {
+            base_styles.left.clone()
+        },
+        right: if matches!(base_styles.right, StyleProp::Default) {
+            StyleProp::Value(Units::Stretch(1.0))
+        } else {
+            base_styles.right.clone()
+        },
+        top: if matches!(base_styles.top, StyleProp::Default) {
+            StyleProp::Value(Units::Stretch(1.0))
+        } else {
+            base_styles.top.clone()
+        },
+        bottom: if matches!(base_styles.bottom, StyleProp::Default) {
+            StyleProp::Value(Units::Stretch(1.0))
+        } else {
+            base_styles.bottom.clone()
+        },
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles.clone()
+    };
+
+    if is_visible {
+        rsx! {
+            <>
+                <Background styles={Some(backdrop_styles)} on_event={Some(on_backdrop_event)} />
+                <Element styles={Some(dialog_styles)}>
+                    {children}
+                </Element>
+            </>
+        }
+    } else {
+    }
+}
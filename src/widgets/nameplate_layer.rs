@@ -0,0 +1,358 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::bevy::ScreenProjection;
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, MutableBound, PointerEvents, VecTracker,
+};
+use bevy::{math::Vec3, prelude::Entity};
+use kayak_font::KayakFont;
+
+use crate::widgets::anchor_to_entity::track_entity;
+use crate::widgets::{Background, If, Text};
+
+/// The font size of the nameplates (in pixels)
+const FONT_SIZE: f32 = 13.0;
+/// The padding around the text of a nameplate (in pixels)
+const PADDING: (f32, f32) = (6.0, 2.0);
+/// The opacity of nameplates faded by [NameplateDeclutter::Fade]
+const FADED_ALPHA: f32 = 0.2;
+
+/// A label following a Bevy entity within a [NameplateLayer]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nameplate {
+    pub entity: Entity,
+    pub text: String,
+    /// The color of the text. If `None`, the layer's `color` style is used.
+    pub color: Option<Color>,
+    /// Nameplates with a higher priority are placed first, so they're never moved or hidden to
+    /// make room for those with a lower one
+    pub priority: i32,
+}
+
+impl Nameplate {
+    pub fn new<S: Into<String>>(entity: Entity, text: S) -> Self {
+        Self {
+            entity,
+            text: text.into(),
+            color: None,
+            priority: 0,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// How a [NameplateLayer] resolves nameplates overlapping ones with a higher priority
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameplateDeclutter {
+    /// Move the nameplate up until it no longer overlaps
+    Stack,
+    /// Keep the nameplate in place, but make it mostly transparent
+    Fade,
+    /// Hide the nameplate
+    Hide,
+}
+
+impl Default for NameplateDeclutter {
+    fn default() -> Self {
+        Self::Stack
+    }
+}
+
+/// Shows nameplates above many Bevy entities, keeping them from piling on top of each other
+///
+/// Every frame, the nameplates are projected onto the screen and placed in order of priority
+/// (ties keep the order of `nameplates`). A nameplate overlapping one placed before it is then
+/// stacked above it, faded, or hidden, depending on `declutter`. Nameplates of entities that are
+/// off-screen are hidden, and so are those after the first `max_visible`. The layer is expected
+/// to cover the window (e.g. by being within an [App](crate::widgets::App)) and doesn't block the
+/// cursor.
+///
+/// # Arguments
+///
+/// * `nameplates`: The nameplates to show
+/// * `world_offset`: An offset (in world units) added to the position of every entity, e.g. to show
+///   the nameplates above the characters' heads
+/// * `declutter`: How overlapping nameplates are resolved
+/// * `spacing`: The space kept between nameplates (in pixels)
+/// * `max_visible`: The most nameplates shown at once. If `None`, all of them can be shown.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the layer. The `background_color` style applies to
+/// the nameplates and the `color` style to their text.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Nameplate, NameplateDeclutter, NameplateLayer};
+///
+/// let nameplates = vec![
+///     Nameplate::new(merchant, "Merchant").with_priority(1),
+///     Nameplate::new(guard, "Guard"),
+/// ];
+///
+/// rsx! {
+///     <NameplateLayer
+///         nameplates={nameplates}
+///         world_offset={Vec3::new(0.0, 2.0, 0.0)}
+///         declutter={NameplateDeclutter::Stack}
+///         spacing={2.0}
+///     />
+/// }
+/// ```
+#[widget]
+pub fn NameplateLayer(
+    nameplates: Vec<Nameplate>,
+    world_offset: Vec3,
+    declutter: NameplateDeclutter,
+    spacing: f32,
+    max_visible: Option<usize>,
+) {
+    // === State === //
+    // The projections of the entities, reported by each nameplate as they're tracked
+    let (projections, _, projections_state) =
+        use_state!(HashMap::<Entity, ScreenProjection>::new());
+    let mut retained = projections.clone();
+    retained.retain(|entity, _| {
+        nameplates
+            .iter()
+            .any(|nameplate| nameplate.entity == *entity)
+    });
+    if retained.len() != projections.len() {
+        projections_state.set(retained);
+    }
+
+    let font: Binding<Option<KayakFont>> = context.get_asset("Roboto".to_string());
+    context.bind(&font);
+    let font = font.get();
+
+    // === Layout === //
+    let origin = context
+        .widget_manager
+        .get_layout(&self.id)
+        .map_or((0.0, 0.0), |layout| (layout.posx, layout.posy));
+
+    let mut order = (0..nameplates.len()).collect::<Vec<_>>();
+    order.sort_by_key(|index| Reverse(nameplates[*index].priority));
+
+    let mut placements = vec![None; nameplates.len()];
+    let mut placed = Vec::<Rect>::new();
+    let mut shown = 0;
+    for index in order {
+        if max_visible.map_or(false, |max_visible| shown >= max_visible) {
+            break;
+        }
+        let nameplate = &nameplates[index];
+        let projection = match projections.get(&nameplate.entity) {
+            Some(projection) if projection.is_visible => projection,
+            _ => continue,
+        };
+
+        let size = measure(font.as_ref(), &nameplate.text);
+        let mut rect = Rect {
+            x: projection.position.0 - size.0 / 2.0,
+            y: projection.position.1 - size.1,
+            width: size.0,
+            height: size.1,
+        };
+        let mut alpha = 1.0;
+        match declutter {
+            NameplateDeclutter::Stack => {
+                // Each move places the nameplate above another one, so this ends within as many moves
+                for _ in 0..=placed.len() {
+                    let top_overlap = placed
+                        .iter()
+                        .filter(|other| rect.overlaps(other, spacing))
+                        .map(|other| other.y)
+                        .fold(None, |top: Option<f32>, y| {
+                            Some(top.map_or(y, |top| top.min(y)))
+                        });
+                    match top_overlap {
+                        Some(y) => rect.y = y - spacing - rect.height,
+                        None => break,
+                    }
+                }
+            }
+            NameplateDeclutter::Fade => {
+                if placed.iter().any(|other| rect.overlaps(other, spacing)) {
+                    alpha = FADED_ALPHA;
+                }
+            }
+            NameplateDeclutter::Hide => {
+                if placed.iter().any(|other| rect.overlaps(other, spacing)) {
+                    continue;
+                }
+            }
+        }
+
+        // Faded nameplates don't push the others around
+        if alpha == 1.0 {
+            placed.push(rect);
+        }
+        placements[index] = Some((rect, alpha));
+        shown += 1;
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    let background_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.0, 0.0, 0.0, 0.6));
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        ..base_styles
+    });
+
+    let labels = nameplates
+        .into_iter()
+        .zip(placements)
+        .map(|(nameplate, placement)| {
+            let (rect, alpha) = placement.unwrap_or_default();
+            constructor! {
+                <NameplateLabel
+                    entity={Some(nameplate.entity)}
+                    world_offset={world_offset}
+                    projections={projections_state.clone()}
+                    text={nameplate.text}
+                    color={nameplate.color.unwrap_or(text_color)}
+                    background_color={background_color}
+                    position={(rect.x - origin.0, rect.y - origin.1)}
+                    size={(rect.width, rect.height)}
+                    alpha={alpha}
+                    is_shown={placement.is_some()}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={labels} />
+    }
+}
+
+/// A single nameplate of a [NameplateLayer], which also tracks the projection of its entity
+#[widget]
+fn NameplateLabel(
+    entity: Option<Entity>,
+    world_offset: Vec3,
+    projections: Binding<HashMap<Entity, ScreenProjection>>,
+    text: String,
+    color: Color,
+    background_color: Color,
+    position: (f32, f32),
+    size: (f32, f32),
+    alpha: f32,
+    is_shown: bool,
+) {
+    // === Tracking === //
+    let (_, _, projection_state) = use_state!(None::<ScreenProjection>);
+    let projection = track_entity(context, self.id, entity, world_offset, projection_state);
+    if let Some(entity) = entity {
+        let mut reported = projections.get();
+        if reported.get(&entity) != projection.as_ref() {
+            match projection {
+                Some(projection) => reported.insert(entity, projection),
+                None => reported.remove(&entity),
+            };
+            // This re-renders the layer, which places the nameplate
+            projections.set(reported);
+        }
+    }
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(position.0)),
+        top: StyleProp::Value(Units::Pixels(position.1)),
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Auto),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let background_styles = Style {
+        background_color: StyleProp::Value(Color {
+            a: background_color.a * alpha,
+            ..background_color
+        }),
+        border_radius: StyleProp::Value((3.0, 3.0, 3.0, 3.0)),
+        // The text wraps within its parent, so the size is measured beforehand
+        width: StyleProp::Value(Units::Pixels(size.0)),
+        height: StyleProp::Value(Units::Pixels(size.1)),
+        padding_left: StyleProp::Value(Units::Pixels(PADDING.0)),
+        padding_right: StyleProp::Value(Units::Pixels(PADDING.0)),
+        padding_top: StyleProp::Value(Units::Pixels(PADDING.1)),
+        padding_bottom: StyleProp::Value(Units::Pixels(PADDING.1)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(Color {
+            a: color.a * alpha,
+            ..color
+        }),
+        ..Style::default()
+    };
+
+    rsx! {
+        <If condition={is_shown}>
+            <Background styles={Some(background_styles)}>
+                <Text content={text} size={FONT_SIZE} styles={Some(text_styles)} />
+            </Background>
+        </If>
+    }
+}
+
+/// The screen area covered by a nameplate
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Rect {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl Rect {
+    /// Checks if the rects are closer than `spacing` to each other
+    fn overlaps(&self, other: &Rect, spacing: f32) -> bool {
+        self.x < other.x + other.width + spacing
+            && other.x < self.x + self.width + spacing
+            && self.y < other.y + other.height + spacing
+            && other.y < self.y + self.height + spacing
+    }
+}
+
+/// Measures the size of a nameplate with the given text, including its padding
+fn measure(font: Option<&KayakFont>, text: &str) -> (f32, f32) {
+    let width: f32 = match font {
+        Some(font) => text
+            .chars()
+            .map(|c| {
+                font.get_glyph(c)
+                    .map_or(0.0, |glyph| glyph.advance * FONT_SIZE)
+            })
+            .sum(),
+        None => text.chars().count() as f32 * FONT_SIZE * 0.5,
+    };
+    (width + PADDING.0 * 2.0, FONT_SIZE * 1.2 + PADDING.1 * 2.0)
+}
@@ -1,17 +1,45 @@
 use crate::core::{
-    layout_cache::Space,
-    render_command::RenderCommand,
-    rsx,
-    styles::{Style, StyleProp},
-    widget, Children,
+    layout_cache::Space, render_command::RenderCommand, rsx, styles::Style, widget, Children,
 };
 
+use crate::widgets::image::{
+    bind_image_status, image_status_styles, set_alpha_hit_test, ImageStatus,
+};
+
+/// A widget that renders an image as a nine-patch
+///
+/// The widget re-renders automatically once its image finishes loading.
+///
+/// # Arguments
+///
+/// * `handle`: The image's ID (see `ImageManager`)
+/// * `border`: The size of the image's unstretched borders
+/// * `children`: The widget's children
+/// * `loading_style`: If set, these styles are applied over `styles` and rendered as a quad while the image loads
+/// * `error_style`: If set, these styles are applied over `styles` and rendered as a quad if the image fails to load
+/// * `alpha_hit_threshold`: If set, the cursor only hits the image where its alpha is above this
+///   value, which needs the pixels of the image to be read once it loads (8-bit RGBA images only)
 #[widget]
-pub fn NinePatch(handle: u16, border: Space, children: Children) {
-    *styles = Some(Style {
-        render_command: StyleProp::Value(RenderCommand::NinePatch { handle, border }),
-        ..styles.clone().unwrap_or_default()
-    });
+pub fn NinePatch(
+    handle: u16,
+    border: Space,
+    children: Children,
+    loading_style: Option<Style>,
+    error_style: Option<Style>,
+    alpha_hit_threshold: Option<u8>,
+) {
+    let status = bind_image_status(context, handle);
+    let mut image_styles = image_status_styles(
+        status,
+        RenderCommand::NinePatch { handle, border },
+        styles.clone().unwrap_or_default(),
+        loading_style,
+        error_style,
+    );
+    if let (ImageStatus::Loaded, Some(threshold)) = (status, alpha_hit_threshold) {
+        set_alpha_hit_test(context, &mut image_styles, handle, threshold, Some(border));
+    }
+    *styles = Some(image_styles);
 
     rsx! {
         <>
@@ -0,0 +1,201 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    widget, Bound, EventType, Notification, NotificationKind, OnEvent, PointerEvents, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// The width of each notification (in pixels)
+const TOAST_WIDTH: f32 = 280.0;
+/// The space between notifications and around the stack (in pixels)
+const TOAST_GAP: f32 = 8.0;
+
+/// The corner of the screen a [NotificationArea] stacks its notifications in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Default for NotificationCorner {
+    fn default() -> Self {
+        Self::TopRight
+    }
+}
+
+/// Shows the notifications pushed with `KayakContext::push_notification`
+///
+/// Notifications stack in the given corner, with the newest closest to the corner's edge, and are
+/// dismissed once their duration runs out or when clicked. The area covers its parent without
+/// blocking the cursor outside of the notifications themselves, so it's usually placed last in the
+/// [App](crate::widgets::App).
+///
+/// # Arguments
+///
+/// * `corner`: The corner the notifications stack in
+/// * `max_visible`: The most notifications shown at once. Older ones are hidden until there's room.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the area. The `color` style applies to the text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, EventType, Notification, NotificationKind, OnEvent};
+/// # use kayak_ui::widgets::{Button, NotificationArea};
+///
+/// let on_event = OnEvent::new(|context, event| {
+///     if event.event_type == EventType::Click {
+///         context.push_notification(Notification::new("Saved!").with_kind(NotificationKind::Success));
+///     }
+/// });
+///
+/// rsx! {
+///     <>
+///         <Button on_event={Some(on_event)} />
+///         <NotificationArea max_visible={Some(3)} />
+///     </>
+/// }
+/// ```
+#[widget]
+pub fn NotificationArea(corner: NotificationCorner, max_visible: Option<usize>) {
+    let notifications = context.notifications();
+    context.bind(&notifications);
+    let mut notifications = notifications.get();
+    if let Some(max_visible) = max_visible {
+        let hidden = notifications.len().saturating_sub(max_visible);
+        notifications.drain(..hidden);
+    }
+
+    let is_top = matches!(
+        corner,
+        NotificationCorner::TopLeft | NotificationCorner::TopRight
+    );
+    let is_left = matches!(
+        corner,
+        NotificationCorner::TopLeft | NotificationCorner::BottomLeft
+    );
+    if is_top {
+        // The newest notification should be closest to the top edge
+        notifications.reverse();
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        pointer_events: StyleProp::Value(PointerEvents::ChildrenOnly),
+        ..base_styles
+    });
+
+    let (edge, far_edge) = (
+        StyleProp::Value(Units::Pixels(TOAST_GAP)),
+        StyleProp::Value(Units::Stretch(1.0)),
+    );
+    let stack_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Pixels(TOAST_WIDTH)),
+        height: StyleProp::Value(Units::Auto),
+        left: if is_left {
+            edge.clone()
+        } else {
+            far_edge.clone()
+        },
+        right: if is_left {
+            far_edge.clone()
+        } else {
+            edge.clone()
+        },
+        top: if is_top {
+            edge.clone()
+        } else {
+            far_edge.clone()
+        },
+        bottom: if is_top { far_edge } else { edge },
+        pointer_events: StyleProp::Value(PointerEvents::ChildrenOnly),
+        ..Style::default()
+    };
+
+    let toasts = notifications
+        .into_iter()
+        .enumerate()
+        .map(|(index, notification)| {
+            constructor! {
+                <NotificationToast notification={notification} is_first={index == 0} text_color={text_color} />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <Element styles={Some(stack_styles)}>
+            <VecTracker data={toasts} />
+        </Element>
+    }
+}
+
+/// A single notification of a [NotificationArea]
+#[widget]
+fn NotificationToast(notification: Notification, is_first: bool, text_color: Color) {
+    let id = notification.id;
+    self.on_event = Some(OnEvent::new(move |ctx, event| {
+        if event.event_type == EventType::Click {
+            ctx.dismiss_notification(id);
+            event.stop_propagation();
+        }
+    }));
+
+    let accent_color = match notification.kind {
+        NotificationKind::Info => Color::new(0.2, 0.45, 0.8, 1.0),
+        NotificationKind::Success => Color::new(0.22, 0.62, 0.33, 1.0),
+        NotificationKind::Warning => Color::new(0.85, 0.62, 0.15, 1.0),
+        NotificationKind::Error => Color::new(0.8, 0.25, 0.25, 1.0),
+    };
+    let remaining = notification.remaining();
+    let has_progress = remaining.is_some();
+
+    let toast_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        background_color: StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 0.95)),
+        border_radius: StyleProp::Value((6.0, 6.0, 6.0, 6.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        top: StyleProp::Value(Units::Pixels(if is_first { 0.0 } else { TOAST_GAP })),
+        padding_left: StyleProp::Value(Units::Pixels(12.0)),
+        padding_right: StyleProp::Value(Units::Pixels(12.0)),
+        padding_top: StyleProp::Value(Units::Pixels(10.0)),
+        padding_bottom: StyleProp::Value(Units::Pixels(10.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(text_color),
+        ..Style::default()
+    };
+    let progress_styles = Style {
+        background_color: StyleProp::Value(accent_color),
+        border_radius: StyleProp::Value((1.0, 1.0, 1.0, 1.0)),
+        width: StyleProp::Value(Units::Percentage(remaining.unwrap_or(1.0) * 100.0)),
+        height: StyleProp::Value(Units::Pixels(2.0)),
+        top: StyleProp::Value(Units::Pixels(8.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(toast_styles)}>
+            <Text content={notification.message} size={14.0} styles={Some(text_styles)} />
+            <If condition={has_progress}>
+                <Background styles={Some(progress_styles)} />
+            </If>
+        </Background>
+    }
+}
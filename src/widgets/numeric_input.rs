@@ -0,0 +1,182 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Bound, EventType, Handler, KeyCode, MutableBound, OnEvent,
+};
+
+use crate::widgets::{Button, ChangeEvent, CharFilter, OnChange, Text, TextBox};
+
+/// The width of the increment and decrement buttons (in pixels)
+const BUTTON_WIDTH: f32 = 26.0;
+
+/// A text box for entering numbers, with buttons to step the value up and down
+///
+/// Only digits, a single decimal point, and (if `min` is negative) a leading minus sign can be
+/// typed. Typed values are reported as soon as they are a valid number within range; out of range
+/// values are clamped when `Return` is pressed. When focused, `Up` and `Down` step the value.
+///
+/// # Arguments
+///
+/// * `value`: The current value
+/// * `min`: The minimum value
+/// * `max`: The maximum value. If it isn't greater than `min`, the value is unbounded.
+/// * `step`: How much the buttons and arrow keys change the value by (1 if not positive)
+/// * `on_value_changed`: Called with the next desired value when the user changes it
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the text box and buttons.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::NumericInput;
+///
+/// let (count, set_count, ..) = use_state!(3.0);
+/// let on_value_changed = Handler::new(move |value| {
+///     set_count(value);
+/// });
+///
+/// rsx! {
+///     <NumericInput value={count} min={0.0} max={10.0} step={1.0} on_value_changed={Some(on_value_changed)} />
+/// }
+/// ```
+#[widget]
+pub fn NumericInput(
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    on_value_changed: Option<Handler<f64>>,
+) {
+    let step = if step > 0.0 { step } else { 1.0 };
+
+    // === State === //
+    // The text is kept separately so incomplete numbers (e.g. "-" or "1.") can be typed
+    let (mut text, _, text_state) = use_state!(format_value(value));
+    let (synced_value, _, synced_state) = use_state!(value);
+    if synced_value != value {
+        synced_state.set(value);
+        if text.parse::<f64>().ok() != Some(value) {
+            // The value was changed by something other than typing
+            text = format_value(value);
+            text_state.set(text.clone());
+        }
+    }
+
+    let set_value = {
+        let text_state = text_state.clone();
+        let on_value_changed = on_value_changed.clone();
+        move |next: f64| {
+            let next = clamp_value(next, min, max);
+            text_state.set(format_value(next));
+            if next != value {
+                if let Some(ref on_value_changed) = on_value_changed {
+                    on_value_changed.call(next);
+                }
+            }
+        }
+    };
+
+    // === Events === //
+    let cloned_set_value = set_value.clone();
+    let cloned_text_state = text_state.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Up => cloned_set_value(value + step),
+            KeyCode::Down => cloned_set_value(value - step),
+            KeyCode::Return => {
+                // Commit whatever was typed, even if it was out of range
+                let typed = cloned_text_state.get().parse::<f64>().unwrap_or(value);
+                cloned_set_value(typed);
+            }
+            _ => {}
+        },
+        _ => {}
+    }));
+
+    let cloned_set_value = set_value.clone();
+    let on_decrement = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => cloned_set_value(value - step),
+        _ => {}
+    });
+    let cloned_set_value = set_value.clone();
+    let on_increment = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => cloned_set_value(value + step),
+        _ => {}
+    });
+
+    let on_change = OnChange::new(move |event: ChangeEvent| {
+        text_state.set(event.value.clone());
+        if let Ok(parsed) = event.value.parse::<f64>() {
+            if parsed != value && in_range(parsed, min, max) {
+                if let Some(ref on_value_changed) = on_value_changed {
+                    on_value_changed.call(parsed);
+                }
+            }
+        }
+    });
+
+    let allow_negative = min < 0.0 || max <= min;
+    let char_filter = CharFilter::new(move |c, current| {
+        c.is_ascii_digit()
+            || (c == '.' && !current.contains('.'))
+            || (c == '-' && allow_negative && current.is_empty())
+    });
+
+    // === Styles === //
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(26.0)),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let button_styles = Style {
+        width: StyleProp::Value(Units::Pixels(BUTTON_WIDTH)),
+        height: StyleProp::Value(Units::Pixels(26.0)),
+        left: StyleProp::Value(Units::Pixels(2.0)),
+        ..Style::default()
+    };
+    let text_box_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <TextBox
+                styles={Some(text_box_styles)}
+                value={text}
+                on_change={Some(on_change)}
+                char_filter={Some(char_filter)}
+            />
+            <Button styles={Some(button_styles.clone())} on_event={Some(on_decrement)}>
+                <Text content={"-".to_string()} size={16.0} line_height={Some(26.0)} />
+            </Button>
+            <Button styles={Some(button_styles)} on_event={Some(on_increment)}>
+                <Text content={"+".to_string()} size={16.0} line_height={Some(26.0)} />
+            </Button>
+        </>
+    }
+}
+
+fn in_range(value: f64, min: f64, max: f64) -> bool {
+    max <= min || (value >= min && value <= max)
+}
+
+fn clamp_value(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        value.clamp(min, max)
+    } else {
+        value
+    }
+}
+
+fn format_value(value: f64) -> String {
+    // Trim the float noise left behind by repeated steps (e.g. 0.30000000000000004)
+    let rounded = (value * 1e9).round() / 1e9;
+    format!("{}", rounded)
+}
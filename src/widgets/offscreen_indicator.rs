@@ -0,0 +1,172 @@
+use crate::bevy::ScreenProjection;
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget,
+};
+use bevy::{math::Vec3, prelude::Entity};
+
+use crate::widgets::anchor_to_entity::track_entity;
+use crate::widgets::{Background, If, Image};
+
+/// The size of the pointer showing the direction of the entity (in pixels)
+const POINTER_SIZE: f32 = 8.0;
+
+/// Points toward a Bevy entity from the edge of the window while it's off-screen
+///
+/// While the entity is off-screen (or behind the camera), the indicator is kept on the edge of the
+/// window, along the line from the center of the window to the entity, with a small pointer on the
+/// side facing the entity. It's hidden while the entity is visible, so it's commonly combined with
+/// an [AnchorToEntity](crate::widgets::AnchorToEntity) marker for objectives. The UI is expected
+/// to cover the window (e.g. by being within an [App](crate::widgets::App)).
+///
+/// # Arguments
+///
+/// * `entity`: The entity to point toward. If `None`, nothing is shown.
+/// * `world_offset`: An offset (in world units) added to the entity's position
+/// * `icon`: The image ID of the icon to show (see `ImageManager`). If `None`, a circle is shown.
+/// * `size`: The width and height of the icon (in pixels)
+/// * `edge_padding`: The space kept between the edges of the window and the indicator (in pixels)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the indicator. The `color` style
+/// applies to the pointer and the circle shown without an icon.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::OffscreenIndicator;
+///
+/// rsx! {
+///     <OffscreenIndicator entity={Some(objective)} icon={Some(objective_icon)} size={32.0} edge_padding={16.0} />
+/// }
+/// ```
+#[widget]
+pub fn OffscreenIndicator(
+    entity: Option<Entity>,
+    world_offset: Vec3,
+    icon: Option<u16>,
+    size: f32,
+    edge_padding: f32,
+) {
+    // === State === //
+    let (_, _, projection_state) = use_state!(None::<ScreenProjection>);
+    let projection = track_entity(context, self.id, entity, world_offset, projection_state);
+    let is_shown = projection.map_or(false, |projection| !projection.is_visible);
+
+    // === Layout === //
+    let parent_origin = context
+        .widget_manager
+        .get_valid_parent(self.id)
+        .and_then(|parent_id| context.widget_manager.get_layout(&parent_id))
+        .map_or((0.0, 0.0), |layout| (layout.posx, layout.posy));
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+
+    let center = (
+        bounds.posx + bounds.width / 2.0,
+        bounds.posy + bounds.height / 2.0,
+    );
+    let projected = projection.map_or(center, |projection| projection.position);
+    let direction = normalize((projected.0 - center.0, projected.1 - center.1));
+    let position = edge_position(
+        center,
+        direction,
+        (
+            bounds.width / 2.0 - edge_padding - size / 2.0,
+            bounds.height / 2.0 - edge_padding - size / 2.0,
+        ),
+    );
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(position.0 - size / 2.0 - parent_origin.0)),
+        top: StyleProp::Value(Units::Pixels(position.1 - size / 2.0 - parent_origin.1)),
+        width: StyleProp::Value(Units::Pixels(size)),
+        height: StyleProp::Value(Units::Pixels(size)),
+        ..base_styles
+    });
+
+    let has_icon = icon.is_some();
+    let icon = icon.unwrap_or_default();
+    let icon_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let circle_styles = Style {
+        background_color: StyleProp::Value(color),
+        border_radius: StyleProp::Value((size / 2.0, size / 2.0, size / 2.0, size / 2.0)),
+        ..icon_styles.clone()
+    };
+    // The pointer sits just outside of the icon, on the side facing the entity
+    let pointer_distance = size / 2.0 + POINTER_SIZE / 2.0 + 2.0;
+    let pointer_styles = Style {
+        background_color: StyleProp::Value(color),
+        border_radius: StyleProp::Value((2.0, 2.0, 2.0, 2.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(
+            size / 2.0 + direction.0 * pointer_distance - POINTER_SIZE / 2.0,
+        )),
+        top: StyleProp::Value(Units::Pixels(
+            size / 2.0 + direction.1 * pointer_distance - POINTER_SIZE / 2.0,
+        )),
+        width: StyleProp::Value(Units::Pixels(POINTER_SIZE)),
+        height: StyleProp::Value(Units::Pixels(POINTER_SIZE)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <If condition={is_shown}>
+            <If condition={has_icon}>
+                <Image handle={icon} styles={Some(icon_styles)} />
+            </If>
+            <If condition={!has_icon}>
+                <Background styles={Some(circle_styles)} />
+            </If>
+            <Background styles={Some(pointer_styles)} />
+        </If>
+    }
+}
+
+fn normalize(vector: (f32, f32)) -> (f32, f32) {
+    let length = (vector.0 * vector.0 + vector.1 * vector.1).sqrt();
+    if length > 0.0 {
+        (vector.0 / length, vector.1 / length)
+    } else {
+        (0.0, -1.0)
+    }
+}
+
+/// Get where the ray from `center` in `direction` leaves the rect of the given half size
+fn edge_position(center: (f32, f32), direction: (f32, f32), half_size: (f32, f32)) -> (f32, f32) {
+    let half_size = (half_size.0.max(0.0), half_size.1.max(0.0));
+    let scale_x = if direction.0 != 0.0 {
+        half_size.0 / direction.0.abs()
+    } else {
+        f32::MAX
+    };
+    let scale_y = if direction.1 != 0.0 {
+        half_size.1 / direction.1.abs()
+    } else {
+        f32::MAX
+    };
+    let scale = scale_x.min(scale_y);
+    (
+        center.0 + direction.0 * scale,
+        center.1 + direction.1 * scale,
+    )
+}
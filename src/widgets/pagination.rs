@@ -0,0 +1,249 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, EventType, Handler, KeyCode, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Text};
+
+/// The most page buttons shown by default (including the first and last pages and the ellipses)
+const DEFAULT_MAX_VISIBLE: usize = 7;
+/// The width and height of each button (in pixels)
+const BUTTON_SIZE: f32 = 28.0;
+/// The space between buttons (in pixels)
+const BUTTON_GAP: f32 = 4.0;
+
+/// An entry of a [Pagination]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PaginationItem {
+    First,
+    Previous,
+    Page(usize),
+    Ellipsis,
+    Next,
+    Last,
+}
+
+/// A row of buttons for moving between pages
+///
+/// Besides the page buttons, the row has first, previous, next, and last controls. When there are
+/// more pages than fit in `max_visible` buttons, the pages far from the current one are collapsed
+/// into ellipses, always keeping the first and last pages. While focused, the `Left` and `Right`
+/// keys move to the previous and next pages, and `Home` and `End` to the first and last ones.
+///
+/// # Arguments
+///
+/// * `current_page`: The page currently shown, from 1 to `total_pages`
+/// * `total_pages`: The number of pages
+/// * `max_visible`: The most page buttons shown at once (including the ellipses). Defaults to 7.
+/// * `on_page_changed`: Called when the user picks another page. Contains the page (starting at 1).
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the row. The `color` style applies to the buttons' text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::Pagination;
+///
+/// let on_page_changed = Handler::new(|page: usize| println!("Showing page {}", page));
+///
+/// rsx! {
+///     <Pagination current_page={3} total_pages={20} on_page_changed={Some(on_page_changed)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Pagination(
+    current_page: usize,
+    total_pages: usize,
+    max_visible: Option<usize>,
+    on_page_changed: Option<Handler<usize>>,
+) {
+    let total_pages = total_pages.max(1);
+    let current_page = current_page.clamp(1, total_pages);
+
+    // === Events === //
+    let cloned_on_page_changed = on_page_changed.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| {
+        if let EventType::KeyDown(evt) = event.event_type {
+            let page = match evt.key() {
+                KeyCode::Left => Some(current_page.saturating_sub(1).max(1)),
+                KeyCode::Right => Some((current_page + 1).min(total_pages)),
+                KeyCode::Home => Some(1),
+                KeyCode::End => Some(total_pages),
+                _ => None,
+            };
+            if let Some(page) = page {
+                event.stop_propagation();
+                if page != current_page {
+                    if let Some(ref on_page_changed) = cloned_on_page_changed {
+                        on_page_changed.call(page);
+                    }
+                }
+            }
+        }
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(BUTTON_SIZE))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    let mut items = vec![PaginationItem::First, PaginationItem::Previous];
+    items.extend(get_page_items(
+        current_page,
+        total_pages,
+        max_visible.unwrap_or(DEFAULT_MAX_VISIBLE),
+    ));
+    items.extend([PaginationItem::Next, PaginationItem::Last]);
+
+    let buttons = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let (page, label) = match item {
+                PaginationItem::First => (Some(1), "<<".to_string()),
+                PaginationItem::Previous => {
+                    (Some(current_page.saturating_sub(1).max(1)), "<".to_string())
+                }
+                PaginationItem::Page(page) => (Some(page), page.to_string()),
+                PaginationItem::Ellipsis => (None, "...".to_string()),
+                PaginationItem::Next => {
+                    (Some((current_page + 1).min(total_pages)), ">".to_string())
+                }
+                PaginationItem::Last => (Some(total_pages), ">>".to_string()),
+            };
+            let is_current = item == PaginationItem::Page(current_page);
+            // Controls leading to the current page (and ellipses) can't be pressed
+            let disabled = page.map_or(true, |page| page == current_page);
+            let on_page_changed = on_page_changed.clone();
+            constructor! {
+                <PaginationButton
+                    label={label}
+                    page={page.unwrap_or_default()}
+                    is_first={index == 0}
+                    is_current={is_current}
+                    disabled={disabled}
+                    text_color={text_color}
+                    on_page_changed={on_page_changed}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={buttons} />
+    }
+}
+
+/// Get the pages shown between the first/previous and next/last controls
+///
+/// At most `max_visible` items are returned (though never fewer than 5, which is the least that
+/// can fit the first, current, and last pages along with the ellipses between them).
+fn get_page_items(
+    current_page: usize,
+    total_pages: usize,
+    max_visible: usize,
+) -> Vec<PaginationItem> {
+    let max_visible = max_visible.max(5);
+    if total_pages <= max_visible {
+        return (1..=total_pages).map(PaginationItem::Page).collect();
+    }
+
+    // The pages around the current one, between the ellipses
+    let window = max_visible - 4;
+    let start = current_page.saturating_sub(window / 2).max(1);
+    let end = start + window - 1;
+
+    let mut items = Vec::with_capacity(max_visible);
+    if start <= 3 {
+        // Close to the start, so only collapse the pages before the last one
+        items.extend((1..=max_visible - 2).map(PaginationItem::Page));
+        items.extend([PaginationItem::Ellipsis, PaginationItem::Page(total_pages)]);
+    } else if end >= total_pages - 2 {
+        // Close to the end, so only collapse the pages after the first one
+        items.extend([PaginationItem::Page(1), PaginationItem::Ellipsis]);
+        items.extend((total_pages + 3 - max_visible..=total_pages).map(PaginationItem::Page));
+    } else {
+        items.extend([PaginationItem::Page(1), PaginationItem::Ellipsis]);
+        items.extend((start..=end).map(PaginationItem::Page));
+        items.extend([PaginationItem::Ellipsis, PaginationItem::Page(total_pages)]);
+    }
+    items
+}
+
+/// A single button of a [Pagination]
+#[widget]
+fn PaginationButton(
+    label: String,
+    page: usize,
+    is_first: bool,
+    is_current: bool,
+    disabled: bool,
+    text_color: Color,
+    on_page_changed: Option<Handler<usize>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::Click if !disabled => {
+            if let Some(ref on_page_changed) = on_page_changed {
+                on_page_changed.call(page);
+            }
+        }
+        _ => {}
+    }));
+
+    let button_styles = Style {
+        background_color: StyleProp::Value(if is_current {
+            Color::new(0.2, 0.35, 0.6, 1.0)
+        } else if is_hovered && !disabled {
+            Color::new(0.176, 0.196, 0.215, 1.0)
+        } else {
+            Color::new(0.0781, 0.0898, 0.101, 1.0)
+        }),
+        border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+        left: StyleProp::Value(Units::Pixels(if is_first { 0.0 } else { BUTTON_GAP })),
+        width: StyleProp::Value(Units::Auto),
+        min_width: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        height: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(if disabled && !is_current {
+            Color::new(text_color.r, text_color.g, text_color.b, 0.4)
+        } else {
+            text_color
+        }),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(button_styles)}>
+            <Text content={label} size={14.0} line_height={Some(BUTTON_SIZE)} styles={Some(text_styles)} />
+        </Background>
+    }
+}
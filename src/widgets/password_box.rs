@@ -0,0 +1,210 @@
+use kayak_font::KayakFont;
+
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Color, EventType, KeyCode, OnEvent,
+};
+
+use crate::widgets::text_box::is_backspace;
+use crate::widgets::{Background, ChangeEvent, Clip, Element, If, Image, OnChange, Text};
+
+/// The character shown in place of each character of the password
+const MASK_CHAR: char = '•';
+/// The character shown instead if the font doesn't have [MASK_CHAR]
+const FALLBACK_MASK_CHAR: char = '*';
+/// The width and height of the reveal toggle (in pixels)
+const TOGGLE_SIZE: f32 = 18.0;
+
+/// A single-line text input that hides its content, for passwords and other secrets
+///
+/// Like [TextBox](crate::widgets::TextBox), the password box doesn't store its value: `on_change`
+/// is called with the edited value, which should then be passed back through `value`. The value
+/// is displayed as bullets unless revealed with the optional toggle. While hidden, the copy and
+/// cut shortcuts (`Ctrl+C`, `Ctrl+X`, and the dedicated keys) are consumed by the password box, so
+/// they never reach any copy handlers further up the tree.
+///
+/// # Arguments
+///
+/// * `value`: The password
+/// * `on_change`: Called with the edited password
+/// * `placeholder`: The text shown while the value is empty
+/// * `show_reveal_toggle`: Whether a toggle to reveal the password is shown on the right
+/// * `reveal_icon`: The image ID of the toggle's icon (see `ImageManager`). If `None`, the toggle
+///   is labelled "Show" or "Hide".
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the password box. The `color` style applies to the
+/// text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{OnChange, PasswordBox};
+///
+/// let on_change = OnChange::new(|event| println!("{} characters", event.value.len()));
+///
+/// rsx! {
+///     <PasswordBox value={String::new()} on_change={Some(on_change)} show_reveal_toggle={true} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn PasswordBox(
+    value: String,
+    on_change: Option<OnChange>,
+    placeholder: Option<String>,
+    show_reveal_toggle: bool,
+    reveal_icon: Option<u16>,
+) {
+    let (has_focus, set_has_focus, ..) = use_state!(false);
+    let (is_revealed, set_is_revealed, ..) = use_state!(false);
+
+    // === Events === //
+    let mut current_value = value.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::CharInput { c } => {
+            if !has_focus {
+                return;
+            }
+            if is_backspace(c) {
+                if current_value.pop().is_none() {
+                    return;
+                }
+            } else if !c.is_control() {
+                current_value.push(c);
+            } else {
+                return;
+            }
+            if let Some(on_change) = on_change.as_ref() {
+                if let Ok(mut on_change) = on_change.0.write() {
+                    on_change(ChangeEvent {
+                        value: current_value.clone(),
+                    });
+                }
+            }
+        }
+        EventType::KeyDown(evt) => {
+            let is_copy = matches!(evt.key(), KeyCode::Copy | KeyCode::Cut)
+                || (matches!(evt.key(), KeyCode::C | KeyCode::X)
+                    && (evt.is_ctrl_pressed() || evt.is_meta_pressed()));
+            if has_focus && is_copy && !is_revealed {
+                event.stop_propagation();
+                event.prevent_default();
+            }
+        }
+        EventType::Focus => set_has_focus(true),
+        EventType::Blur => set_has_focus(false),
+        _ => {}
+    }));
+
+    // === Styles === //
+    let current_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Pixels(26.0)),
+        ..current_styles.clone()
+    });
+
+    let background_styles = Style {
+        background_color: StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0)),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(26.0)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..current_styles.clone()
+    };
+
+    let font: Binding<Option<KayakFont>> = context.get_asset("Roboto".to_string());
+    context.bind(&font);
+    let mask_char = match font.get() {
+        Some(font) if font.get_glyph(MASK_CHAR).is_none() => FALLBACK_MASK_CHAR,
+        _ => MASK_CHAR,
+    };
+
+    let is_empty = value.is_empty();
+    let text_styles = if is_empty {
+        Style {
+            color: StyleProp::Value(Color::new(0.5, 0.5, 0.5, 1.0)),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            color: current_styles.color,
+            ..Style::default()
+        }
+    };
+    let content = if is_empty {
+        placeholder.unwrap_or_default()
+    } else if is_revealed {
+        value
+    } else {
+        value.chars().map(|_| mask_char).collect()
+    };
+
+    // === Reveal Toggle === //
+    let on_toggle_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => {
+            set_is_revealed(!is_revealed);
+            event.stop_propagation();
+        }
+        _ => {}
+    });
+    let toggle_styles = Style {
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Pixels(5.0)),
+        width: StyleProp::Value(Units::Auto),
+        min_width: StyleProp::Value(Units::Pixels(TOGGLE_SIZE)),
+        height: StyleProp::Value(Units::Pixels(TOGGLE_SIZE)),
+        ..Style::default()
+    };
+    let has_icon = reveal_icon.is_some();
+    let reveal_icon = reveal_icon.unwrap_or_default();
+    let icon_styles = Style {
+        color: StyleProp::Value(if is_revealed {
+            Color::WHITE
+        } else {
+            Color::new(0.6, 0.6, 0.6, 1.0)
+        }),
+        width: StyleProp::Value(Units::Pixels(TOGGLE_SIZE)),
+        height: StyleProp::Value(Units::Pixels(TOGGLE_SIZE)),
+        ..Style::default()
+    };
+    let toggle_label = if is_revealed { "Hide" } else { "Show" }.to_string();
+    let toggle_text_styles = Style {
+        color: StyleProp::Value(Color::new(0.6, 0.6, 0.6, 1.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(background_styles)}>
+            <Clip>
+                <Text
+                    content={content}
+                    size={14.0}
+                    line_height={Some(22.0)}
+                    styles={Some(text_styles)}
+                />
+            </Clip>
+            <If condition={show_reveal_toggle}>
+                <Element styles={Some(toggle_styles)} on_event={Some(on_toggle_event)}>
+                    <If condition={has_icon}>
+                        <Image handle={reveal_icon} styles={Some(icon_styles)} />
+                    </If>
+                    <If condition={!has_icon}>
+                        <Text
+                            content={toggle_label}
+                            size={12.0}
+                            line_height={Some(TOGGLE_SIZE)}
+                            styles={Some(toggle_text_styles)}
+                        />
+                    </If>
+                </Element>
+            </If>
+        </Background>
+    }
+}
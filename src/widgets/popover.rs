@@ -0,0 +1,228 @@
+use crate::core::{
+    color::Color,
+    layout_cache::Rect,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Binding, Bound, Children, EventType, Fragment, MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If};
+
+/// The z-index offset of the popover, placing it above its surrounding widgets
+const POPOVER_Z_INDEX: f32 = 3000.0;
+
+/// The side of its anchor a [Popover] opens on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverPlacement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Default for PopoverPlacement {
+    fn default() -> Self {
+        Self::Bottom
+    }
+}
+
+impl PopoverPlacement {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Floating content shown next to its children (the anchor) while open
+///
+/// The content opens on the `placement` side of the anchor, flipping to the other side when it
+/// wouldn't fit within the window, and is kept within the window along the other axis. Pressing
+/// anywhere outside of the content closes the popover. While open, the rest of the UI doesn't
+/// receive pointer events.
+///
+/// The anchor's position comes from the last computed layout, so the popover follows it when it
+/// moves.
+///
+/// # Arguments
+///
+/// * `children`: The anchor the content is placed next to
+/// * `content`: The content of the popover
+/// * `is_open`: Whether the popover is open. Closing the popover sets this to false.
+/// * `placement`: The preferred side of the anchor to open on
+/// * `size`: The size of the popover (in pixels)
+/// * `gap`: The space between the anchor and the popover (in pixels)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the anchor. The `background_color`
+/// style applies to the popover.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, constructor, rsx, Binding, Bound, EventType, MutableBound, OnEvent};
+/// # use kayak_ui::widgets::{Button, Popover, Text};
+///
+/// let is_open = bind(false);
+/// let cloned_is_open = is_open.clone();
+/// let on_event = OnEvent::new(move |_, event| {
+///     if event.event_type == EventType::Click {
+///         cloned_is_open.set(true);
+///     }
+/// });
+/// let content = constructor! { <><Text content={"Details".to_string()} size={14.0} /></> };
+///
+/// rsx! {
+///     <Popover content={content} is_open={is_open} size={(200.0, 120.0)}>
+///         <Button on_event={Some(on_event)} />
+///     </Popover>
+/// }
+/// ```
+#[widget]
+pub fn Popover(
+    children: Children,
+    content: Fragment,
+    is_open: Binding<bool>,
+    placement: PopoverPlacement,
+    size: (f32, f32),
+    gap: f32,
+) {
+    context.bind(&is_open);
+    let open = is_open.get();
+    if open {
+        context.push_modal(self.id);
+    } else {
+        context.remove_modal(self.id);
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..base_styles.clone()
+    });
+
+    let anchor = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+    let rect = get_popover_rect(placement, &anchor, size, gap, &bounds);
+
+    // Covers the whole UI in order to close the popover when pressing outside of it
+    let catcher_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(bounds.posx - anchor.posx)),
+        top: StyleProp::Value(Units::Pixels(bounds.posy - anchor.posy)),
+        width: StyleProp::Value(Units::Pixels(bounds.width)),
+        height: StyleProp::Value(Units::Pixels(bounds.height)),
+        z_index: StyleProp::Value(POPOVER_Z_INDEX),
+        ..Style::default()
+    };
+    let on_catcher_event = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseDown | EventType::ContextMenu => {
+            if !rect.contains(&ctx.last_mouse_position()) {
+                is_open.set(false);
+            }
+        }
+        _ => {}
+    });
+
+    let popover_styles = Style {
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+        } else {
+            base_styles.background_color
+        },
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(rect.posx - bounds.posx)),
+        top: StyleProp::Value(Units::Pixels(rect.posy - bounds.posy)),
+        width: StyleProp::Value(Units::Pixels(rect.width)),
+        height: StyleProp::Value(Units::Pixels(rect.height)),
+        ..Style::default()
+    };
+
+    let content = vec![content];
+    rsx! {
+        <>
+            {children}
+            <If condition={open}>
+                <Element styles={Some(catcher_styles)} on_event={Some(on_catcher_event)}>
+                    <Background styles={Some(popover_styles)}>
+                        <VecTracker data={content} />
+                    </Background>
+                </Element>
+            </If>
+        </>
+    }
+}
+
+/// Get the bounds of a popover of the given size placed next to the anchor
+///
+/// The popover flips to the opposite side when it doesn't fit within the bounds on the preferred
+/// one (and does on the other), then is kept within the bounds along the other axis.
+fn get_popover_rect(
+    placement: PopoverPlacement,
+    anchor: &Rect,
+    size: (f32, f32),
+    gap: f32,
+    bounds: &Rect,
+) -> Rect {
+    let place = |placement: PopoverPlacement| match placement {
+        PopoverPlacement::Top => (
+            anchor.posx + (anchor.width - size.0) / 2.0,
+            anchor.posy - gap - size.1,
+        ),
+        PopoverPlacement::Bottom => (
+            anchor.posx + (anchor.width - size.0) / 2.0,
+            anchor.posy + anchor.height + gap,
+        ),
+        PopoverPlacement::Left => (
+            anchor.posx - gap - size.0,
+            anchor.posy + (anchor.height - size.1) / 2.0,
+        ),
+        PopoverPlacement::Right => (
+            anchor.posx + anchor.width + gap,
+            anchor.posy + (anchor.height - size.1) / 2.0,
+        ),
+    };
+    let fits = |(x, y): (f32, f32)| match placement {
+        PopoverPlacement::Top | PopoverPlacement::Bottom => {
+            y >= bounds.posy && y + size.1 <= bounds.posy + bounds.height
+        }
+        PopoverPlacement::Left | PopoverPlacement::Right => {
+            x >= bounds.posx && x + size.0 <= bounds.posx + bounds.width
+        }
+    };
+
+    let mut position = place(placement);
+    if !fits(position) {
+        let flipped = place(placement.opposite());
+        if fits(flipped) {
+            position = flipped;
+        }
+    }
+
+    // Keep the popover within the bounds, favoring its top-left corner if it's larger than them
+    let max_x = bounds.posx + bounds.width - size.0;
+    let max_y = bounds.posy + bounds.height - size.1;
+    Rect {
+        posx: position.0.min(max_x).max(bounds.posx),
+        posy: position.1.min(max_y).max(bounds.posy),
+        width: size.0,
+        height: size.1,
+        z_index: 0.0,
+    }
+}
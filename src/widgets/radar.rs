@@ -0,0 +1,255 @@
+use std::time::Instant;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, VecTracker,
+};
+
+use crate::widgets::{Background, Line, Sector};
+
+/// How long the sweep takes to go around once by default (in seconds)
+const DEFAULT_SWEEP_DURATION: f32 = 3.0;
+/// The width and height of each blip by default (in pixels)
+const DEFAULT_BLIP_SIZE: f32 = 6.0;
+/// The trail drawn behind the sweep, as (size, alpha) pairs. The size is a portion of a full turn.
+const SWEEP_TRAIL: [(f32, f32); 3] = [(0.02, 0.3), (0.06, 0.15), (0.15, 0.08)];
+/// The least alpha of a blip, reached just before the sweep passes over it again
+const MIN_BLIP_ALPHA: f32 = 0.25;
+
+/// What a [RadarBlip] represents, which determines its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadarBlipKind {
+    Neutral,
+    Friendly,
+    Hostile,
+    Objective,
+}
+
+impl Default for RadarBlipKind {
+    fn default() -> Self {
+        Self::Neutral
+    }
+}
+
+impl RadarBlipKind {
+    /// The color blips of this kind are drawn with
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Neutral => Color::new(0.8, 0.8, 0.8, 1.0),
+            Self::Friendly => Color::new(0.3, 0.6, 1.0, 1.0),
+            Self::Hostile => Color::new(1.0, 0.25, 0.2, 1.0),
+            Self::Objective => Color::new(1.0, 0.8, 0.2, 1.0),
+        }
+    }
+}
+
+/// A point plotted on a [Radar]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RadarBlip {
+    /// The direction of the blip (in radians), clockwise from the top of the radar
+    pub angle: f32,
+    /// The distance between the blip and the center of the radar (in the same units as the
+    /// radar's `range`)
+    pub distance: f32,
+    pub kind: RadarBlipKind,
+}
+
+impl RadarBlip {
+    pub fn new(angle: f32, distance: f32, kind: RadarBlipKind) -> Self {
+        Self {
+            angle,
+            distance,
+            kind,
+        }
+    }
+}
+
+/// A circular radar plotting blips around its center, with a rotating sweep
+///
+/// Blips fade after the sweep passes over them and brighten again the next time around. Blips
+/// further than `range` from the center are outside of the radar's circle, so they aren't shown.
+/// While the sweep is enabled, the radar re-renders every frame.
+///
+/// # Arguments
+///
+/// * `blips`: The blips to plot. The radar re-renders when they change.
+/// * `range`: The distance shown between the center and the edge of the radar
+/// * `sweep_duration`: How long the sweep takes to go around once (in seconds). Defaults to 3.
+///   If 0, the sweep is hidden and the blips don't fade.
+/// * `blip_size`: The width and height of each blip (in pixels). Defaults to 6.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the area the radar's circle is centered in. The
+/// `background_color` style applies to the circle and the `color` style to the sweep and the
+/// lines through the center.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, rsx};
+/// # use kayak_ui::widgets::{Radar, RadarBlip, RadarBlipKind};
+///
+/// let blips = bind(vec![RadarBlip::new(0.5, 40.0, RadarBlipKind::Hostile)]);
+///
+/// rsx! {
+///     <Radar blips={blips} range={100.0} />
+/// }
+/// ```
+#[widget]
+pub fn Radar(
+    blips: Binding<Vec<RadarBlip>>,
+    range: f32,
+    sweep_duration: Option<f32>,
+    blip_size: Option<f32>,
+) {
+    context.bind(&blips);
+    let sweep_duration = sweep_duration.unwrap_or(DEFAULT_SWEEP_DURATION);
+    let blip_size = blip_size.unwrap_or(DEFAULT_BLIP_SIZE);
+    let has_sweep = sweep_duration > 0.0;
+
+    // === Animation === //
+    let (started_at, set_started_at, ..) = use_state!(None::<Instant>);
+    let (_, set_last_frame, ..) = use_state!(None::<Instant>);
+    // The portion of a full turn the sweep is at, clockwise from the top
+    let sweep = if has_sweep {
+        let now = context.now();
+        let started_at = started_at.unwrap_or_else(|| {
+            set_started_at(Some(now));
+            now
+        });
+        // Changing this state re-renders the radar next frame, so the sweep keeps moving
+        set_last_frame(Some(now));
+        (now.saturating_duration_since(started_at).as_secs_f32() / sweep_duration).fract()
+    } else {
+        0.0
+    };
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles
+        .color
+        .resolve_or(context.theme(), Color::new(0.3, 1.0, 0.4, 1.0));
+    let background_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.05, 0.12, 0.07, 0.85));
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..base_styles
+    });
+
+    let layout = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let radius = layout.width.min(layout.height) / 2.0;
+    let center = (layout.width / 2.0, layout.height / 2.0);
+
+    let circle_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value((radius, radius, radius, radius)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(center.0 - radius)),
+        top: StyleProp::Value(Units::Pixels(center.1 - radius)),
+        width: StyleProp::Value(Units::Pixels(radius * 2.0)),
+        height: StyleProp::Value(Units::Pixels(radius * 2.0)),
+        ..Style::default()
+    };
+
+    let cross_styles = Style {
+        color: StyleProp::Value(Color { a: 0.25, ..color }),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Pixels(0.0)),
+        height: StyleProp::Value(Units::Pixels(0.0)),
+        ..Style::default()
+    };
+    let cross = vec![
+        ((radius, 0.0), (radius, radius * 2.0)),
+        ((0.0, radius), (radius * 2.0, radius)),
+    ]
+    .into_iter()
+    .map(|(from, to)| {
+        let cross_styles = cross_styles.clone();
+        constructor! {
+            <Line from={from} to={to} thickness={1.0} styles={Some(cross_styles)} />
+        }
+    })
+    .collect::<Vec<_>>();
+
+    let trail = if has_sweep {
+        SWEEP_TRAIL
+            .iter()
+            .map(|&(size, alpha)| {
+                let trail_styles = Style {
+                    background_color: StyleProp::Value(Color { a: alpha, ..color }),
+                    position_type: StyleProp::Value(PositionType::SelfDirected),
+                    width: StyleProp::Value(Units::Stretch(1.0)),
+                    height: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Style::default()
+                };
+                constructor! {
+                    <Sector start={sweep - size} sweep={size} styles={Some(trail_styles)} />
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    // Blips are kept fully within the circle
+    let plot_radius = (radius - blip_size / 2.0).max(0.0);
+    let blips = blips
+        .get()
+        .into_iter()
+        .filter(|blip| range > 0.0 && blip.distance >= 0.0 && blip.distance <= range)
+        .map(|blip| {
+            let distance = blip.distance / range * plot_radius;
+            let (x, y) = (
+                radius + blip.angle.sin() * distance,
+                radius - blip.angle.cos() * distance,
+            );
+            let alpha = if has_sweep {
+                // How long ago the sweep passed over the blip, as a portion of a full turn
+                let since_swept = (sweep - blip.angle / std::f32::consts::TAU).rem_euclid(1.0);
+                1.0 - since_swept * (1.0 - MIN_BLIP_ALPHA)
+            } else {
+                1.0
+            };
+            let blip_color = blip.kind.color();
+            let blip_styles = Style {
+                background_color: StyleProp::Value(Color {
+                    a: blip_color.a * alpha,
+                    ..blip_color
+                }),
+                border_radius: StyleProp::Value((
+                    blip_size / 2.0,
+                    blip_size / 2.0,
+                    blip_size / 2.0,
+                    blip_size / 2.0,
+                )),
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Pixels(x - blip_size / 2.0)),
+                top: StyleProp::Value(Units::Pixels(y - blip_size / 2.0)),
+                width: StyleProp::Value(Units::Pixels(blip_size)),
+                height: StyleProp::Value(Units::Pixels(blip_size)),
+                ..Style::default()
+            };
+            constructor! {
+                <Background styles={Some(blip_styles)} />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <Background styles={Some(circle_styles)}>
+            <VecTracker data={trail} />
+            <VecTracker data={cross} />
+            <VecTracker data={blips} />
+        </Background>
+    }
+}
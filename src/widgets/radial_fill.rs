@@ -0,0 +1,33 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children,
+};
+
+/// A widget that draws a pie slice of its background, sweeping clockwise from the top
+///
+/// This is useful for radial progress bars and cooldown overlays.
+///
+/// # Arguments
+///
+/// * `fill`: The portion of the widget covered by the slice, from 0 (nothing) to 1 (everything)
+/// * `children`: The widget's children (they aren't affected by the fill)
+///
+/// # Styles
+///
+/// This widget accepts all styles. The `background_color` and `border_radius` styles apply to
+/// the slice.
+#[widget]
+pub fn RadialFill(children: Children, fill: f32) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::RadialFill { fill }),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -0,0 +1,216 @@
+use std::f32::consts::PI;
+
+use crate::core::{
+    canvas::Painter,
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, EventType, Handler, Index, KayakContext, KeyCode, OnEvent,
+};
+
+use crate::widgets::{Canvas, OnDraw};
+
+/// The number of stars shown when `count` is zero
+const DEFAULT_COUNT: usize = 5;
+/// The default size of each star (in pixels)
+const STAR_SIZE: f32 = 24.0;
+/// The space between stars (in pixels)
+const STAR_GAP: f32 = 4.0;
+/// The radius of the inner corners of a star, relative to its outer corners
+const INNER_RADIUS: f32 = 0.4;
+
+/// A row of stars for rating something
+///
+/// Hovering the stars highlights them up to the one under the cursor, and clicking commits that
+/// rating. With `allow_half`, the left half of a star selects half of it. When focused, the arrow
+/// keys change the rating by one star (or half of one) and `Home`/`End` jump to no stars and all
+/// of them.
+///
+/// # Arguments
+///
+/// * `value`: The current rating, from zero to `count`
+/// * `count`: The number of stars (5 if zero)
+/// * `allow_half`: If true, half stars can be selected
+/// * `on_change`: Called with the next desired rating when the user selects one
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the row of stars. The `color` style applies to the
+/// highlighted stars and the `background_color` style to the others. The stars are as large as the
+/// row is tall, which is 24 pixels by default.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+///
+/// let (rating, set_rating, ..) = use_state!(3.5);
+/// let on_change = Handler::new(move |value| {
+///     set_rating(value);
+/// });
+///
+/// rsx! {
+///     <Rating value={rating} allow_half={true} on_change={Some(on_change)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Rating(value: f32, count: usize, allow_half: bool, on_change: Option<Handler<f32>>) {
+    let count = if count == 0 { DEFAULT_COUNT } else { count };
+    let (hovered, set_hovered, ..) = use_state!(None::<f32>);
+
+    // === Events === //
+    let increment = if allow_half { 0.5 } else { 1.0 };
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::Hover => {
+            let next_hovered = value_at_cursor(ctx, &event.current_target, count, allow_half);
+            if next_hovered != hovered {
+                set_hovered(next_hovered);
+            }
+        }
+        EventType::MouseOut => {
+            if hovered.is_some() {
+                set_hovered(None);
+            }
+        }
+        EventType::Click => {
+            if let Some(next_value) = value_at_cursor(ctx, &event.current_target, count, allow_half)
+            {
+                change(&on_change, value, next_value);
+            }
+        }
+        EventType::KeyDown(evt) => {
+            let next_value = match evt.key() {
+                KeyCode::Right | KeyCode::Up => value + increment,
+                KeyCode::Left | KeyCode::Down => value - increment,
+                KeyCode::Home => 0.0,
+                KeyCode::End => count as f32,
+                _ => return,
+            };
+            // Snap, in case the current value isn't a multiple of the increment
+            let next_value = (next_value / increment).round() * increment;
+            change(&on_change, value, next_value.clamp(0.0, count as f32));
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let filled_color = base_styles
+        .color
+        .resolve_or(context.theme(), Color::new(1.0, 0.776, 0.239, 1.0));
+    let empty_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(
+                count as f32 * (STAR_SIZE + STAR_GAP) - STAR_GAP,
+            ))
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(STAR_SIZE))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    let shown_value = hovered.unwrap_or(value);
+    let on_draw = OnDraw::new(move |painter| {
+        let size = painter.size().1;
+        for star in 0..count {
+            let origin = (star as f32 * (size + STAR_GAP), 0.0);
+            let fill = (shown_value - star as f32).clamp(0.0, 1.0);
+            fill_star(painter, origin, size, size, empty_color);
+            if fill > 0.0 {
+                fill_star(painter, origin, size, size * fill, filled_color);
+            }
+        }
+    });
+
+    rsx! {
+        <Canvas on_draw={Some(on_draw)} />
+    }
+}
+
+/// Calls `on_change` if the rating changed
+fn change(on_change: &Option<Handler<f32>>, value: f32, next_value: f32) {
+    if next_value != value {
+        if let Some(on_change) = on_change {
+            on_change.call(next_value);
+        }
+    }
+}
+
+/// Calculates the rating corresponding to the current cursor position over the given stars
+fn value_at_cursor(
+    context: &KayakContext,
+    rating: &Index,
+    count: usize,
+    allow_half: bool,
+) -> Option<f32> {
+    let layout = context.widget_manager.get_layout(rating)?;
+    let size = layout.height;
+    if size <= 0.0 {
+        return None;
+    }
+    let x = context.last_mouse_position().0 - layout.posx;
+    if x < 0.0 {
+        return None;
+    }
+
+    let stride = size + STAR_GAP;
+    let star = ((x / stride) as usize).min(count - 1);
+    let within = x - star as f32 * stride;
+    if allow_half && within < size / 2.0 {
+        Some(star as f32 + 0.5)
+    } else {
+        Some(star as f32 + 1.0)
+    }
+}
+
+/// Fills a five-pointed star with the given top-left corner and size, up to `width` from its left
+///
+/// The painter has no polygons, so the star is filled by a rect for each row of pixels.
+fn fill_star(painter: &mut Painter, origin: (f32, f32), size: f32, width: f32, color: Color) {
+    let radius = size / 2.0;
+    let center = (origin.0 + radius, origin.1 + radius);
+    let corners = (0..10)
+        .map(|corner| {
+            let angle = -PI / 2.0 + corner as f32 * PI / 5.0;
+            let radius = if corner % 2 == 0 {
+                radius
+            } else {
+                radius * INNER_RADIUS
+            };
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let max_x = origin.0 + width;
+    for row in 0..size.ceil() as usize {
+        let y = origin.1 + row as f32 + 0.5;
+        let mut crossings = Vec::new();
+        for (index, from) in corners.iter().enumerate() {
+            let to = corners[(index + 1) % corners.len()];
+            if (from.1 <= y && y < to.1) || (to.1 <= y && y < from.1) {
+                crossings.push(from.0 + (y - from.1) / (to.1 - from.1) * (to.0 - from.0));
+            }
+        }
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for span in crossings.chunks_exact(2) {
+            let (start, end) = (span[0], span[1].min(max_x));
+            if end > start {
+                painter.draw_rect((start, y - 0.5), (end - start, 1.0), color);
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Instant;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, PointerEvents, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Line};
+
+/// The length of each arm of the crosshair by default (in pixels)
+const DEFAULT_ARM_LENGTH: f32 = 8.0;
+/// The thickness of the lines by default (in pixels)
+const DEFAULT_THICKNESS: f32 = 2.0;
+/// How quickly the shown spread catches up with `spread` (the portion of the difference closed
+/// per second, roughly)
+const SPREAD_SPEED: f32 = 15.0;
+/// How long the hit marker is shown (in seconds)
+const HIT_MARKER_DURATION: f32 = 0.2;
+/// The distance between the center and the start of each hit marker line (in pixels)
+const HIT_MARKER_GAP: f32 = 4.0;
+/// The length of each hit marker line (in pixels)
+const HIT_MARKER_LENGTH: f32 = 6.0;
+
+/// A crosshair centered within the widget, whose arms move apart as `spread` grows
+///
+/// The arms don't jump when `spread` changes: they smoothly move to their new position, so the app
+/// can set `spread` directly from the weapon's current accuracy. Increasing `hit_count` (e.g. each
+/// time a shot lands) briefly flashes a hit marker.
+///
+/// The reticle doesn't receive pointer events, so it can be placed over the rest of the UI.
+///
+/// # Arguments
+///
+/// * `spread`: The distance between the center and the start of each arm (in pixels)
+/// * `arm_length`: The length of each arm (in pixels). Defaults to 8.
+/// * `thickness`: The thickness of the lines (in pixels). Defaults to 2.
+/// * `show_dot`: Whether a dot is drawn at the center
+/// * `hit_count`: The number of hits so far. The hit marker flashes whenever it changes.
+/// * `hit_marker_color`: The color of the hit marker. Defaults to the reticle's color.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the area the reticle is centered in. The `color`
+/// style applies to the reticle.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::Reticle;
+///
+/// let spread = 6.0;
+/// let hit_count = 0;
+///
+/// rsx! {
+///     <Reticle spread={spread} show_dot={true} hit_count={hit_count} />
+/// }
+/// ```
+#[widget]
+pub fn Reticle(
+    spread: f32,
+    arm_length: Option<f32>,
+    thickness: Option<f32>,
+    show_dot: bool,
+    hit_count: usize,
+    hit_marker_color: Option<Color>,
+) {
+    let arm_length = arm_length.unwrap_or(DEFAULT_ARM_LENGTH);
+    let thickness = thickness.unwrap_or(DEFAULT_THICKNESS);
+
+    // === Animation === //
+    // Changing any of these re-renders the reticle next frame, until it settles
+    let (shown_spread, set_shown_spread, ..) = use_state!(spread);
+    let (last_frame, set_last_frame, ..) = use_state!(None::<Instant>);
+    let (last_hit_count, set_last_hit_count, ..) = use_state!(hit_count);
+    let (hit_at, set_hit_at, ..) = use_state!(None::<Instant>);
+
+    let now = context.now();
+    let elapsed = last_frame.map_or(0.0, |last_frame| {
+        now.saturating_duration_since(last_frame).as_secs_f32()
+    });
+    let shown_spread = if (shown_spread - spread).abs() < 0.05 {
+        if shown_spread != spread {
+            set_shown_spread(spread);
+        }
+        spread
+    } else {
+        let next_spread =
+            shown_spread + (spread - shown_spread) * (elapsed * SPREAD_SPEED).min(1.0);
+        set_shown_spread(next_spread);
+        next_spread
+    };
+
+    let hit_at = if last_hit_count != hit_count {
+        set_last_hit_count(hit_count);
+        set_hit_at(Some(now));
+        Some(now)
+    } else {
+        hit_at
+    };
+    let hit_marker_alpha = hit_at.map_or(0.0, |hit_at| {
+        1.0 - now.saturating_duration_since(hit_at).as_secs_f32() / HIT_MARKER_DURATION
+    });
+    if hit_at.is_some() && hit_marker_alpha <= 0.0 {
+        set_hit_at(None);
+    }
+
+    let is_animating = shown_spread != spread || hit_marker_alpha > 0.0;
+    if is_animating {
+        set_last_frame(Some(now));
+    } else if last_frame.is_some() {
+        set_last_frame(None);
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    let hit_marker_color = hit_marker_color.unwrap_or(color);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        ..base_styles
+    });
+
+    // The lines are drawn relative to this zero-sized center
+    let center_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Pixels(0.0)),
+        height: StyleProp::Value(Units::Pixels(0.0)),
+        ..Style::default()
+    };
+
+    let line_styles = Style {
+        color: StyleProp::Value(color),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Pixels(0.0)),
+        height: StyleProp::Value(Units::Pixels(0.0)),
+        ..Style::default()
+    };
+    let (start, end) = (shown_spread, shown_spread + arm_length);
+    let mut lines = vec![
+        ((0.0, -start), (0.0, -end), line_styles.clone()),
+        ((0.0, start), (0.0, end), line_styles.clone()),
+        ((-start, 0.0), (-end, 0.0), line_styles.clone()),
+        ((start, 0.0), (end, 0.0), line_styles.clone()),
+    ];
+    if hit_marker_alpha > 0.0 {
+        let hit_marker_styles = Style {
+            color: StyleProp::Value(Color {
+                a: hit_marker_color.a * hit_marker_alpha,
+                ..hit_marker_color
+            }),
+            ..line_styles
+        };
+        // Diagonal lines, one pointing into each corner
+        let (start, end) = (
+            HIT_MARKER_GAP / 2.0_f32.sqrt(),
+            (HIT_MARKER_GAP + HIT_MARKER_LENGTH) / 2.0_f32.sqrt(),
+        );
+        for (x, y) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+            lines.push((
+                (x * start, y * start),
+                (x * end, y * end),
+                hit_marker_styles.clone(),
+            ));
+        }
+    }
+    let lines = lines
+        .into_iter()
+        .map(|(from, to, line_styles)| {
+            constructor! {
+                <Line from={from} to={to} thickness={thickness} styles={Some(line_styles)} />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let dot_styles = Style {
+        background_color: StyleProp::Value(color),
+        border_radius: StyleProp::Value((
+            thickness / 2.0,
+            thickness / 2.0,
+            thickness / 2.0,
+            thickness / 2.0,
+        )),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(-thickness / 2.0)),
+        top: StyleProp::Value(Units::Pixels(-thickness / 2.0)),
+        width: StyleProp::Value(Units::Pixels(thickness)),
+        height: StyleProp::Value(Units::Pixels(thickness)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Element styles={Some(center_styles)}>
+            <VecTracker data={lines} />
+            <If condition={show_dot}>
+                <Background styles={Some(dot_styles)} />
+            </If>
+        </Element>
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use kayak_font::KayakFont;
+
+use crate::core::{
+    color::Color,
+    render_command::{RenderCommand, TextRun},
+    styles::{Style, StyleProp, Units},
+    widget, Binding, Bound,
+};
+
+/// The font size of spans without their own size by default (in pixels)
+const DEFAULT_SIZE: f32 = 14.0;
+
+/// A span of text within a [RichText], with its own style
+///
+/// Unset styles fall back to those of the rich text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub color: Option<Color>,
+    pub size: Option<f32>,
+    pub font: Option<String>,
+    pub bold: bool,
+    pub underline: bool,
+}
+
+impl TextSpan {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_font<S: Into<String>>(mut self, font: S) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+}
+
+/// A paragraph made of differently styled spans
+///
+/// The spans are laid out inline, one after the other, and wrapped as a single paragraph within
+/// the width of the parent (like [Text](crate::widgets::Text)). Line breaks (`\n`) within spans
+/// are kept. Spans of different sizes share their lines' baseline.
+///
+/// # Arguments
+///
+/// * `spans`: The spans of the paragraph
+/// * `size`: The font size of the spans without their own (in pixels). Defaults to 14.
+/// * `line_height`: The height of each line, relative to its largest font size. Defaults to 1.2.
+/// * `font`: The name of the font of the spans without their own. Defaults to Roboto.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the paragraph. The `color` style applies to the
+/// spans without their own color.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Color, rsx};
+/// # use kayak_ui::widgets::{RichText, TextSpan};
+///
+/// let spans = vec![
+///     TextSpan::new("You found "),
+///     TextSpan::new("Excalibur").with_color(Color::new(1.0, 0.6, 0.0, 1.0)).bold(),
+///     TextSpan::new("! Press "),
+///     TextSpan::new("E").underline(),
+///     TextSpan::new(" to equip it."),
+/// ];
+///
+/// rsx! {
+///     <RichText spans={spans} />
+/// }
+/// ```
+#[widget]
+pub fn RichText(
+    spans: Vec<TextSpan>,
+    size: Option<f32>,
+    line_height: Option<f32>,
+    font: Option<String>,
+) {
+    let default_size = size.unwrap_or(DEFAULT_SIZE);
+    let line_height = line_height.unwrap_or(1.2);
+    let default_font = font.unwrap_or_else(|| "Roboto".to_string());
+
+    let mut fonts = HashMap::<String, Option<KayakFont>>::new();
+    for name in spans
+        .iter()
+        .map(|span| span.font.clone().unwrap_or_else(|| default_font.clone()))
+    {
+        if !fonts.contains_key(&name) {
+            let font: Binding<Option<KayakFont>> = context.get_asset(name.clone());
+            context.bind(&font);
+            fonts.insert(name, font.get());
+        }
+    }
+
+    let max_width = context
+        .widget_manager
+        .get_valid_parent(parent_id.unwrap())
+        .and_then(|parent_id| context.widget_manager.get_layout(&parent_id))
+        .map_or(f32::MAX, |layout| layout.width);
+
+    // === Layout === //
+    let mut layout = ParagraphLayout::new(max_width, line_height);
+    for span in spans {
+        let font_name = span.font.unwrap_or_else(|| default_font.clone());
+        let font = fonts.get(&font_name).and_then(|font| font.as_ref());
+        let template = TextRun {
+            font: font_name.clone(),
+            size: span.size.unwrap_or(default_size),
+            color: span.color,
+            bold: span.bold,
+            underline: span.underline,
+            ..Default::default()
+        };
+        for (index, line) in span.text.split('\n').enumerate() {
+            if index > 0 {
+                layout.break_line(template.size);
+            }
+            for piece in line.split_inclusive(' ') {
+                let word_width = measure(font, piece.trim_end_matches(' '), template.size);
+                let piece_width = measure(font, piece, template.size);
+                layout.push(piece, word_width, piece_width, &template);
+            }
+        }
+    }
+    let (runs, size) = layout.finish();
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::RichText { runs }),
+        width: StyleProp::Value(Units::Pixels(size.0)),
+        height: StyleProp::Value(Units::Pixels(size.1)),
+        ..styles.clone().unwrap_or_default()
+    });
+}
+
+/// Breaks runs of text into lines, placing each run on its line
+struct ParagraphLayout {
+    max_width: f32,
+    line_height: f32,
+    /// The runs of the lines laid out so far, positioned relative to the paragraph
+    runs: Vec<TextRun>,
+    /// The runs of the current line, positioned relative to the start of the line
+    line: Vec<TextRun>,
+    /// The top of the current line
+    y: f32,
+    /// The width of the current line
+    x: f32,
+    width: f32,
+}
+
+impl ParagraphLayout {
+    fn new(max_width: f32, line_height: f32) -> Self {
+        Self {
+            max_width,
+            line_height,
+            runs: Vec::new(),
+            line: Vec::new(),
+            y: 0.0,
+            x: 0.0,
+            width: 0.0,
+        }
+    }
+
+    /// Adds a word (and the spaces after it) to the current line, wrapping if it doesn't fit
+    fn push(&mut self, piece: &str, word_width: f32, piece_width: f32, template: &TextRun) {
+        if self.x > 0.0 && self.x + word_width > self.max_width {
+            self.break_line(template.size);
+        }
+        if self.x == 0.0 && piece.trim().is_empty() {
+            // Don't start wrapped lines with spaces
+            return;
+        }
+
+        match self.line.last_mut() {
+            Some(last)
+                if last.font == template.font
+                    && last.size == template.size
+                    && last.color == template.color
+                    && last.bold == template.bold
+                    && last.underline == template.underline =>
+            {
+                last.content.push_str(piece);
+            }
+            _ => self.line.push(TextRun {
+                content: piece.to_string(),
+                position: (self.x, 0.0),
+                ..template.clone()
+            }),
+        }
+        self.x += piece_width;
+    }
+
+    /// Ends the current line, which is as tall as `size` if it's empty
+    fn break_line(&mut self, size: f32) {
+        // A line's runs share its baseline, placed below its largest font
+        let largest = self
+            .line
+            .iter()
+            .map(|run| run.size)
+            .fold(if self.line.is_empty() { size } else { 0.0 }, f32::max);
+        for mut run in self.line.drain(..) {
+            if run.underline {
+                // Don't underline the spaces at the end of the line
+                run.content.truncate(run.content.trim_end().len());
+            }
+            run.position.1 = self.y + largest - run.size;
+            self.runs.push(run);
+        }
+        self.width = self.width.max(self.x);
+        self.y += largest * self.line_height;
+        self.x = 0.0;
+    }
+
+    /// Ends the last line, returning the positioned runs and the size of the paragraph
+    fn finish(mut self) -> (Vec<TextRun>, (f32, f32)) {
+        if !self.line.is_empty() {
+            self.break_line(0.0);
+        }
+        (self.runs, (self.width, self.y))
+    }
+}
+
+/// Measures the width of the given text, estimating it if the font isn't loaded yet
+fn measure(font: Option<&KayakFont>, text: &str, size: f32) -> f32 {
+    match font {
+        Some(font) => text
+            .chars()
+            .map(|c| font.get_glyph(c).map_or(0.0, |glyph| glyph.advance * size))
+            .sum(),
+        None => text.chars().count() as f32 * size * 0.5,
+    }
+}
@@ -0,0 +1,112 @@
+use crate::core::{rsx, use_state, widget, Bound, Children, Handler, MutableBound};
+
+/// The routing state shared between a [Router] and its [Routes](Route)
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RouterState {
+    /// The currently active route
+    pub current: String,
+    /// The previously active route (if any)
+    pub previous: Option<String>,
+}
+
+/// A container that mounts one of its [Routes](Route) based on the given route
+///
+/// Only the active route's children are constructed: inactive routes render nothing at all, so
+/// screens are built lazily the first time they become active and torn down when left.
+///
+/// Since `route` is a plain value, it can come from anywhere: a `use_state!` binding, a global
+/// binding, or a Bevy `State` (see `sync_state_route` in `kayak_ui::bevy`).
+///
+/// # Arguments
+///
+/// * `children`: The [Routes](Route) to choose from
+/// * `route`: The name of the active route
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state};
+/// # use kayak_ui::widgets::{Route, Router};
+///
+/// let (route, set_route, ..) = use_state!("main_menu".to_string());
+///
+/// rsx! {
+///     <Router route={route}>
+///         <Route path={"main_menu".to_string()}>
+///             <MainMenu />
+///         </Route>
+///         <Route path={"settings".to_string()}>
+///             <Settings />
+///         </Route>
+///     </Router>
+/// }
+/// ```
+#[widget]
+pub fn Router(children: Children, route: String) {
+    let state = context.create_provider(RouterState {
+        current: route.clone(),
+        previous: None,
+    });
+
+    let current_state = state.get();
+    if current_state.current != route {
+        state.set(RouterState {
+            current: route,
+            previous: Some(current_state.current),
+        });
+    }
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// A screen within a [Router], rendered only while its `path` is the active route
+///
+/// # Arguments
+///
+/// * `children`: The screen's content
+/// * `path`: The route name this screen is mounted for
+/// * `on_enter`: Called when this route becomes active. Contains the previous route (if any).
+/// * `on_exit`: Called when this route stops being active. Contains the next route.
+///
+/// Routes switch instantly: they don't animate anything themselves. The `on_enter` and `on_exit`
+/// handlers are where to react to the switch instead (e.g. starting an animation within the
+/// incoming screen, or pushing/popping the screen stack).
+#[widget]
+pub fn Route(
+    children: Children,
+    path: String,
+    on_enter: Option<Handler<Option<String>>>,
+    on_exit: Option<Handler<String>>,
+) {
+    let state = context
+        .create_consumer::<RouterState>()
+        .expect("Route requires Router as an ancestor");
+    context.bind(&state);
+
+    let RouterState { current, previous } = state.get();
+    let is_active = current == path;
+    let (was_active, set_was_active, ..) = use_state!(false);
+
+    if is_active != was_active {
+        set_was_active(is_active);
+        if is_active {
+            if let Some(ref on_enter) = on_enter {
+                on_enter.call(previous);
+            }
+        } else if let Some(ref on_exit) = on_exit {
+            on_exit.call(current);
+        }
+    }
+
+    if is_active {
+        rsx! {
+            <>
+                {children}
+            </>
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use crate::core::{
+    color::Color,
+    layout_cache::Rect,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Children, EventType, Index, KayakContext, MutableBound,
+    OnEvent,
+};
+
+use crate::widgets::{Background, If};
+
+/// The thickness of the scrollbar thumbs (in pixels)
+const THUMB_THICKNESS: f32 = 6.0;
+/// The minimum length of the scrollbar thumbs (in pixels)
+const MIN_THUMB_LENGTH: f32 = 20.0;
+/// The space between the scrollbar thumbs and the edges of the box (in pixels)
+const THUMB_MARGIN: f32 = 2.0;
+/// How close to the bottom a sticky [ScrollBox] must be scrolled to stick again (in pixels)
+const STICK_THRESHOLD: f32 = 1.0;
+
+/// The directions a [ScrollBox] can scroll in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollDirection {
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl Default for ScrollDirection {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+impl ScrollDirection {
+    fn is_vertical(&self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+
+    fn is_horizontal(&self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+}
+
+/// The scroll position of a [ScrollBox], which can be shared with other widgets (like a
+/// [Minimap](crate::widgets::Minimap))
+///
+/// The scroll box keeps the sizes up to date after every layout. Setting `offset` scrolls the box
+/// (clamped to its content).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ScrollPosition {
+    /// How far the content is scrolled (in pixels)
+    pub offset: (f32, f32),
+    /// The size of the visible area of the box (in pixels)
+    pub viewport_size: (f32, f32),
+    /// The size of the content of the box (in pixels)
+    pub content_size: (f32, f32),
+    /// The scroll box this position belongs to, once it has rendered
+    pub scroll_box: Option<Index>,
+}
+
+/// A container that clips its children and lets the user scroll through them with the mouse wheel
+///
+/// Scrolling translates the layout of all children, so hit-testing matches what is displayed.
+/// Scroll events that can't be handled (e.g. because the box is already scrolled to the end)
+/// propagate to any outer scroll boxes.
+///
+/// # Arguments
+///
+/// * `children`: The content to scroll through
+/// * `direction`: The directions the content can be scrolled in
+/// * `hide_scrollbars`: If true, the scrollbar thumbs are not displayed
+/// * `stick_to_bottom`: If true, the box stays scrolled to the bottom as content is added, unless
+///   the user scrolls up (e.g. for logs and chats). Scrolling back down sticks it again.
+/// * `position`: If set, the scroll position is kept in this binding instead of the box's own
+///   state, so other widgets can follow and change it
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the scroll box container. The `color` style applies
+/// to the scrollbar thumbs.
+#[widget]
+pub fn ScrollBox(
+    children: Children,
+    direction: ScrollDirection,
+    hide_scrollbars: bool,
+    stick_to_bottom: bool,
+    position: Option<Binding<ScrollPosition>>,
+) {
+    // === State === //
+    let (_, _, own_position) = use_state!(ScrollPosition::default());
+    let position = position.unwrap_or(own_position);
+    context.bind(&position);
+    let current_position = position.get();
+    let (is_stuck, set_is_stuck, ..) = use_state!(true);
+
+    // The content size is measured from the last layout, which used the last applied offset
+    let metrics = ScrollMetrics::measure(context, self.id);
+    let offset = metrics.map_or(current_position.offset, |metrics| {
+        let mut offset = metrics.clamp(current_position.offset);
+        if stick_to_bottom && is_stuck {
+            offset.1 = metrics.max_offset().1;
+        }
+        offset
+    });
+    let next_position = metrics.map_or(
+        ScrollPosition {
+            offset,
+            scroll_box: Some(self.id),
+            ..current_position
+        },
+        |metrics| ScrollPosition {
+            offset,
+            viewport_size: (metrics.viewport.width, metrics.viewport.height),
+            content_size: metrics.content_size,
+            scroll_box: Some(self.id),
+        },
+    );
+    if next_position != current_position {
+        position.set(next_position);
+    }
+    context.set_scroll_offset(self.id, offset);
+
+    // === Events === //
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::Scroll(scroll) => {
+            let metrics = if let Some(metrics) = ScrollMetrics::measure(ctx, event.current_target) {
+                metrics
+            } else {
+                return;
+            };
+
+            let (delta_x, delta_y) = scroll.pixel_delta();
+            let current_position = position.get();
+            let current = current_position.offset;
+            let mut next = current;
+            if direction.is_vertical() {
+                next.1 += delta_y;
+            }
+            if direction.is_horizontal() {
+                // Allow vertical wheels to scroll horizontal-only boxes
+                next.0 += if direction.is_vertical() {
+                    delta_x
+                } else {
+                    delta_x + delta_y
+                };
+            }
+            let next = metrics.clamp(next);
+            if stick_to_bottom {
+                set_is_stuck(next.1 >= metrics.max_offset().1 - STICK_THRESHOLD);
+            }
+
+            if next != current {
+                position.set(ScrollPosition {
+                    offset: next,
+                    ..current_position
+                });
+                event.stop_propagation();
+            }
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Clip),
+        width: if matches!(base_styles.width, StyleProp::Value(..)) {
+            base_styles.width.clone()
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        },
+        height: if matches!(base_styles.height, StyleProp::Value(..)) {
+            base_styles.height.clone()
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        },
+        ..base_styles.clone()
+    });
+
+    let thumb_color = if matches!(base_styles.color, StyleProp::Value(..)) {
+        base_styles.color.clone()
+    } else {
+        StyleProp::Value(Color::new(1.0, 1.0, 1.0, 0.35))
+    };
+
+    let mut show_vertical = false;
+    let mut show_horizontal = false;
+    let mut vertical_styles = Style::default();
+    let mut horizontal_styles = Style::default();
+    if let Some(metrics) = metrics {
+        let max_offset = metrics.max_offset();
+        let viewport = metrics.viewport;
+        // Thumbs are children of the box, so they need to counter its scroll offset
+        if direction.is_vertical() && max_offset.1 > 0.0 && !hide_scrollbars {
+            show_vertical = true;
+            let (length, position) = thumb_geometry(
+                viewport.height,
+                metrics.content_size.1,
+                offset.1,
+                max_offset.1,
+            );
+            vertical_styles = Style {
+                left: StyleProp::Value(Units::Pixels(
+                    viewport.width - THUMB_THICKNESS - THUMB_MARGIN + offset.0,
+                )),
+                top: StyleProp::Value(Units::Pixels(position + offset.1)),
+                width: StyleProp::Value(Units::Pixels(THUMB_THICKNESS)),
+                height: StyleProp::Value(Units::Pixels(length)),
+                ..thumb_styles(thumb_color.clone())
+            };
+        }
+        if direction.is_horizontal() && max_offset.0 > 0.0 && !hide_scrollbars {
+            show_horizontal = true;
+            let (length, position) = thumb_geometry(
+                viewport.width,
+                metrics.content_size.0,
+                offset.0,
+                max_offset.0,
+            );
+            horizontal_styles = Style {
+                left: StyleProp::Value(Units::Pixels(position + offset.0)),
+                top: StyleProp::Value(Units::Pixels(
+                    viewport.height - THUMB_THICKNESS - THUMB_MARGIN + offset.1,
+                )),
+                width: StyleProp::Value(Units::Pixels(length)),
+                height: StyleProp::Value(Units::Pixels(THUMB_THICKNESS)),
+                ..thumb_styles(thumb_color)
+            };
+        }
+    }
+
+    rsx! {
+        <>
+            {children}
+            <If condition={show_vertical}>
+                <Background styles={Some(vertical_styles)} />
+            </If>
+            <If condition={show_horizontal}>
+                <Background styles={Some(horizontal_styles)} />
+            </If>
+        </>
+    }
+}
+
+/// The sizes needed to scroll a [ScrollBox]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScrollMetrics {
+    /// The layout of the box itself
+    viewport: Rect,
+    /// The size of the box's content (relative to the box's position)
+    content_size: (f32, f32),
+}
+
+impl ScrollMetrics {
+    /// Measures the scroll box from its last calculated layout
+    fn measure(context: &KayakContext, scroll_box: Index) -> Option<Self> {
+        let widget_manager = &context.widget_manager;
+        let viewport = *widget_manager.get_layout(&scroll_box)?;
+        let offset = widget_manager.get_scroll_offset(scroll_box);
+
+        let mut content_size = (0.0f32, 0.0f32);
+        for node in widget_manager.node_tree.flatten_node(scroll_box) {
+            if node == scroll_box {
+                continue;
+            }
+            if !widget_manager.node_tree.is_descendant(node, scroll_box) {
+                // Left the subtree
+                break;
+            }
+            if let Some(layout) = widget_manager.get_layout(&node) {
+                // Undo the scroll offset applied to the layout
+                let right = layout.posx + offset.0 + layout.width - viewport.posx;
+                let bottom = layout.posy + offset.1 + layout.height - viewport.posy;
+                content_size = (content_size.0.max(right), content_size.1.max(bottom));
+            }
+        }
+
+        Some(Self {
+            viewport,
+            content_size,
+        })
+    }
+
+    /// The furthest the content can be scrolled
+    fn max_offset(&self) -> (f32, f32) {
+        (
+            (self.content_size.0 - self.viewport.width).max(0.0),
+            (self.content_size.1 - self.viewport.height).max(0.0),
+        )
+    }
+
+    fn clamp(&self, offset: (f32, f32)) -> (f32, f32) {
+        let max_offset = self.max_offset();
+        (
+            offset.0.clamp(0.0, max_offset.0),
+            offset.1.clamp(0.0, max_offset.1),
+        )
+    }
+}
+
+/// Calculates the length and position of a scrollbar thumb along its axis
+fn thumb_geometry(viewport: f32, content: f32, offset: f32, max_offset: f32) -> (f32, f32) {
+    let track = viewport - THUMB_MARGIN * 2.0;
+    let length = (track * viewport / content)
+        .max(MIN_THUMB_LENGTH)
+        .min(track);
+    let position = THUMB_MARGIN + (track - length) * (offset / max_offset);
+    (length, position)
+}
+
+fn thumb_styles(color: StyleProp<Color>) -> Style {
+    Style {
+        background_color: color,
+        border_radius: StyleProp::Value((
+            THUMB_THICKNESS / 2.0,
+            THUMB_THICKNESS / 2.0,
+            THUMB_THICKNESS / 2.0,
+            THUMB_THICKNESS / 2.0,
+        )),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        ..Style::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScrollBox;
+    use crate::core::{
+        styles::{Style, StyleProp, Units},
+        InputEvent, KayakContext, ScrollEvent, ScrollUnit, WidgetBuilder,
+    };
+    use crate::widgets::Element;
+
+    fn sized(width: f32, height: f32) -> Style {
+        Style {
+            width: StyleProp::Value(Units::Pixels(width)),
+            height: StyleProp::Value(Units::Pixels(height)),
+            ..Style::default()
+        }
+    }
+
+    fn scroll_down(context: &mut KayakContext, delta_y: f32) {
+        context.process_events(vec![
+            InputEvent::MouseMoved((50.0, 50.0)),
+            InputEvent::Scroll(ScrollEvent {
+                delta_x: 0.0,
+                delta_y,
+                unit: ScrollUnit::Pixel,
+            }),
+        ]);
+        context.render();
+    }
+
+    #[test]
+    fn scrolls_past_the_limit_of_inner_boxes_should_scroll_outer_boxes() {
+        let mut context = KayakContext::new();
+        // The inner box can scroll 200 pixels, and the outer one 100
+        WidgetBuilder::new::<ScrollBox>()
+            .prop(|scroll_box| scroll_box.hide_scrollbars = true)
+            .styles(sized(100.0, 100.0))
+            .child(
+                WidgetBuilder::new::<ScrollBox>()
+                    .prop(|scroll_box| scroll_box.hide_scrollbars = true)
+                    .styles(sized(100.0, 100.0))
+                    .child(WidgetBuilder::new::<Element>().styles(sized(100.0, 300.0))),
+            )
+            .child(WidgetBuilder::new::<Element>().styles(sized(100.0, 100.0)))
+            .render_root(&mut context);
+        context.render();
+
+        let tree = &context.widget_manager.tree;
+        let outer = tree.root_node.unwrap();
+        let inner = tree.children.get(&outer).unwrap()[0];
+
+        scroll_down(&mut context, 150.0);
+        assert_eq!((0.0, 150.0), context.get_scroll_offset(inner));
+        assert_eq!((0.0, 0.0), context.get_scroll_offset(outer));
+
+        // Reaching the limit still scrolls the inner box
+        scroll_down(&mut context, 150.0);
+        assert_eq!((0.0, 200.0), context.get_scroll_offset(inner));
+        assert_eq!((0.0, 0.0), context.get_scroll_offset(outer));
+
+        scroll_down(&mut context, 50.0);
+        assert_eq!((0.0, 200.0), context.get_scroll_offset(inner));
+        assert_eq!((0.0, 50.0), context.get_scroll_offset(outer));
+    }
+}
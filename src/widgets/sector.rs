@@ -0,0 +1,34 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children,
+};
+
+/// A widget that draws a pie slice of the circle inscribed in it
+///
+/// Unlike [RadialFill](crate::widgets::RadialFill), the slice can start at any angle, which makes
+/// it useful for radar sweeps and highlighting a direction.
+///
+/// # Arguments
+///
+/// * `start`: Where the slice starts, as a portion of a full turn clockwise from the top
+/// * `sweep`: The size of the slice, as a portion of a full turn (1 draws the whole circle)
+/// * `children`: The widget's children (they aren't affected by the slice)
+///
+/// # Styles
+///
+/// This widget accepts all styles. The `background_color` style applies to the slice.
+#[widget]
+pub fn Sector(children: Children, start: f32, sweep: f32) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Sector { start, sweep }),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -0,0 +1,281 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, EventType, Handler, Index, KeyCode, MutableBound, OnEvent,
+    VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// The height of the select button and of each option (in pixels)
+const ITEM_HEIGHT: f32 = 26.0;
+/// The z-index offset of the option list, placing it above its surrounding widgets
+const LIST_Z_INDEX: f32 = 1000.0;
+
+/// A dropdown for selecting one of several options
+///
+/// Clicking the select opens a floating list of its options, drawn above other widgets. The list
+/// closes when an option is chosen, when clicking anywhere else, when focus moves to another
+/// widget, or when `Escape` is pressed. While open, the rest of the UI doesn't receive pointer
+/// events, so clicking outside of the list only closes it. When focused, `Up` and `Down` select
+/// the previous or next option without opening the list.
+///
+/// # Arguments
+///
+/// * `options`: The options to choose from
+/// * `selected`: The index of the selected option (if any)
+/// * `placeholder`: The text displayed while no option is selected
+/// * `on_selection_changed`: Called when the user chooses an option. Contains the option's index.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the select container. The `background_color` and
+/// `color` styles apply to both the button and the option list.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::Select;
+///
+/// let (selected, set_selected, ..) = use_state!(Some(0));
+/// let on_selection_changed = Handler::new(move |index| {
+///     set_selected(Some(index));
+/// });
+///
+/// rsx! {
+///     <Select
+///         options={vec!["Low".to_string(), "Medium".to_string(), "High".to_string()]}
+///         selected={selected}
+///         on_selection_changed={Some(on_selection_changed)}
+///     />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Select(
+    options: Vec<String>,
+    selected: Option<usize>,
+    placeholder: Option<String>,
+    on_selection_changed: Option<Handler<usize>>,
+) {
+    // === State === //
+    let (is_open, _, open_state) = use_state!(false);
+    if is_open {
+        context.push_modal(self.id);
+    } else {
+        context.remove_modal(self.id);
+    }
+
+    // === Events === //
+    let option_count = options.len();
+    let cloned_open_state = open_state.clone();
+    let cloned_on_selection_changed = on_selection_changed.clone();
+    let select_id = self.id;
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::Click => cloned_open_state.set(!is_open),
+        EventType::Blur if is_open => {
+            // Pressing the open list blurs the select without focusing anything, in which case the
+            // options and the catcher close it instead
+            let moved_away = ctx.current_focus().map_or(false, |focus| {
+                !ctx.widget_manager.tree.is_descendant(focus, select_id)
+            });
+            if moved_away {
+                cloned_open_state.set(false);
+            }
+        }
+        EventType::KeyDown(evt) => match evt.key() {
+            KeyCode::Escape if is_open => {
+                cloned_open_state.set(false);
+                // Closing the list takes precedence over back navigation
+                event.prevent_default();
+            }
+            KeyCode::Return | KeyCode::Space if !evt.is_repeat() => cloned_open_state.set(!is_open),
+            KeyCode::Up | KeyCode::Down if option_count > 0 => {
+                let next = match (evt.key(), selected) {
+                    (KeyCode::Up, Some(index)) => index.saturating_sub(1),
+                    (KeyCode::Down, Some(index)) => (index + 1).min(option_count - 1),
+                    _ => 0,
+                };
+                if Some(next) != selected {
+                    if let Some(ref on_selection_changed) = cloned_on_selection_changed {
+                        on_selection_changed.call(next);
+                    }
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        ..base_styles.clone()
+    });
+
+    let background_color = if matches!(base_styles.background_color, StyleProp::Default) {
+        StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+    } else {
+        base_styles.background_color.clone()
+    };
+
+    let button_styles = Style {
+        background_color: background_color.clone(),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..Style::default()
+    };
+
+    let anchor = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    let bounds = context
+        .widget_manager
+        .node_tree
+        .root_node
+        .and_then(|root| context.widget_manager.get_layout(&root).copied())
+        .unwrap_or_default();
+
+    // Covers the whole UI in order to close the list when clicking outside of it
+    let catcher_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(bounds.posx - anchor.posx)),
+        top: StyleProp::Value(Units::Pixels(bounds.posy - anchor.posy)),
+        width: StyleProp::Value(Units::Pixels(bounds.width)),
+        height: StyleProp::Value(Units::Pixels(bounds.height)),
+        z_index: StyleProp::Value(LIST_Z_INDEX),
+        ..Style::default()
+    };
+    let cloned_open_state = open_state.clone();
+    let on_catcher_event = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => {
+            if event.target == event.current_target {
+                cloned_open_state.set(false);
+            }
+            // Clicks within the list shouldn't toggle the select
+            event.stop_propagation();
+        }
+        _ => {}
+    });
+
+    let list_styles = Style {
+        background_color,
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(anchor.posx - bounds.posx)),
+        top: StyleProp::Value(Units::Pixels(anchor.posy - bounds.posy + ITEM_HEIGHT + 2.0)),
+        width: StyleProp::Value(Units::Pixels(anchor.width)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    let text_styles = if selected.is_some() {
+        Style {
+            color: base_styles.color.clone(),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            color: StyleProp::Value(Color::new(0.5, 0.5, 0.5, 1.0)),
+            ..Style::default()
+        }
+    };
+
+    let content = selected
+        .and_then(|index| options.get(index).cloned())
+        .or(placeholder)
+        .unwrap_or_default();
+
+    let items = options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let content = option.clone();
+            let open_state = open_state.clone();
+            let on_selection_changed = on_selection_changed.clone();
+            constructor! {
+                <SelectOption
+                    content={content}
+                    index={index}
+                    is_selected={selected == Some(index)}
+                    select_id={select_id}
+                    open_state={open_state}
+                    on_selection_changed={on_selection_changed}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <Background styles={Some(button_styles)}>
+                <Text content={content} size={14.0} line_height={Some(22.0)} styles={Some(text_styles)} />
+            </Background>
+            <If condition={is_open}>
+                <Element styles={Some(catcher_styles)} on_event={Some(on_catcher_event)}>
+                    <Background styles={Some(list_styles)}>
+                        <VecTracker data={items} />
+                    </Background>
+                </Element>
+            </If>
+        </>
+    }
+}
+
+/// A single option within an open [Select]
+#[widget]
+fn SelectOption(
+    content: String,
+    index: usize,
+    is_selected: bool,
+    select_id: Index,
+    open_state: Binding<bool>,
+    on_selection_changed: Option<Handler<usize>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::Click => {
+            if let Some(ref on_selection_changed) = on_selection_changed {
+                on_selection_changed.call(index);
+            }
+            open_state.set(false);
+            event.stop_propagation();
+            // Pressing the option blurred the select
+            ctx.set_focus(select_id);
+        }
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        _ => {}
+    }));
+
+    let background_color = if is_hovered || is_selected {
+        StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0))
+    } else {
+        StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.0))
+    };
+
+    let background_styles = Style {
+        background_color,
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+        padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(5.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(background_styles)}>
+            <Text content={content} size={14.0} line_height={Some(22.0)} />
+        </Background>
+    }
+}
@@ -0,0 +1,352 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Children, EventType, Handler, KeyCode, OnEvent,
+};
+
+use crate::widgets::{Background, Checkbox, Element, If, Select, Slider, SliderOrientation, Text};
+
+/// The height of each [SettingRow] (in pixels)
+const ROW_HEIGHT: f32 = 36.0;
+/// The width of the control on the right of each [SettingRow] (in pixels)
+const CONTROL_WIDTH: f32 = 200.0;
+
+/// A titled group of settings rows
+///
+/// # Arguments
+///
+/// * `children`: The section's rows
+/// * `title`: The title shown above the rows
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the section container. The `color` style applies
+/// to the title.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{SettingsSection, ToggleSetting};
+///
+/// rsx! {
+///     <SettingsSection title={"Video".to_string()}>
+///         <ToggleSetting setting={"video.vsync".to_string()} label={"VSync".to_string()} default={true} />
+///     </SettingsSection>
+/// }
+/// ```
+#[widget]
+pub fn SettingsSection(children: Children, title: String) {
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        height: StyleProp::Value(Units::Auto),
+        padding_bottom: StyleProp::Value(Units::Pixels(16.0)),
+        ..base_styles.clone()
+    });
+
+    let title_styles = Style {
+        color: base_styles.color,
+        ..Style::default()
+    };
+    let separator_styles = Style {
+        background_color: StyleProp::Value(Color::new(1.0, 1.0, 1.0, 0.15)),
+        top: StyleProp::Value(Units::Pixels(4.0)),
+        bottom: StyleProp::Value(Units::Pixels(4.0)),
+        height: StyleProp::Value(Units::Pixels(1.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <Text content={title} size={18.0} styles={Some(title_styles)} />
+            <Background styles={Some(separator_styles)} />
+            {children}
+        </>
+    }
+}
+
+/// A single setting, with its label on the left and its control on the right
+///
+/// Every row has the same height and the same control width, so the controls of a
+/// [SettingsSection] line up. The prebuilt rows ([ToggleSetting], [SliderSetting],
+/// [DropdownSetting], and [KeyBindingSetting]) are built from this and store their values in the
+/// context's `Settings`.
+///
+/// # Arguments
+///
+/// * `children`: The control, placed on the right
+/// * `label`: The name of the setting
+/// * `description`: A short explanation shown below the label
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the row container. The `color` style applies to
+/// the label.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Button, SettingRow, Text};
+///
+/// rsx! {
+///     <SettingRow label={"Controls".to_string()}>
+///         <Button><Text content={"Reset".to_string()} size={14.0} /></Button>
+///     </SettingRow>
+/// }
+/// ```
+#[widget]
+pub fn SettingRow(children: Children, label: String, description: Option<String>) {
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        top: StyleProp::Value(Units::Pixels(4.0)),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(ROW_HEIGHT))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles.clone()
+    });
+
+    let label_column_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Column),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+    let label_styles = Style {
+        color: base_styles.color,
+        ..Style::default()
+    };
+
+    let has_description = description.is_some();
+    let description = description.unwrap_or_default();
+    let description_styles = Style {
+        color: StyleProp::Value(Color::new(0.6, 0.6, 0.6, 1.0)),
+        ..Style::default()
+    };
+
+    let control_styles = Style {
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Pixels(12.0)),
+        width: StyleProp::Value(Units::Pixels(CONTROL_WIDTH)),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    rsx! {
+        <>
+            <Element styles={Some(label_column_styles)}>
+                <Text content={label} size={14.0} styles={Some(label_styles)} />
+                <If condition={has_description}>
+                    <Text content={description} size={11.0} styles={Some(description_styles)} />
+                </If>
+            </Element>
+            <Element styles={Some(control_styles)}>
+                {children}
+            </Element>
+        </>
+    }
+}
+
+/// A [SettingRow] with a checkbox, storing `true` or `false`
+///
+/// # Arguments
+///
+/// * `setting`: The key the value is stored under in the context's `Settings`
+/// * `label`: The name of the setting
+/// * `description`: A short explanation shown below the label
+/// * `default`: The value used until the setting is stored
+#[widget]
+pub fn ToggleSetting(setting: String, label: String, description: Option<String>, default: bool) {
+    let settings = context.settings();
+    context.bind(&settings.binding());
+    let checked = settings.get_as::<bool>(&setting).unwrap_or(default);
+
+    let on_change = Handler::new(move |checked: bool| settings.set(&setting, checked));
+
+    rsx! {
+        <SettingRow label={label} description={description}>
+            <Checkbox checked={checked} on_change={Some(on_change)} />
+        </SettingRow>
+    }
+}
+
+/// A [SettingRow] with a slider, storing a number
+///
+/// # Arguments
+///
+/// * `setting`: The key the value is stored under in the context's `Settings`
+/// * `label`: The name of the setting
+/// * `description`: A short explanation shown below the label
+/// * `min`: The minimum value
+/// * `max`: The maximum value (must be greater than `min`)
+/// * `step`: The increment values snap to. Set to zero for a continuous slider.
+/// * `default`: The value used until the setting is stored
+#[widget]
+pub fn SliderSetting(
+    setting: String,
+    label: String,
+    description: Option<String>,
+    min: f32,
+    max: f32,
+    step: f32,
+    default: f32,
+) {
+    let settings = context.settings();
+    context.bind(&settings.binding());
+    let value = settings
+        .get_as::<f32>(&setting)
+        .unwrap_or(default)
+        .clamp(min, max.max(min));
+
+    let on_change = Handler::new(move |value: f32| settings.set(&setting, value));
+
+    rsx! {
+        <SettingRow label={label} description={description}>
+            <Slider
+                value={value}
+                min={min}
+                max={max}
+                step={step}
+                orientation={SliderOrientation::Horizontal}
+                on_change={Some(on_change)}
+            />
+        </SettingRow>
+    }
+}
+
+/// A [SettingRow] with a dropdown, storing the selected option's text
+///
+/// # Arguments
+///
+/// * `setting`: The key the value is stored under in the context's `Settings`
+/// * `label`: The name of the setting
+/// * `description`: A short explanation shown below the label
+/// * `options`: The options to choose from
+/// * `default`: The index of the option used until the setting is stored
+#[widget]
+pub fn DropdownSetting(
+    setting: String,
+    label: String,
+    description: Option<String>,
+    options: Vec<String>,
+    default: Option<usize>,
+) {
+    let settings = context.settings();
+    context.bind(&settings.binding());
+    let selected = settings
+        .get(&setting)
+        .and_then(|value| options.iter().position(|option| *option == value))
+        .or(default);
+
+    let cloned_options = options.clone();
+    let on_selection_changed = Handler::new(move |index: usize| {
+        if let Some(option) = cloned_options.get(index) {
+            settings.set(&setting, option);
+        }
+    });
+
+    rsx! {
+        <SettingRow label={label} description={description}>
+            <Select
+                options={options}
+                selected={selected}
+                on_selection_changed={Some(on_selection_changed)}
+            />
+        </SettingRow>
+    }
+}
+
+/// A [SettingRow] with a key binding button, storing the name of a [KeyCode] (e.g. `"Space"`)
+///
+/// Clicking the button makes it wait for a key: the next key pressed becomes the binding and
+/// `Escape` cancels. The stored name can be turned back into a key with [KeyCode::from_name].
+///
+/// # Arguments
+///
+/// * `setting`: The key the value is stored under in the context's `Settings`
+/// * `label`: The name of the setting
+/// * `description`: A short explanation shown below the label
+/// * `default`: The key used until the setting is stored. If `None`, the action is unbound.
+#[widget]
+pub fn KeyBindingSetting(
+    setting: String,
+    label: String,
+    description: Option<String>,
+    default: Option<KeyCode>,
+) {
+    let settings = context.settings();
+    context.bind(&settings.binding());
+    let key = settings
+        .get(&setting)
+        .and_then(|name| KeyCode::from_name(&name))
+        .or(default);
+
+    let on_captured =
+        Handler::new(move |key: KeyCode| settings.set(&setting, format!("{:?}", key)));
+
+    rsx! {
+        <SettingRow label={label} description={description}>
+            <KeyCaptureButton key={key} on_captured={Some(on_captured)} />
+        </SettingRow>
+    }
+}
+
+/// The button of a [KeyBindingSetting], which captures the next key pressed after a click
+#[widget(focusable)]
+fn KeyCaptureButton(key: Option<KeyCode>, on_captured: Option<Handler<KeyCode>>) {
+    let (is_capturing, set_is_capturing, ..) = use_state!(false);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => set_is_capturing(!is_capturing),
+        EventType::KeyDown(evt) if is_capturing => {
+            if evt.key() != KeyCode::Escape {
+                if let Some(ref on_captured) = on_captured {
+                    on_captured.call(evt.key());
+                }
+            }
+            set_is_capturing(false);
+            // The captured key (including `Escape`) shouldn't trigger anything else
+            event.stop_propagation();
+            event.prevent_default();
+        }
+        EventType::Blur => set_is_capturing(false),
+        _ => {}
+    }));
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Quad),
+        background_color: StyleProp::Value(if is_capturing {
+            Color::new(0.0781, 0.0898, 0.101, 1.0)
+        } else {
+            Color::new(0.176, 0.196, 0.215, 1.0)
+        }),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        height: StyleProp::Value(Units::Pixels(26.0)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        padding_top: StyleProp::Value(Units::Stretch(1.0)),
+        padding_bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let content = match (is_capturing, key) {
+        (true, _) => "Press a key...".to_string(),
+        (false, Some(key)) => format!("{:?}", key),
+        (false, None) => "Unbound".to_string(),
+    };
+
+    rsx! {
+        <Text content={content} size={14.0} />
+    }
+}
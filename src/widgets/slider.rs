@@ -0,0 +1,268 @@
+use crate::core::{
+    color::Color,
+    layout_cache::Rect,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, EventType, Handler, Index, KayakContext, KeyCode, OnEvent,
+};
+
+use crate::widgets::Background;
+
+/// The size of the slider's thumb (in pixels)
+const THUMB_SIZE: f32 = 16.0;
+/// The thickness of the slider's track (in pixels)
+const TRACK_SIZE: f32 = 4.0;
+
+/// The direction a [Slider] moves along
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliderOrientation {
+    /// The minimum value is on the left
+    Horizontal,
+    /// The minimum value is on the bottom
+    Vertical,
+}
+
+impl Default for SliderOrientation {
+    fn default() -> Self {
+        Self::Horizontal
+    }
+}
+
+/// A draggable slider for selecting a value within a range
+///
+/// The slider captures the cursor when pressed, so dragging continues even when the cursor
+/// leaves its bounds. When focused, the arrow keys move the value by `step` (or by 1% of the
+/// range if `step` is zero) and `Home`/`End` jump to `min`/`max`.
+///
+/// # Arguments
+///
+/// * `value`: The current value
+/// * `min`: The minimum value
+/// * `max`: The maximum value (must be greater than `min`)
+/// * `step`: The increment values snap to. Set to zero for a continuous slider.
+/// * `orientation`: Whether the slider is horizontal or vertical
+/// * `on_change`: Called with the next desired value when the user moves the slider
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the slider container. The `background_color` style
+/// applies to the track and the `color` style applies to the thumb.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+///
+/// let (volume, set_volume, ..) = use_state!(0.5);
+/// let on_change = Handler::new(move |value| {
+///     set_volume(value);
+/// });
+///
+/// rsx! {
+///     <Slider value={volume} min={0.0} max={1.0} step={0.1} on_change={Some(on_change)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn Slider(
+    value: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    orientation: SliderOrientation,
+    on_change: Option<Handler<f32>>,
+) {
+    let is_vertical = matches!(orientation, SliderOrientation::Vertical);
+
+    // === Events === //
+    let mut current_value = value;
+    self.on_event = Some(OnEvent::new(move |ctx, event| {
+        let next_value = match event.event_type {
+            EventType::MouseDown => {
+                event.capture_cursor();
+                value_at_cursor(ctx, &event.current_target, min, max, is_vertical)
+            }
+            EventType::MouseMove => {
+                value_at_cursor(ctx, &event.current_target, min, max, is_vertical)
+            }
+            EventType::KeyDown(evt) => {
+                let increment = if step > 0.0 {
+                    step
+                } else {
+                    (max - min) / 100.0
+                };
+                match evt.key() {
+                    KeyCode::Right | KeyCode::Up => Some(current_value + increment),
+                    KeyCode::Left | KeyCode::Down => Some(current_value - increment),
+                    KeyCode::Home => Some(min),
+                    KeyCode::End => Some(max),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(next_value) = next_value {
+            let next_value = snap(next_value, min, max, step);
+            if next_value != current_value {
+                current_value = next_value;
+                if let Some(ref on_change) = on_change {
+                    on_change.call(next_value);
+                }
+            }
+        }
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let (default_width, default_height) = if is_vertical {
+        (Units::Pixels(THUMB_SIZE), Units::Stretch(1.0))
+    } else {
+        (Units::Stretch(1.0), Units::Pixels(THUMB_SIZE))
+    };
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: if matches!(base_styles.width, StyleProp::Default) {
+            StyleProp::Value(default_width)
+        } else {
+            base_styles.width.clone()
+        },
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(default_height)
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles.clone()
+    });
+
+    let track_color = if matches!(base_styles.background_color, StyleProp::Default) {
+        StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+    } else {
+        base_styles.background_color.clone()
+    };
+    let thumb_color = if matches!(base_styles.color, StyleProp::Default) {
+        StyleProp::Value(Color::new(0.933, 0.745, 0.745, 1.0))
+    } else {
+        base_styles.color.clone()
+    };
+
+    let half_thumb = Units::Pixels(THUMB_SIZE / 2.0);
+    let track_styles = if is_vertical {
+        Style {
+            position_type: StyleProp::Value(PositionType::SelfDirected),
+            left: StyleProp::Value(Units::Stretch(1.0)),
+            right: StyleProp::Value(Units::Stretch(1.0)),
+            top: StyleProp::Value(half_thumb),
+            bottom: StyleProp::Value(half_thumb),
+            width: StyleProp::Value(Units::Pixels(TRACK_SIZE)),
+            height: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            position_type: StyleProp::Value(PositionType::SelfDirected),
+            left: StyleProp::Value(half_thumb),
+            right: StyleProp::Value(half_thumb),
+            top: StyleProp::Value(Units::Stretch(1.0)),
+            bottom: StyleProp::Value(Units::Stretch(1.0)),
+            width: StyleProp::Value(Units::Stretch(1.0)),
+            height: StyleProp::Value(Units::Pixels(TRACK_SIZE)),
+            ..Style::default()
+        }
+    };
+    let track_styles = Style {
+        background_color: track_color,
+        border_radius: StyleProp::Value((2.0, 2.0, 2.0, 2.0)),
+        ..track_styles
+    };
+
+    // Stretch units split the remaining space, placing the thumb proportionally along the track
+    let t = normalize(value, min, max);
+    let thumb_styles = if is_vertical {
+        Style {
+            top: StyleProp::Value(Units::Stretch(1.0 - t)),
+            bottom: StyleProp::Value(Units::Stretch(t)),
+            left: StyleProp::Value(Units::Stretch(1.0)),
+            right: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            left: StyleProp::Value(Units::Stretch(t)),
+            right: StyleProp::Value(Units::Stretch(1.0 - t)),
+            top: StyleProp::Value(Units::Stretch(1.0)),
+            bottom: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        }
+    };
+    let thumb_styles = Style {
+        background_color: thumb_color,
+        border_radius: StyleProp::Value((
+            THUMB_SIZE / 2.0,
+            THUMB_SIZE / 2.0,
+            THUMB_SIZE / 2.0,
+            THUMB_SIZE / 2.0,
+        )),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Pixels(THUMB_SIZE)),
+        height: StyleProp::Value(Units::Pixels(THUMB_SIZE)),
+        ..thumb_styles
+    };
+
+    rsx! {
+        <>
+            <Background styles={Some(track_styles)} />
+            <Background styles={Some(thumb_styles)} />
+        </>
+    }
+}
+
+/// Calculates the value corresponding to the current cursor position over the given slider
+fn value_at_cursor(
+    context: &KayakContext,
+    slider: &Index,
+    min: f32,
+    max: f32,
+    is_vertical: bool,
+) -> Option<f32> {
+    let layout: Rect = *context.widget_manager.get_layout(slider)?;
+    let (x, y) = context.last_mouse_position();
+    let t = if is_vertical {
+        let length = layout.height - THUMB_SIZE;
+        if length <= 0.0 {
+            return None;
+        }
+        1.0 - (y - layout.posy - THUMB_SIZE / 2.0) / length
+    } else {
+        let length = layout.width - THUMB_SIZE;
+        if length <= 0.0 {
+            return None;
+        }
+        (x - layout.posx - THUMB_SIZE / 2.0) / length
+    };
+
+    Some(min + t.clamp(0.0, 1.0) * (max - min))
+}
+
+/// Maps the given value to the range `[0, 1]`
+fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Clamps the given value to the range and snaps it to the nearest step (if any)
+fn snap(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    if max <= min {
+        return min;
+    }
+
+    let value = value.clamp(min, max);
+    if step > 0.0 {
+        (min + ((value - min) / step).round() * step).min(max)
+    } else {
+        value
+    }
+}
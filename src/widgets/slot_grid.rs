@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use crate::core::{
+    bind,
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Fragment, Handler, KayakContext, KeyCode,
+    MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, If, Text};
+
+/// The space between neighboring slots (in pixels)
+const SLOT_SPACING: f32 = 4.0;
+
+/// An item stored in a [SlotGrid] slot
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SlotItem {
+    /// Identifies the kind of item. Dropping an item onto one with the same ID merges them.
+    pub id: String,
+    /// The size of the stack (not shown if 1 or less)
+    pub count: u32,
+}
+
+impl SlotItem {
+    pub fn new(id: impl Into<String>, count: u32) -> Self {
+        Self {
+            id: id.into(),
+            count,
+        }
+    }
+}
+
+/// Builds the content of an occupied [SlotGrid] slot, given the slot's index and its item
+///
+/// The content is usually built with the `constructor!` macro.
+#[derive(Clone)]
+pub struct SlotRenderer(pub Arc<dyn Fn(usize, &SlotItem) -> Fragment + Send + Sync>);
+
+impl SlotRenderer {
+    pub fn new<F: Fn(usize, &SlotItem) -> Fragment + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl PartialEq for SlotRenderer {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for SlotRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SlotRenderer").finish()
+    }
+}
+
+/// An item dropped from one slot onto another (possibly in a different [SlotGrid])
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SlotDrop {
+    /// The `name` of the grid the item was dragged from
+    pub from_grid: String,
+    pub from_slot: usize,
+    /// The `name` of the grid the item was dropped on
+    pub to_grid: String,
+    pub to_slot: usize,
+}
+
+/// The slot an item is being dragged from
+#[derive(Debug, Clone, PartialEq)]
+struct SlotDragSource {
+    grid: String,
+    slot: usize,
+    item: SlotItem,
+}
+
+/// The drag shared by every [SlotGrid], so items can be dragged between grids
+#[derive(Clone)]
+struct SlotDragState(Binding<Option<SlotDragSource>>);
+
+/// A grid of fixed-size slots, such as an inventory or a hotbar
+///
+/// Items can be dragged from one slot to another, including to slots of other grids. The grid
+/// doesn't move items itself: dropping an item calls `on_merge` if the target slot holds an item
+/// with the same ID and `on_swap` otherwise, and the app should update `items` in response. The
+/// handlers of the grid the item was dropped on are the ones called.
+///
+/// When focused, the arrow keys move the selection and the number keys select one of the first
+/// ten slots (like a hotbar).
+///
+/// # Arguments
+///
+/// * `name`: Identifies the grid in [SlotDrop] events
+/// * `items`: The item in each slot. The grid has one slot per entry.
+/// * `columns`: The number of slots in each row (at least 1)
+/// * `slot_size`: The width and height of each slot (in pixels)
+/// * `selected`: The index of the selected slot (if any)
+/// * `render_item`: Builds the content of occupied slots. If `None`, the item's ID and count are shown.
+/// * `on_select`: Called when the user clicks a slot or moves the selection. Contains the slot's index.
+/// * `on_swap`: Called when an item is dropped onto an empty slot or one with a different item
+/// * `on_merge`: Called when an item is dropped onto a slot with an item of the same ID
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the grid container. The `background_color` style
+/// applies to the slots and the `color` style applies to the default item text.
+#[widget(focusable)]
+pub fn SlotGrid(
+    name: String,
+    items: Vec<Option<SlotItem>>,
+    columns: usize,
+    slot_size: f32,
+    selected: Option<usize>,
+    render_item: Option<SlotRenderer>,
+    on_select: Option<Handler<usize>>,
+    on_swap: Option<Handler<SlotDrop>>,
+    on_merge: Option<Handler<SlotDrop>>,
+) {
+    let columns = columns.max(1);
+    let slot_size = if slot_size > 0.0 { slot_size } else { 48.0 };
+    let drag_state = shared_drag_state(context);
+    context.bind(&drag_state);
+
+    // === Events === //
+    let slot_count = items.len();
+    let cloned_on_select = on_select.clone();
+    let cloned_drag_state = drag_state.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::KeyDown(evt) => {
+            let next = match evt.key() {
+                KeyCode::Left => selected.map(|index| index.saturating_sub(1)),
+                KeyCode::Right => selected.map(|index| index + 1),
+                KeyCode::Up => selected.map(|index| index.saturating_sub(columns)),
+                KeyCode::Down => selected.map(|index| index + columns),
+                key => hotbar_index(key),
+            };
+            let next = match (next, selected) {
+                (None, None) if is_arrow_key(evt.key()) => Some(0),
+                (next, _) => next,
+            };
+
+            if let Some(next) = next.filter(|next| *next < slot_count && Some(*next) != selected) {
+                if let Some(ref on_select) = cloned_on_select {
+                    on_select.call(next);
+                }
+            }
+        }
+        EventType::MouseUp => {
+            // The slots handle drops, so any drag still active was released outside of them
+            if cloned_drag_state.get().is_some() {
+                cloned_drag_state.set(None);
+            }
+        }
+        _ => {}
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let slot_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.0781, 0.0898, 0.101, 1.0));
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Auto),
+        background_color: StyleProp::Default,
+        ..base_styles
+    });
+
+    let drag_source = drag_state.get();
+    let rows = items
+        .chunks(columns)
+        .enumerate()
+        .map(|(row, row_items)| {
+            let slots = row_items
+                .iter()
+                .enumerate()
+                .map(|(column, item)| {
+                    let index = row * columns + column;
+                    let content = item.as_ref().and_then(|item| {
+                        render_item
+                            .as_ref()
+                            .map(|render_item| (render_item.0)(index, item))
+                    });
+                    let is_drag_source = drag_source
+                        .as_ref()
+                        .map_or(false, |source| source.grid == name && source.slot == index);
+                    let item = item.clone();
+                    let name = name.clone();
+                    let drag_state = drag_state.clone();
+                    let on_select = on_select.clone();
+                    let on_swap = on_swap.clone();
+                    let on_merge = on_merge.clone();
+                    constructor! {
+                        <SlotGridSlot
+                            grid={name}
+                            index={index}
+                            column={column}
+                            item={item}
+                            content={content}
+                            slot_size={slot_size}
+                            slot_color={slot_color}
+                            text_color={text_color}
+                            is_selected={selected == Some(index)}
+                            is_drag_source={is_drag_source}
+                            drag_state={drag_state}
+                            on_select={on_select}
+                            on_swap={on_swap}
+                            on_merge={on_merge}
+                        />
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let row_styles = Style {
+                layout_type: StyleProp::Value(LayoutType::Row),
+                width: StyleProp::Value(Units::Auto),
+                height: StyleProp::Value(Units::Auto),
+                top: StyleProp::Value(Units::Pixels(if row == 0 { 0.0 } else { SLOT_SPACING })),
+                ..Style::default()
+            };
+            constructor! {
+                <Element styles={Some(row_styles)}>
+                    <VecTracker data={slots} />
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={rows} />
+    }
+}
+
+/// A single slot of a [SlotGrid]
+#[widget]
+fn SlotGridSlot(
+    grid: String,
+    index: usize,
+    column: usize,
+    item: Option<SlotItem>,
+    content: Option<Fragment>,
+    slot_size: f32,
+    slot_color: Color,
+    text_color: Color,
+    is_selected: bool,
+    is_drag_source: bool,
+    drag_state: Binding<Option<SlotDragSource>>,
+    on_select: Option<Handler<usize>>,
+    on_swap: Option<Handler<SlotDrop>>,
+    on_merge: Option<Handler<SlotDrop>>,
+) {
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    let is_drop_target = is_hovered && !is_drag_source && drag_state.get().is_some();
+
+    let cloned_item = item.clone();
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::MouseDown => {
+            let source = cloned_item.clone().map(|item| SlotDragSource {
+                grid: grid.clone(),
+                slot: index,
+                item,
+            });
+            drag_state.set(source);
+        }
+        EventType::MouseUp => {
+            if let Some(source) = drag_state.get() {
+                drag_state.set(None);
+                event.stop_propagation();
+                if source.grid == grid && source.slot == index {
+                    return;
+                }
+
+                let drop = SlotDrop {
+                    from_grid: source.grid,
+                    from_slot: source.slot,
+                    to_grid: grid.clone(),
+                    to_slot: index,
+                };
+                let is_merge = cloned_item
+                    .as_ref()
+                    .map_or(false, |item| item.id == source.item.id);
+                let handler = if is_merge { &on_merge } else { &on_swap };
+                if let Some(handler) = handler {
+                    handler.call(drop);
+                }
+            }
+        }
+        EventType::Click => {
+            if let Some(ref on_select) = on_select {
+                on_select.call(index);
+            }
+        }
+        _ => {}
+    }));
+
+    let background_color = if is_drop_target {
+        Color::new(0.2, 0.35, 0.6, 1.0)
+    } else if is_drag_source {
+        // Dim the item while it's being dragged away
+        Color::new(slot_color.r, slot_color.g, slot_color.b, slot_color.a * 0.5)
+    } else {
+        slot_color
+    };
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: StyleProp::Value(Units::Pixels(slot_size)),
+        height: StyleProp::Value(Units::Pixels(slot_size)),
+        left: StyleProp::Value(Units::Pixels(if column == 0 { 0.0 } else { SLOT_SPACING })),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    let border_styles = Style {
+        background_color: StyleProp::Value(if is_selected {
+            Color::new(0.933, 0.745, 0.745, 1.0)
+        } else {
+            background_color
+        }),
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding_left: StyleProp::Value(Units::Pixels(2.0)),
+        padding_right: StyleProp::Value(Units::Pixels(2.0)),
+        padding_top: StyleProp::Value(Units::Pixels(2.0)),
+        padding_bottom: StyleProp::Value(Units::Pixels(2.0)),
+        ..Style::default()
+    };
+    let inner_styles = Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value((4.0, 4.0, 4.0, 4.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let text_styles = Style {
+        color: StyleProp::Value(text_color),
+        left: StyleProp::Value(Units::Pixels(4.0)),
+        top: StyleProp::Value(Units::Pixels(4.0)),
+        ..Style::default()
+    };
+
+    let has_custom_content = content.is_some();
+    let content = content.into_iter().collect::<Vec<_>>();
+    let label = item.map_or(String::new(), |item| {
+        if item.count > 1 {
+            format!("{} x{}", item.id, item.count)
+        } else {
+            item.id
+        }
+    });
+
+    rsx! {
+        <Background styles={Some(border_styles)}>
+            <Background styles={Some(inner_styles)}>
+                <VecTracker data={content} />
+                <If condition={!has_custom_content && !label.is_empty()}>
+                    <Text content={label} size={12.0} styles={Some(text_styles)} />
+                </If>
+            </Background>
+        </Background>
+    }
+}
+
+/// Gets the drag state shared by every [SlotGrid], creating it if needed
+fn shared_drag_state(context: &mut KayakContext) -> Binding<Option<SlotDragSource>> {
+    if let Ok(state) = context.get_global_state::<SlotDragState>() {
+        return state.0.clone();
+    }
+
+    let state = bind(None);
+    context.set_global_state(SlotDragState(state.clone()));
+    state
+}
+
+/// Maps the number keys to the first ten slots, in the order they appear on the keyboard
+fn hotbar_index(key: KeyCode) -> Option<usize> {
+    match key {
+        KeyCode::Key1 => Some(0),
+        KeyCode::Key2 => Some(1),
+        KeyCode::Key3 => Some(2),
+        KeyCode::Key4 => Some(3),
+        KeyCode::Key5 => Some(4),
+        KeyCode::Key6 => Some(5),
+        KeyCode::Key7 => Some(6),
+        KeyCode::Key8 => Some(7),
+        KeyCode::Key9 => Some(8),
+        KeyCode::Key0 => Some(9),
+        _ => None,
+    }
+}
+
+fn is_arrow_key(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down
+    )
+}
@@ -0,0 +1,202 @@
+use crate::core::{
+    color::Color,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Fragment, MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Clip};
+
+/// The thickness of the divider between the panes (in pixels)
+const DIVIDER_SIZE: f32 = 6.0;
+
+/// The direction a [SplitPane] places its panes in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplitDirection {
+    /// The panes are side by side, with a vertical divider between them
+    Horizontal,
+    /// The panes are stacked, with a horizontal divider between them
+    Vertical,
+}
+
+impl Default for SplitDirection {
+    fn default() -> Self {
+        Self::Horizontal
+    }
+}
+
+/// A container showing two panes separated by a divider that can be dragged to resize them
+///
+/// While dragging, the divider captures the cursor, so it keeps following the mouse even when the
+/// cursor leaves the thin divider.
+///
+/// # Arguments
+///
+/// * `first`: The content of the left (or top) pane
+/// * `second`: The content of the right (or bottom) pane
+/// * `direction`: Whether the panes are side by side or stacked
+/// * `split_ratio`: The portion of the space given to the first pane (from 0 to 1). Dragging the
+///   divider updates it. If `None`, the widget manages the ratio itself, starting at 0.5.
+/// * `min_first`: The smallest size of the first pane (in pixels)
+/// * `min_second`: The smallest size of the second pane (in pixels)
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container of the panes. The `color` style
+/// applies to the divider.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{bind, constructor, rsx, Children};
+/// # use kayak_ui::widgets::{SplitPane, Text};
+///
+/// let children = Children::None;
+/// let first = constructor! { <><Text content={"Files".to_string()} size={14.0} /></> };
+/// let second = constructor! { <><Text content={"Editor".to_string()} size={14.0} /></> };
+/// let ratio = bind(0.25);
+///
+/// rsx! {
+///     <SplitPane first={first} second={second} split_ratio={Some(ratio)} min_first={120.0} />
+/// }
+/// ```
+#[widget]
+pub fn SplitPane(
+    first: Fragment,
+    second: Fragment,
+    direction: SplitDirection,
+    split_ratio: Option<Binding<f32>>,
+    min_first: f32,
+    min_second: f32,
+) {
+    // === State === //
+    let (_, _, internal_ratio) = use_state!(0.5f32);
+    let ratio_state = split_ratio.unwrap_or(internal_ratio);
+    context.bind(&ratio_state);
+    let ratio = ratio_state.get().clamp(0.0, 1.0);
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+
+    // === Events === //
+    let is_horizontal = direction == SplitDirection::Horizontal;
+    let pane_id = self.id;
+    // The cursor position and ratio at the start of the current drag
+    let mut drag_start: Option<(f32, f32)> = None;
+    let on_divider_event = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseIn => set_is_hovered(true),
+        EventType::MouseOut => set_is_hovered(false),
+        EventType::MouseDown => {
+            event.capture_cursor();
+            let (x, y) = ctx.last_mouse_position();
+            drag_start = Some((if is_horizontal { x } else { y }, ratio_state.get()));
+        }
+        EventType::MouseMove => {
+            if let Some((start, start_ratio)) = drag_start {
+                let layout = ctx
+                    .widget_manager
+                    .get_layout(&pane_id)
+                    .copied()
+                    .unwrap_or_default();
+                let available = if is_horizontal {
+                    layout.width
+                } else {
+                    layout.height
+                } - DIVIDER_SIZE;
+                if available <= 0.0 {
+                    return;
+                }
+
+                let (x, y) = ctx.last_mouse_position();
+                let position = if is_horizontal { x } else { y };
+                let next_ratio = clamp_ratio(
+                    start_ratio + (position - start) / available,
+                    min_first / available,
+                    1.0 - min_second / available,
+                );
+                if next_ratio != ratio_state.get() {
+                    ratio_state.set(next_ratio);
+                }
+            }
+        }
+        EventType::MouseUp => drag_start = None,
+        _ => {}
+    });
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let divider_color = base_styles
+        .color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(if is_horizontal {
+            LayoutType::Row
+        } else {
+            LayoutType::Column
+        }),
+        ..base_styles
+    });
+
+    let first_styles = pane_styles(is_horizontal, ratio, min_first);
+    let second_styles = pane_styles(is_horizontal, 1.0 - ratio, min_second);
+    let divider_styles = Style {
+        background_color: StyleProp::Value(if is_hovered {
+            Color::new(0.2, 0.35, 0.6, 1.0)
+        } else {
+            divider_color
+        }),
+        width: StyleProp::Value(if is_horizontal {
+            Units::Pixels(DIVIDER_SIZE)
+        } else {
+            Units::Stretch(1.0)
+        }),
+        height: StyleProp::Value(if is_horizontal {
+            Units::Stretch(1.0)
+        } else {
+            Units::Pixels(DIVIDER_SIZE)
+        }),
+        ..Style::default()
+    };
+
+    let first = vec![first];
+    let second = vec![second];
+
+    rsx! {
+        <>
+            <Clip styles={Some(first_styles)}>
+                <VecTracker data={first} />
+            </Clip>
+            <Background styles={Some(divider_styles)} on_event={Some(on_divider_event)} />
+            <Clip styles={Some(second_styles)}>
+                <VecTracker data={second} />
+            </Clip>
+        </>
+    }
+}
+
+fn pane_styles(is_horizontal: bool, ratio: f32, min_size: f32) -> Style {
+    let (size, min_size) = (
+        StyleProp::Value(Units::Stretch(ratio)),
+        StyleProp::Value(Units::Pixels(min_size)),
+    );
+    if is_horizontal {
+        Style {
+            width: size,
+            min_width: min_size,
+            height: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        }
+    } else {
+        Style {
+            height: size,
+            min_height: min_size,
+            width: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        }
+    }
+}
+
+/// Clamps the ratio to the given range, favoring the first pane's minimum if they overlap
+fn clamp_ratio(ratio: f32, min: f32, max: f32) -> f32 {
+    ratio.min(max).max(min).clamp(0.0, 1.0)
+}
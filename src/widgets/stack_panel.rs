@@ -0,0 +1,145 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, Children,
+};
+
+use crate::widgets::Element;
+
+/// The direction a [StackPanel] stacks its children in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackDirection {
+    /// From top to bottom
+    Vertical,
+    /// From left to right
+    Horizontal,
+}
+
+impl Default for StackDirection {
+    fn default() -> Self {
+        Self::Vertical
+    }
+}
+
+/// How a [StackPanel] aligns its children across its direction (horizontally for vertical
+/// panels and vertically for horizontal ones)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackAlignment {
+    /// Stretching children fill the panel, and the others are placed at its start
+    Stretch,
+    /// To the left (or top) of the panel
+    Start,
+    /// To the center of the panel
+    Center,
+    /// To the right (or bottom) of the panel
+    End,
+}
+
+impl Default for StackAlignment {
+    fn default() -> Self {
+        Self::Stretch
+    }
+}
+
+/// A container stacking its children in a column or a row, with space between them
+///
+/// Alignment only moves children that have a size of their own (in pixels or `Auto`), since
+/// stretching children share the free space with the alignment.
+///
+/// # Arguments
+///
+/// * `direction`: Whether the children are stacked vertically or horizontally
+/// * `spacing`: The space between children (in pixels)
+/// * `padding`: The space between the children and the edges of the panel (in pixels)
+/// * `alignment`: How the children are aligned across the direction of the panel
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the panel. The padding styles take precedence over
+/// the `padding` prop.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::StackAlignment;
+///
+/// rsx! {
+///     <StackPanel spacing={8.0} padding={16.0} alignment={StackAlignment::Center}>
+///         <Text content={"Settings".to_string()} size={24.0} />
+///         <Text content={"Volume".to_string()} size={16.0} />
+///     </StackPanel>
+/// }
+/// ```
+#[widget]
+pub fn StackPanel(
+    children: Children,
+    direction: StackDirection,
+    spacing: f32,
+    padding: f32,
+    alignment: StackAlignment,
+) {
+    let base_styles = styles.clone().unwrap_or_default();
+    let padding_or = |prop: &StyleProp<Units>| {
+        if matches!(prop, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(padding))
+        } else {
+            prop.clone()
+        }
+    };
+    // The stack needs to fit the children if the panel fits them, and to fill it otherwise
+    let fit = |prop: &StyleProp<Units>| {
+        if matches!(prop, StyleProp::Value(Units::Auto)) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            StyleProp::Value(Units::Stretch(1.0))
+        }
+    };
+    let stack_width = fit(&base_styles.width);
+    let stack_height = fit(&base_styles.height);
+
+    // The padding is applied by the panel, as the alignment is applied by the stack through its
+    // own padding (the space around its children)
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        padding_left: padding_or(&base_styles.padding_left),
+        padding_right: padding_or(&base_styles.padding_right),
+        padding_top: padding_or(&base_styles.padding_top),
+        padding_bottom: padding_or(&base_styles.padding_bottom),
+        ..base_styles
+    });
+
+    let (start, end) = match alignment {
+        StackAlignment::Stretch => (Units::Pixels(0.0), Units::Pixels(0.0)),
+        StackAlignment::Start => (Units::Pixels(0.0), Units::Stretch(1.0)),
+        StackAlignment::Center => (Units::Stretch(1.0), Units::Stretch(1.0)),
+        StackAlignment::End => (Units::Stretch(1.0), Units::Pixels(0.0)),
+    };
+    let stack_styles = match direction {
+        StackDirection::Vertical => Style {
+            layout_type: StyleProp::Value(LayoutType::Column),
+            row_between: StyleProp::Value(Units::Pixels(spacing)),
+            padding_left: StyleProp::Value(start),
+            padding_right: StyleProp::Value(end),
+            width: stack_width,
+            height: stack_height,
+            ..Style::default()
+        },
+        StackDirection::Horizontal => Style {
+            layout_type: StyleProp::Value(LayoutType::Row),
+            col_between: StyleProp::Value(Units::Pixels(spacing)),
+            padding_top: StyleProp::Value(start),
+            padding_bottom: StyleProp::Value(end),
+            width: stack_width,
+            height: stack_height,
+            ..Style::default()
+        },
+    };
+
+    rsx! {
+        <Element styles={Some(stack_styles)}>
+            {children}
+        </Element>
+    }
+}
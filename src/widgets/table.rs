@@ -0,0 +1,359 @@
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Clip, Text};
+
+/// The height of the header row and of each data row (in pixels)
+const ROW_HEIGHT: f32 = 26.0;
+/// The width of the handles used to resize columns (in pixels)
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+/// The horizontal space between a cell's border and its text (in pixels)
+const CELL_PADDING: f32 = 6.0;
+
+/// The order a [Table] column is sorted in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Describes a column of a [Table]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TableColumn {
+    /// The text displayed in the header
+    pub title: String,
+    /// The initial width of the column (in pixels)
+    pub width: f32,
+    /// The smallest width the user can resize the column to (in pixels)
+    pub min_width: f32,
+    /// Whether clicking the column's header calls `on_sort`
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    pub fn new(title: impl Into<String>, width: f32) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            min_width: 30.0,
+            sortable: false,
+        }
+    }
+
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+}
+
+/// A table laying out rows of cells in aligned columns below a header row
+///
+/// Columns can be resized by dragging the right edge of their header. Clicking a row selects it,
+/// and clicking the header of a sortable column calls `on_sort`. The table doesn't reorder its rows
+/// itself, so the app should sort its data in response and update `sorted_by`.
+///
+/// # Arguments
+///
+/// * `columns`: The columns of the table
+/// * `rows`: The text of each cell, row by row. Cells beyond the number of columns are ignored.
+/// * `selected`: The index of the selected row (if any)
+/// * `sorted_by`: The index of the column the rows are sorted by (if any) and in which order
+/// * `on_select`: Called when the user clicks a row. Contains the row's index.
+/// * `on_sort`: Called when the user clicks a sortable column's header. Contains the column's index.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the table container. The `background_color` style
+/// applies to the header and the `color` style applies to the text.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::widgets::{Table, TableColumn};
+///
+/// let columns = vec![
+///     TableColumn::new("Name", 160.0).sortable(),
+///     TableColumn::new("Level", 60.0),
+/// ];
+/// let rows = vec![
+///     vec!["Ayla".to_string(), "12".to_string()],
+///     vec!["Brom".to_string(), "9".to_string()],
+/// ];
+/// let (selected, set_selected, ..) = use_state!(None);
+/// let on_select = Handler::new(move |index| {
+///     set_selected(Some(index));
+/// });
+///
+/// rsx! {
+///     <Table columns={columns} rows={rows} selected={selected} on_select={Some(on_select)} />
+/// }
+/// ```
+#[widget]
+pub fn Table(
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    selected: Option<usize>,
+    sorted_by: Option<(usize, SortOrder)>,
+    on_select: Option<Handler<usize>>,
+    on_sort: Option<Handler<usize>>,
+) {
+    // === State === //
+    let initial_widths = columns
+        .iter()
+        .map(|column| column.width)
+        .collect::<Vec<_>>();
+    let (widths, _, widths_state) = use_state!(initial_widths.clone());
+    let widths = if widths.len() == columns.len() {
+        widths
+    } else {
+        // The columns changed, so the user's resizing no longer applies
+        widths_state.set(initial_widths.clone());
+        initial_widths
+    };
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Auto)
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles.clone()
+    });
+
+    let header_color = base_styles
+        .background_color
+        .resolve_or(context.theme(), Color::new(0.176, 0.196, 0.215, 1.0));
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+
+    let header_styles = Style {
+        background_color: StyleProp::Value(header_color),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(ROW_HEIGHT)),
+        width: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    let headers = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let column = column.clone();
+            let sort_order = sorted_by
+                .filter(|(sorted, _)| *sorted == index)
+                .map(|(_, order)| order);
+            let widths_state = widths_state.clone();
+            let on_sort = on_sort.clone();
+            constructor! {
+                <TableHeaderCell
+                    column={column}
+                    index={index}
+                    sort_order={sort_order}
+                    text_color={text_color}
+                    widths_state={widths_state}
+                    on_sort={on_sort}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let rows = rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, cells)| {
+            let widths = widths.clone();
+            let on_select = on_select.clone();
+            constructor! {
+                <TableRow
+                    cells={cells}
+                    index={index}
+                    widths={widths}
+                    is_selected={selected == Some(index)}
+                    text_color={text_color}
+                    on_select={on_select}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <>
+            <Background styles={Some(header_styles)}>
+                <VecTracker data={headers} />
+            </Background>
+            <VecTracker data={rows} />
+        </>
+    }
+}
+
+/// The header of a single [Table] column, including its resize handle
+#[widget]
+fn TableHeaderCell(
+    column: TableColumn,
+    index: usize,
+    sort_order: Option<SortOrder>,
+    text_color: Color,
+    widths_state: Binding<Vec<f32>>,
+    on_sort: Option<Handler<usize>>,
+) {
+    context.bind(&widths_state);
+    let width = widths_state
+        .get()
+        .get(index)
+        .copied()
+        .unwrap_or(column.width);
+
+    let sortable = column.sortable;
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click if sortable => {
+            if let Some(ref on_sort) = on_sort {
+                on_sort.call(index);
+            }
+        }
+        _ => {}
+    }));
+
+    // The cursor position and column width at the start of the current drag
+    let mut drag_start: Option<(f32, f32)> = None;
+    let min_width = column.min_width;
+    let on_handle_event = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseDown => {
+            event.capture_cursor();
+            drag_start = Some((ctx.last_mouse_position().0, width));
+        }
+        EventType::MouseMove => {
+            if let Some((start_x, start_width)) = drag_start {
+                let next_width =
+                    (start_width + ctx.last_mouse_position().0 - start_x).max(min_width);
+                let mut widths = widths_state.get();
+                if let Some(width) = widths.get_mut(index) {
+                    if *width != next_width {
+                        *width = next_width;
+                        widths_state.set(widths);
+                    }
+                }
+            }
+        }
+        EventType::MouseUp => drag_start = None,
+        _ => {}
+    });
+
+    let cell_styles = Style {
+        color: StyleProp::Value(text_color),
+        width: StyleProp::Value(Units::Pixels(width)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let handle_styles = Style {
+        background_color: StyleProp::Value(Color::new(
+            text_color.r,
+            text_color.g,
+            text_color.b,
+            0.2,
+        )),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(width - RESIZE_HANDLE_WIDTH)),
+        width: StyleProp::Value(Units::Pixels(RESIZE_HANDLE_WIDTH)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+
+    let title = match sort_order {
+        Some(SortOrder::Ascending) => format!("{} ^", column.title),
+        Some(SortOrder::Descending) => format!("{} v", column.title),
+        None => column.title,
+    };
+
+    rsx! {
+        <Clip styles={Some(cell_styles)}>
+            <TableCellText content={title} />
+            <Background styles={Some(handle_styles)} on_event={Some(on_handle_event)} />
+        </Clip>
+    }
+}
+
+/// A single row of [Table] cells
+#[widget]
+fn TableRow(
+    cells: Vec<String>,
+    index: usize,
+    widths: Vec<f32>,
+    is_selected: bool,
+    text_color: Color,
+    on_select: Option<Handler<usize>>,
+) {
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click => {
+            if let Some(ref on_select) = on_select {
+                on_select.call(index);
+            }
+        }
+        _ => {}
+    }));
+
+    let row_color = if is_selected {
+        Color::new(0.2, 0.35, 0.6, 1.0)
+    } else if index % 2 == 1 {
+        // Stripe every other row so long rows are easier to follow
+        Color::new(1.0, 1.0, 1.0, 0.03)
+    } else {
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    };
+
+    let row_styles = Style {
+        background_color: StyleProp::Value(row_color),
+        color: StyleProp::Value(text_color),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(ROW_HEIGHT)),
+        width: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+
+    let cells = cells
+        .into_iter()
+        .zip(widths)
+        .map(|(content, width)| {
+            let cell_styles = Style {
+                width: StyleProp::Value(Units::Pixels(width)),
+                height: StyleProp::Value(Units::Stretch(1.0)),
+                ..Style::default()
+            };
+            constructor! {
+                <Clip styles={Some(cell_styles)}>
+                    <TableCellText content={content} />
+                </Clip>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <Background styles={Some(row_styles)}>
+            <VecTracker data={cells} />
+        </Background>
+    }
+}
+
+/// The padded text of a [Table] cell
+#[widget]
+fn TableCellText(content: String) {
+    let text_styles = Style {
+        left: StyleProp::Value(Units::Pixels(CELL_PADDING)),
+        right: StyleProp::Value(Units::Pixels(CELL_PADDING)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Text content={content} size={14.0} line_height={Some(ROW_HEIGHT)} styles={Some(text_styles)} />
+    }
+}
@@ -0,0 +1,627 @@
+use std::cmp::Ordering;
+use std::time::Instant;
+
+use kayak_font::KayakFont;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, KeyCode, KeyboardEvent, OnEvent, VecTracker,
+};
+
+use crate::widgets::text_box::is_backspace;
+use crate::widgets::{Background, ChangeEvent, Clip, Element, If, OnChange, Text};
+
+/// The font size of the text (in pixels)
+const FONT_SIZE: f32 = 14.0;
+/// The height of each line (in pixels)
+const LINE_HEIGHT: f32 = 20.0;
+/// The space between the edges of the text area and the text (in pixels)
+const PADDING: f32 = 5.0;
+/// The height of the text area when none is given (in pixels)
+const DEFAULT_HEIGHT: f32 = 100.0;
+/// The thickness of the caret (in pixels)
+const CARET_THICKNESS: f32 = 1.5;
+/// How long the caret stays shown (and then hidden) while blinking (in seconds)
+const CARET_BLINK_INTERVAL: f32 = 0.5;
+
+/// A line of a [TextArea], as displayed after wrapping
+#[derive(Debug, Clone, PartialEq)]
+struct VisualLine {
+    /// The index of the line's first character (in chars, not bytes)
+    start: usize,
+    /// The index after the line's last character, excluding the line break
+    end: usize,
+    /// The position of the caret before each of the line's characters and after the last one
+    offsets: Vec<f32>,
+    /// Whether the line continues on the next one (instead of ending at a line break or the end
+    /// of the text)
+    is_wrapped: bool,
+}
+
+impl VisualLine {
+    fn width(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+
+    /// Get the index of the character boundary closest to the given position on this line
+    fn get_index_at(&self, x: f32) -> usize {
+        // The end of a wrapped line is displayed at the start of the next one
+        let last = if self.is_wrapped {
+            self.offsets.len().saturating_sub(2)
+        } else {
+            self.offsets.len().saturating_sub(1)
+        };
+        let index = self.offsets[..=last]
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - x)
+                    .abs()
+                    .partial_cmp(&(*b - x).abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map_or(0, |(index, _)| index);
+        self.start + index
+    }
+}
+
+/// A multi-line text input, with word wrapping, a blinking caret, selection, and scrolling
+///
+/// Like [TextBox](crate::widgets::TextBox), the text area doesn't store its value: `on_change`
+/// is called with the edited value, which should then be passed back through `value`. Pressing
+/// `Enter` inserts a line break. While focused, the arrow keys, `Home`, and `End` move the caret
+/// (extending the selection while `Shift` is pressed), `Ctrl+A` selects everything, and the text
+/// can be selected by dragging. The text scrolls with the mouse wheel and follows the caret.
+///
+/// # Arguments
+///
+/// * `value`: The text
+/// * `on_change`: Called with the edited text
+/// * `placeholder`: The text shown while the value is empty
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the text area. It's 100 pixels tall unless a height
+/// is given. The `color` style applies to the text and the caret.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{OnChange, TextArea};
+///
+/// let on_change = OnChange::new(|event| println!("{}", event.value));
+///
+/// rsx! {
+///     <TextArea value={"First line\nSecond line".to_string()} on_change={Some(on_change)} />
+/// }
+/// ```
+#[widget(focusable)]
+pub fn TextArea(value: String, on_change: Option<OnChange>, placeholder: Option<String>) {
+    let char_count = value.chars().count();
+
+    // === State === //
+    let (has_focus, set_has_focus, ..) = use_state!(false);
+    let (caret, set_caret, ..) = use_state!(char_count);
+    let (anchor, set_anchor, ..) = use_state!(None::<usize>);
+    let (scroll, set_scroll, ..) = use_state!(0.0f32);
+    // The caret the scroll was last adjusted for, so it follows the caret only when it moves
+    let (followed_caret, set_followed_caret, ..) = use_state!(None::<usize>);
+    let (blink_start, set_blink_start, ..) = use_state!(None::<Instant>);
+    let (_, set_last_frame, ..) = use_state!(None::<Instant>);
+    // Changing this re-renders the text area once it has been laid out, so the text can be
+    // wrapped. It only changes when the text area gets (or loses) a width, so a collapsed text
+    // area isn't re-rendered again and again.
+    let (_, set_is_unsized, ..) = use_state!(false);
+
+    let caret = caret.min(char_count);
+    let anchor = anchor.map(|anchor| anchor.min(char_count));
+    let selection = anchor
+        .filter(|anchor| *anchor != caret)
+        .map(|anchor| (anchor.min(caret), anchor.max(caret)));
+
+    // === Layout === //
+    let layout = context
+        .widget_manager
+        .get_layout(&self.id)
+        .copied()
+        .unwrap_or_default();
+    set_is_unsized(layout.width <= 0.0);
+    let content_width = (layout.width - PADDING * 2.0).max(0.0);
+    let view_height = (layout.height - PADDING * 2.0).max(0.0);
+
+    let font: Binding<Option<KayakFont>> = context.get_asset("Roboto".to_string());
+    context.bind(&font);
+    let lines = match font.get() {
+        Some(font) if content_width > 0.0 => wrap_lines(&font, &value, content_width),
+        _ => wrap_lines_unmeasured(&value),
+    };
+    let content_height = lines.len() as f32 * LINE_HEIGHT;
+    let max_scroll = (content_height - view_height).max(0.0);
+
+    let (caret_line, caret_x) = get_caret_position(&lines, caret);
+    let caret_y = caret_line as f32 * LINE_HEIGHT;
+    let mut scroll = scroll.clamp(0.0, max_scroll);
+    if followed_caret != Some(caret) {
+        // Keep the caret's line in view
+        if caret_y < scroll {
+            scroll = caret_y;
+        } else if caret_y + LINE_HEIGHT > scroll + view_height {
+            scroll = (caret_y + LINE_HEIGHT - view_height).min(max_scroll);
+        }
+        set_scroll(scroll);
+        set_followed_caret(Some(caret));
+        // Moving the caret restarts the blinking, so it's shown right away
+        set_blink_start(Some(context.now()));
+    }
+
+    // === Caret === //
+    let is_caret_shown = if has_focus && selection.is_none() {
+        // Changing this state re-renders the text area next frame, so the caret keeps blinking
+        let now = context.now();
+        set_last_frame(Some(now));
+        let elapsed = blink_start.map_or(0.0, |blink_start| {
+            now.saturating_duration_since(blink_start).as_secs_f32()
+        });
+        (elapsed / CARET_BLINK_INTERVAL) as u32 % 2 == 0
+    } else {
+        false
+    };
+
+    // === Events === //
+    let mut current_value = value.clone();
+    let mut current_caret = caret;
+    let mut current_anchor = anchor;
+    let cloned_lines = lines.clone();
+    self.on_event = Some(OnEvent::new(move |ctx, event| {
+        let mut edit = None;
+        match event.event_type {
+            EventType::Focus => set_has_focus(true),
+            EventType::Blur => {
+                set_has_focus(false);
+                set_anchor(None);
+            }
+            EventType::MouseDown => {
+                if let Some(layout) = ctx.widget_manager.get_layout(&event.current_target) {
+                    let (x, y) = ctx.last_mouse_position();
+                    let index = get_index_at(
+                        &cloned_lines,
+                        x - layout.posx - PADDING,
+                        y - layout.posy - PADDING + scroll,
+                    );
+                    current_caret = index;
+                    current_anchor = Some(index);
+                    set_caret(index);
+                    set_anchor(Some(index));
+                    event.capture_cursor();
+                }
+            }
+            EventType::MouseMove => {
+                // Only sent while the cursor is captured, so the user is selecting text
+                if let Some(layout) = ctx.widget_manager.get_layout(&event.current_target) {
+                    let (x, y) = ctx.last_mouse_position();
+                    let index = get_index_at(
+                        &cloned_lines,
+                        x - layout.posx - PADDING,
+                        y - layout.posy - PADDING + scroll,
+                    );
+                    if index != current_caret {
+                        current_caret = index;
+                        set_caret(index);
+                    }
+                }
+            }
+            EventType::Scroll(scroll_event) => {
+                let next_scroll = (scroll + scroll_event.pixel_delta().1).clamp(0.0, max_scroll);
+                if next_scroll != scroll {
+                    set_scroll(next_scroll);
+                    event.stop_propagation();
+                }
+            }
+            EventType::CharInput { c } => {
+                if !has_focus {
+                    return;
+                }
+                if is_backspace(c) {
+                    edit = Some(String::new());
+                    if current_anchor.map_or(true, |anchor| anchor == current_caret)
+                        && current_caret > 0
+                    {
+                        current_anchor = Some(current_caret - 1);
+                    }
+                } else if c == '\r' || c == '\n' {
+                    edit = Some("\n".to_string());
+                } else if !c.is_control() {
+                    edit = Some(c.to_string());
+                }
+            }
+            EventType::KeyDown(evt) => {
+                if !has_focus {
+                    return;
+                }
+                if evt.key() == KeyCode::Delete {
+                    edit = Some(String::new());
+                    if current_anchor.map_or(true, |anchor| anchor == current_caret)
+                        && current_caret < current_value.chars().count()
+                    {
+                        current_anchor = Some(current_caret + 1);
+                    }
+                } else if let Some((caret, anchor)) = handle_navigation(
+                    &cloned_lines,
+                    &evt,
+                    current_caret,
+                    current_anchor,
+                    current_value.chars().count(),
+                ) {
+                    event.stop_propagation();
+                    current_caret = caret;
+                    current_anchor = anchor;
+                    set_caret(caret);
+                    set_anchor(anchor);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(text) = edit {
+            // Replace the selection (or insert at the caret if there's none)
+            let anchor = current_anchor.unwrap_or(current_caret);
+            let (start, end) = (anchor.min(current_caret), anchor.max(current_caret));
+            if start == end && text.is_empty() {
+                // Nothing to delete
+                return;
+            }
+            let (start_byte, end_byte) = (
+                get_byte_index(&current_value, start),
+                get_byte_index(&current_value, end),
+            );
+            current_value.replace_range(start_byte..end_byte, &text);
+            current_caret = start + text.chars().count();
+            current_anchor = None;
+            set_caret(current_caret);
+            set_anchor(None);
+            if let Some(on_change) = on_change.as_ref() {
+                if let Ok(mut on_change) = on_change.0.write() {
+                    on_change(ChangeEvent {
+                        value: current_value.clone(),
+                    });
+                }
+            }
+        }
+    }));
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(DEFAULT_HEIGHT))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles.clone()
+    });
+
+    let background_styles = Style {
+        background_color: if matches!(base_styles.background_color, StyleProp::Default) {
+            StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0))
+        } else {
+            base_styles.background_color.clone()
+        },
+        border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding_left: StyleProp::Value(Units::Pixels(PADDING)),
+        padding_right: StyleProp::Value(Units::Pixels(PADDING)),
+        padding_top: StyleProp::Value(Units::Pixels(PADDING)),
+        padding_bottom: StyleProp::Value(Units::Pixels(PADDING)),
+        ..Style::default()
+    };
+
+    // Only the lines in view are rendered
+    let first_visible = (scroll / LINE_HEIGHT).floor() as usize;
+    let last_visible = ((scroll + view_height) / LINE_HEIGHT).ceil() as usize;
+    let line_widgets = lines
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index >= first_visible && *index <= last_visible)
+        .map(|(index, line)| {
+            let content = value
+                .chars()
+                .skip(line.start)
+                .take(line.end - line.start)
+                .collect::<String>();
+            let line_styles = Style {
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                top: StyleProp::Value(Units::Pixels(index as f32 * LINE_HEIGHT - scroll)),
+                // Wider than the wrapped line, so the text isn't wrapped again
+                width: StyleProp::Value(Units::Pixels(content_width + FONT_SIZE * 2.0)),
+                height: StyleProp::Value(Units::Pixels(LINE_HEIGHT)),
+                ..Style::default()
+            };
+            let text_styles = Style {
+                color: StyleProp::Value(text_color),
+                ..Style::default()
+            };
+            constructor! {
+                <Element styles={Some(line_styles)}>
+                    <Text
+                        content={content}
+                        size={FONT_SIZE}
+                        line_height={Some(LINE_HEIGHT)}
+                        styles={Some(text_styles)}
+                    />
+                </Element>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let highlight_rects = selection.map_or(Vec::new(), |(start, end)| {
+        get_highlight_rects(&lines, start, end, scroll)
+    });
+    let has_selection = !highlight_rects.is_empty();
+    let overlay_styles = Style {
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        width: StyleProp::Value(Units::Pixels(0.0)),
+        height: StyleProp::Value(Units::Pixels(0.0)),
+        ..Style::default()
+    };
+    let highlight_styles = Style {
+        background_color: StyleProp::Value(Color::new(0.3, 0.45, 0.75, 0.5)),
+        ..overlay_styles.clone()
+    };
+    let caret_styles = Style {
+        color: StyleProp::Value(text_color),
+        ..overlay_styles
+    };
+    let caret_position = (caret_x, caret_y - scroll);
+
+    let is_empty = value.is_empty();
+    let placeholder = placeholder.unwrap_or_default();
+    let placeholder_styles = Style {
+        color: StyleProp::Value(Color::new(0.5, 0.5, 0.5, 1.0)),
+        ..Style::default()
+    };
+
+    rsx! {
+        <Background styles={Some(background_styles)}>
+            <Clip>
+                <If condition={has_selection}>
+                    <TextHighlight rects={highlight_rects} styles={Some(highlight_styles)} />
+                </If>
+                <If condition={is_empty}>
+                    <Text
+                        content={placeholder}
+                        size={FONT_SIZE}
+                        line_height={Some(LINE_HEIGHT)}
+                        styles={Some(placeholder_styles)}
+                    />
+                </If>
+                <VecTracker data={line_widgets} />
+                <If condition={is_caret_shown}>
+                    <TextCaret position={caret_position} styles={Some(caret_styles)} />
+                </If>
+            </Clip>
+        </Background>
+    }
+}
+
+/// The highlighted selection of a [TextArea]
+#[widget]
+fn TextHighlight(rects: Vec<(f32, f32, f32, f32)>) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Highlight { rects }),
+        ..styles.clone().unwrap_or_default()
+    });
+}
+
+/// The caret of a [TextArea]
+#[widget]
+fn TextCaret(position: (f32, f32)) {
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Caret {
+            position,
+            height: LINE_HEIGHT,
+            thickness: CARET_THICKNESS,
+        }),
+        ..styles.clone().unwrap_or_default()
+    });
+}
+
+/// Split the text into the lines it's displayed as, wrapping words that don't fit in `max_width`
+///
+/// Words longer than a whole line are split between characters.
+fn wrap_lines(font: &KayakFont, value: &str, max_width: f32) -> Vec<VisualLine> {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut lines = Vec::new();
+
+    // The line being built, and the last place it can be wrapped at (after a space)
+    let mut line = VisualLine {
+        start: 0,
+        end: 0,
+        offsets: vec![0.0],
+        is_wrapped: false,
+    };
+    let mut wrap_at = None;
+    let mut previous = None;
+    for (index, c) in chars.iter().copied().enumerate() {
+        if c == '\n' {
+            line.end = index;
+            lines.push(line);
+            line = VisualLine {
+                start: index + 1,
+                end: index + 1,
+                offsets: vec![0.0],
+                is_wrapped: false,
+            };
+            wrap_at = None;
+            previous = None;
+            continue;
+        }
+
+        let advance = font
+            .get_glyph(c)
+            .map_or(0.0, |glyph| glyph.advance * FONT_SIZE)
+            + previous.map_or(0.0, |previous| font.get_kerning(previous, c) * FONT_SIZE);
+        let x = line.width() + advance;
+        if x > max_width && !c.is_whitespace() && index > line.start {
+            // Move the current word to a new line (or just this character, if the word is the
+            // whole line)
+            let split = wrap_at.unwrap_or(index);
+            let mut next = VisualLine {
+                start: split,
+                end: split,
+                offsets: vec![0.0],
+                is_wrapped: false,
+            };
+            let split_x = line.offsets[split - line.start];
+            for offset in line.offsets.drain(split - line.start + 1..) {
+                next.offsets.push(offset - split_x);
+            }
+            line.end = split;
+            line.is_wrapped = true;
+            lines.push(line);
+            line = next;
+            wrap_at = None;
+        }
+
+        let x = line.width() + advance;
+        line.offsets.push(x);
+        if c.is_whitespace() {
+            wrap_at = Some(index + 1);
+        }
+        previous = Some(c);
+    }
+    line.end = chars.len();
+    lines.push(line);
+
+    lines
+}
+
+/// Split the text at its line breaks, for when it can't be measured yet
+fn wrap_lines_unmeasured(value: &str) -> Vec<VisualLine> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in value.split('\n') {
+        let end = start + line.chars().count();
+        lines.push(VisualLine {
+            start,
+            end,
+            offsets: vec![0.0; end - start + 1],
+            is_wrapped: false,
+        });
+        start = end + 1;
+    }
+    lines
+}
+
+/// Get the line the caret is displayed on and its position along it
+fn get_caret_position(lines: &[VisualLine], caret: usize) -> (usize, f32) {
+    // A caret at the end of a wrapped line is displayed at the start of the next one
+    let line_index = lines
+        .iter()
+        .rposition(|line| line.start <= caret)
+        .unwrap_or_default();
+    let line = &lines[line_index];
+    let offset = line
+        .offsets
+        .get(caret.min(line.end) - line.start)
+        .copied()
+        .unwrap_or_default();
+    (line_index, offset)
+}
+
+/// Get the index of the character boundary closest to the given position (relative to the top-left
+/// corner of the text)
+fn get_index_at(lines: &[VisualLine], x: f32, y: f32) -> usize {
+    let line_index = ((y / LINE_HEIGHT).max(0.0) as usize).min(lines.len() - 1);
+    lines[line_index].get_index_at(x)
+}
+
+/// Get the rects covering the selected text on each line, relative to the top-left corner of the
+/// text area's content
+fn get_highlight_rects(
+    lines: &[VisualLine],
+    start: usize,
+    end: usize,
+    scroll: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.start <= end && line.end >= start)
+        .filter_map(|(index, line)| {
+            let from = line.offsets[start.max(line.start) - line.start];
+            let to = line.offsets[end.min(line.end) - line.start];
+            // Selected line breaks are shown as a bit of highlight at the end of the line
+            let to = if end > line.end && !line.is_wrapped {
+                to + FONT_SIZE / 3.0
+            } else {
+                to
+            };
+            if to > from {
+                Some((
+                    from,
+                    index as f32 * LINE_HEIGHT - scroll,
+                    to - from,
+                    LINE_HEIGHT,
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Get the caret and selection anchor after the given navigation key, if it's one
+fn handle_navigation(
+    lines: &[VisualLine],
+    evt: &KeyboardEvent,
+    caret: usize,
+    anchor: Option<usize>,
+    char_count: usize,
+) -> Option<(usize, Option<usize>)> {
+    let (line_index, x) = get_caret_position(lines, caret);
+    let selection = anchor
+        .filter(|anchor| *anchor != caret)
+        .map(|anchor| (anchor.min(caret), anchor.max(caret)));
+    let next_caret = match evt.key() {
+        KeyCode::A if evt.is_ctrl_pressed() => return Some((char_count, Some(0))),
+        // Without shift, the left and right keys collapse the selection to its edges
+        KeyCode::Left => match selection {
+            Some((start, _)) if !evt.is_shift_pressed() => start,
+            _ => caret.saturating_sub(1),
+        },
+        KeyCode::Right => match selection {
+            Some((_, end)) if !evt.is_shift_pressed() => end,
+            _ => (caret + 1).min(char_count),
+        },
+        KeyCode::Up if line_index > 0 => lines[line_index - 1].get_index_at(x),
+        KeyCode::Up => 0,
+        KeyCode::Down if line_index + 1 < lines.len() => lines[line_index + 1].get_index_at(x),
+        KeyCode::Down => char_count,
+        KeyCode::Home => lines[line_index].start,
+        KeyCode::End => lines[line_index].get_index_at(f32::MAX),
+        _ => return None,
+    };
+
+    let next_anchor = if evt.is_shift_pressed() {
+        Some(anchor.unwrap_or(caret))
+    } else {
+        None
+    };
+    Some((next_caret, next_anchor))
+}
+
+/// Get the byte index of the character at the given index (or the length of the string)
+fn get_byte_index(value: &str, char_index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(char_index)
+        .map_or(value.len(), |(index, _)| index)
+}
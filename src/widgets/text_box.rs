@@ -34,11 +34,45 @@ impl std::fmt::Debug for OnChange {
     }
 }
 
+/// Decides which typed characters a [TextBox] accepts
+///
+/// The filter is called with each typed character and the current value, and returns true if the
+/// character should be added. Rejected characters never reach `on_change`.
+#[derive(Clone)]
+pub struct CharFilter(pub Arc<dyn Fn(char, &str) -> bool + Send + Sync + 'static>);
+
+impl CharFilter {
+    pub fn new<F: Fn(char, &str) -> bool + Send + Sync + 'static>(f: F) -> CharFilter {
+        CharFilter(Arc::new(f))
+    }
+
+    pub fn accepts(&self, c: char, value: &str) -> bool {
+        (self.0)(c, value)
+    }
+}
+
+impl PartialEq for CharFilter {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for CharFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CharFilter").finish()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Focus(pub bool);
 
 #[widget(focusable)]
-pub fn TextBox(value: String, on_change: Option<OnChange>, placeholder: Option<String>) {
+pub fn TextBox(
+    value: String,
+    on_change: Option<OnChange>,
+    placeholder: Option<String>,
+    char_filter: Option<CharFilter>,
+) {
     let current_styles = styles.clone().unwrap_or_default();
     *styles = Some(Style {
         render_command: StyleProp::Value(RenderCommand::Layout),
@@ -81,6 +115,11 @@ pub fn TextBox(value: String, on_change: Option<OnChange>, placeholder: Option<S
                     current_value.truncate(current_value.len() - 1);
                 }
             } else if !c.is_control() {
+                if let Some(ref char_filter) = char_filter {
+                    if !char_filter.accepts(c, &current_value) {
+                        return;
+                    }
+                }
                 current_value.push(c);
             }
             if let Some(on_change) = cloned_on_change.as_ref() {
@@ -131,6 +170,32 @@ pub fn TextBox(value: String, on_change: Option<OnChange>, placeholder: Option<S
 /// Checks if the given character contains the "Backspace" sequence
 ///
 /// Context: [Wikipedia](https://en.wikipedia.org/wiki/Backspace#Common_use)
-fn is_backspace(c: char) -> bool {
+pub(crate) fn is_backspace(c: char) -> bool {
     c == '\u{8}' || c == '\u{7f}'
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{OnChange, TextBox};
+    use crate::core::{KayakContext, WidgetBuilder};
+
+    #[test]
+    fn simulated_typing_should_change_the_value() {
+        let mut context = KayakContext::new();
+        let value = Arc::new(Mutex::new(String::new()));
+        let on_change = {
+            let value = value.clone();
+            OnChange::new(move |event| *value.lock().unwrap() = event.value)
+        };
+        WidgetBuilder::new::<TextBox>()
+            .prop(|text_box| text_box.on_change = Some(on_change))
+            .render_root(&mut context);
+        context.render();
+
+        let text_box = context.find_all_by_name("TextBox")[0];
+        context.simulate_typing(text_box, "Hi!");
+        assert_eq!("Hi!", *value.lock().unwrap());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::core::{
+    layout_cache::Space,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, Bound, Children, EventType, KayakContext, OnEvent,
+};
+
+use crate::widgets::NinePatch;
+
+/// An image drawn as a nine-patch (see [NinePatch])
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NinePatchTexture {
+    /// The image's ID (see `ImageManager`)
+    pub handle: u16,
+    /// The size of the image's unstretched borders
+    pub border: Space,
+}
+
+impl NinePatchTexture {
+    pub fn new(handle: u16, border: Space) -> Self {
+        Self { handle, border }
+    }
+}
+
+/// The textures a [TexturedButton] swaps between
+///
+/// Any state without a texture falls back to `normal`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ButtonTextures {
+    pub normal: NinePatchTexture,
+    pub hovered: Option<NinePatchTexture>,
+    pub pressed: Option<NinePatchTexture>,
+    pub disabled: Option<NinePatchTexture>,
+}
+
+/// The theme used by [TexturedButton] and [Panel] for anything not set on the widget itself
+///
+/// Provide it with a [TexturedThemeProvider]. Sounds are asset paths, played through Bevy's
+/// `Audio` resource (if it exists).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TexturedTheme {
+    pub button: ButtonTextures,
+    /// The space between a button's edges and its content. Defaults to the texture's border.
+    pub button_padding: Option<Space>,
+    pub panel: NinePatchTexture,
+    /// The space between a panel's edges and its content. Defaults to the texture's border.
+    pub panel_padding: Option<Space>,
+    /// Played when the cursor enters a button
+    pub hover_sound: Option<String>,
+    /// Played when a button is pressed
+    pub press_sound: Option<String>,
+}
+
+/// Provides a [TexturedTheme] to the [TexturedButton] and [Panel] widgets below it
+///
+/// # Arguments
+///
+/// * `initial_theme`: The theme to provide
+/// * `children`: The widget's children
+#[widget]
+pub fn TexturedThemeProvider(initial_theme: TexturedTheme, children: Children) {
+    context.create_provider(initial_theme);
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// A button drawn with nine-patch textures that change when hovered, pressed, or disabled
+///
+/// Anything not given as a prop is taken from the nearest [TexturedThemeProvider], including the
+/// sounds played on hover and press. Clicks bubble up to the button's `on_event` unless it's disabled.
+///
+/// # Arguments
+///
+/// * `children`: The button's content
+/// * `textures`: The textures to use instead of the theme's
+/// * `padding`: The space between the button's edges and its content
+/// * `disabled`: Whether the button ignores presses and clicks
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the button itself.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, EventType, OnEvent};
+/// # use kayak_ui::widgets::{Text, TexturedButton};
+///
+/// let on_event = OnEvent::new(|_, event| match event.event_type {
+///     EventType::Click => println!("Clicked!"),
+///     _ => {}
+/// });
+///
+/// rsx! {
+///     <TexturedButton on_event={Some(on_event)}>
+///         <Text content={"Play".to_string()} size={20.0} />
+///     </TexturedButton>
+/// }
+/// ```
+#[widget(focusable)]
+pub fn TexturedButton(
+    children: Children,
+    textures: Option<ButtonTextures>,
+    padding: Option<Space>,
+    disabled: bool,
+) {
+    let theme = consume_theme(context);
+    let textures = textures.unwrap_or(theme.button);
+
+    // === State === //
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    let (is_pressed, set_is_pressed, ..) = use_state!(false);
+
+    // === Events === //
+    let hover_sound = theme.hover_sound.clone();
+    let press_sound = theme.press_sound.clone();
+    let on_face_event = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseIn => {
+            set_is_hovered(true);
+            if !disabled {
+                if let Some(ref sound) = hover_sound {
+                    play_sound(ctx, sound);
+                }
+            }
+        }
+        EventType::MouseOut => {
+            set_is_hovered(false);
+            set_is_pressed(false);
+        }
+        EventType::MouseDown if !disabled => {
+            set_is_pressed(true);
+            if let Some(ref sound) = press_sound {
+                play_sound(ctx, sound);
+            }
+        }
+        EventType::MouseUp => set_is_pressed(false),
+        EventType::Click if disabled => event.stop_propagation(),
+        _ => {}
+    });
+
+    // === Styles === //
+    let texture = if disabled {
+        textures.disabled
+    } else if is_pressed {
+        textures.pressed.or(textures.hovered)
+    } else if is_hovered {
+        textures.hovered
+    } else {
+        None
+    }
+    .unwrap_or(textures.normal);
+
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            StyleProp::Value(Units::Pixels(45.0))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    let padding = padding
+        .or(theme.button_padding)
+        .unwrap_or(textures.normal.border);
+    let face_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..padded_styles(padding)
+    };
+
+    rsx! {
+        <NinePatch
+            handle={texture.handle}
+            border={texture.border}
+            styles={Some(face_styles)}
+            on_event={Some(on_face_event)}
+        >
+            {children}
+        </NinePatch>
+    }
+}
+
+/// A container drawn with a nine-patch texture, with its content inset by padding
+///
+/// Anything not given as a prop is taken from the nearest [TexturedThemeProvider].
+///
+/// # Arguments
+///
+/// * `children`: The panel's content
+/// * `texture`: The texture to use instead of the theme's
+/// * `padding`: The space between the panel's edges and its content
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the panel itself. Padding styles take precedence
+/// over the `padding` prop.
+#[widget]
+pub fn Panel(children: Children, texture: Option<NinePatchTexture>, padding: Option<Space>) {
+    let theme = consume_theme(context);
+    let texture = texture.unwrap_or(theme.panel);
+    let padding = padding.or(theme.panel_padding).unwrap_or(texture.border);
+
+    let base_styles = styles.clone().unwrap_or_default();
+    let padding_styles = padded_styles(padding);
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::NinePatch {
+            handle: texture.handle,
+            border: texture.border,
+        }),
+        padding_left: pick_padding(&base_styles.padding_left, &padding_styles.padding_left),
+        padding_right: pick_padding(&base_styles.padding_right, &padding_styles.padding_right),
+        padding_top: pick_padding(&base_styles.padding_top, &padding_styles.padding_top),
+        padding_bottom: pick_padding(&base_styles.padding_bottom, &padding_styles.padding_bottom),
+        ..base_styles
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Gets the nearest [TexturedTheme] (re-rendering the current widget when it changes), or the
+/// default theme if there's no provider
+fn consume_theme(context: &mut KayakContext) -> TexturedTheme {
+    if let Some(theme) = context.create_consumer::<TexturedTheme>() {
+        context.bind(&theme);
+        theme.get()
+    } else {
+        TexturedTheme::default()
+    }
+}
+
+fn padded_styles(padding: Space) -> Style {
+    Style {
+        padding_left: StyleProp::Value(Units::Pixels(padding.left)),
+        padding_right: StyleProp::Value(Units::Pixels(padding.right)),
+        padding_top: StyleProp::Value(Units::Pixels(padding.top)),
+        padding_bottom: StyleProp::Value(Units::Pixels(padding.bottom)),
+        ..Style::default()
+    }
+}
+
+fn pick_padding(base: &StyleProp<Units>, fallback: &StyleProp<Units>) -> StyleProp<Units> {
+    if matches!(base, StyleProp::Default) {
+        fallback.clone()
+    } else {
+        base.clone()
+    }
+}
+
+/// Plays the sound at the given asset path, if the app has audio
+#[allow(unused_variables)]
+pub(crate) fn play_sound(context: &mut KayakContext, path: &str) {
+    #[cfg(feature = "bevy_renderer")]
+    {
+        use bevy::prelude::{AssetServer, Audio, AudioSource, Res, World};
+
+        let has_audio = if let Ok(world) = context.get_global_state::<World>() {
+            world.contains_resource::<Audio>() && world.contains_resource::<AssetServer>()
+        } else {
+            false
+        };
+
+        if has_audio {
+            context.query_world::<(Res<AssetServer>, Res<Audio>), _, _>(|(asset_server, audio)| {
+                audio.play(asset_server.load::<AudioSource, _>(path));
+            });
+        }
+    }
+}
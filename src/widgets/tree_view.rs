@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use crate::core::{
+    color::Color,
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, EventType, Handler, MutableBound, OnEvent, VecTracker,
+};
+
+use crate::widgets::{Background, Element, Fold, Text};
+
+/// The height of each row (in pixels)
+const ROW_HEIGHT: f32 = 22.0;
+/// How far each level of the tree is indented (in pixels)
+const INDENT: f32 = 16.0;
+
+/// A node within a [TreeView]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TreeViewNode {
+    /// Identifies the node across re-renders, so it should be unique within the tree
+    pub key: String,
+    pub label: String,
+    pub children: Vec<TreeViewNode>,
+}
+
+impl TreeViewNode {
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<TreeViewNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// A widget displaying hierarchical data as a tree of expandable nodes
+///
+/// Nodes with children are rendered as [Folds](Fold) and expand or collapse when their label is
+/// clicked. Clicking any node selects it. Both the expanded nodes and the selection are tracked by
+/// the nodes' keys, so they survive re-renders even if nodes are added, removed, or reordered.
+///
+/// # Arguments
+///
+/// * `nodes`: The top-level nodes of the tree
+/// * `selected`: If set, the key of the selected node. Otherwise, the widget manages the selection itself.
+/// * `on_select`: Called when the user clicks a node. Contains the node's key.
+/// * `on_expand`: Called when the user expands or collapses a node. Contains the node's key and
+/// whether it is now expanded.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the tree container. The `color` style applies to
+/// the node labels.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::{TreeView, TreeViewNode};
+///
+/// let nodes = vec![
+///     TreeViewNode::new("assets", "assets").with_children(vec![
+///         TreeViewNode::new("assets/fonts", "fonts"),
+///         TreeViewNode::new("assets/images", "images"),
+///     ]),
+///     TreeViewNode::new("src", "src"),
+/// ];
+/// let on_select = Handler::new(|key: String| {
+///     println!("Selected {}", key);
+/// });
+///
+/// rsx! {
+///     <TreeView nodes={nodes} on_select={Some(on_select)} />
+/// }
+/// ```
+#[widget]
+pub fn TreeView(
+    nodes: Vec<TreeViewNode>,
+    selected: Option<String>,
+    on_select: Option<Handler<String>>,
+    on_expand: Option<Handler<(String, bool)>>,
+) {
+    // === State === //
+    let (_, _, expanded_state) = use_state!(HashSet::<String>::new());
+    let (_, _, selected_state) = use_state!(selected.clone());
+    if selected.is_some() && selected != selected_state.get() {
+        // This is a controlled state
+        selected_state.set(selected);
+    }
+
+    // === Styles === //
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        height: StyleProp::Value(Units::Auto),
+        ..base_styles.clone()
+    });
+
+    let text_color = base_styles.color.resolve_or(context.theme(), Color::WHITE);
+
+    let items = nodes
+        .into_iter()
+        .map(|node| {
+            let expanded_state = expanded_state.clone();
+            let selected_state = selected_state.clone();
+            let on_select = on_select.clone();
+            let on_expand = on_expand.clone();
+            constructor! {
+                <TreeViewItem
+                    node={node}
+                    text_color={text_color}
+                    expanded_state={expanded_state}
+                    selected_state={selected_state}
+                    on_select={on_select}
+                    on_expand={on_expand}
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        <VecTracker data={items} />
+    }
+}
+
+/// A single node (and its descendants) within a [TreeView]
+#[widget]
+fn TreeViewItem(
+    node: TreeViewNode,
+    text_color: Color,
+    expanded_state: Binding<HashSet<String>>,
+    selected_state: Binding<Option<String>>,
+    on_select: Option<Handler<String>>,
+    on_expand: Option<Handler<(String, bool)>>,
+) {
+    context.bind(&expanded_state);
+    context.bind(&selected_state);
+    let is_expanded = expanded_state.get().contains(&node.key);
+    let is_selected = selected_state.get().as_ref() == Some(&node.key);
+
+    // Selected labels are highlighted by color, since a Fold's background covers its children too
+    let label_color = if is_selected {
+        Color::new(0.4, 0.6, 1.0, 1.0)
+    } else {
+        text_color
+    };
+
+    let select = {
+        let key = node.key.clone();
+        let selected_state = selected_state.clone();
+        let on_select = on_select.clone();
+        move || {
+            selected_state.set(Some(key.clone()));
+            if let Some(ref on_select) = on_select {
+                on_select.call(key.clone());
+            }
+        }
+    };
+
+    if node.children.is_empty() {
+        self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+            EventType::Click => select(),
+            _ => {}
+        }));
+
+        let row_styles = Style {
+            color: StyleProp::Value(label_color),
+            height: StyleProp::Value(Units::Pixels(ROW_HEIGHT)),
+            // Line up with the labels of sibling folds
+            padding_left: StyleProp::Value(Units::Pixels(INDENT)),
+            ..Style::default()
+        };
+        let label = node.label;
+        rsx! {
+            <Element styles={Some(row_styles)}>
+                <Text content={label} size={14.0} line_height={Some(ROW_HEIGHT)} />
+            </Element>
+        }
+    } else {
+        let key = node.key.clone();
+        let cloned_expanded_state = expanded_state.clone();
+        let cloned_on_expand = on_expand.clone();
+        let on_change = Handler::new(move |open: bool| {
+            let mut expanded = cloned_expanded_state.get();
+            if open {
+                expanded.insert(key.clone());
+            } else {
+                expanded.remove(&key);
+            }
+            cloned_expanded_state.set(expanded);
+            if let Some(ref on_expand) = cloned_on_expand {
+                on_expand.call((key.clone(), open));
+            }
+            select();
+        });
+
+        let fold_styles = Style {
+            color: StyleProp::Value(label_color),
+            ..Style::default()
+        };
+
+        let children_styles = Style {
+            height: StyleProp::Value(Units::Auto),
+            padding_left: StyleProp::Value(Units::Pixels(INDENT)),
+            ..Style::default()
+        };
+
+        // Runs along the children, marking how deep they are nested
+        let guide_styles = Style {
+            background_color: StyleProp::Value(Color::new(
+                text_color.r,
+                text_color.g,
+                text_color.b,
+                0.2,
+            )),
+            position_type: StyleProp::Value(PositionType::SelfDirected),
+            left: StyleProp::Value(Units::Pixels(INDENT / 2.0)),
+            width: StyleProp::Value(Units::Pixels(1.0)),
+            height: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::default()
+        };
+
+        let label = format!("{} {}", if is_expanded { "-" } else { "+" }, node.label);
+        let children = node
+            .children
+            .into_iter()
+            .map(|node| {
+                let expanded_state = expanded_state.clone();
+                let selected_state = selected_state.clone();
+                let on_select = on_select.clone();
+                let on_expand = on_expand.clone();
+                constructor! {
+                    <TreeViewItem
+                        node={node}
+                        text_color={text_color}
+                        expanded_state={expanded_state}
+                        selected_state={selected_state}
+                        on_select={on_select}
+                        on_expand={on_expand}
+                    />
+                }
+            })
+            .collect::<Vec<_>>();
+
+        rsx! {
+            <Fold label={label} open={Some(is_expanded)} on_change={Some(on_change)} styles={Some(fold_styles)}>
+                <Element styles={Some(children_styles)}>
+                    <Background styles={Some(guide_styles)} />
+                    <VecTracker data={children} />
+                </Element>
+            </Fold>
+        }
+    }
+}
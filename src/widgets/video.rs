@@ -0,0 +1,70 @@
+use crate::core::{
+    render_command::{ImageFit, RenderCommand},
+    rsx,
+    styles::Style,
+    widget, Children,
+};
+
+use crate::widgets::image::{bind_image_status, image_status_styles};
+
+/// A widget that renders an image whose contents change every frame, like a video or a render
+/// target
+///
+/// The image's size is looked up every frame, so it may be resized or have its pixels replaced
+/// without re-rendering the widget. To show a different image (e.g. when each video frame is
+/// decoded into its own image), point the handle at it with `ImageManager::replace`.
+///
+/// # Arguments
+///
+/// * `handle`: The image's ID (see `ImageManager`)
+/// * `fit`: How the image is fitted within the widget. Defaults to stretching it.
+/// * `crop`: The region of the image to show, as its top-left and bottom-right corners relative
+///   to the size of the image. If `None`, all of it is shown.
+/// * `children`: The widget's children
+/// * `loading_style`: If set, these styles are applied over `styles` and rendered as a quad while the image loads
+/// * `error_style`: If set, these styles are applied over `styles` and rendered as a quad if the image fails to load
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{render_command::ImageFit, rsx};
+/// # use kayak_ui::widgets::Video;
+///
+/// rsx! {
+///     <Video
+///         handle={0}
+///         fit={ImageFit::Cover}
+///         crop={Some(((0.0, 0.0), (0.5, 1.0)))}
+///     />
+/// }
+/// ```
+#[widget]
+pub fn Video(
+    handle: u16,
+    fit: ImageFit,
+    crop: Option<((f32, f32), (f32, f32))>,
+    children: Children,
+    loading_style: Option<Style>,
+    error_style: Option<Style>,
+) {
+    let (uv_min, uv_max) = crop.unwrap_or(((0.0, 0.0), (1.0, 1.0)));
+    let status = bind_image_status(context, handle);
+    *styles = Some(image_status_styles(
+        status,
+        RenderCommand::Texture {
+            handle,
+            uv_min,
+            uv_max,
+            fit,
+        },
+        styles.clone().unwrap_or_default(),
+        loading_style,
+        error_style,
+    ));
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -0,0 +1,71 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children, EventType, Handler, OnEvent,
+};
+
+/// A container that reports when it scrolls into or out of view
+///
+/// The container is within view while any part of it lies within the window and isn't clipped by
+/// its ancestors (e.g. the visible area of a [ScrollBox](crate::widgets::ScrollBox)). This is
+/// checked after every render, so handlers are called the frame after the container moves.
+///
+/// # Arguments
+///
+/// * `on_enter_view`: Called once the container is within view (including when it first renders
+///   within view)
+/// * `on_exit_view`: Called once the container is no longer within view
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container, like an
+/// [Element](crate::widgets::Element).
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::{Text, ViewObserver};
+///
+/// let on_enter_view = Handler::new(|_| println!("Achievement seen"));
+///
+/// rsx! {
+///     <ViewObserver on_enter_view={Some(on_enter_view)}>
+///         <Text content={"Achievement unlocked!".to_string()} />
+///     </ViewObserver>
+/// }
+/// ```
+#[widget]
+pub fn ViewObserver(
+    children: Children,
+    on_enter_view: Option<Handler>,
+    on_exit_view: Option<Handler>,
+) {
+    context.watch_visibility(self.id);
+
+    self.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::EnterView => {
+            if let Some(ref on_enter_view) = on_enter_view {
+                on_enter_view.call(());
+            }
+        }
+        EventType::ExitView => {
+            if let Some(ref on_exit_view) = on_exit_view {
+                on_exit_view.call(());
+            }
+        }
+        _ => {}
+    }));
+
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        ..styles.clone().unwrap_or_default()
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -2,12 +2,98 @@ use crate::core::{
     color::Color,
     render_command::RenderCommand,
     rsx,
-    styles::{PositionType, Style, StyleProp, Units},
-    widget, Children,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Bound, Children, CursorIcon, EventType, Handler, MutableBound, OnEvent,
 };
 
-use crate::widgets::{Background, Clip, Element, Text};
+use crate::widgets::{Background, Clip, Element, If, Text};
 
+/// The height of the title bar (in pixels)
+const TITLE_HEIGHT: f32 = 24.0;
+/// The size of the title bar buttons (in pixels)
+const BUTTON_SIZE: f32 = 18.0;
+/// The thickness of the resize handles along the edges (in pixels)
+const HANDLE_THICKNESS: f32 = 5.0;
+/// The size of the resize handles in the corners (in pixels)
+const CORNER_SIZE: f32 = 12.0;
+/// The smallest size a window can be resized to (in pixels)
+const MIN_SIZE: (f32, f32) = (100.0, 60.0);
+
+/// The position and size of a [Window] (in pixels)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct WindowFrame {
+    position: (f32, f32),
+    size: (f32, f32),
+}
+
+/// The edges moved by a resize handle
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ResizeEdges {
+    left: bool,
+    right: bool,
+    top: bool,
+    bottom: bool,
+}
+
+/// A resize of a [Window] in progress
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct WindowResize {
+    edges: ResizeEdges,
+    /// The cursor position when the resize started
+    start_cursor: (f32, f32),
+    /// The frame of the window when the resize started
+    start_frame: WindowFrame,
+}
+
+/// A window with a title bar
+///
+/// With `collapsible`, a button in the title bar collapses the window to its title bar (and
+/// expands it back). With `on_close`, a button closes it: the handler should stop rendering the
+/// window. With `resizable`, the edges and corners of the window can be dragged to resize it, down
+/// to 100 by 60 pixels, showing a resize cursor while hovered.
+///
+/// The window starts at the given `position` and `size`, and keeps them until the user resizes
+/// it.
+///
+/// # Arguments
+///
+/// * `position`: The position of the top-left corner of the window (in pixels)
+/// * `size`: The size of the window (in pixels)
+/// * `title`: The text shown in the title bar
+/// * `collapsible`: If true, the window can be collapsed to its title bar
+/// * `resizable`: If true, the window can be resized by dragging its edges and corners
+/// * `on_close`: Called when the close button is clicked. There's no close button without it.
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the window, though its position and size are set by
+/// the arguments.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx, use_state};
+///
+/// let (is_open, set_is_open, ..) = use_state!(true);
+/// let on_close = Handler::new(move |_| {
+///     set_is_open(false);
+/// });
+///
+/// rsx! {
+///     <If condition={is_open}>
+///         <Window
+///             position={(50.0, 50.0)}
+///             size={(300.0, 200.0)}
+///             title={"Inventory".to_string()}
+///             collapsible={true}
+///             resizable={true}
+///             on_close={Some(on_close)}
+///         >
+///             {children}
+///         </Window>
+///     </If>
+/// }
+/// ```
 #[widget]
 pub fn Window(
     children: Children,
@@ -15,7 +101,20 @@ pub fn Window(
     position: (f32, f32),
     size: (f32, f32),
     title: String,
+    collapsible: bool,
+    resizable: bool,
+    on_close: Option<Handler>,
 ) {
+    // === State === //
+    let (is_collapsed, set_is_collapsed, ..) = use_state!(false);
+    // The frame set by resizing, which replaces the one given as arguments
+    let (resized_frame, _, frame_state) = use_state!(None::<WindowFrame>);
+    let (_, _, resize_state) = use_state!(None::<WindowResize>);
+    let frame = resized_frame.unwrap_or(WindowFrame { position, size });
+    let is_collapsed = collapsible && is_collapsed;
+    let (position, size) = (frame.position, frame.size);
+    let height = if is_collapsed { TITLE_HEIGHT } else { size.1 };
+
     *styles = Some(Style {
         background_color: StyleProp::Value(Color::new(0.125, 0.125, 0.125, 1.0)),
         border_radius: StyleProp::Value((5.0, 5.0, 5.0, 5.0)),
@@ -24,9 +123,9 @@ pub fn Window(
         left: StyleProp::Value(Units::Pixels(position.0)),
         top: StyleProp::Value(Units::Pixels(position.1)),
         width: StyleProp::Value(Units::Pixels(size.0)),
-        height: StyleProp::Value(Units::Pixels(size.1)),
+        height: StyleProp::Value(Units::Pixels(height)),
         max_width: StyleProp::Value(Units::Pixels(size.0)),
-        max_height: StyleProp::Value(Units::Pixels(size.1)),
+        max_height: StyleProp::Value(Units::Pixels(height)),
         ..styles.clone().unwrap_or_default()
     });
 
@@ -36,21 +135,23 @@ pub fn Window(
         padding_top: StyleProp::Value(Units::Pixels(5.0)),
         padding_bottom: StyleProp::Value(Units::Pixels(5.0)),
         width: StyleProp::Value(Units::Pixels(size.0)),
-        height: StyleProp::Value(Units::Pixels(size.1)),
+        height: StyleProp::Value(Units::Pixels(height)),
         max_width: StyleProp::Value(Units::Pixels(size.0)),
-        max_height: StyleProp::Value(Units::Pixels(size.1)),
+        max_height: StyleProp::Value(Units::Pixels(height)),
         ..Style::default()
     };
 
     let title_background_styles = Style {
         background_color: StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0)),
         border_radius: StyleProp::Value((5.0, 0.0, 0.0, 5.0)),
-        height: StyleProp::Value(Units::Pixels(24.0)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(TITLE_HEIGHT)),
         left: StyleProp::Value(Units::Pixels(0.0)),
         right: StyleProp::Value(Units::Pixels(0.0)),
         top: StyleProp::Value(Units::Pixels(0.0)),
         bottom: StyleProp::Value(Units::Pixels(0.0)),
         padding_left: StyleProp::Value(Units::Pixels(5.0)),
+        padding_right: StyleProp::Value(Units::Pixels(3.0)),
         // padding_top: StyleProp::Value(Units::Stretch(1.0)),
         // padding_bottom: StyleProp::Value(Units::Stretch(1.0)),
         ..Style::default()
@@ -58,6 +159,7 @@ pub fn Window(
 
     let title_text_styles = Style {
         height: StyleProp::Value(Units::Pixels(25.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
         ..Style::default()
     };
 
@@ -69,15 +171,229 @@ pub fn Window(
         ..Style::default()
     };
 
+    // === Title Bar Buttons === //
+    let button_styles = Style {
+        background_color: StyleProp::Value(Color::new(0.176, 0.196, 0.215, 1.0)),
+        border_radius: StyleProp::Value((3.0, 3.0, 3.0, 3.0)),
+        width: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        height: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Pixels(3.0)),
+        padding_left: StyleProp::Value(Units::Stretch(1.0)),
+        padding_right: StyleProp::Value(Units::Stretch(1.0)),
+        padding_top: StyleProp::Value(Units::Stretch(1.0)),
+        padding_bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Style::default()
+    };
+    let button_text_styles = Style {
+        width: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Auto),
+        ..Style::default()
+    };
+    let (close_button_styles, close_text_styles) =
+        (button_styles.clone(), button_text_styles.clone());
+
+    let on_collapse = OnEvent::new(move |_, event| {
+        if let EventType::Click = event.event_type {
+            set_is_collapsed(!is_collapsed);
+        }
+    });
+    let has_close_button = on_close.is_some();
+    let on_close_click = OnEvent::new(move |_, event| {
+        if let EventType::Click = event.event_type {
+            if let Some(ref on_close) = on_close {
+                on_close.call(());
+            }
+        }
+    });
+    let collapse_symbol = if is_collapsed { "+" } else { "-" }.to_string();
+
+    // === Resize Handles === //
+    let can_resize = resizable && !is_collapsed;
+    let make_handle = |edges: ResizeEdges| {
+        let frame_state = frame_state.clone();
+        let resize_state = resize_state.clone();
+        let icon = cursor_icon(edges);
+        OnEvent::new(move |ctx, event| match event.event_type {
+            EventType::MouseIn => ctx.set_cursor_icon(icon),
+            EventType::MouseOut => {
+                // Keep the cursor while dragging outside the handle
+                if resize_state.get().is_none() {
+                    ctx.set_cursor_icon(CursorIcon::Default);
+                }
+            }
+            EventType::MouseDown => {
+                event.capture_cursor();
+                event.stop_propagation();
+                resize_state.set(Some(WindowResize {
+                    edges,
+                    start_cursor: ctx.last_mouse_position(),
+                    start_frame: frame,
+                }));
+            }
+            EventType::MouseMove => {
+                if let Some(resize) = resize_state.get() {
+                    let next_frame = resize_frame(&resize, ctx.last_mouse_position());
+                    if frame_state.get() != Some(next_frame) {
+                        frame_state.set(Some(next_frame));
+                    }
+                }
+            }
+            EventType::MouseUp => {
+                resize_state.set(None);
+                ctx.set_cursor_icon(CursorIcon::Default);
+            }
+            _ => {}
+        })
+    };
+    let edge = |left, right, top, bottom| ResizeEdges {
+        left,
+        right,
+        top,
+        bottom,
+    };
+    let on_resize_top = make_handle(edge(false, false, true, false));
+    let on_resize_bottom = make_handle(edge(false, false, false, true));
+    let on_resize_left = make_handle(edge(true, false, false, false));
+    let on_resize_right = make_handle(edge(false, true, false, false));
+    let on_resize_top_left = make_handle(edge(true, false, true, false));
+    let on_resize_top_right = make_handle(edge(false, true, true, false));
+    let on_resize_bottom_left = make_handle(edge(true, false, false, true));
+    let on_resize_bottom_right = make_handle(edge(false, true, false, true));
+
+    let corner = Units::Pixels(CORNER_SIZE);
+    let thickness = Units::Pixels(HANDLE_THICKNESS);
+    let zero = Units::Pixels(0.0);
+    let stretch = Units::Stretch(1.0);
+    let top_styles = handle_styles(corner, corner, zero, stretch, stretch, thickness);
+    let bottom_styles = handle_styles(corner, corner, stretch, zero, stretch, thickness);
+    let left_styles = handle_styles(zero, stretch, corner, corner, thickness, stretch);
+    let right_styles = handle_styles(stretch, zero, corner, corner, thickness, stretch);
+    let top_left_styles = handle_styles(zero, stretch, zero, stretch, corner, corner);
+    let top_right_styles = handle_styles(stretch, zero, zero, stretch, corner, corner);
+    let bottom_left_styles = handle_styles(zero, stretch, stretch, zero, corner, corner);
+    let bottom_right_styles = handle_styles(stretch, zero, stretch, zero, corner, corner);
+
     let title = title.clone();
     rsx! {
-        <Clip styles={Some(clip_styles)}>
-            <Background styles={Some(title_background_styles)}>
-                <Text styles={Some(title_text_styles)} size={16.0} content={title} />
-            </Background>
-            <Element styles={Some(content_styles)}>
-                {children}
-            </Element>
-        </Clip>
+        <>
+            <Clip styles={Some(clip_styles)}>
+                <Background styles={Some(title_background_styles)}>
+                    <Text styles={Some(title_text_styles)} size={16.0} content={title} />
+                    <If condition={collapsible}>
+                        <Background styles={Some(button_styles)} on_event={Some(on_collapse)}>
+                            <Text styles={Some(button_text_styles)} size={14.0} content={collapse_symbol} />
+                        </Background>
+                    </If>
+                    <If condition={has_close_button}>
+                        <Background styles={Some(close_button_styles)} on_event={Some(on_close_click)}>
+                            <Text styles={Some(close_text_styles)} size={14.0} content={"x".to_string()} />
+                        </Background>
+                    </If>
+                </Background>
+                <If condition={!is_collapsed}>
+                    <Element styles={Some(content_styles)}>
+                        {children}
+                    </Element>
+                </If>
+            </Clip>
+            <If condition={can_resize}>
+                <Background styles={Some(top_styles)} on_event={Some(on_resize_top)} />
+                <Background styles={Some(bottom_styles)} on_event={Some(on_resize_bottom)} />
+                <Background styles={Some(left_styles)} on_event={Some(on_resize_left)} />
+                <Background styles={Some(right_styles)} on_event={Some(on_resize_right)} />
+                <Background styles={Some(top_left_styles)} on_event={Some(on_resize_top_left)} />
+                <Background styles={Some(top_right_styles)} on_event={Some(on_resize_top_right)} />
+                <Background styles={Some(bottom_left_styles)} on_event={Some(on_resize_bottom_left)} />
+                <Background styles={Some(bottom_right_styles)} on_event={Some(on_resize_bottom_right)} />
+            </If>
+        </>
+    }
+}
+
+/// The styles of an invisible resize handle, placed within the window
+fn handle_styles(
+    left: Units,
+    right: Units,
+    top: Units,
+    bottom: Units,
+    width: Units,
+    height: Units,
+) -> Style {
+    Style {
+        background_color: StyleProp::Value(Color::new(0.0, 0.0, 0.0, 0.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(left),
+        right: StyleProp::Value(right),
+        top: StyleProp::Value(top),
+        bottom: StyleProp::Value(bottom),
+        width: StyleProp::Value(width),
+        height: StyleProp::Value(height),
+        ..Style::default()
+    }
+}
+
+/// The cursor shown over the resize handle moving the given edges
+fn cursor_icon(edges: ResizeEdges) -> CursorIcon {
+    let horizontal = edges.left || edges.right;
+    let vertical = edges.top || edges.bottom;
+    match (horizontal, vertical) {
+        (true, true) if edges.left == edges.top => CursorIcon::ResizeNwSe,
+        (true, true) => CursorIcon::ResizeNeSw,
+        (true, false) => CursorIcon::ResizeHorizontal,
+        _ => CursorIcon::ResizeVertical,
+    }
+}
+
+/// Calculates the frame of a window being resized, given the current cursor position
+///
+/// Moving the left or top edge moves the window, keeping the opposite edge in place.
+fn resize_frame(resize: &WindowResize, cursor: (f32, f32)) -> WindowFrame {
+    let WindowFrame { position, size } = resize.start_frame;
+    let delta = (
+        cursor.0 - resize.start_cursor.0,
+        cursor.1 - resize.start_cursor.1,
+    );
+    let edges = resize.edges;
+
+    let (x, width) = resize_axis(
+        position.0,
+        size.0,
+        delta.0,
+        edges.left,
+        edges.right,
+        MIN_SIZE.0,
+    );
+    let (y, height) = resize_axis(
+        position.1,
+        size.1,
+        delta.1,
+        edges.top,
+        edges.bottom,
+        MIN_SIZE.1,
+    );
+    WindowFrame {
+        position: (x, y),
+        size: (width, height),
+    }
+}
+
+/// Resizes along one axis, returning the next position and size
+fn resize_axis(
+    position: f32,
+    size: f32,
+    delta: f32,
+    moves_start: bool,
+    moves_end: bool,
+    min_size: f32,
+) -> (f32, f32) {
+    if moves_start {
+        let next_size = (size - delta).max(min_size);
+        (position + size - next_size, next_size)
+    } else if moves_end {
+        (position, (size + delta).max(min_size))
+    } else {
+        (position, size)
     }
 }
@@ -0,0 +1,71 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units, Wrap, WrapAlignment},
+    widget, Children,
+};
+
+/// A container laying out its children from left to right, wrapping them onto a new row whenever
+/// the current one is full
+///
+/// This is useful for lists of tags or grids of inventory slots, where the number of items per
+/// row depends on the available width. Children should have a size in pixels (or `Auto` for
+/// text), as stretching children have no width of their own to wrap by.
+///
+/// # Arguments
+///
+/// * `row_spacing`: The space between rows (in pixels)
+/// * `column_spacing`: The space between the children of a row (in pixels)
+/// * `alignment`: How the children of each row are aligned horizontally
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the panel, which stretches to the width of its
+/// parent by default. Unless a height is given, it's as tall as its rows (as measured by the
+/// previous layout, so adding a row takes an extra frame to show).
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::core::styles::WrapAlignment;
+///
+/// rsx! {
+///     <WrapPanel row_spacing={4.0} column_spacing={4.0} alignment={WrapAlignment::Center}>
+///         <Text content={"rust".to_string()} size={14.0} />
+///         <Text content={"bevy".to_string()} size={14.0} />
+///         <Text content={"ui".to_string()} size={14.0} />
+///     </WrapPanel>
+/// }
+/// ```
+#[widget]
+pub fn WrapPanel(
+    children: Children,
+    row_spacing: f32,
+    column_spacing: f32,
+    alignment: WrapAlignment,
+) {
+    let base_styles = styles.clone().unwrap_or_default();
+    *styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        wrap: StyleProp::Value(Wrap {
+            row_spacing,
+            column_spacing,
+            alignment,
+        }),
+        height: if matches!(base_styles.height, StyleProp::Default) {
+            let height = context.widget_manager.get_wrapped_height(self.id);
+            StyleProp::Value(Units::Pixels(height.unwrap_or_default()))
+        } else {
+            base_styles.height.clone()
+        },
+        ..base_styles
+    });
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}